@@ -13,6 +13,7 @@ const BIG_TYPES_NAMES: &[&str] = &[
     "EventLogActivityProjection",
     "SlimLinkedOCEL",
     "IndexLinkedOCEL",
+    "PetriNet",
 ];
 
 /// Removes/elide lifetimes and other special cases (i.e., certain generics) from types
@@ -125,6 +126,42 @@ fn is_mut_big_type_ref(ty: &Type) -> Option<String> {
     }
 }
 
+/// Container around big type references in arguments (e.g., `Vec<&OCEL>` or `Option<&EventLog>`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BigTypeContainer {
+    /// A list of big types, passed as a list of registry IDs
+    Vec,
+    /// An optional big type, passed as a nullable registry ID
+    Option,
+}
+
+/// Check if a type is a `Vec` or `Option` of (immutable) big type references (e.g., `Vec<&OCEL>`)
+/// Returns the kind of container and the inner big type reference if it is.
+fn big_type_container(ty: &Type) -> Option<(BigTypeContainer, Type)> {
+    let Type::Path(tp) = ty else {
+        return None;
+    };
+    let segment = tp.path.segments.last()?;
+    let container = match segment.ident.to_string().as_str() {
+        "Vec" => BigTypeContainer::Vec,
+        "Option" => BigTypeContainer::Option,
+        _ => return None,
+    };
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner)
+            if args.args.len() == 1
+                && is_big_type_ref(inner)
+                && is_mut_big_type_ref(inner).is_none() =>
+        {
+            Some((container, inner.clone()))
+        }
+        _ => None,
+    }
+}
+
 #[derive(Default)]
 struct RegisterBindingAttrs {
     stringify_error: bool,
@@ -261,6 +298,15 @@ pub fn register_binding(args: TokenStream, item: TokenStream) -> TokenStream {
             quote! {
                 #maybe_ref crate::bindings::extract_param::<#ty_without_ref>(arg_map, #name, state, || Some(#default_expr))?
             }
+        } else if let Some((container, inner)) = big_type_container(ty_without_ref) {
+            match container {
+                BigTypeContainer::Vec => quote! {
+                    crate::bindings::extract_param_list::<#inner>(arg_map, #name, state)?
+                },
+                BigTypeContainer::Option => quote! {
+                    crate::bindings::extract_param_optional::<#inner>(arg_map, #name, state)?
+                },
+            }
         } else {
             quote! {
                 #maybe_ref crate::bindings::extract_param::<#ty_without_ref>(arg_map, #name, state, || None)?
@@ -281,6 +327,30 @@ pub fn register_binding(args: TokenStream, item: TokenStream) -> TokenStream {
                     "x-widget": "entity-selector"
                 })));
              }
+        } else if let Some((container, inner)) = big_type_container(ty_without_ref) {
+            let type_name = is_big_type(&inner).unwrap();
+            match container {
+                BigTypeContainer::Vec => quote! {
+                    args_schema.push((#name.to_string(), serde_json::json!({
+                        "type": "array",
+                        "title": format!("List of {}", #type_name),
+                        "items": {
+                            "type": "string",
+                            "title": #type_name,
+                            "x-registry-ref": #type_name,
+                            "x-widget": "entity-selector"
+                        }
+                    })));
+                },
+                BigTypeContainer::Option => quote! {
+                    args_schema.push((#name.to_string(), serde_json::json!({
+                        "type": ["string", "null"],
+                        "title": #type_name,
+                        "x-registry-ref": #type_name,
+                        "x-widget": "entity-selector"
+                    })));
+                },
+            }
         } else {
             quote! { args_schema.push((#name.to_string(), serde_json::to_value(schemars::schema_for!(#ty_without_ref)).unwrap())); }
         }
@@ -316,7 +386,13 @@ pub fn register_binding(args: TokenStream, item: TokenStream) -> TokenStream {
 
     let required_arg_names = args_info
         .iter()
-        .filter(|(_, _, _, _, opts, _)| opts.default_value.is_none())
+        .filter(|(_, _, _, ty_without_ref, opts, _)| {
+            opts.default_value.is_none()
+                && !matches!(
+                    big_type_container(ty_without_ref),
+                    Some((BigTypeContainer::Option, _))
+                )
+        })
         .map(|(name, _, _, _, _, _)| name);
 
     // 4. Generate the Execution Logic
@@ -339,6 +415,14 @@ pub fn register_binding(args: TokenStream, item: TokenStream) -> TokenStream {
     };
 
     let execution_block = if has_any_mut_big_type {
+        if args_info
+            .iter()
+            .any(|(_, _, _, ty_without_ref, ..)| big_type_container(ty_without_ref).is_some())
+        {
+            panic!(
+                "Vec or Option big type arguments can not be combined with &mut big type arguments"
+            );
+        }
         // Mutable big type path: use write lock
         // 1. Generate JSON extractions for non-mut-big-type params (no state needed)
         let json_extractions: Vec<_> = args_info
@@ -396,7 +480,7 @@ pub fn register_binding(args: TokenStream, item: TokenStream) -> TokenStream {
         let mut_serialization = if let Some(type_name) = is_big_type(&ret_type) {
             let variant_ident = format_ident!("{}", type_name);
             quote! {
-                let id = format!("res_{}", uuid::Uuid::new_v4());
+                let id = crate::utils::new_id("res_");
                 __state_guard.insert(id.clone(), crate::bindings::RegistryItem::#variant_ident(result));
                 serde_json::to_vec(&id).map_err(|e| e.to_string())
             }
@@ -406,7 +490,7 @@ pub fn register_binding(args: TokenStream, item: TokenStream) -> TokenStream {
 
         quote! {
             #(#json_extractions)*
-            let mut __state_guard = state_lock.items.write().map_err(|e| e.to_string())?;
+            let mut __state_guard = state_lock.write();
             #(#mut_extractions)*
             let result = #fn_ident( #(#call_args),* );
             #mut_serialization
@@ -415,17 +499,17 @@ pub fn register_binding(args: TokenStream, item: TokenStream) -> TokenStream {
         let variant_ident = format_ident!("{}", type_name);
         quote! {
             let result = {
-                let state_guard = state_lock.items.read().map_err(|e| e.to_string())?;
+                let state_guard = state_lock.read();
                 let state = &*state_guard;
                 #fn_ident( #(#extractions),* )
             };
-            let id = format!("res_{}", uuid::Uuid::new_v4());
+            let id = crate::utils::new_id("res_");
             state_lock.add(&id, crate::bindings::RegistryItem::#variant_ident(result));
             serde_json::to_vec(&id).map_err(|e| e.to_string())
         }
     } else {
         quote! {
-            let state_guard = state_lock.items.read().map_err(|e| e.to_string())?;
+            let state_guard = state_lock.read();
             let state = &*state_guard;
             let result = #fn_ident( #(#extractions),* );
             #serialization_logic
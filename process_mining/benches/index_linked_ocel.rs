@@ -0,0 +1,38 @@
+//! Benchmark the time taken to build an IndexLinkedOCEL (sequentially and in parallel)
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use process_mining::{
+    core::event_data::object_centric::linked_ocel::IndexLinkedOCEL, test_utils::get_test_data_path,
+    Importable, OCEL,
+};
+use std::time::Duration;
+
+fn bench_index_linked_ocel(c: &mut Criterion) {
+    let root = get_test_data_path().join("ocel");
+    for (name, path) in [
+        ("order-management", root.join("order-management.json")),
+        ("p2p", root.join("ocel2-p2p.json")),
+    ] {
+        let ocel = OCEL::import_from_path(&path).unwrap();
+        let mut group = c.benchmark_group(format!("index_linked_ocel/{name}"));
+        group.sample_size(20);
+        group.measurement_time(Duration::from_secs(20));
+        group.bench_function("sequential", |b| {
+            b.iter_batched(
+                || ocel.clone(),
+                |ocel| black_box(IndexLinkedOCEL::from_ocel(ocel)),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_function("parallel", |b| {
+            b.iter_batched(
+                || ocel.clone(),
+                |ocel| black_box(IndexLinkedOCEL::from_ocel_parallel(ocel)),
+                BatchSize::LargeInput,
+            )
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_index_linked_ocel);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+//! Benchmark the peak memory usage of importing an OCEL XML file
+//! from a reader (incrementally) vs. from a slice (reading the whole file first)
+use std::{fs::File, io::BufReader, path::Path};
+
+use process_mining::{
+    core::event_data::object_centric::ocel_xml::xml_ocel_import::{
+        import_ocel_xml_reader, import_ocel_xml_slice,
+    },
+    test_utils::get_test_data_path,
+    OCEL,
+};
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+/// Run `import` and return the peak number of allocated bytes
+fn peak_bytes(path: &Path, import: fn(&Path) -> OCEL) -> usize {
+    let _profiler = dhat::Profiler::builder().testing().build();
+    let ocel = import(path);
+    let stats = dhat::HeapStats::get();
+    drop(ocel);
+    stats.max_bytes
+}
+
+fn main() {
+    let path = get_test_data_path()
+        .join("ocel")
+        .join("order-management.xml");
+    let reader = peak_bytes(&path, |path| {
+        import_ocel_xml_reader(BufReader::new(File::open(path).unwrap())).unwrap()
+    });
+    let slice = peak_bytes(&path, |path| {
+        import_ocel_xml_slice(&std::fs::read(path).unwrap()).unwrap()
+    });
+    println!(
+        "Peak memory (reader): {:.2} MiB",
+        reader as f64 / 1024.0 / 1024.0
+    );
+    println!(
+        "Peak memory (slice): {:.2} MiB",
+        slice as f64 / 1024.0 / 1024.0
+    );
+}
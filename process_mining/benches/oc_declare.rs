@@ -0,0 +1,130 @@
+//! Benchmarks for OC-DECLARE
+//!
+//! - Looking up the (time-filtered) target events of OC-DECLARE constraints
+//!   by scanning all events of an object vs. using the time-sorted E2O index
+//! - Object type cardinality lookups (counting objects vs. constant-time lookup) and the full discovery on order-management
+use chrono::{DateTime, Duration};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use process_mining::conformance::oc_declare::{
+    get_evs_with_objs_in_range_perf, get_evs_with_objs_perf,
+};
+use process_mining::core::{
+    event_data::object_centric::linked_ocel::{
+        slim_linked_ocel::ObjectIndex, LinkedOCELAccess, SlimLinkedOCEL,
+    },
+    process_models::oc_declare::{EventOrSynthetic, SetFilter},
+};
+use process_mining::discovery::object_centric::oc_declare::{
+    discover_behavior_constraints, OCDeclareDiscoveryOptions,
+};
+use process_mining::{test_utils::get_test_data_path, Importable, OCEL};
+use std::ops::Bound;
+
+/// Dense synthetic OCEL: few objects, each involved in many `a` and `b` events
+fn dense_ocel(num_objects: usize, num_events: usize) -> SlimLinkedOCEL {
+    let mut locel = SlimLinkedOCEL::new();
+    locel.add_event_type("a", Vec::new());
+    locel.add_event_type("b", Vec::new());
+    locel.add_object_type("o", Vec::new());
+    let obs: Vec<ObjectIndex> = (0..num_objects)
+        .map(|_| locel.add_object("o", None, Vec::new(), Vec::new()).unwrap())
+        .collect();
+    let start = DateTime::parse_from_rfc3339("2020-01-01T00:00:00+00:00").unwrap();
+    for i in 0..num_events {
+        let etype = if i % 2 == 0 { "a" } else { "b" };
+        let ob = obs[(i / 2) % num_objects];
+        locel.add_event(
+            etype,
+            start + Duration::seconds(i as i64),
+            None,
+            Vec::new(),
+            vec![(String::new(), ob)],
+        );
+    }
+    locel
+}
+
+fn bench_oc_declare_targets(c: &mut Criterion) {
+    let locel = dense_ocel(10, 20_000);
+    let sources = EventOrSynthetic::get_all_syn_evs(&locel, "a");
+    let mut group = c.benchmark_group("oc_declare/eventually_follows_targets");
+    group.sample_size(10);
+    group.bench_function("scan", |b| {
+        b.iter(|| {
+            sources
+                .iter()
+                .map(|src| {
+                    let time = src.get_timestamp(&locel);
+                    let obs: Vec<_> = src.get_e2o(&locel).collect();
+                    let filter = [SetFilter::All(obs)];
+                    get_evs_with_objs_perf(&filter, &locel, "b")
+                        .filter(|e| e.get_timestamp(&locel) > time)
+                        .count()
+                })
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("indexed", |b| {
+        b.iter(|| {
+            sources
+                .iter()
+                .map(|src| {
+                    let time = src.get_timestamp(&locel);
+                    let obs: Vec<_> = src.get_e2o(&locel).collect();
+                    let filter = [SetFilter::All(obs)];
+                    get_evs_with_objs_in_range_perf(
+                        &filter,
+                        &locel,
+                        "b",
+                        (Bound::Excluded(time), Bound::Unbounded),
+                    )
+                    .count()
+                })
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+fn bench_oc_declare_discovery(c: &mut Criterion) {
+    let path = get_test_data_path()
+        .join("ocel")
+        .join("order-management.json");
+    let locel = SlimLinkedOCEL::from_ocel(OCEL::import_from_path(&path).unwrap());
+    let object_types: Vec<String> = locel.get_ob_types().map(|ot| ot.to_string()).collect();
+    let mut group = c.benchmark_group("oc_declare/order-management");
+    // Object type cardinalities are looked up repeatedly when sorting bindings
+    group.bench_function("ob_type_count_scan", |b| {
+        b.iter(|| {
+            object_types
+                .iter()
+                .map(|ot| locel.get_obs_of_type(ot).count())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("ob_type_count_lookup", |b| {
+        b.iter(|| {
+            object_types
+                .iter()
+                .map(|ot| locel.num_obs_of_type(ot))
+                .sum::<usize>()
+        })
+    });
+    group.sample_size(10);
+    group.bench_function("discover_behavior_constraints", |b| {
+        b.iter(|| {
+            black_box(discover_behavior_constraints(
+                &locel,
+                OCDeclareDiscoveryOptions::default(),
+            ))
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_oc_declare_targets,
+    bench_oc_declare_discovery
+);
+criterion_main!(benches);
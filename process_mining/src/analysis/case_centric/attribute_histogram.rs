@@ -0,0 +1,170 @@
+//! Histograms of Numeric Event Attributes
+//!
+//! Useful for exploring the distribution (and data quality) of numeric attributes, e.g., costs or amounts.
+
+use macros_process_mining::register_binding;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::event_data::case_centric::{AttributeValue, XESEditableAttribute},
+    EventLog,
+};
+
+/// Histogram over numeric values with equal-width bins
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Histogram {
+    /// Bin edges (ascending, one more than the number of bins)
+    ///
+    /// Bin `i` covers the values in `[edges[i], edges[i + 1])`, the last bin also includes its upper edge.
+    /// Empty if there are no values.
+    pub edges: Vec<f64>,
+    /// Number of values per bin
+    pub counts: Vec<usize>,
+    /// Number of values in the histogram (i.e., the sum of `counts`)
+    pub num_values: usize,
+    /// Number of skipped values (e.g., absent or non-numeric)
+    pub num_skipped: usize,
+}
+
+impl Histogram {
+    /// Compute a histogram with `bins` equal-width bins between the minimum and maximum of the passed values
+    ///
+    /// Non-finite values (`NaN` and infinities) are skipped (and counted in [`Histogram::num_skipped`], in addition to `num_skipped`).
+    /// At least one bin is used. If all values are equal, all of them are counted in the first bin.
+    pub fn from_values(
+        values: impl IntoIterator<Item = f64>,
+        bins: usize,
+        num_skipped: usize,
+    ) -> Self {
+        let mut num_skipped = num_skipped;
+        let values: Vec<f64> = values
+            .into_iter()
+            .filter(|v| {
+                let keep = v.is_finite();
+                if !keep {
+                    num_skipped += 1;
+                }
+                keep
+            })
+            .collect();
+        if values.is_empty() {
+            return Self {
+                num_skipped,
+                ..Default::default()
+            };
+        }
+        let bins = bins.max(1);
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        // Divide first, so that the width does not overflow for very large (finite) ranges
+        let width = max / bins as f64 - min / bins as f64;
+        let edges = (0..=bins)
+            .map(|i| {
+                if i == bins {
+                    max
+                } else {
+                    min + width * i as f64
+                }
+            })
+            .collect();
+        let mut counts = vec![0; bins];
+        for v in &values {
+            let bin = if width > 0.0 {
+                (((v - min) / width) as usize).min(bins - 1)
+            } else {
+                0
+            };
+            counts[bin] += 1;
+        }
+        Self {
+            edges,
+            counts,
+            num_values: values.len(),
+            num_skipped,
+        }
+    }
+}
+
+/// Get the numeric value of an attribute value (for integer or float values)
+fn numeric_value(value: &AttributeValue) -> Option<f64> {
+    match value {
+        AttributeValue::Int(i) => Some(*i as f64),
+        AttributeValue::Float(f) => Some(*f),
+        _ => None,
+    }
+}
+
+/// Compute a [`Histogram`] with `bins` equal-width bins over a numeric (integer or float) event attribute
+///
+/// Events without the attribute or with a non-numeric value are skipped (see [`Histogram::num_skipped`]).
+#[register_binding]
+pub fn numeric_attribute_histogram(log: &EventLog, key: impl AsRef<str>, bins: usize) -> Histogram {
+    let key = key.as_ref();
+    let mut num_skipped = 0;
+    let values: Vec<f64> = log
+        .traces
+        .iter()
+        .flat_map(|t| &t.events)
+        .filter_map(|e| {
+            let value = e
+                .attributes
+                .get_by_key(key)
+                .and_then(|a| numeric_value(&a.value));
+            if value.is_none() {
+                num_skipped += 1;
+            }
+            value
+        })
+        .collect();
+    Histogram::from_values(values, bins, num_skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event_data::case_centric::Attribute;
+
+    #[test]
+    fn test_numeric_attribute_histogram() {
+        let mut log = crate::event_log!(["a", "b", "c"], ["a", "b"]);
+        let values = [
+            Some(AttributeValue::Int(0)),
+            Some(AttributeValue::Float(2.5)),
+            Some(AttributeValue::String("x".to_string())),
+            Some(AttributeValue::Int(10)),
+            None,
+        ];
+        let events = log.traces.iter_mut().flat_map(|t| t.events.iter_mut());
+        for (e, v) in events.zip(values) {
+            if let Some(v) = v {
+                e.attributes
+                    .add_attribute(Attribute::new("cost".to_string(), v));
+            }
+        }
+        let hist = numeric_attribute_histogram(&log, "cost", 2);
+        assert_eq!(hist.edges, vec![0.0, 5.0, 10.0]);
+        assert_eq!(hist.counts, vec![2, 1]);
+        assert_eq!(hist.num_values, 3);
+        assert_eq!(hist.num_skipped, 2);
+
+        let hist = Histogram::from_values([1.0, 1.0, f64::NAN], 3, 0);
+        assert_eq!(hist.counts, vec![2, 0, 0]);
+        assert_eq!(hist.num_skipped, 1);
+        let hist = Histogram::from_values([0.0, 4.0, f64::INFINITY, f64::NEG_INFINITY], 2, 0);
+        assert_eq!(hist.edges, vec![0.0, 2.0, 4.0]);
+        assert_eq!(hist.counts, vec![1, 1]);
+        assert_eq!(hist.num_skipped, 2);
+        let hist = Histogram::from_values([f64::MIN, f64::MAX], 2, 0);
+        assert!(hist.edges.iter().all(|e| e.is_finite()));
+        assert_eq!(hist.counts, vec![1, 1]);
+        assert_eq!(
+            numeric_attribute_histogram(&log, "missing", 4),
+            Histogram {
+                num_skipped: 5,
+                ..Default::default()
+            }
+        );
+    }
+}
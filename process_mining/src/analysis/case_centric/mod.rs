@@ -1,4 +1,5 @@
 //! Case-centric Process Analysis
 
+pub mod attribute_histogram;
 pub mod dotted_chart;
 pub mod event_timestamp_histogram;
@@ -0,0 +1,114 @@
+//! Histograms of Numeric OCEL Attributes
+//!
+//! See [`Histogram`] and the case-centric [`numeric_attribute_histogram`](crate::analysis::case_centric::attribute_histogram::numeric_attribute_histogram).
+
+use macros_process_mining::register_binding;
+
+pub use crate::analysis::case_centric::attribute_histogram::Histogram;
+use crate::core::{event_data::object_centric::OCELAttributeValue, OCEL};
+
+fn numeric_value(value: &OCELAttributeValue) -> Option<f64> {
+    match value {
+        OCELAttributeValue::Integer(i) => Some(*i as f64),
+        OCELAttributeValue::Float(f) => Some(*f),
+        _ => None,
+    }
+}
+
+/// Compute a [`Histogram`] with `bins` equal-width bins over a numeric (integer or float) event attribute of an OCEL
+///
+/// Events without the attribute or with a non-numeric value are skipped (see [`Histogram::num_skipped`]).
+#[register_binding]
+pub fn ocel_event_attribute_histogram(ocel: &OCEL, key: impl AsRef<str>, bins: usize) -> Histogram {
+    let key = key.as_ref();
+    let mut num_skipped = 0;
+    let values: Vec<f64> = ocel
+        .events
+        .iter()
+        .filter_map(|e| {
+            let value = e
+                .attributes
+                .iter()
+                .find(|a| a.name == key)
+                .and_then(|a| numeric_value(&a.value));
+            if value.is_none() {
+                num_skipped += 1;
+            }
+            value
+        })
+        .collect();
+    Histogram::from_values(values, bins, num_skipped)
+}
+
+/// Compute a [`Histogram`] with `bins` equal-width bins over a numeric (integer or float) object attribute of an OCEL
+///
+/// As object attributes can change over time, every (timestamped) value of an object is counted.
+/// Non-numeric values as well as objects without any value for the attribute are skipped (see [`Histogram::num_skipped`]).
+#[register_binding]
+pub fn ocel_object_attribute_histogram(
+    ocel: &OCEL,
+    key: impl AsRef<str>,
+    bins: usize,
+) -> Histogram {
+    let key = key.as_ref();
+    let mut num_skipped = 0;
+    let mut values = Vec::new();
+    for o in &ocel.objects {
+        let mut has_value = false;
+        for a in o.attributes.iter().filter(|a| a.name == key) {
+            has_value = true;
+            match numeric_value(&a.value) {
+                Some(v) => values.push(v),
+                None => num_skipped += 1,
+            }
+        }
+        if !has_value {
+            num_skipped += 1;
+        }
+    }
+    Histogram::from_values(values, bins, num_skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event_data::object_centric::{OCELEventAttribute, OCELObjectAttribute};
+
+    #[test]
+    fn test_ocel_attribute_histograms() {
+        let mut ocel = crate::ocel![
+            events:
+            ("place", ["o:1"]),
+            ("place", ["o:2"]),
+            ("pay", ["o:1"]),
+            o2o:
+        ];
+        for (e, v) in ocel.events.iter_mut().zip([
+            OCELAttributeValue::Integer(1),
+            OCELAttributeValue::Float(3.0),
+            OCELAttributeValue::String("x".to_string()),
+        ]) {
+            e.attributes.push(OCELEventAttribute {
+                name: "price".to_string(),
+                value: v,
+            });
+        }
+        let hist = ocel_event_attribute_histogram(&ocel, "price", 2);
+        assert_eq!(hist.edges, vec![1.0, 2.0, 3.0]);
+        assert_eq!(hist.counts, vec![1, 1]);
+        assert_eq!(hist.num_skipped, 1);
+
+        let o1 = ocel.objects.iter_mut().find(|o| o.id == "o:1").unwrap();
+        for v in [10_i64, 20] {
+            o1.attributes.push(OCELObjectAttribute::new(
+                "weight",
+                v,
+                chrono::DateTime::UNIX_EPOCH,
+            ));
+        }
+        let hist = ocel_object_attribute_histogram(&ocel, "weight", 1);
+        assert_eq!(hist.counts, vec![2]);
+        assert_eq!(hist.num_values, 2);
+        assert_eq!(hist.num_skipped, 1);
+    }
+}
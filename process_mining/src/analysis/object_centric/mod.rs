@@ -1,5 +1,8 @@
 //! Object-centric Process Analysis
 
+pub mod attribute_histogram;
 pub mod object_attribute_changes;
+pub mod object_interaction_graph;
+pub mod object_lifetimes;
 pub mod oc_performance;
 pub mod oc_statistics;
@@ -0,0 +1,212 @@
+//! Object interaction graph: which objects co-participate in events (and how often).
+
+use std::collections::{BTreeMap, HashMap};
+
+use macros_process_mining::register_binding;
+use rayon::prelude::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::event_data::object_centric::linked_ocel::{
+    slim_linked_ocel::{EventIndex, ObjectIndex},
+    LinkedOCELAccess, SlimLinkedOCEL,
+};
+
+/// Object (node) of an [`InteractionGraph`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InteractionGraphNode {
+    /// Object ID
+    pub id: String,
+    /// Object type
+    pub object_type: String,
+}
+
+/// Undirected edge of an [`InteractionGraph`] between two objects sharing at least one event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct InteractionGraphEdge {
+    /// Index of the first object in [`InteractionGraph::nodes`] (always smaller than `target`)
+    pub source: usize,
+    /// Index of the second object in [`InteractionGraph::nodes`]
+    pub target: usize,
+    /// Number of events both objects are involved in
+    pub weight: usize,
+}
+
+/// Summary of the interactions between objects of two object types
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InteractionTypePairSummary {
+    /// First object type (lexicographically not greater than `object_type_b`)
+    pub object_type_a: String,
+    /// Second object type
+    pub object_type_b: String,
+    /// Number of interacting object pairs (i.e., edges)
+    pub num_edges: usize,
+    /// Sum of the edge weights (i.e., shared events, counted once per object pair)
+    pub total_weight: usize,
+}
+
+/// Object interaction graph of an OCEL (see [`object_interaction_graph`])
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct InteractionGraph {
+    /// All objects of the OCEL (including objects not interacting with any other object)
+    pub nodes: Vec<InteractionGraphNode>,
+    /// Edges, sorted by `(source, target)`
+    pub edges: Vec<InteractionGraphEdge>,
+}
+
+impl InteractionGraph {
+    /// Summarize the edges per (unordered) pair of object types
+    ///
+    /// The result is sorted by the object type pair.
+    pub fn type_pair_summary(&self) -> Vec<InteractionTypePairSummary> {
+        let mut summary: BTreeMap<(&str, &str), (usize, usize)> = BTreeMap::new();
+        for edge in &self.edges {
+            let a = self.nodes[edge.source].object_type.as_str();
+            let b = self.nodes[edge.target].object_type.as_str();
+            let key = if a <= b { (a, b) } else { (b, a) };
+            let entry = summary.entry(key).or_default();
+            entry.0 += 1;
+            entry.1 += edge.weight;
+        }
+        summary
+            .into_iter()
+            .map(
+                |((a, b), (num_edges, total_weight))| InteractionTypePairSummary {
+                    object_type_a: a.to_string(),
+                    object_type_b: b.to_string(),
+                    num_edges,
+                    total_weight,
+                },
+            )
+            .collect()
+    }
+
+    /// Convert the graph to an (undirected) DOT graph source
+    ///
+    /// Nodes are labeled with the object ID and type, edges with their weight.
+    pub fn to_dot(&self) -> String {
+        let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut dot = String::from("graph {\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            dot.push_str(&format!(
+                "  n{i} [label=\"{}\\n({})\"];\n",
+                escape(&node.id),
+                escape(&node.object_type)
+            ));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "  n{} -- n{} [label=\"{}\"];\n",
+                edge.source, edge.target, edge.weight
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Compute the object interaction graph of an OCEL
+///
+/// Objects are nodes, and two objects are connected by an edge if they are both related (via E2O) to at least one
+/// common event. Edges are weighted by the number of such shared events.
+/// Multiple E2O relationships (e.g., with different qualifiers) between the same event and object count once.
+#[register_binding]
+pub fn object_interaction_graph(locel: &SlimLinkedOCEL) -> InteractionGraph {
+    let num_events = locel.get_num_evs() as u32;
+    let weights: HashMap<(usize, usize), usize> = (0..num_events)
+        .into_par_iter()
+        .fold(HashMap::new, |mut acc, i| {
+            let mut obs: Vec<usize> = EventIndex::from(i)
+                .get_e2o(locel)
+                .map(|o| o.into_inner() as usize)
+                .collect();
+            obs.sort_unstable();
+            obs.dedup();
+            for (pos, &a) in obs.iter().enumerate() {
+                for &b in &obs[pos + 1..] {
+                    *acc.entry((a, b)).or_insert(0) += 1;
+                }
+            }
+            acc
+        })
+        .reduce(HashMap::new, |mut a, b| {
+            for (k, v) in b {
+                *a.entry(k).or_insert(0) += v;
+            }
+            a
+        });
+    let nodes = (0..locel.get_num_obs() as u32)
+        .map(ObjectIndex::from)
+        .map(|o| InteractionGraphNode {
+            id: locel.get_ob_id(o).to_string(),
+            object_type: locel.get_ob_type_of(o).to_string(),
+        })
+        .collect();
+    let mut edges: Vec<InteractionGraphEdge> = weights
+        .into_iter()
+        .map(|((source, target), weight)| InteractionGraphEdge {
+            source,
+            target,
+            weight,
+        })
+        .collect();
+    edges.sort_by_key(|e| (e.source, e.target));
+    InteractionGraph { nodes, edges }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_interaction_graph() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:1"]),
+            ("ship", ["p:1"]),
+            o2o:
+            ("o:1", "i:1")
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let graph = object_interaction_graph(&locel);
+        assert_eq!(graph.nodes.len(), 4);
+        let weight_between = |a: &str, b: &str| {
+            let a = graph.nodes.iter().position(|n| n.id == a).unwrap();
+            let b = graph.nodes.iter().position(|n| n.id == b).unwrap();
+            graph
+                .edges
+                .iter()
+                .find(|e| (e.source, e.target) == (a.min(b), a.max(b)))
+                .map(|e| e.weight)
+        };
+        assert_eq!(weight_between("o:1", "i:1"), Some(2));
+        assert_eq!(weight_between("o:1", "i:2"), Some(1));
+        assert_eq!(weight_between("i:1", "i:2"), Some(1));
+        assert_eq!(weight_between("o:1", "p:1"), None);
+
+        let summary = graph.type_pair_summary();
+        assert_eq!(
+            summary,
+            vec![
+                InteractionTypePairSummary {
+                    object_type_a: "i".to_string(),
+                    object_type_b: "i".to_string(),
+                    num_edges: 1,
+                    total_weight: 1,
+                },
+                InteractionTypePairSummary {
+                    object_type_a: "i".to_string(),
+                    object_type_b: "o".to_string(),
+                    num_edges: 2,
+                    total_weight: 3,
+                },
+            ]
+        );
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("graph {"));
+        assert_eq!(dot.matches(" -- ").count(), 3);
+    }
+}
@@ -0,0 +1,135 @@
+//! Object lifetimes over [`SlimLinkedOCEL`]: the time span between the first and last event an
+//! object participates in.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset};
+use macros_process_mining::register_binding;
+use rayon::prelude::*;
+
+use crate::core::event_data::object_centric::linked_ocel::{
+    slim_linked_ocel::ObjectIndex, LinkedOCELAccess, SlimLinkedOCEL,
+};
+
+/// First and last event timestamp of each object, based on its reverse-E2O events.
+///
+/// Objects not related to any event are absent from the result.
+pub fn object_lifetimes(
+    locel: &SlimLinkedOCEL,
+) -> HashMap<ObjectIndex, (DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    (0..locel.get_num_obs() as u32)
+        .into_par_iter()
+        .filter_map(|i| {
+            let o = ObjectIndex::from(i);
+            let mut times = o.get_e2o_rev(locel).map(|e| e.get_time(locel));
+            let first = times.next()?;
+            let (first, last) = times.fold((first, first), |(f, l), t| (f.min(t), l.max(t)));
+            Some((o, (*first, *last)))
+        })
+        .collect()
+}
+
+/// Average object lifetime per object type, in microseconds.
+///
+/// The lifetime of an object is the span between its first and last event (see
+/// [`object_lifetimes`]). Returns one row `(object_type, avg_lifetime_us)` per object type with at
+/// least one object related to an event, sorted by object type.
+///
+/// Lifetimes which are not representable in microseconds (i.e., longer than ~292,000 years) are skipped.
+#[register_binding]
+pub fn object_lifetime_stats(locel: &SlimLinkedOCEL) -> Vec<(String, f64)> {
+    // Sums are accumulated as `i128`, so they can not overflow (even for many long lifetimes)
+    let mut sums: HashMap<&String, (i128, usize)> = HashMap::new();
+    for (o, (first, last)) in object_lifetimes(locel) {
+        let Some(lifetime) = (last - first).num_microseconds() else {
+            continue;
+        };
+        let entry = sums.entry(o.get_ob_type(locel)).or_insert((0, 0));
+        entry.0 += i128::from(lifetime);
+        entry.1 += 1;
+    }
+    let mut rows: Vec<(String, f64)> = sums
+        .into_iter()
+        .map(|(ot, (sum, count))| (ot.clone(), sum as f64 / count as f64))
+        .collect();
+    rows.sort_by(|a, b| a.0.cmp(&b.0));
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::core::event_data::object_centric::OCELObject;
+
+    #[test]
+    fn test_object_lifetimes() {
+        let mut ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pick", ["i:1"]),
+            ("pick", ["i:2"]),
+            ("ship", ["o:1", "i:1"]),
+            ("place", ["o:2"]),
+            o2o:
+        ];
+        ocel.objects.push(OCELObject {
+            id: "i:3".to_string(),
+            object_type: "i".to_string(),
+            attributes: Vec::new(),
+            relationships: Vec::new(),
+        });
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let lifetimes = object_lifetimes(&locel);
+        let at = |secs: i64| {
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0)
+                .unwrap()
+                .fixed_offset()
+                + chrono::TimeDelta::seconds(secs)
+        };
+        let lifetime = |id: &str| lifetimes.get(&locel.get_ob_by_id(id).unwrap()).copied();
+        assert_eq!(lifetime("o:1"), Some((at(0), at(3))));
+        assert_eq!(lifetime("i:1"), Some((at(0), at(3))));
+        assert_eq!(lifetime("i:2"), Some((at(0), at(2))));
+        assert_eq!(lifetime("o:2"), Some((at(4), at(4))));
+        assert_eq!(lifetime("i:3"), None);
+        assert_eq!(lifetimes.len(), 4);
+
+        let stats = object_lifetime_stats(&locel);
+        assert_eq!(
+            stats,
+            vec![
+                ("i".to_string(), 2_500_000.0),
+                ("o".to_string(), 1_500_000.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_object_lifetime_stats_long_lifetimes() {
+        let mut ocel = crate::ocel![
+            events:
+            ("start", ["a:1", "a:2", "b:1"]),
+            ("end", ["a:1", "a:2"]),
+            ("end", ["b:1"]),
+            o2o:
+        ];
+        let at = |year: i32| {
+            Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0)
+                .unwrap()
+                .fixed_offset()
+        };
+        // Lifetimes of `a:1` and `a:2` (200,000 years) are representable, but their sum overflows `i64`
+        ocel.events[0].time = at(-100_000);
+        ocel.events[1].time = at(100_000);
+        // The lifetime of `b:1` (400,000 years) is not representable in microseconds
+        ocel.events[2].time = at(262_000);
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let lifetime = (at(100_000) - at(-100_000)).num_microseconds().unwrap();
+        assert_eq!(
+            object_lifetime_stats(&locel),
+            vec![("a".to_string(), lifetime as f64)]
+        );
+    }
+}
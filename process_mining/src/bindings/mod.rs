@@ -39,19 +39,25 @@ use crate::core::{
         },
     },
     io::ExtensionWithMime,
-    EventLog,
+    EventLog, PetriNet,
 };
 use macros_process_mining::register_binding;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::{collections::HashMap, fmt::Display};
-use std::{str::FromStr, sync::RwLock};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+use std::{
+    str::FromStr,
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
 
 /// Manually maintained Registry enum of 'big' types
 ///
 /// NOTE: When extending this with a new variant, make sure to also update `BIG_TYPES_NAMES` in the macro crate.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[allow(clippy::large_enum_variant, missing_docs)]
 pub enum RegistryItem {
     EventLogActivityProjection(EventLogActivityProjection),
@@ -59,6 +65,7 @@ pub enum RegistryItem {
     SlimLinkedOCEL(SlimLinkedOCEL),
     EventLog(EventLog),
     OCEL(OCEL),
+    PetriNet(PetriNet),
 }
 
 impl From<EventLog> for RegistryItem {
@@ -86,8 +93,13 @@ impl From<SlimLinkedOCEL> for RegistryItem {
         Self::SlimLinkedOCEL(value)
     }
 }
+impl From<PetriNet> for RegistryItem {
+    fn from(value: PetriNet) -> Self {
+        Self::PetriNet(value)
+    }
+}
 
-#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq, JsonSchema)]
 #[allow(missing_docs)]
 pub enum RegistryItemKind {
     EventLogActivityProjection,
@@ -95,6 +107,7 @@ pub enum RegistryItemKind {
     SlimLinkedOCEL,
     EventLog,
     OCEL,
+    PetriNet,
 }
 
 impl Display for RegistryItemKind {
@@ -105,6 +118,7 @@ impl Display for RegistryItemKind {
             RegistryItemKind::SlimLinkedOCEL => "SlimLinkedOCEL",
             RegistryItemKind::EventLog => "EventLog",
             RegistryItemKind::OCEL => "OCEL",
+            RegistryItemKind::PetriNet => "PetriNet",
         };
         write!(f, "{}", s)
     }
@@ -119,6 +133,7 @@ impl RegistryItemKind {
             RegistryItemKind::EventLogActivityProjection,
             RegistryItemKind::SlimLinkedOCEL,
             RegistryItemKind::IndexLinkedOCEL,
+            RegistryItemKind::PetriNet,
         ]
     }
 
@@ -132,6 +147,7 @@ impl RegistryItemKind {
             RegistryItemKind::EventLog => EventLog::known_import_formats(),
             RegistryItemKind::OCEL => OCEL::known_import_formats(),
             RegistryItemKind::SlimLinkedOCEL => OCEL::known_import_formats(),
+            RegistryItemKind::PetriNet => PetriNet::known_import_formats(),
         }
     }
     /// Get known export formats
@@ -144,6 +160,7 @@ impl RegistryItemKind {
             RegistryItemKind::EventLog => EventLog::known_export_formats(),
             RegistryItemKind::OCEL => OCEL::known_export_formats(),
             RegistryItemKind::SlimLinkedOCEL => OCEL::known_export_formats(),
+            RegistryItemKind::PetriNet => PetriNet::known_export_formats(),
         }
     }
 }
@@ -158,6 +175,7 @@ impl std::str::FromStr for RegistryItemKind {
             "EventLog" => Ok(RegistryItemKind::EventLog),
             "OCEL" => Ok(RegistryItemKind::OCEL),
             "SlimLinkedOCEL" => Ok(RegistryItemKind::SlimLinkedOCEL),
+            "PetriNet" => Ok(RegistryItemKind::PetriNet),
             _ => Err(format!("Unknown RegistryItemKind: {}", s)),
         }
     }
@@ -183,33 +201,51 @@ impl RegistryItem {
             RegistryItem::EventLogActivityProjection(proj) => {
                 serde_json::to_value(proj).map_err(|e| e.to_string())
             }
+            RegistryItem::PetriNet(net) => serde_json::to_value(net).map_err(|e| e.to_string()),
         }
     }
 
     /// Try to load a registry item from a file path based on the expected type name
+    ///
+    /// The file format is inferred from the file extension (e.g., `.json`, `.xml`, or `.sqlite` for OCEL).
+    /// If loading fails (e.g., because of an unsupported extension), the error lists the supported formats.
     pub fn load_from_path(item_kind: &RegistryItemKind, path: &str) -> Result<Self, String> {
         let path = std::path::Path::new(path);
-
-        match item_kind {
-            RegistryItemKind::EventLog => Ok(RegistryItem::EventLog(
-                EventLog::import_from_path(path).map_err(|e| e.to_string())?,
-            )),
-            RegistryItemKind::OCEL => Ok(RegistryItem::OCEL(
-                OCEL::import_from_path(path).map_err(|e| e.to_string())?,
-            )),
-            RegistryItemKind::SlimLinkedOCEL => Ok(RegistryItem::SlimLinkedOCEL({
-                SlimLinkedOCEL::import_from_path(path).map_err(|e| e.to_string())?
-            })),
-            RegistryItemKind::IndexLinkedOCEL => Ok(RegistryItem::IndexLinkedOCEL(
-                IndexLinkedOCEL::import_from_path(path).map_err(|e| e.to_string())?,
-            )),
+        let err = |e: String| {
+            format!(
+                "Could not load {item_kind} from '{}': {e} (supported formats: {})",
+                path.display(),
+                item_kind
+                    .known_import_formats()
+                    .iter()
+                    .map(|f| f.extension.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+        let item = match item_kind {
+            RegistryItemKind::EventLog => EventLog::import_from_path(path)
+                .map(RegistryItem::EventLog)
+                .map_err(|e| e.to_string()),
+            RegistryItemKind::OCEL => OCEL::import_from_path(path)
+                .map(RegistryItem::OCEL)
+                .map_err(|e| e.to_string()),
+            RegistryItemKind::SlimLinkedOCEL => SlimLinkedOCEL::import_from_path(path)
+                .map(RegistryItem::SlimLinkedOCEL)
+                .map_err(|e| e.to_string()),
+            RegistryItemKind::IndexLinkedOCEL => IndexLinkedOCEL::import_from_path(path)
+                .map(RegistryItem::IndexLinkedOCEL)
+                .map_err(|e| e.to_string()),
             RegistryItemKind::EventLogActivityProjection => {
-                Ok(RegistryItem::EventLogActivityProjection(
-                    EventLogActivityProjection::import_from_path(path)
-                        .map_err(|e| e.to_string())?,
-                ))
+                EventLogActivityProjection::import_from_path(path)
+                    .map(RegistryItem::EventLogActivityProjection)
+                    .map_err(|e| e.to_string())
             }
-        }
+            RegistryItemKind::PetriNet => PetriNet::import_from_path(path)
+                .map(RegistryItem::PetriNet)
+                .map_err(|e| e.to_string()),
+        };
+        item.map_err(err)
     }
 
     /// Try to load a registry item from bytes based on the expected type name and format
@@ -239,6 +275,9 @@ impl RegistryItem {
                         .map_err(|e| e.to_string())?,
                 ))
             }
+            RegistryItemKind::PetriNet => Ok(RegistryItem::PetriNet(
+                PetriNet::import_from_bytes(data, format).map_err(|e| e.to_string())?,
+            )),
         }
     }
 
@@ -252,6 +291,49 @@ impl RegistryItem {
             RegistryItem::EventLog(_) => RegistryItemKind::EventLog,
             RegistryItem::OCEL(_) => RegistryItemKind::OCEL,
             RegistryItem::SlimLinkedOCEL(_) => RegistryItemKind::SlimLinkedOCEL,
+            RegistryItem::PetriNet(_) => RegistryItemKind::PetriNet,
+        }
+    }
+
+    /// Get a short [`ItemSummary`] (i.e., the sizes) of this item
+    pub fn summary(&self) -> ItemSummary {
+        match self {
+            RegistryItem::EventLogActivityProjection(projection) => ItemSummary {
+                num_traces: Some(projection.traces.iter().map(|(_, f)| *f as usize).sum()),
+                num_events: Some(
+                    projection
+                        .traces
+                        .iter()
+                        .map(|(t, f)| t.len() * *f as usize)
+                        .sum(),
+                ),
+                ..Default::default()
+            },
+            RegistryItem::IndexLinkedOCEL(locel) => ItemSummary {
+                num_events: Some(locel.get_num_evs()),
+                num_objects: Some(locel.get_num_obs()),
+                ..Default::default()
+            },
+            RegistryItem::SlimLinkedOCEL(locel) => ItemSummary {
+                num_events: Some(locel.get_num_evs()),
+                num_objects: Some(locel.get_num_obs()),
+                ..Default::default()
+            },
+            RegistryItem::EventLog(log) => ItemSummary {
+                num_traces: Some(log.traces.len()),
+                num_events: Some(log.traces.iter().map(|t| t.events.len()).sum()),
+                ..Default::default()
+            },
+            RegistryItem::OCEL(ocel) => ItemSummary {
+                num_events: Some(ocel.events.len()),
+                num_objects: Some(ocel.objects.len()),
+                ..Default::default()
+            },
+            RegistryItem::PetriNet(pn) => ItemSummary {
+                num_places: Some(pn.places.len()),
+                num_transitions: Some(pn.transitions.len()),
+                ..Default::default()
+            },
         }
     }
 
@@ -269,6 +351,7 @@ impl RegistryItem {
             RegistryItem::EventLogActivityProjection(x) => {
                 x.export_to_path(path).map_err(|e| e.to_string())
             }
+            RegistryItem::PetriNet(x) => x.export_to_path(path).map_err(|e| e.to_string()),
         }
     }
 
@@ -292,6 +375,9 @@ impl RegistryItem {
             RegistryItem::EventLogActivityProjection(x) => x
                 .export_to_writer(&mut bytes, format)
                 .map_err(|e| e.to_string())?,
+            RegistryItem::PetriNet(x) => x
+                .export_to_writer(&mut bytes, format)
+                .map_err(|e| e.to_string())?,
         };
         Ok(bytes)
     }
@@ -319,6 +405,28 @@ impl RegistryItem {
     }
 }
 
+/// Summary of the size of a [`RegistryItem`]
+///
+/// Only the counts applicable to the kind of item are set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ItemSummary {
+    /// Number of events (for event logs, activity projections, and OCEL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_events: Option<usize>,
+    /// Number of objects (for OCEL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_objects: Option<usize>,
+    /// Number of traces (for event logs and activity projections)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_traces: Option<usize>,
+    /// Number of places (for Petri nets)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_places: Option<usize>,
+    /// Number of transitions (for Petri nets)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_transitions: Option<usize>,
+}
+
 /// Inner App State
 pub type InnerAppState = HashMap<String, RegistryItem>;
 /// State that can store 'big' types
@@ -328,16 +436,121 @@ pub struct AppState {
     pub items: RwLock<InnerAppState>,
 }
 impl AppState {
+    /// Acquire read access to the stored items
+    ///
+    /// If the lock is poisoned (i.e., a thread panicked while holding it), it is recovered instead of propagating the panic.
+    pub fn read(&self) -> RwLockReadGuard<'_, InnerAppState> {
+        self.items.read().unwrap_or_else(|e| e.into_inner())
+    }
+    /// Acquire write access to the stored items
+    ///
+    /// If the lock is poisoned (i.e., a thread panicked while holding it), it is recovered instead of propagating the panic.
+    pub fn write(&self) -> RwLockWriteGuard<'_, InnerAppState> {
+        self.items.write().unwrap_or_else(|e| e.into_inner())
+    }
+    /// Remove all stored items (replacing the inner map) and clear a poisoned lock
+    pub fn reset(&self) {
+        *self.write() = InnerAppState::new();
+        self.items.clear_poison();
+    }
     /// Add the passed registry item
     pub fn add(&self, id: impl Into<String>, item: impl Into<RegistryItem>) {
-        self.items.write().unwrap().insert(id.into(), item.into());
+        self.write().insert(id.into(), item.into());
     }
     /// Check if the state contains the passed key
     pub fn contains_key(&self, id: &str) -> bool {
-        self.items.read().unwrap().contains_key(id)
+        self.read().contains_key(id)
+    }
+    /// List the IDs and kinds of all currently stored items (sorted by ID)
+    pub fn snapshot(&self) -> Vec<(String, RegistryItemKind)> {
+        let mut ret: Vec<_> = self
+            .read()
+            .iter()
+            .map(|(id, item)| (id.clone(), item.kind()))
+            .collect();
+        ret.sort_by(|(a, _), (b, _)| a.cmp(b));
+        ret
+    }
+    /// List the IDs, kinds, and summaries of all currently stored items (sorted by ID)
+    pub fn list_items(&self) -> Vec<(String, RegistryItemKind, ItemSummary)> {
+        let mut ret: Vec<_> = self
+            .read()
+            .iter()
+            .map(|(id, item)| (id.clone(), item.kind(), item.summary()))
+            .collect();
+        ret.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+        ret
+    }
+    /// Remove the item with the passed ID (e.g., to free memory)
+    ///
+    /// Returns `true` if an item was removed.
+    pub fn remove(&self, id: &str) -> bool {
+        self.write().remove(id).is_some()
+    }
+    /// Export all stored items to files in the passed directory (which is created if it does not exist)
+    ///
+    /// Each item is written to `<id>.<extension>`, using the first known export format of its kind
+    /// (see [`RegistryItemKind::known_export_formats`]).
+    /// Characters of IDs which are not safe in file names (e.g., path separators) are percent-encoded
+    /// (see `export_file_stem`), and a `~<n>` suffix is added to file names that would only differ in case,
+    /// so that every item is written to a separate file.
+    ///
+    /// Each item is copied before writing it, so the registry is not locked during file I/O.
+    /// Items removed concurrently are skipped.
+    ///
+    /// Returns the paths of the written files (sorted by ID).
+    pub fn export_all(
+        &self,
+        dir: impl AsRef<std::path::Path>,
+    ) -> Result<Vec<std::path::PathBuf>, String> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        let mut used_file_names = HashSet::new();
+        let mut paths = Vec::new();
+        for (id, kind) in self.snapshot() {
+            let format = kind
+                .known_export_formats()
+                .into_iter()
+                .next()
+                .ok_or_else(|| format!("No export format known for {kind}"))?;
+            let Some(item) = self.read().get(&id).cloned() else {
+                continue;
+            };
+            let stem = export_file_stem(&id);
+            let mut file_name = format!("{stem}.{}", format.extension);
+            let mut n = 1;
+            while !used_file_names.insert(file_name.to_lowercase()) {
+                n += 1;
+                file_name = format!("{stem}~{n}.{}", format.extension);
+            }
+            let path = dir.join(file_name);
+            item.export_to_path(&path)
+                .map_err(|e| format!("Failed to export {id}: {e}"))?;
+            paths.push(path);
+        }
+        Ok(paths)
     }
 }
 
+/// File name (without extension) for exporting the item with the passed ID
+///
+/// Alphanumeric characters, `-`, `_`, and `.` are kept, all other characters are percent-encoded (e.g., `/` as `%2F`).
+/// Different IDs thus always result in different file names.
+fn export_file_stem(id: &str) -> String {
+    let mut ret = String::with_capacity(id.len());
+    for c in id.chars() {
+        if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+            ret.push(c);
+        } else {
+            let mut buf = [0; 4];
+            for b in c.encode_utf8(&mut buf).bytes() {
+                ret.push_str(&format!("%{b:02X}"));
+            }
+        }
+    }
+    ret
+}
+
 /// Function Binding
 #[derive(Debug)]
 pub struct Binding {
@@ -435,6 +648,41 @@ pub fn extract_param<'a, T: FromContext<'a>>(
     }
 }
 
+/// Extract a list parameter (e.g., a list of registry IDs of big types) with access to the state.
+///
+/// Used by the `#[register_binding]` macro for `Vec<&BigType>` parameters.
+pub fn extract_param_list<'a, T: FromContext<'a>>(
+    m: &serde_json::Map<String, Value>,
+    k: &str,
+    s: &'a InnerAppState,
+) -> Result<Vec<T>, String> {
+    let x = m
+        .get(k)
+        .ok_or_else(|| format!("Missing required argument {k}"))?;
+    x.as_array()
+        .ok_or_else(|| format!("Invalid Argument: {k}\nExpected Array"))?
+        .iter()
+        .map(|v| T::from_context(v, s).map_err(|e| format!("Invalid Argument: {k}\n{e}")))
+        .collect()
+}
+
+/// Extract an optional parameter (e.g., a nullable registry ID of a big type) with access to the state.
+///
+/// Missing or `null` arguments are extracted as `None`.
+/// Used by the `#[register_binding]` macro for `Option<&BigType>` parameters.
+pub fn extract_param_optional<'a, T: FromContext<'a>>(
+    m: &serde_json::Map<String, Value>,
+    k: &str,
+    s: &'a InnerAppState,
+) -> Result<Option<T>, String> {
+    match m.get(k) {
+        None | Some(Value::Null) => Ok(None),
+        Some(x) => T::from_context(x, s)
+            .map(Some)
+            .map_err(|e| format!("Invalid Argument: {k}\n{e}")),
+    }
+}
+
 /// Extract a JSON-deserializable parameter without requiring state access.
 ///
 /// Used by the `#[register_binding]` macro for functions with `&mut` big type parameters,
@@ -481,11 +729,31 @@ pub fn resolve_argument(
 ) -> Result<Value, String> {
     let schema_obj = schema.as_object().ok_or("Invalid schema")?;
 
+    // Case 0: List of Registry References (resolve every item)
+    if let Some(items_schema) = schema_obj
+        .get("items")
+        .filter(|s| s.get("x-registry-ref").is_some())
+    {
+        let values = match value {
+            Value::Array(values) => values,
+            Value::String(s) => match serde_json::from_str::<Value>(&s) {
+                Ok(Value::Array(values)) => values,
+                _ => vec![Value::String(s)],
+            },
+            v => return Ok(v),
+        };
+        return values
+            .into_iter()
+            .map(|v| resolve_argument(arg_name, v, items_schema, state))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array);
+    }
+
     // Case 1: Registry Reference
     if let Some(arg_ref) = schema_obj.get("x-registry-ref").and_then(|r| r.as_str()) {
         // If the value is already a string ID that exists in the registry, use it.
         if let Some(id) = value.as_str() {
-            let mut items = state.items.write().map_err(|e| e.to_string())?;
+            let mut items = state.write();
             if let Some(item) = items.get(id) {
                 if item.kind().to_string() == arg_ref {
                     return Ok(value);
@@ -515,7 +783,7 @@ pub fn resolve_argument(
 
             // Otherwise, try to load it from file
             let item = RegistryItem::load_from_path(&RegistryItemKind::from_str(arg_ref)?, id)?;
-            let stored_name = format!("A{}_{}", arg_name, uuid::Uuid::new_v4());
+            let stored_name = crate::utils::new_id(&format!("A{arg_name}_"));
             state.add(&stored_name, item);
             return Ok(serde_json::Value::String(stored_name));
         }
@@ -621,6 +889,66 @@ pub fn slim_link_ocel(ocel: &OCEL) -> SlimLinkedOCEL {
     SlimLinkedOCEL::from_ocel(ocel.clone())
 }
 
+// Listing the registry items requires access to the app state itself,
+// so this binding is registered manually instead of via `#[register_binding]`.
+inventory::submit! {
+    Binding {
+        id: concat!(module_path!(), "::list_registry_items"),
+        name: "list_registry_items",
+        handler: |_args, state| serde_json::to_vec(&state.list_items()).map_err(|e| e.to_string()),
+        docs: || {
+            vec![
+                "List the IDs, kinds, and summaries (e.g., number of events) of all items currently stored in the registry (sorted by ID).".to_string(),
+            ]
+        },
+        module: module_path!(),
+        source_path: file!(),
+        source_line: line!(),
+        args: Vec::new,
+        required_args: Vec::new,
+        return_type: || {
+            serde_json::to_value(schemars::schema_for!(
+                Vec<(String, RegistryItemKind, ItemSummary)>
+            ))
+            .unwrap()
+        },
+    }
+}
+
+// Removing registry items requires access to the app state itself,
+// so this binding is registered manually instead of via `#[register_binding]`.
+inventory::submit! {
+    Binding {
+        id: concat!(module_path!(), "::remove_registry_item"),
+        name: "remove_registry_item",
+        handler: |args, state| {
+            let id = args
+                .get("id")
+                .and_then(Value::as_str)
+                .ok_or_else(|| "Missing string argument: id".to_string())?;
+            serde_json::to_vec(&state.remove(id)).map_err(|e| e.to_string())
+        },
+        docs: || {
+            vec![
+                "Remove the item with the passed ID from the registry (e.g., to free memory).".to_string(),
+                String::new(),
+                "Returns `true` if an item was removed.".to_string(),
+            ]
+        },
+        module: module_path!(),
+        source_path: file!(),
+        source_line: line!(),
+        args: || {
+            vec![(
+                "id".to_string(),
+                serde_json::to_value(schemars::schema_for!(String)).unwrap(),
+            )]
+        },
+        required_args: || vec!["id".to_string()],
+        return_type: || serde_json::to_value(schemars::schema_for!(bool)).unwrap(),
+    }
+}
+
 #[register_binding]
 /// This is a test function.
 ///
@@ -630,6 +958,18 @@ pub fn test_some_inputs(s: String, n: usize, i: i32, f: f64, b: bool) -> String
     format!("s={},n={},i={},f={},b={}", s, n, i, f, b)
 }
 
+#[register_binding]
+/// This is a test function for list and optional big type arguments.
+///
+/// Returns the combined number of events of all given OCELs.
+pub fn test_combined_num_events(ocels: Vec<&OCEL>, extra: Option<&OCEL>) -> usize {
+    ocels
+        .iter()
+        .chain(extra.iter())
+        .map(|ocel| ocel.events.len())
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils::get_test_data_path;
@@ -649,6 +989,238 @@ mod tests {
         serde_json::to_writer_pretty(&file, &bindings).unwrap();
     }
 
+    #[test]
+    fn test_app_state_snapshot_and_export() {
+        let state = AppState::default();
+        state.add("log", crate::event_log!(["a", "b"], ["a", "c"]));
+        state.add(
+            "nested/ocel",
+            crate::ocel![
+                events:
+                ("place", ["o:1"]),
+                o2o:
+            ],
+        );
+        assert_eq!(
+            state.snapshot(),
+            vec![
+                ("log".to_string(), RegistryItemKind::EventLog),
+                ("nested/ocel".to_string(), RegistryItemKind::OCEL),
+            ]
+        );
+        // IDs that only differ in path separators or case must not overwrite each other
+        state.add(
+            "nested_ocel",
+            crate::ocel![events: ("place", ["o:1"]), o2o:],
+        );
+        state.add("Log", crate::event_log!(["a"]));
+        let dir = std::env::temp_dir().join(format!("app-state-export-{}", std::process::id()));
+        let paths = state.export_all(&dir).unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                dir.join("Log.xes"),
+                dir.join("log~2.xes"),
+                dir.join("nested%2Focel.json"),
+                dir.join("nested_ocel.json")
+            ]
+        );
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 4);
+        let ocel =
+            RegistryItem::load_from_path(&RegistryItemKind::OCEL, paths[2].to_str().unwrap())
+                .unwrap();
+        assert_eq!(ocel.kind(), RegistryItemKind::OCEL);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_ocel_from_path_by_extension() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            o2o:
+        ];
+        let dir = std::env::temp_dir().join(format!("load-from-path-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for ext in ["json", "xml"] {
+            let path = dir.join(format!("ocel.{ext}"));
+            ocel.export_to_path(&path).unwrap();
+            let item = RegistryItem::load_from_path(
+                &RegistryItemKind::IndexLinkedOCEL,
+                path.to_str().unwrap(),
+            )
+            .unwrap();
+            let RegistryItem::IndexLinkedOCEL(locel) = item else {
+                panic!("Expected IndexLinkedOCEL");
+            };
+            assert_eq!(locel.get_ocel_ref().events.len(), 1);
+            assert_eq!(locel.get_ocel_ref().objects.len(), 2);
+        }
+        let path = dir.join("ocel.txt");
+        std::fs::write(&path, "").unwrap();
+        let err = RegistryItem::load_from_path(
+            &RegistryItemKind::IndexLinkedOCEL,
+            path.to_str().unwrap(),
+        )
+        .unwrap_err();
+        assert!(err.contains("supported formats: json"), "{err}");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "ocel-sqlite")]
+    #[test]
+    fn test_load_linked_ocel_from_sqlite_path() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            ("pack", ["o:1"]),
+            o2o:
+            ("o:1", "i:1")
+        ];
+        let dir = std::env::temp_dir().join(format!("load-from-sqlite-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ocel.sqlite");
+        ocel.export_to_path(&path).unwrap();
+        let path = path.to_str().unwrap();
+        let RegistryItem::SlimLinkedOCEL(slim) =
+            RegistryItem::load_from_path(&RegistryItemKind::SlimLinkedOCEL, path).unwrap()
+        else {
+            panic!("Expected SlimLinkedOCEL");
+        };
+        assert_eq!(slim.get_all_evs().count(), 2);
+        assert_eq!(slim.get_all_obs().count(), 2);
+        let RegistryItem::IndexLinkedOCEL(locel) =
+            RegistryItem::load_from_path(&RegistryItemKind::IndexLinkedOCEL, path).unwrap()
+        else {
+            panic!("Expected IndexLinkedOCEL");
+        };
+        assert_eq!(locel.get_all_evs().count(), 2);
+        assert_eq!(locel.get_all_obs().count(), 2);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_vec_and_option_big_type_args() {
+        let state = AppState::default();
+        state.add(
+            "a",
+            crate::ocel![
+                events:
+                ("place", ["o:1"]),
+                ("pay", ["o:1"]),
+                o2o:
+            ],
+        );
+        state.add(
+            "b",
+            crate::ocel![
+                events:
+                ("place", ["o:2"]),
+                o2o:
+            ],
+        );
+        let binding = get_fn_binding("process_mining::bindings::test_combined_num_events").unwrap();
+        let args = (binding.args)();
+        assert_eq!(args[0].1["type"], "array");
+        assert_eq!(args[0].1["items"]["x-registry-ref"], "OCEL");
+        assert_eq!(args[1].1["x-registry-ref"], "OCEL");
+        assert_eq!((binding.required_args)(), vec!["ocels".to_string()]);
+
+        let res = call(binding, &serde_json::json!({"ocels": ["a", "b"]}), &state).unwrap();
+        assert_eq!(serde_json::from_slice::<usize>(&res).unwrap(), 3);
+        let res = call(
+            binding,
+            &serde_json::json!({"ocels": ["b"], "extra": "a"}),
+            &state,
+        )
+        .unwrap();
+        assert_eq!(serde_json::from_slice::<usize>(&res).unwrap(), 3);
+        let res = call(
+            binding,
+            &serde_json::json!({"ocels": [], "extra": null}),
+            &state,
+        )
+        .unwrap();
+        assert_eq!(serde_json::from_slice::<usize>(&res).unwrap(), 0);
+        assert!(call(binding, &serde_json::json!({"ocels": ["a", "c"]}), &state).is_err());
+    }
+
+    #[test]
+    fn test_app_state_recovers_from_poisoned_lock() {
+        let state = std::sync::Arc::new(AppState::default());
+        state.add("a", crate::ocel![events: ("place", ["o:1"]), o2o:]);
+        let poisoning_state = std::sync::Arc::clone(&state);
+        std::thread::spawn(move || {
+            let _guard = poisoning_state.items.write().unwrap();
+            panic!("Poison the lock");
+        })
+        .join()
+        .unwrap_err();
+        assert!(state.items.is_poisoned());
+        // Accessors still work on the poisoned lock
+        assert!(state.contains_key("a"));
+
+        state.reset();
+        assert!(!state.items.is_poisoned());
+        assert!(state.snapshot().is_empty());
+        state.add("b", crate::ocel![events: ("place", ["o:2"]), o2o:]);
+        let binding = get_fn_binding("process_mining::bindings::test_combined_num_events").unwrap();
+        let res = call(binding, &serde_json::json!({"ocels": ["b"]}), &state).unwrap();
+        assert_eq!(serde_json::from_slice::<usize>(&res).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_list_and_remove_registry_items() {
+        let state = AppState::default();
+        state.add("log", crate::event_log!(["a", "b"], ["a", "c", "d"]));
+        state.add(
+            "ocel",
+            crate::ocel![events: ("place", ["o:1", "i:1"]), o2o:],
+        );
+        let expected = vec![
+            (
+                "log".to_string(),
+                RegistryItemKind::EventLog,
+                ItemSummary {
+                    num_events: Some(5),
+                    num_traces: Some(2),
+                    ..Default::default()
+                },
+            ),
+            (
+                "ocel".to_string(),
+                RegistryItemKind::OCEL,
+                ItemSummary {
+                    num_events: Some(1),
+                    num_objects: Some(2),
+                    ..Default::default()
+                },
+            ),
+        ];
+        assert_eq!(state.list_items(), expected);
+        let binding = get_fn_binding("process_mining::bindings::list_registry_items").unwrap();
+        let res = call(binding, &serde_json::json!({}), &state).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Vec<(String, RegistryItemKind, ItemSummary)>>(&res).unwrap(),
+            expected
+        );
+
+        assert!(state.remove("log"));
+        assert!(!state.remove("log"));
+        assert_eq!(
+            state.snapshot(),
+            vec![("ocel".to_string(), RegistryItemKind::OCEL)]
+        );
+        let binding = get_fn_binding("process_mining::bindings::remove_registry_item").unwrap();
+        assert_eq!((binding.required_args)(), vec!["id".to_string()]);
+        let res = call(binding, &serde_json::json!({"id": "ocel"}), &state).unwrap();
+        assert!(serde_json::from_slice::<bool>(&res).unwrap());
+        let res = call(binding, &serde_json::json!({"id": "ocel"}), &state).unwrap();
+        assert!(!serde_json::from_slice::<bool>(&res).unwrap());
+        assert!(call(binding, &serde_json::json!({}), &state).is_err());
+        assert!(state.snapshot().is_empty());
+    }
+
     #[test]
     fn test_consistent_registry_item_variants() {
         // Ensure that we have the expected variants
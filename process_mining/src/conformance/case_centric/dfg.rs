@@ -0,0 +1,358 @@
+//! Alignments of Traces on Directly-Follows Graphs
+//!
+//! A trace is aligned to a [`DirectlyFollowsGraph`] by searching for a cheapest edit of the trace into
+//! a path of the graph, i.e., a sequence of activities starting with a start activity, ending with an end activity,
+//! and where every pair of consecutive activities is a directly-follows relation.
+//!
+//! The search space has at most `(trace length + 1) * (number of activities + 1)` states,
+//! so alignments can be computed much cheaper than alignments on Petri nets.
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use rayon::prelude::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    event_data::case_centric::utils::activity_projection::EventLogActivityProjection,
+    process_models::case_centric::dfg::dfg_struct::DirectlyFollowsGraph,
+};
+
+///
+/// Move of an alignment of a trace on a [`DirectlyFollowsGraph`]
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type")]
+pub enum DfgMove {
+    /// The activity occurs both in the trace and in the path of the graph
+    Synchronous {
+        /// Activity
+        activity: String,
+    },
+    /// The activity only occurs in the trace (i.e., it is skipped)
+    Log {
+        /// Activity
+        activity: String,
+    },
+    /// The activity only occurs in the path of the graph (i.e., it is inserted)
+    Model {
+        /// Activity
+        activity: String,
+    },
+}
+
+impl DfgMove {
+    /// Cost of this move: `0` for synchronous moves, `1` otherwise
+    pub fn cost(&self) -> usize {
+        match self {
+            DfgMove::Synchronous { .. } => 0,
+            DfgMove::Log { .. } | DfgMove::Model { .. } => 1,
+        }
+    }
+}
+
+/// Activities of a [`DirectlyFollowsGraph`] as indices, with their successors
+struct DfgIndex {
+    activities: Vec<String>,
+    act_to_index: HashMap<String, usize>,
+    start: Vec<usize>,
+    is_end: Vec<bool>,
+    successors: Vec<Vec<usize>>,
+}
+
+impl DfgIndex {
+    fn new(dfg: &DirectlyFollowsGraph<'_>) -> Self {
+        let mut activities: Vec<String> = dfg
+            .activities
+            .keys()
+            .cloned()
+            .chain(dfg.start_activities.iter().cloned())
+            .chain(dfg.end_activities.iter().cloned())
+            .chain(
+                dfg.directly_follows_relations
+                    .keys()
+                    .flat_map(|(a, b)| [a.to_string(), b.to_string()]),
+            )
+            .collect();
+        activities.sort();
+        activities.dedup();
+        let act_to_index: HashMap<String, usize> = activities
+            .iter()
+            .enumerate()
+            .map(|(i, a)| (a.clone(), i))
+            .collect();
+        let mut start: Vec<usize> = dfg
+            .start_activities
+            .iter()
+            .map(|a| act_to_index[a])
+            .collect();
+        start.sort_unstable();
+        let mut is_end = vec![false; activities.len()];
+        for a in &dfg.end_activities {
+            is_end[act_to_index[a]] = true;
+        }
+        let mut successors = vec![Vec::new(); activities.len()];
+        for (a, b) in dfg.directly_follows_relations.keys() {
+            successors[act_to_index[a.as_ref()]].push(act_to_index[b.as_ref()]);
+        }
+        successors.iter_mut().for_each(|s| s.sort_unstable());
+        Self {
+            activities,
+            act_to_index,
+            start,
+            is_end,
+            successors,
+        }
+    }
+
+    /// Successors of `node`, where `None` is the (artificial) node before any start activity
+    fn successors(&self, node: Option<usize>) -> &[usize] {
+        match node {
+            Some(a) => &self.successors[a],
+            None => &self.start,
+        }
+    }
+
+    fn align(&self, trace: &[&str]) -> (Vec<DfgMove>, usize) {
+        let n = trace.len();
+        let num_nodes = self.activities.len() + 1;
+        // Graph node of each trace activity (if it is contained in the graph)
+        let trace_nodes: Vec<Option<usize>> = trace
+            .iter()
+            .map(|a| self.act_to_index.get(*a).copied())
+            .collect();
+        // Heuristic: Events whose activity does not occur in the graph always require a log move
+        let mut remaining_unknown = vec![0; n + 1];
+        for i in (0..n).rev() {
+            remaining_unknown[i] = remaining_unknown[i + 1] + usize::from(trace_nodes[i].is_none());
+        }
+        // State (i, node): The first i events of the trace were processed and the path ends in node
+        // (node index 0 is the artificial node before any start activity)
+        let state_index = |i: usize, node: Option<usize>| i * num_nodes + node.map_or(0, |a| a + 1);
+        let mut cost = vec![usize::MAX; (n + 1) * num_nodes];
+        let mut predecessor: Vec<Option<(usize, DfgMoveKind)>> = vec![None; (n + 1) * num_nodes];
+        let mut queue = BinaryHeap::new();
+        cost[state_index(0, None)] = 0;
+        queue.push(Reverse((remaining_unknown[0], 0, 0_usize, None::<usize>)));
+        while let Some(Reverse((_, c, i, node))) = queue.pop() {
+            let s = state_index(i, node);
+            if c > cost[s] {
+                continue;
+            }
+            if i == n && node.is_some_and(|a| self.is_end[a]) {
+                return (self.reconstruct(&predecessor, trace, s, num_nodes), c);
+            }
+            let mut relax = |c_new: usize, i_new: usize, node_new: Option<usize>, kind| {
+                let s_new = state_index(i_new, node_new);
+                if c_new < cost[s_new] {
+                    cost[s_new] = c_new;
+                    predecessor[s_new] = Some((s, kind));
+                    queue.push(Reverse((
+                        c_new + remaining_unknown[i_new],
+                        c_new,
+                        i_new,
+                        node_new,
+                    )));
+                }
+            };
+            if i < n {
+                relax(c + 1, i + 1, node, DfgMoveKind::Log);
+                if let Some(a) = trace_nodes[i] {
+                    if self.successors(node).contains(&a) {
+                        relax(c, i + 1, Some(a), DfgMoveKind::Synchronous);
+                    }
+                }
+            }
+            for b in self.successors(node) {
+                relax(c + 1, i, Some(*b), DfgMoveKind::Model);
+            }
+        }
+        // No path from a start to an end activity exists
+        let moves = trace
+            .iter()
+            .map(|a| DfgMove::Log {
+                activity: a.to_string(),
+            })
+            .collect();
+        (moves, n)
+    }
+
+    fn reconstruct(
+        &self,
+        predecessor: &[Option<(usize, DfgMoveKind)>],
+        trace: &[&str],
+        mut s: usize,
+        num_nodes: usize,
+    ) -> Vec<DfgMove> {
+        let mut moves = Vec::new();
+        while let Some((prev, kind)) = predecessor[s] {
+            let (i, node) = (s / num_nodes, s % num_nodes);
+            moves.push(match kind {
+                DfgMoveKind::Synchronous => DfgMove::Synchronous {
+                    activity: trace[i - 1].to_string(),
+                },
+                DfgMoveKind::Log => DfgMove::Log {
+                    activity: trace[i - 1].to_string(),
+                },
+                DfgMoveKind::Model => DfgMove::Model {
+                    activity: self.activities[node - 1].clone(),
+                },
+            });
+            s = prev;
+        }
+        moves.reverse();
+        moves
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DfgMoveKind {
+    Synchronous,
+    Log,
+    Model,
+}
+
+///
+/// Align a trace to a [`DirectlyFollowsGraph`]
+///
+/// The trace is given as activity indices into `activities` (e.g., the activities of an [`EventLogActivityProjection`]).
+/// Returns the moves of an optimal alignment together with its cost, where log and model moves have cost `1`
+/// and synchronous moves have cost `0` (see [`DfgMove::cost`]).
+///
+/// The alignment is computed using A* search on the product of the trace and the graph.
+/// If the graph does not contain any path from a start activity to an end activity,
+/// all events are aligned as log moves.
+///
+pub fn align_trace_to_dfg(
+    dfg: &DirectlyFollowsGraph<'_>,
+    activities: &[String],
+    trace: &[usize],
+) -> (Vec<DfgMove>, usize) {
+    let trace: Vec<&str> = trace.iter().map(|a| activities[*a].as_str()).collect();
+    DfgIndex::new(dfg).align(&trace)
+}
+
+///
+/// Compute the alignment-based fitness of an [`EventLogActivityProjection`] on a [`DirectlyFollowsGraph`]
+///
+/// The fitness of a trace is `1 - cost / (trace length + shortest path length)`, where the shortest path length
+/// is the cost of aligning the empty trace (i.e., the worst possible cost).
+/// The fitness of the log is computed analogously over all traces (weighted by their frequencies).
+///
+/// Note that every path of a [`DirectlyFollowsGraph`] contains at least one activity, so empty traces
+/// always have fitness `0.0` (except if the graph contains no path at all, see [`align_trace_to_dfg`]).
+/// If the log contains no traces (or only empty traces and the graph contains no path), the fitness is `1.0`.
+///
+/// Traces are aligned in parallel.
+///
+pub fn dfg_alignment_fitness(
+    dfg: &DirectlyFollowsGraph<'_>,
+    log_projection: &EventLogActivityProjection,
+) -> f64 {
+    let index = DfgIndex::new(dfg);
+    let (_, empty_cost) = index.align(&[]);
+    let (cost, worst_cost) = log_projection
+        .traces
+        .par_iter()
+        .map(|(trace, frequency)| {
+            let trace: Vec<&str> = trace
+                .iter()
+                .map(|a| log_projection.activities[*a].as_str())
+                .collect();
+            let (_, cost) = index.align(&trace);
+            (
+                cost as u64 * frequency,
+                (trace.len() + empty_cost) as u64 * frequency,
+            )
+        })
+        .reduce(|| (0, 0), |(c1, w1), (c2, w2)| (c1 + c2, w1 + w2));
+    if worst_cost == 0 {
+        return 1.0;
+    }
+    1.0 - cost as f64 / worst_cost as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn abc_dfg() -> DirectlyFollowsGraph<'static> {
+        // a -> b -> c, with a loop on b
+        let mut dfg = DirectlyFollowsGraph::new();
+        for a in ["a", "b", "c"] {
+            dfg.add_activity(a.to_string(), 1);
+        }
+        dfg.add_start_activity("a".to_string());
+        dfg.add_end_activity("c".to_string());
+        for (a, b) in [("a", "b"), ("b", "b"), ("b", "c")] {
+            dfg.add_df_relation(Cow::Borrowed(a), Cow::Borrowed(b), 1);
+        }
+        dfg
+    }
+
+    #[test]
+    fn test_align_trace_to_dfg() {
+        let dfg = abc_dfg();
+        let activities: Vec<String> = ["a", "b", "c", "x"].map(String::from).to_vec();
+
+        let (moves, cost) = align_trace_to_dfg(&dfg, &activities, &[0, 1, 1, 2]);
+        assert_eq!(cost, 0);
+        assert_eq!(moves.len(), 4);
+
+        // Missing b and additional x
+        let (moves, cost) = align_trace_to_dfg(&dfg, &activities, &[0, 3, 2]);
+        assert_eq!(cost, 2);
+        assert_eq!(
+            moves,
+            vec![
+                DfgMove::Synchronous {
+                    activity: "a".to_string()
+                },
+                DfgMove::Log {
+                    activity: "x".to_string()
+                },
+                DfgMove::Model {
+                    activity: "b".to_string()
+                },
+                DfgMove::Synchronous {
+                    activity: "c".to_string()
+                },
+            ]
+        );
+        assert_eq!(moves.iter().map(DfgMove::cost).sum::<usize>(), cost);
+
+        // Empty trace: Shortest path a, b, c
+        let (moves, cost) = align_trace_to_dfg(&dfg, &activities, &[]);
+        assert_eq!(cost, 3);
+        assert!(moves.iter().all(|m| matches!(m, DfgMove::Model { .. })));
+    }
+
+    #[test]
+    fn test_dfg_alignment_fitness() {
+        let dfg = abc_dfg();
+        let activities: Vec<String> = ["a", "b", "c", "x"].map(String::from).to_vec();
+        let act_to_index = activities
+            .iter()
+            .enumerate()
+            .map(|(i, a)| (a.clone(), i))
+            .collect();
+        let mut log_projection = EventLogActivityProjection {
+            activities,
+            act_to_index,
+            traces: vec![(vec![0, 1, 2], 3)],
+        };
+        assert_eq!(dfg_alignment_fitness(&dfg, &log_projection), 1.0);
+        // Cost 2, worst cost 3 + 3
+        log_projection.traces = vec![(vec![0, 3, 2], 1)];
+        assert!((dfg_alignment_fitness(&dfg, &log_projection) - 2.0 / 3.0).abs() < 1e-9);
+        // Empty traces can not be replayed (the graph contains no empty path)
+        log_projection.traces = vec![(vec![], 2)];
+        assert_eq!(dfg_alignment_fitness(&dfg, &log_projection), 0.0);
+        log_projection.traces = vec![];
+        assert_eq!(dfg_alignment_fitness(&dfg, &log_projection), 1.0);
+    }
+}
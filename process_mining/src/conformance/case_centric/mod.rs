@@ -1,3 +1,6 @@
 //! Case-centric Conformance-checking Approaches
+pub mod dfg;
+#[cfg(feature = "token-based-replay")]
+pub mod online;
 #[cfg(feature = "token-based-replay")]
 pub mod token_based_replay;
@@ -0,0 +1,273 @@
+//! Online (Incremental) Token-based Replay on Petri Nets
+//!
+//! Instead of replaying a complete event log (see the `token_based_replay` module), a [`ReplayState`]
+//! processes the events of a single case one by one, e.g., as they arrive from a streaming event source.
+//!
+//! 🔐 Requires the `token-based-replay` feature to be enabled.
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::token_based_replay::{TokenBasedReplayError, TokenBasedReplayResult};
+use crate::core::{
+    process_models::case_centric::petri_net::petri_net_struct::{ArcType, Marking, PlaceID},
+    PetriNet,
+};
+
+///
+/// Outcome of processing a single event in a [`ReplayState`]
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type")]
+pub enum Move {
+    /// The event corresponds to an enabled transition, which was fired
+    Synchronous,
+    /// The event corresponds to a transition which was not enabled
+    ///
+    /// The transition was still fired, by creating the `missing` tokens (i.e., some model behavior was skipped)
+    Model {
+        /// Number of tokens which were missing to fire the transition
+        missing: u64,
+    },
+    /// The event does not correspond to any transition of the Petri net and was ignored (i.e., a move only on the log)
+    Log,
+}
+
+/// Weighted input and output places of a transition
+#[derive(Debug, Clone, Default)]
+struct TransitionEffect {
+    pre: Vec<(PlaceID, u64)>,
+    post: Vec<(PlaceID, u64)>,
+}
+
+///
+/// State for the online token-based replay of a single case on a [`PetriNet`]
+///
+/// Events are processed one by one using [`ReplayState::process`].
+/// Tokens are counted as in the (batch) token-based replay, so that the fitness of the events processed so far
+/// is available at any time (see [`ReplayState::fitness_so_far`]).
+///
+/// As for the (batch) token-based replay, the Petri net must have unique labels and no silent transitions.
+///
+#[derive(Debug, Clone)]
+pub struct ReplayState {
+    transitions_per_label: HashMap<String, TransitionEffect>,
+    initial_marking: Marking,
+    final_marking: Marking,
+    marking: Marking,
+    result: TokenBasedReplayResult,
+    num_log_moves: usize,
+}
+
+impl ReplayState {
+    ///
+    /// Create a new [`ReplayState`] for the given [`PetriNet`], starting in its initial marking
+    ///
+    /// The net needs to have an initial marking, exactly one final marking, unique labels and no silent transitions.
+    ///
+    pub fn new(petri_net: &PetriNet) -> Result<Self, TokenBasedReplayError> {
+        let initial_marking = petri_net
+            .initial_marking
+            .clone()
+            .ok_or(TokenBasedReplayError::NoInitialMarking)?;
+        let final_marking = match petri_net.final_markings.as_deref() {
+            None | Some([]) => return Err(TokenBasedReplayError::NoFinalMarking),
+            Some([m]) => m.clone(),
+            Some(_) => return Err(TokenBasedReplayError::TooManyFinalMarkings),
+        };
+        if petri_net.contains_duplicate_or_silent_transitions() {
+            return Err(TokenBasedReplayError::DuplicateLabelOrSilentTransitionError);
+        }
+        let mut effects: HashMap<_, TransitionEffect> = HashMap::new();
+        for arc in &petri_net.arcs {
+            match arc.from_to {
+                ArcType::PlaceTransition(p, t) => effects
+                    .entry(t)
+                    .or_default()
+                    .pre
+                    .push((PlaceID(p), arc.weight)),
+                ArcType::TransitionPlace(t, p) => effects
+                    .entry(t)
+                    .or_default()
+                    .post
+                    .push((PlaceID(p), arc.weight)),
+            }
+        }
+        // All transitions are labeled uniquely (checked above)
+        let transitions_per_label = petri_net
+            .transitions
+            .iter()
+            .filter_map(|(id, t)| {
+                let label = t.label.clone()?;
+                Some((label, effects.remove(id).unwrap_or_default()))
+            })
+            .collect();
+        let mut ret = Self {
+            transitions_per_label,
+            marking: Marking::new(),
+            initial_marking,
+            final_marking,
+            result: TokenBasedReplayResult::new(),
+            num_log_moves: 0,
+        };
+        ret.reset();
+        Ok(ret)
+    }
+
+    ///
+    /// Reset the state to the initial marking (e.g., to start replaying a new case)
+    ///
+    pub fn reset(&mut self) {
+        self.marking = self.initial_marking.clone();
+        self.result = TokenBasedReplayResult {
+            produced: self.initial_marking.values().sum(),
+            ..Default::default()
+        };
+        self.num_log_moves = 0;
+    }
+
+    fn count_missing(&self, effect: &TransitionEffect) -> u64 {
+        effect
+            .pre
+            .iter()
+            .map(|(p, w)| w.saturating_sub(self.marking.get(p).copied().unwrap_or_default()))
+            .sum()
+    }
+
+    ///
+    /// Process the next event (with the given activity) of the case
+    ///
+    pub fn process(&mut self, activity: &str) -> Move {
+        let Some(effect) = self.transitions_per_label.get(activity) else {
+            self.num_log_moves += 1;
+            return Move::Log;
+        };
+        let missing = self.count_missing(effect);
+        for (p, w) in &effect.pre {
+            let tokens = self.marking.entry(*p).or_default();
+            *tokens = tokens.saturating_sub(*w);
+            self.result.consumed += w;
+        }
+        for (p, w) in &effect.post {
+            *self.marking.entry(*p).or_default() += w;
+            self.result.produced += w;
+        }
+        self.result.missing += missing;
+        if missing == 0 {
+            Move::Synchronous
+        } else {
+            Move::Model { missing }
+        }
+    }
+
+    ///
+    /// Get the current marking
+    ///
+    pub fn marking(&self) -> &Marking {
+        &self.marking
+    }
+
+    ///
+    /// Get the number of processed events which did not correspond to any transition (see [`Move::Log`])
+    ///
+    pub fn num_log_moves(&self) -> usize {
+        self.num_log_moves
+    }
+
+    ///
+    /// Get the token counts of the events processed so far, as if the case ended now
+    ///
+    /// I.e., the tokens of the final marking are consumed from the current marking.
+    ///
+    pub fn result_so_far(&self) -> TokenBasedReplayResult {
+        let mut result = self.result.clone();
+        let mut remaining = self.marking.clone();
+        for (p, w) in &self.final_marking {
+            let tokens = remaining.entry(*p).or_default();
+            result.missing += w.saturating_sub(*tokens);
+            *tokens = tokens.saturating_sub(*w);
+            result.consumed += w;
+        }
+        result.remaining = remaining.values().sum();
+        result
+    }
+
+    ///
+    /// Get the fitness of the events processed so far, as if the case ended now (see [`ReplayState::result_so_far`])
+    ///
+    pub fn fitness_so_far(&self) -> f64 {
+        self.result_so_far().compute_fitness()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sequence of `a` and `b`
+    fn sequence_net() -> PetriNet {
+        let mut net = PetriNet::new();
+        let p1 = net.add_place(None);
+        let p2 = net.add_place(None);
+        let p3 = net.add_place(None);
+        let a = net.add_transition(Some("a".into()), None);
+        let b = net.add_transition(Some("b".into()), None);
+        net.add_arc(ArcType::place_to_transition(p1, a), None);
+        net.add_arc(ArcType::transition_to_place(a, p2), None);
+        net.add_arc(ArcType::place_to_transition(p2, b), None);
+        net.add_arc(ArcType::transition_to_place(b, p3), None);
+        net.initial_marking = Some(Marking::from([(p1, 1)]));
+        net.final_markings = Some(vec![Marking::from([(p3, 1)])]);
+        net
+    }
+
+    #[test]
+    fn online_replay_moves() {
+        let mut state = ReplayState::new(&sequence_net()).unwrap();
+        assert_eq!(state.fitness_so_far(), 0.0);
+        assert_eq!(state.process("a"), Move::Synchronous);
+        assert_eq!(state.process("x"), Move::Log);
+        assert_eq!(state.process("b"), Move::Synchronous);
+        assert_eq!(state.num_log_moves(), 1);
+        assert_eq!(state.fitness_so_far(), 1.0);
+
+        state.reset();
+        assert_eq!(state.process("b"), Move::Model { missing: 1 });
+        let result = state.result_so_far();
+        assert_eq!(result.produced, 2);
+        assert_eq!(result.consumed, 2);
+        assert_eq!(result.missing, 1);
+        assert_eq!(result.remaining, 1);
+        assert_eq!(state.fitness_so_far(), 0.5);
+    }
+
+    #[test]
+    fn online_replay_requires_markings() {
+        let mut net = sequence_net();
+        net.final_markings = None;
+        assert!(matches!(
+            ReplayState::new(&net),
+            Err(TokenBasedReplayError::NoFinalMarking)
+        ));
+    }
+
+    #[test]
+    fn online_replay_rejects_silent_and_duplicate_labels() {
+        let mut net = sequence_net();
+        let p = net.add_place(None);
+        let silent = net.add_transition(None, None);
+        net.add_arc(ArcType::place_to_transition(p, silent), None);
+        assert!(matches!(
+            ReplayState::new(&net),
+            Err(TokenBasedReplayError::DuplicateLabelOrSilentTransitionError)
+        ));
+
+        let mut net = sequence_net();
+        net.add_transition(Some("a".into()), None);
+        assert!(matches!(
+            ReplayState::new(&net),
+            Err(TokenBasedReplayError::DuplicateLabelOrSilentTransitionError)
+        ));
+    }
+}
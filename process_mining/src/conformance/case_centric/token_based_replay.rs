@@ -177,9 +177,42 @@ pub fn apply_token_based_replay(
 }
 
 ///
-/// Changes the [`DMatrix`]'s data type to be [`i64`] from [`u8`]
+/// Fitness report of a token-based replay (see [`check_fitness`])
 ///
-fn change_matrix_type_to_i64(input: &DMatrix<u8>) -> DMatrix<i64> {
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FitnessReport {
+    /// Token-based fitness value between 0 and 1 (see [`TokenBasedReplayResult::compute_fitness`])
+    pub fitness: f64,
+    /// Number of traces replayed
+    pub num_traces: u64,
+    /// Token counts of the replay
+    #[serde(flatten)]
+    pub tokens: TokenBasedReplayResult,
+}
+
+///
+/// Check the token-based fitness of an event log on a Petri net
+///
+/// See [`apply_token_based_replay`] for the requirements on the Petri net.
+///
+#[cfg(feature = "token-based-replay")]
+#[register_binding]
+pub fn check_fitness(
+    net: &PetriNet,
+    log: &EventLogActivityProjection,
+) -> Result<FitnessReport, TokenBasedReplayError> {
+    let tokens = apply_token_based_replay(net, log)?;
+    Ok(FitnessReport {
+        fitness: tokens.compute_fitness(),
+        num_traces: log.traces.iter().map(|(_, freq)| freq).sum(),
+        tokens,
+    })
+}
+
+///
+/// Changes the [`DMatrix`]'s data type to be [`i64`] from [`u64`]
+///
+fn change_matrix_type_to_i64(input: &DMatrix<u64>) -> DMatrix<i64> {
     input.map(|e| e as i64)
 }
 
@@ -294,4 +327,60 @@ mod tests {
         assert_eq!(result_2.missing, 2 + 3);
         assert_eq!(result_2.remaining, 2 + 3);
     }
+
+    #[test]
+    fn token_based_replay_weighted_arcs_test() {
+        let mut net = PetriNet::new();
+        let p1 = net.add_place(None);
+        let p2 = net.add_place(None);
+        let t1 = net.add_transition(Some("a".into()), None);
+        net.add_arc(ArcType::place_to_transition(p1, t1), Some(2));
+        net.add_arc(ArcType::transition_to_place(t1, p2), None);
+        net.initial_marking = Some(Marking::from([(p1, 1)]));
+        net.final_markings = Some(vec![Marking::from([(p2, 1)])]);
+
+        let mut trace = Trace::new();
+        trace.events.push(Event::new("a".to_string()));
+        let mut event_log = EventLog::new();
+        event_log.traces.push(trace);
+
+        let result = apply_token_based_replay(&net, &(&event_log).into()).unwrap();
+        // Firing `a` consumes two tokens from `p1`, but only one is available
+        assert_eq!(result.produced, 2);
+        assert_eq!(result.consumed, 3);
+        assert_eq!(result.missing, 1);
+        assert_eq!(result.remaining, 0);
+    }
+
+    #[test]
+    fn check_fitness_test() {
+        let mut net = PetriNet::new();
+        let p1 = net.add_place(None);
+        let p2 = net.add_place(None);
+        let t1 = net.add_transition(Some("a".into()), None);
+        net.add_arc(ArcType::place_to_transition(p1, t1), None);
+        net.add_arc(ArcType::transition_to_place(t1, p2), None);
+        net.initial_marking = Some(Marking::from([(p1, 1)]));
+        net.final_markings = Some(vec![Marking::from([(p2, 1)])]);
+
+        let mut event_log = EventLog::new();
+        for activities in [vec!["a"], vec!["a"], vec![]] {
+            let mut trace = Trace::new();
+            for a in activities {
+                trace.events.push(Event::new(a.to_string()));
+            }
+            event_log.traces.push(trace);
+        }
+
+        let report = check_fitness(&net, &(&event_log).into()).unwrap();
+        assert_eq!(report.num_traces, 3);
+        // The empty trace misses one token and leaves one remaining
+        assert_eq!(report.tokens.missing, 1);
+        assert_eq!(report.tokens.remaining, 1);
+        assert_eq!(report.tokens.produced, 5);
+        assert_eq!(report.fitness, 0.8);
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["missing"], 1);
+        assert_eq!(json["fitness"], 0.8);
+    }
 }
@@ -1,46 +1,80 @@
 //! Performance-focused implementations of checking OC-DECLARE constraints
-use std::sync::atomic::AtomicU32;
+use std::{
+    ops::{Bound, RangeBounds},
+    sync::atomic::AtomicU32,
+};
 
 use crate::core::{
     event_data::object_centric::linked_ocel::{
         slim_linked_ocel::ObjectIndex, LinkedOCELAccess, SlimLinkedOCEL,
     },
     process_models::oc_declare::{
-        EventOrSynthetic, OCDeclareArc, OCDeclareArcLabel, OCDeclareArcType, SetFilter,
+        EventOrSynthetic, OCDeclareArc, OCDeclareArcLabel, OCDeclareArcType,
+        OCDeclareUnaryConstraint, SetFilter,
     },
 };
 
 use chrono::{DateTime, FixedOffset};
 use macros_process_mining::register_binding;
 use rayon::prelude::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Time range (lower and upper bound) of events
+pub type EventTimeRange = (Bound<DateTime<FixedOffset>>, Bound<DateTime<FixedOffset>>);
 
 /// Get all events of the given event type satisfying the filters
 pub fn get_evs_with_objs_perf<'a>(
     objs: &'a [SetFilter<&ObjectIndex>],
     linked_ocel: &'a SlimLinkedOCEL,
     etype: &'a str,
+) -> impl Iterator<Item = EventOrSynthetic> + use<'a> {
+    get_evs_with_objs_in_range_perf(
+        objs,
+        linked_ocel,
+        etype,
+        (Bound::Unbounded, Bound::Unbounded),
+    )
+}
+
+/// Get all events of the given event type satisfying the filters, which occurred in the given time range
+///
+/// The candidate events of the first filter are binary-searched in the time-sorted E2O index
+/// (see [`SlimLinkedOCEL::get_e2o_rev_by_time`]), instead of scanning and filtering all events of the objects.
+pub fn get_evs_with_objs_in_range_perf<'a>(
+    objs: &'a [SetFilter<&ObjectIndex>],
+    linked_ocel: &'a SlimLinkedOCEL,
+    etype: &'a str,
+    range: EventTimeRange,
 ) -> impl Iterator<Item = EventOrSynthetic> + use<'a> {
     let initial: Box<dyn Iterator<Item = EventOrSynthetic>> = if objs.is_empty() {
-        Box::new(EventOrSynthetic::get_all_syn_evs(linked_ocel, etype).into_iter())
+        Box::new(
+            EventOrSynthetic::get_all_syn_evs(linked_ocel, etype)
+                .into_iter()
+                .filter(move |e| range.contains(&e.get_timestamp(linked_ocel))),
+        )
     } else {
         match &objs[0] {
-            SetFilter::Any(items) => Box::new(
-                items
-                    .iter()
-                    .flat_map(|o| EventOrSynthetic::get_all_of_et_for_ob(linked_ocel, etype, **o)),
-            ),
+            SetFilter::Any(items) => Box::new(items.iter().flat_map(move |o| {
+                EventOrSynthetic::get_all_of_et_for_ob_in_range(linked_ocel, etype, **o, range)
+            })),
             SetFilter::All(items) => {
                 if items.is_empty() {
                     Box::new(Vec::new().into_iter())
                 } else {
                     Box::new(
-                        EventOrSynthetic::get_all_of_et_for_ob(linked_ocel, etype, *items[0])
-                            .filter(|e| {
-                                items
-                                    .iter()
-                                    .skip(1)
-                                    .all(|o| e.get_e2o_set(linked_ocel).contains(o))
-                            }),
+                        EventOrSynthetic::get_all_of_et_for_ob_in_range(
+                            linked_ocel,
+                            etype,
+                            *items[0],
+                            range,
+                        )
+                        .filter(|e| {
+                            items
+                                .iter()
+                                .skip(1)
+                                .all(|o| e.get_e2o_set(linked_ocel).contains(o))
+                        }),
                     )
                 }
             }
@@ -140,6 +174,26 @@ pub fn get_for_all_evs_perf(
     violated_evs_count as f64 / ev_count as f64
 }
 
+/// Get the number of source events violating this constraint arc, together with the total number of source events
+///
+/// Returns the tuple `(number of violating source events, number of source events)`
+pub fn get_for_all_evs_perf_counts(
+    from_et: &str,
+    to_et: &str,
+    label: &OCDeclareArcLabel,
+    arc_type: &OCDeclareArcType,
+    counts: &(Option<usize>, Option<usize>),
+    linked_ocel: &SlimLinkedOCEL,
+) -> (usize, usize) {
+    let evs = EventOrSynthetic::get_all_syn_evs(linked_ocel, from_et);
+    let ev_count = evs.len();
+    let violated_evs_count = evs
+        .into_par_iter()
+        .filter(|ev| get_for_ev_perf(ev, label, to_et, arc_type, counts, linked_ocel))
+        .count();
+    (violated_evs_count, ev_count)
+}
+
 /// Checks whether the number of events violating this constraint arc is below (<=) the given noise threshold
 ///
 /// Returns false, if the fraction of events violating the constraint is above the noise threshold.
@@ -224,63 +278,149 @@ pub fn get_for_ev_perf(
 ) -> bool {
     let syn_time = ev_index.get_timestamp(linked_ocel);
     label.get_bindings(ev_index, linked_ocel).any(|binding| {
-        match arc_type {
-            OCDeclareArcType::AS | OCDeclareArcType::EF | OCDeclareArcType::EP => {
-                let target_ev_iterator = get_evs_with_objs_perf(&binding, linked_ocel, to_et)
-                    .filter(|ev2| {
-                        let ev2_time = ev2.get_timestamp(linked_ocel);
-                        match arc_type {
-                            OCDeclareArcType::EF => syn_time < ev2_time,
-                            OCDeclareArcType::EP => syn_time > ev2_time,
-                            OCDeclareArcType::AS => true,
-                            _ => unreachable!("DF should not go here."),
-                        }
-                    });
-                if counts.1.is_none() {
-                    // Only take necessary
-                    if counts.0.unwrap_or_default()
-                        > target_ev_iterator
-                            .take(counts.0.unwrap_or_default())
-                            .count()
-                    {
-                        // Violated!
-                        return true;
-                    }
-                } else if let Some(c) = counts.1 {
-                    let count = target_ev_iterator.take(c + 1).count();
-                    if c < count || count < counts.0.unwrap_or_default() {
-                        // Violated
-                        return true;
-                    }
-                }
-                false
-            }
-            OCDeclareArcType::DF | OCDeclareArcType::DP => {
-                let df_ev = get_df_or_dp_event_perf(
-                    &binding,
-                    linked_ocel,
-                    &syn_time,
-                    arc_type == &OCDeclareArcType::DF,
-                );
-                let count = if df_ev.is_some_and(|e| e.get_as_event_type(linked_ocel) == to_et) {
-                    1
-                } else {
-                    0
-                };
-                if let Some(min_c) = counts.0 {
-                    if count < min_c {
-                        return true;
-                    }
+        get_binding_violation_perf(&binding, &syn_time, to_et, arc_type, counts, linked_ocel)
+            .is_some()
+    })
+}
+
+/// Check a single binding of a source event (at `syn_time`)
+///
+/// Returns the number of matching target events if the binding is violated, and `None` otherwise.
+/// Target events are only counted as far as necessary (i.e., up to the max count plus one).
+fn get_binding_violation_perf(
+    binding: &[SetFilter<&ObjectIndex>],
+    syn_time: &DateTime<FixedOffset>,
+    to_et: &str,
+    arc_type: &OCDeclareArcType,
+    counts: &(Option<usize>, Option<usize>),
+    linked_ocel: &SlimLinkedOCEL,
+) -> Option<usize> {
+    match arc_type {
+        OCDeclareArcType::AS | OCDeclareArcType::EF | OCDeclareArcType::EP => {
+            let range = match arc_type {
+                OCDeclareArcType::EF => (Bound::Excluded(*syn_time), Bound::Unbounded),
+                OCDeclareArcType::EP => (Bound::Unbounded, Bound::Excluded(*syn_time)),
+                OCDeclareArcType::AS => (Bound::Unbounded, Bound::Unbounded),
+                _ => unreachable!("DF should not go here."),
+            };
+            let target_ev_iterator =
+                get_evs_with_objs_in_range_perf(binding, linked_ocel, to_et, range);
+            if counts.1.is_none() {
+                // Only take necessary
+                let count = target_ev_iterator
+                    .take(counts.0.unwrap_or_default())
+                    .count();
+                if counts.0.unwrap_or_default() > count {
+                    // Violated!
+                    return Some(count);
                 }
-                if let Some(max_c) = counts.1 {
-                    if count > max_c {
-                        return true;
-                    }
+            } else if let Some(c) = counts.1 {
+                let count = target_ev_iterator.take(c + 1).count();
+                if c < count || count < counts.0.unwrap_or_default() {
+                    // Violated
+                    return Some(count);
                 }
-                false
             }
+            None
         }
-    })
+        OCDeclareArcType::DF | OCDeclareArcType::DP => {
+            let df_ev = get_df_or_dp_event_perf(
+                binding,
+                linked_ocel,
+                syn_time,
+                arc_type == &OCDeclareArcType::DF,
+            );
+            let count = if df_ev.is_some_and(|e| e.get_as_event_type(linked_ocel) == to_et) {
+                1
+            } else {
+                0
+            };
+            if counts.0.is_some_and(|min_c| count < min_c)
+                || counts.1.is_some_and(|max_c| count > max_c)
+            {
+                return Some(count);
+            }
+            None
+        }
+    }
+}
+
+/// Concrete violation of an OC-DECLARE arc by a source event (see [`explain_unsatisfied`])
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ViolationInfo {
+    /// ID of the violating source event
+    ///
+    /// For synthetic init/exit source events, this is the ID of the initialized/exited object.
+    pub source_id: String,
+    /// Event type of the source event (e.g., `<init> orders` for synthetic init events)
+    pub source_event_type: String,
+    /// Timestamp of the source event
+    pub time: DateTime<FixedOffset>,
+    /// Violated binding, i.e., the object IDs target events needed to involve (all or any of them, per set filter)
+    pub binding: Vec<SetFilter<String>>,
+    /// Number of matching target events
+    ///
+    /// Target events are only counted as far as necessary (i.e., at most up to the max count plus one).
+    pub num_targets: usize,
+}
+
+/// Get up to `max_examples` source events violating an OC-DECLARE arc, together with the violated binding
+///
+/// In contrast to computing the full conformance (e.g., [`oc_declare_conformance`]), the search stops
+/// as soon as `max_examples` violations were found.
+/// Source events are checked in event index order, and only the first violated binding of each source event is reported.
+#[register_binding]
+pub fn explain_unsatisfied(
+    ocel: &SlimLinkedOCEL,
+    arc: &OCDeclareArc,
+    max_examples: usize,
+) -> Vec<ViolationInfo> {
+    if max_examples == 0 {
+        return Vec::new();
+    }
+    let mut ret = Vec::new();
+    for ev in EventOrSynthetic::get_all_syn_evs(ocel, arc.from.as_str()) {
+        let syn_time = ev.get_timestamp(ocel);
+        let violation = arc.label.get_bindings(&ev, ocel).find_map(|binding| {
+            get_binding_violation_perf(
+                &binding,
+                &syn_time,
+                arc.to.as_str(),
+                &arc.arc_type,
+                &arc.counts,
+                ocel,
+            )
+            .map(|num_targets| (binding, num_targets))
+        });
+        let Some((binding, num_targets)) = violation else {
+            continue;
+        };
+        let source_id = match &ev {
+            EventOrSynthetic::Event(e) => ocel.get_ev_id(e),
+            EventOrSynthetic::Init(o) | EventOrSynthetic::Exit(o) => ocel.get_ob_id(o),
+        };
+        let to_ids = |obs: &[&ObjectIndex]| -> Vec<String> {
+            obs.iter().map(|o| ocel.get_ob_id(*o).to_string()).collect()
+        };
+        ret.push(ViolationInfo {
+            source_id: source_id.to_string(),
+            source_event_type: ev.get_as_event_type(ocel),
+            time: syn_time,
+            binding: binding
+                .iter()
+                .map(|filter| match filter {
+                    SetFilter::Any(obs) => SetFilter::Any(to_ids(obs)),
+                    SetFilter::All(obs) => SetFilter::All(to_ids(obs)),
+                })
+                .collect(),
+            num_targets,
+        });
+        if ret.len() >= max_examples {
+            break;
+        }
+    }
+    ret
 }
 
 #[register_binding]
@@ -297,3 +437,60 @@ pub fn oc_declare_conformance(ocel: &SlimLinkedOCEL, arc: &OCDeclareArc) -> f64
         ocel,
     )
 }
+
+/// Get fraction of instances violating an OC-DECLARE unary constraint
+///
+/// - For [`OCDeclareUnaryConstraint::Existence`], the instances are all objects of the constraint's object type.
+///   An object violates the constraint if the number of events of the activity involving it is outside the given bounds.
+/// - For [`OCDeclareUnaryConstraint::Absence`], the instances are all events.
+///   An event violates the constraint if it is of the activity.
+///
+/// Returns a value from 0 (all instances satisfy this constraint) to 1 (all instances violate this constraint).
+/// If there are no instances, 0 is returned.
+pub fn get_unary_constraint_perf(
+    constraint: &OCDeclareUnaryConstraint,
+    linked_ocel: &SlimLinkedOCEL,
+) -> f64 {
+    match constraint {
+        OCDeclareUnaryConstraint::Existence {
+            activity,
+            object_type,
+            counts,
+        } => {
+            let obs = linked_ocel.get_obs_of_type(object_type).collect::<Vec<_>>();
+            if obs.is_empty() {
+                return 0.0;
+            }
+            let violated_obs_count = obs
+                .par_iter()
+                .filter(|ob| {
+                    let evs = ob.get_e2o_rev_of_evtype(linked_ocel, activity.as_str());
+                    let count = match counts.1 {
+                        Some(max) => evs.take(max + 1).count(),
+                        None => evs.take(counts.0.unwrap_or_default()).count(),
+                    };
+                    count < counts.0.unwrap_or_default() || counts.1.is_some_and(|max| count > max)
+                })
+                .count();
+            violated_obs_count as f64 / obs.len() as f64
+        }
+        OCDeclareUnaryConstraint::Absence { activity } => {
+            let num_evs = linked_ocel.get_all_evs().count();
+            if num_evs == 0 {
+                return 0.0;
+            }
+            linked_ocel.get_evs_of_type(activity.as_str()).count() as f64 / num_evs as f64
+        }
+    }
+}
+
+#[register_binding]
+/// Returns the confidence conformance of an OC-DECLARE unary constraint on the given OCEL
+///
+/// Returns a value from 0.0 (all instances violate this constraint) to 1.0 (all instances satisfy this constraint)
+pub fn oc_declare_unary_conformance(
+    ocel: &SlimLinkedOCEL,
+    constraint: &OCDeclareUnaryConstraint,
+) -> f64 {
+    1.0 - get_unary_constraint_perf(constraint, ocel)
+}
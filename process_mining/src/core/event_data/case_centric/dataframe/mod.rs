@@ -1,11 +1,13 @@
 //! Conversion of Event Data from/to polars `DataFrame`s
 //!
 //! 🔐 Requires the `dataframes` feature to be enabled.
+use crate::diagnostics::warn_diagnostic;
 use std::{collections::HashSet, time::Instant};
 
 use chrono::DateTime;
 use polars::prelude::*;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
 
 use crate::core::{
     event_data::case_centric::{
@@ -68,29 +70,227 @@ fn attribute_value_to_any_value<'a>(from: &AttributeValue) -> AnyValue<'a> {
             let s = v.to_string();
             AnyValue::StringOwned(s.into())
         }
-        // TODO: Add proper List/Container support
+        // Lossy; see `NestedAttributeMode` for re-importable representations
         AttributeValue::List(l) => AnyValue::StringOwned(format!("{l:?}").into()),
         AttributeValue::Container(c) => AnyValue::StringOwned(format!("{c:?}").into()),
         AttributeValue::None() => AnyValue::Null,
     }
 }
 
+///
+/// How nested attribute values (lists and containers) are represented when converting an [`EventLog`] to a [`DataFrame`]
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NestedAttributeMode {
+    /// Store lists and containers as a single string column containing their debug representation
+    #[default]
+    Debug,
+    /// Flatten container attributes into separate columns using dotted keys
+    /// (e.g., a container `address` with child `city` becomes the column `address.city`).
+    /// Containers are flattened recursively.
+    ///
+    /// List attributes are kept in one row and stored as a JSON array string of their element values
+    /// (element keys are dropped, containers inside lists become JSON objects).
+    Flatten,
+    /// Store lists and containers as a single string column containing the JSON serialization of the [`AttributeValue`]
+    /// (e.g., `{"type":"Container","content":[...]}`).
+    ///
+    /// This representation is lossless: [`convert_dataframe_to_log_with_options`] parses such strings back into
+    /// [`AttributeValue::List`] or [`AttributeValue::Container`] values if [`DataFrameToLogOptions::nested_attributes`] is set to this mode.
+    Json,
+}
+
+///
+/// Options for converting an [`EventLog`] to a [`DataFrame`]
+///
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LogToDataFrameOptions {
+    /// Print timing information while converting
+    pub print_debug: bool,
+    /// How list and container attributes are represented
+    pub nested_attributes: NestedAttributeMode,
+}
+
+///
+/// Options for converting a [`DataFrame`] to an [`EventLog`]
+///
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DataFrameToLogOptions {
+    /// How list and container attributes are represented in the [`DataFrame`]
+    ///
+    /// Only [`NestedAttributeMode::Json`] is parsed back into nested attributes; with the other modes, all string values are kept as strings.
+    pub nested_attributes: NestedAttributeMode,
+}
+
+///
+/// Convert an [`AttributeValue`] to a [`serde_json::Value`]
+///
+/// Dates are represented as RFC 3339 strings, lists as arrays of their element values and containers as objects.
+///
+fn attribute_value_to_json(value: &AttributeValue) -> serde_json::Value {
+    match value {
+        AttributeValue::String(s) => serde_json::Value::String(s.clone()),
+        AttributeValue::Date(d) => serde_json::Value::String(d.to_rfc3339()),
+        AttributeValue::Int(i) => serde_json::Value::from(*i),
+        AttributeValue::Float(f) => serde_json::Value::from(*f),
+        AttributeValue::Boolean(b) => serde_json::Value::Bool(*b),
+        AttributeValue::ID(id) => serde_json::Value::String(id.to_string()),
+        AttributeValue::List(l) => l
+            .iter()
+            .map(|a| attribute_value_to_json(&a.value))
+            .collect(),
+        AttributeValue::Container(c) => c
+            .iter()
+            .map(|a| (a.key.clone(), attribute_value_to_json(&a.value)))
+            .collect::<serde_json::Map<_, _>>()
+            .into(),
+        AttributeValue::None() => serde_json::Value::Null,
+    }
+}
+
+///
+/// Flatten container attributes into dotted keys and serialize lists as JSON strings
+///
+/// See [`NestedAttributeMode::Flatten`]
+///
+fn flatten_nested_attributes(attributes: &Attributes) -> Attributes {
+    fn flatten_into(prefix: &str, attributes: &Attributes, out: &mut Attributes) {
+        for a in attributes {
+            let key = if prefix.is_empty() {
+                a.key.clone()
+            } else {
+                format!("{prefix}.{}", a.key)
+            };
+            match &a.value {
+                AttributeValue::Container(c) => flatten_into(&key, c, out),
+                AttributeValue::List(_) => out.push(Attribute::new(
+                    key,
+                    AttributeValue::String(attribute_value_to_json(&a.value).to_string()),
+                )),
+                v => out.push(Attribute::new(key, v.clone())),
+            }
+        }
+    }
+    let mut out = Attributes::with_capacity(attributes.len());
+    flatten_into("", attributes, &mut out);
+    out
+}
+
+///
+/// Serialize list and container attributes as JSON strings
+///
+/// See [`NestedAttributeMode::Json`]
+///
+fn serialize_nested_attributes(attributes: &Attributes) -> Attributes {
+    attributes
+        .iter()
+        .map(|a| match &a.value {
+            AttributeValue::List(_) | AttributeValue::Container(_) => Attribute::new(
+                a.key.clone(),
+                AttributeValue::String(
+                    serde_json::to_string(&a.value)
+                        .expect("Attribute values can always be serialized to JSON"),
+                ),
+            ),
+            _ => a.clone(),
+        })
+        .collect()
+}
+
+///
+/// Parse a JSON string created using [`NestedAttributeMode::Json`] back into a list or container [`AttributeValue`]
+///
+/// Returns `None` if the string is not a serialized list or container.
+///
+fn parse_nested_attribute_json(s: &str) -> Option<AttributeValue> {
+    if !(s.starts_with(r#"{"type":"List""#) || s.starts_with(r#"{"type":"Container""#)) {
+        return None;
+    }
+    match serde_json::from_str(s) {
+        Ok(v @ (AttributeValue::List(_) | AttributeValue::Container(_))) => Some(v),
+        _ => None,
+    }
+}
+
 ///
 /// Convert an [`EventLog`] to a Polars [`DataFrame`]
 ///
 /// Flattens event log and adds trace-level attributes to events with prefixed attribute key (see [`TRACE_PREFIX`])
 ///
+/// Missing trace/event attributes are filled using the global trace/event attributes of the log (if present).
+///
+/// Each attribute becomes a single column with one [`DataType`]. Attributes with values of different types are converted to strings,
+/// except for attributes with both integer and float values: These are widened to a `Float64` column,
+/// so that their integer values are converted back to [`AttributeValue::Float`] by [`convert_dataframe_to_log`].
+///
 /// Note: This function is only available if the `dataframes` feature is enabled.
 ///
 pub fn convert_log_to_dataframe(
     log: &EventLog,
     print_debug: bool,
 ) -> Result<DataFrame, PolarsError> {
+    convert_log_to_dataframe_with_options(
+        log,
+        &LogToDataFrameOptions {
+            print_debug,
+            ..Default::default()
+        },
+    )
+}
+
+///
+/// Convert an [`EventLog`] to a Polars [`DataFrame`] using the provided [`LogToDataFrameOptions`]
+///
+/// See [`convert_log_to_dataframe`] and [`NestedAttributeMode`] for details.
+///
+/// Note: This function is only available if the `dataframes` feature is enabled.
+///
+pub fn convert_log_to_dataframe_with_options(
+    log: &EventLog,
+    options: &LogToDataFrameOptions,
+) -> Result<DataFrame, PolarsError> {
+    let print_debug = options.print_debug;
+    let transformed_log;
+    let transform: Option<fn(&Attributes) -> Attributes> = match options.nested_attributes {
+        NestedAttributeMode::Debug => None,
+        NestedAttributeMode::Flatten => Some(flatten_nested_attributes),
+        NestedAttributeMode::Json => Some(serialize_nested_attributes),
+    };
+    let log = match transform {
+        None => log,
+        Some(transform) => {
+            transformed_log = EventLog {
+                attributes: log.attributes.clone(),
+                traces: log
+                    .traces
+                    .par_iter()
+                    .map(|t| Trace {
+                        attributes: transform(&t.attributes),
+                        events: t
+                            .events
+                            .iter()
+                            .map(|e| Event {
+                                attributes: transform(&e.attributes),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+                extensions: log.extensions.clone(),
+                classifiers: log.classifiers.clone(),
+                global_trace_attrs: log.global_trace_attrs.as_ref().map(transform),
+                global_event_attrs: log.global_event_attrs.as_ref().map(transform),
+            };
+            &transformed_log
+        }
+    };
     if print_debug {
         println!("Starting converting log to DataFrame");
     }
     let mut now = Instant::now();
-    let all_attributes: HashSet<String> = log
+    let mut all_attributes: HashSet<String> = log
         .traces
         .par_iter()
         .flat_map(|t| {
@@ -113,6 +313,19 @@ pub fn convert_log_to_dataframe(
         })
         .flatten()
         .collect();
+    // Global attributes provide default values, even if the attribute is not set explicitly for any trace/event
+    all_attributes.extend(
+        log.global_trace_attrs
+            .iter()
+            .flatten()
+            .map(|a| TRACE_PREFIX.to_string() + a.key.as_str()),
+    );
+    all_attributes.extend(
+        log.global_event_attrs
+            .iter()
+            .flatten()
+            .map(|a| a.key.clone()),
+    );
     if print_debug {
         println!("Gathering all attributes took {:.2?}", now.elapsed());
     }
@@ -151,8 +364,8 @@ pub fn convert_log_to_dataframe(
             let mut unique_dtypes: HashSet<DataType> = entries.iter().map(|v| v.dtype()).collect();
             unique_dtypes.remove(&DataType::Null);
             if unique_dtypes.len() > 1 {
-                eprintln!(
-                    "Warning: Attribute {k} contains values of different dtypes ({unique_dtypes:?})"
+                warn_diagnostic!(
+                    "Attribute {k} contains values of different dtypes ({unique_dtypes:?})"
                 );
                 if unique_dtypes
                     == vec![DataType::Float64, DataType::Int64]
@@ -240,12 +453,31 @@ fn any_value_to_attribute_value(from: &AnyValue<'_>) -> AttributeValue {
 
 /// Convert Polars [`DataFrame`] to [`EventLog`]
 ///
-///  - Extracts attributes as Strings (converting other formats using debug format macro)
+///  - Extracts attributes based on their [`AnyValue`] type, i.e., integer values result in [`AttributeValue::Int`] and float values in [`AttributeValue::Float`]
+///  - Extracts other attributes as Strings (converting other formats using debug format macro)
 ///  - Assumes valid `EventLog` structure of `DataFrame` (i.e., assuming that [`PREFIXED_TRACE_ID_NAME`] is present)
 ///
+/// To parse list and container attributes serialized using [`NestedAttributeMode::Json`], use [`convert_dataframe_to_log_with_options`].
+///
 /// Note: This function is only available if the `dataframes` feature is enabled.
 ///
 pub fn convert_dataframe_to_log(df: &DataFrame) -> Result<EventLog, PolarsError> {
+    convert_dataframe_to_log_with_options(df, &DataFrameToLogOptions::default())
+}
+
+/// Convert Polars [`DataFrame`] to [`EventLog`] using the provided [`DataFrameToLogOptions`]
+///
+/// See [`convert_dataframe_to_log`] for details.
+/// If [`DataFrameToLogOptions::nested_attributes`] is [`NestedAttributeMode::Json`],
+/// string values containing a serialized list or container attribute are parsed back into the nested [`AttributeValue`].
+///
+/// Note: This function is only available if the `dataframes` feature is enabled.
+///
+pub fn convert_dataframe_to_log_with_options(
+    df: &DataFrame,
+    options: &DataFrameToLogOptions,
+) -> Result<EventLog, PolarsError> {
+    let parse_json = options.nested_attributes == NestedAttributeMode::Json;
     let groups = df.partition_by_stable([PREFIXED_TRACE_ID_NAME], true)?;
     let columns = df.get_column_names();
     let mut log = EventLog {
@@ -267,7 +499,14 @@ pub fn convert_dataframe_to_log(df: &DataFrame) -> Result<EventLog, PolarsError>
                         .iter()
                         .zip(g.get_row(i).unwrap().0.iter())
                         .for_each(|(c, v)| {
-                            let value = any_value_to_attribute_value(v);
+                            let value = match v {
+                                AnyValue::String(s) if parse_json => parse_nested_attribute_json(s),
+                                AnyValue::StringOwned(s) if parse_json => {
+                                    parse_nested_attribute_json(s)
+                                }
+                                _ => None,
+                            }
+                            .unwrap_or_else(|| any_value_to_attribute_value(v));
                             match value {
                                 AttributeValue::None() => {
                                     // Skip!
@@ -309,6 +548,7 @@ mod df_xes_tests {
         core::event_data::case_centric::{
             dataframe::convert_log_to_dataframe,
             xes::import_xes::{import_xes_path, XESImportOptions},
+            AttributeValue,
         },
         test_utils::get_test_data_path,
     };
@@ -345,4 +585,199 @@ mod df_xes_tests {
         println!("Total: {:?}\n\n", now_total.elapsed());
         assert_eq!(converted_log.shape(), (num_events, 16));
     }
+
+    #[test]
+    fn global_attributes_as_defaults() {
+        use crate::core::event_data::case_centric::{Attribute, AttributeValue};
+        let mut log = crate::event_log!(["a", "b"], ["c"]);
+        log.global_event_attrs = Some(vec![Attribute::new(
+            "org:resource".to_string(),
+            AttributeValue::String("UNKNOWN".to_string()),
+        )]);
+        let df = convert_log_to_dataframe(&log, false).unwrap();
+        let resources = df.column("org:resource").unwrap();
+        assert_eq!(resources.len(), 3);
+        assert_eq!(resources.str().unwrap().get(0), Some("UNKNOWN"));
+    }
+
+    #[test]
+    fn flatten_nested_attributes() {
+        use crate::core::event_data::case_centric::{
+            dataframe::{
+                convert_log_to_dataframe_with_options, LogToDataFrameOptions, NestedAttributeMode,
+            },
+            Attribute, AttributeValue,
+        };
+        let mut log = crate::event_log!(["a"]);
+        let event = &mut log.traces[0].events[0];
+        event.attributes.push(Attribute::new(
+            "address".to_string(),
+            AttributeValue::Container(vec![
+                Attribute::new("city".to_string(), AttributeValue::String("Aachen".into())),
+                Attribute::new("zip".to_string(), AttributeValue::Int(52062)),
+            ]),
+        ));
+        event.attributes.push(Attribute::new(
+            "tags".to_string(),
+            AttributeValue::List(vec![
+                Attribute::new("tag".to_string(), AttributeValue::String("x".into())),
+                Attribute::new("tag".to_string(), AttributeValue::Int(2)),
+            ]),
+        ));
+        let df = convert_log_to_dataframe_with_options(
+            &log,
+            &LogToDataFrameOptions {
+                nested_attributes: NestedAttributeMode::Flatten,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(df.column("address").is_err());
+        assert_eq!(
+            df.column("address.city").unwrap().str().unwrap().get(0),
+            Some("Aachen")
+        );
+        assert_eq!(
+            df.column("address.zip").unwrap().i64().unwrap().get(0),
+            Some(52062)
+        );
+        assert_eq!(
+            df.column("tags").unwrap().str().unwrap().get(0),
+            Some(r#"["x",2]"#)
+        );
+    }
+
+    #[test]
+    fn numeric_attribute_types_round_trip() {
+        use crate::core::event_data::case_centric::{
+            dataframe::convert_dataframe_to_log, xes::import_xes::import_xes_slice, AttributeValue,
+            XESEditableAttribute,
+        };
+        let xes = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<log xes.version="1.0">
+  <trace>
+    <string key="concept:name" value="c1"/>
+    <int key="priority" value="2"/>
+    <event>
+      <string key="concept:name" value="a"/>
+      <int key="cost" value="5"/>
+      <float key="weight" value="3.0"/>
+      <int key="amount" value="7"/>
+    </event>
+    <event>
+      <string key="concept:name" value="b"/>
+      <float key="weight" value="0.5"/>
+      <float key="amount" value="1.5"/>
+    </event>
+    <event>
+      <string key="concept:name" value="c"/>
+      <int key="cost" value="9007199254740993"/>
+    </event>
+  </trace>
+</log>"#;
+        let log = import_xes_slice(xes.as_bytes(), false, XESImportOptions::default()).unwrap();
+        let df = convert_log_to_dataframe(&log, false).unwrap();
+        let log2 = convert_dataframe_to_log(&df).unwrap();
+        let trace = &log2.traces[0];
+        assert_eq!(
+            trace.attributes.get_by_key("priority").unwrap().value,
+            AttributeValue::Int(2)
+        );
+        let value = |i: usize, key: &str| {
+            trace.events[i]
+                .attributes
+                .get_by_key(key)
+                .map(|a| a.value.clone())
+        };
+        // Attributes missing in some events keep their type
+        assert_eq!(value(0, "cost"), Some(AttributeValue::Int(5)));
+        assert_eq!(value(1, "cost"), None);
+        assert_eq!(
+            value(2, "cost"),
+            Some(AttributeValue::Int(9_007_199_254_740_993))
+        );
+        assert_eq!(value(0, "weight"), Some(AttributeValue::Float(3.0)));
+        assert_eq!(value(1, "weight"), Some(AttributeValue::Float(0.5)));
+        assert_eq!(value(2, "weight"), None);
+        // Attributes with both integer and float values are widened to floats
+        assert_eq!(value(0, "amount"), Some(AttributeValue::Float(7.0)));
+        assert_eq!(value(1, "amount"), Some(AttributeValue::Float(1.5)));
+    }
+
+    #[test]
+    fn json_nested_attributes_round_trip() {
+        use crate::core::event_data::case_centric::{
+            dataframe::{
+                convert_dataframe_to_log, convert_dataframe_to_log_with_options,
+                convert_log_to_dataframe_with_options, DataFrameToLogOptions,
+                LogToDataFrameOptions, NestedAttributeMode,
+            },
+            xes::import_xes::import_xes_slice,
+            XESEditableAttribute,
+        };
+        let xes = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<log xes.version="2.0" xes.features="nested-attributes">
+  <trace>
+    <string key="concept:name" value="c1"/>
+    <event>
+      <string key="concept:name" value="a"/>
+      <container key="container-test">
+        <string key="name" value="Bridge"/>
+        <float key="length" value="12.5"/>
+        <container key="inner">
+          <int key="spans" value="3"/>
+        </container>
+      </container>
+      <list key="list-test">
+        <int key="value" value="1"/>
+        <date key="value" value="2020-01-01T00:00:00.000+01:00"/>
+        <string key="value" value="x"/>
+      </list>
+    </event>
+  </trace>
+</log>"#;
+        let log = import_xes_slice(xes.as_bytes(), false, XESImportOptions::default()).unwrap();
+        let df = convert_log_to_dataframe_with_options(
+            &log,
+            &LogToDataFrameOptions {
+                nested_attributes: NestedAttributeMode::Json,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let container_json = df.column("container-test").unwrap().str().unwrap().get(0);
+        assert!(container_json.is_some_and(|s| s.starts_with(r#"{"type":"Container""#)));
+
+        // Without opting in, JSON strings are kept as strings
+        let log_strings = convert_dataframe_to_log(&df).unwrap();
+        assert_eq!(
+            log_strings.traces[0].events[0]
+                .attributes
+                .get_by_key("container-test")
+                .map(|a| &a.value),
+            container_json
+                .map(|s| AttributeValue::String(s.to_string()))
+                .as_ref()
+        );
+
+        let log2 = convert_dataframe_to_log_with_options(
+            &df,
+            &DataFrameToLogOptions {
+                nested_attributes: NestedAttributeMode::Json,
+            },
+        )
+        .unwrap();
+        let original = &log.traces[0].events[0].attributes;
+        let round_tripped = &log2.traces[0].events[0].attributes;
+        for key in ["container-test", "list-test"] {
+            assert_eq!(
+                round_tripped.get_by_key(key).map(|a| &a.value),
+                original.get_by_key(key).map(|a| &a.value)
+            );
+        }
+        assert!(matches!(
+            round_tripped.get_by_key("list-test").unwrap().value,
+            AttributeValue::List(ref l) if l.len() == 3
+        ));
+    }
 }
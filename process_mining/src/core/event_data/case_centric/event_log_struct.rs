@@ -1,6 +1,7 @@
 use chrono::{DateTime, FixedOffset};
-use macros_process_mining::RegistryEntity;
+use macros_process_mining::{register_binding, RegistryEntity};
 use ordered_float::OrderedFloat;
+use rayon::prelude::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -164,6 +165,50 @@ impl Hash for AttributeValue {
 impl Eq for AttributeValue {}
 
 impl AttributeValue {
+    ///
+    /// Construct an attribute value from a raw JSON [`serde_json::Value`], coercing it to the most fitting variant
+    ///
+    /// - Integral numbers (fitting into [`i64`]) become [`AttributeValue::Int`], all other numbers [`AttributeValue::Float`]
+    /// - Booleans, as well as the strings `"true"` and `"false"`, become [`AttributeValue::Boolean`]
+    /// - RFC 3339 timestamp strings become [`AttributeValue::Date`], all other strings [`AttributeValue::String`]
+    /// - Arrays become an [`AttributeValue::List`] (with the index of each element as key)
+    /// - Objects become an [`AttributeValue::Container`]
+    /// - `null` becomes [`AttributeValue::None`]
+    ///
+    /// Note that this does _not_ parse the serde representation of [`AttributeValue`] (i.e., with `type` and `content` fields).
+    ///
+    pub fn from_json_value(v: &serde_json::Value) -> AttributeValue {
+        use serde_json::Value;
+        match v {
+            Value::Null => AttributeValue::None(),
+            Value::Bool(b) => AttributeValue::Boolean(*b),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => AttributeValue::Int(i),
+                None => AttributeValue::Float(n.as_f64().unwrap_or(f64::NAN)),
+            },
+            Value::String(s) => match s.as_str() {
+                "true" => AttributeValue::Boolean(true),
+                "false" => AttributeValue::Boolean(false),
+                _ => match DateTime::parse_from_rfc3339(s) {
+                    Ok(dt) => AttributeValue::Date(dt),
+                    Err(_) => AttributeValue::String(s.clone()),
+                },
+            },
+            Value::Array(values) => AttributeValue::List(
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| Attribute::new(i.to_string(), AttributeValue::from_json_value(v)))
+                    .collect(),
+            ),
+            Value::Object(map) => AttributeValue::Container(
+                map.iter()
+                    .map(|(k, v)| Attribute::new(k.clone(), AttributeValue::from_json_value(v)))
+                    .collect(),
+            ),
+        }
+    }
+
     ///
     /// Try to get attribute value as String
     ///
@@ -443,6 +488,25 @@ impl XESEditableAttribute for Attributes {
     }
 }
 
+/// Set the value of the attribute with the given key, adding it if not present yet
+fn set_attribute(attributes: &mut Attributes, key: &str, value: AttributeValue) {
+    match attributes.get_by_key_mut(key) {
+        Some(a) => a.value = value,
+        None => attributes.add_to_attributes(key.to_string(), value),
+    }
+}
+
+/// Rename the attribute with key `old_key` (if present), replacing any attribute with key `new_key`
+fn rename_attribute_key(attributes: &mut Attributes, old_key: &str, new_key: &str) {
+    if attributes.get_by_key(old_key).is_none() {
+        return;
+    }
+    attributes.retain(|a| a.key != new_key);
+    for a in attributes.iter_mut().filter(|a| a.key == old_key) {
+        a.key = new_key.to_string();
+    }
+}
+
 /// Covert a [`HashMap`] of attributes to a [`Attributes`] representation
 pub fn to_attributes(from: HashMap<String, AttributeValue>) -> Attributes {
     from.into_iter()
@@ -585,6 +649,131 @@ impl EventLog {
             .get_by_key_or_global(key, &self.global_trace_attrs)
     }
 
+    ///
+    /// Add an attribute with the given key to every event of the log, computing its value using `value_fn`
+    ///
+    /// If an event already has an attribute with this key, its value is replaced.
+    ///
+    /// Events are processed in parallel.
+    ///
+    pub fn add_event_attribute(
+        &mut self,
+        key: &str,
+        value_fn: impl Fn(&Event) -> AttributeValue + Sync,
+    ) {
+        self.traces.par_iter_mut().for_each(|t| {
+            t.events.iter_mut().for_each(|e| {
+                let value = value_fn(e);
+                set_attribute(&mut e.attributes, key, value);
+            });
+        });
+    }
+
+    ///
+    /// Add an attribute with the given key to every trace of the log, computing its value using `value_fn`
+    ///
+    /// If a trace already has an attribute with this key, its value is replaced.
+    ///
+    /// Traces are processed in parallel.
+    ///
+    pub fn add_trace_attribute(
+        &mut self,
+        key: &str,
+        value_fn: impl Fn(&Trace) -> AttributeValue + Sync,
+    ) {
+        self.traces.par_iter_mut().for_each(|t| {
+            let value = value_fn(t);
+            set_attribute(&mut t.attributes, key, value);
+        });
+    }
+
+    ///
+    /// Rename the attribute `old_key` to `new_key` for all traces and events of the log
+    ///
+    /// The global trace/event attributes and the keys of all classifiers are renamed as well.
+    /// If an event or trace has both attributes, the existing `new_key` attribute is replaced.
+    ///
+    /// Traces are processed in parallel.
+    ///
+    pub fn rename_attribute(&mut self, old_key: &str, new_key: &str) {
+        if old_key == new_key {
+            return;
+        }
+        self.traces.par_iter_mut().for_each(|t| {
+            rename_attribute_key(&mut t.attributes, old_key, new_key);
+            t.events
+                .iter_mut()
+                .for_each(|e| rename_attribute_key(&mut e.attributes, old_key, new_key));
+        });
+        for attrs in [&mut self.global_trace_attrs, &mut self.global_event_attrs]
+            .into_iter()
+            .flatten()
+        {
+            rename_attribute_key(attrs, old_key, new_key);
+        }
+        for classifier in self.classifiers.iter_mut().flatten() {
+            for key in classifier.keys.iter_mut().filter(|k| *k == old_key) {
+                *key = new_key.to_string();
+            }
+        }
+    }
+
+    ///
+    /// Replace the activity labels (i.e., the [`ACTIVITY_NAME`] attribute) of all events using the given mapping
+    ///
+    /// Activities not contained in the mapping are kept as is.
+    ///
+    /// Traces are processed in parallel.
+    ///
+    pub fn remap_activity_labels(&mut self, mapping: &HashMap<String, String>) {
+        self.traces.par_iter_mut().for_each(|t| {
+            for e in &mut t.events {
+                if let Some(Attribute {
+                    value: AttributeValue::String(activity),
+                    ..
+                }) = e.attributes.get_by_key_mut(ACTIVITY_NAME)
+                {
+                    if let Some(new_activity) = mapping.get(activity) {
+                        activity.clone_from(new_activity);
+                    }
+                }
+            }
+        });
+    }
+
+    ///
+    /// Get the variants (i.e., distinct sequences of event classes) of this log with their number of traces
+    ///
+    /// Event classes are determined by the given [`EventLogClassifier`] (see [`EventLogClassifier::get_class_identity_with_globals`]).
+    /// Variants are sorted descending by frequency, and variants with the same frequency are sorted lexicographically.
+    ///
+    pub fn variants(&self, classifier: &EventLogClassifier) -> Vec<(Vec<String>, u64)> {
+        let mut ret: Vec<(Vec<String>, u64)> = self
+            .traces_per_variant(classifier)
+            .into_iter()
+            .map(|(variant, traces)| (variant, traces.len() as u64))
+            .collect();
+        ret.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+        ret
+    }
+
+    /// Get the indices of the traces of each variant (see [`EventLog::variants`])
+    pub(crate) fn traces_per_variant(
+        &self,
+        classifier: &EventLogClassifier,
+    ) -> HashMap<Vec<String>, Vec<usize>> {
+        let mut traces_per_variant: HashMap<Vec<String>, Vec<usize>> = HashMap::new();
+        for (i, t) in self.traces.iter().enumerate() {
+            let variant = t
+                .events
+                .iter()
+                .map(|e| classifier.get_class_identity_with_globals(e, &self.global_event_attrs))
+                .collect();
+            traces_per_variant.entry(variant).or_default().push(i);
+        }
+        traces_per_variant
+    }
+
     #[cfg(feature = "dataframes")]
     ///
     /// Convert this [`EventLog`] to a Polars [`DataFrame`]
@@ -696,3 +885,202 @@ impl EventLogClassifier {
         ret
     }
 }
+
+/// Set a top-level attribute of an [`EventLog`] from a raw JSON value
+///
+/// The value is coerced using [`AttributeValue::from_json_value`].
+/// An existing attribute with the same key is replaced.
+#[register_binding]
+pub fn set_log_attribute(log: &mut EventLog, key: impl AsRef<str>, value: serde_json::Value) {
+    let key = key.as_ref();
+    let value = AttributeValue::from_json_value(&value);
+    match log.attributes.get_by_key_mut(key) {
+        Some(attr) => attr.value = value,
+        None => log.attributes.add_to_attributes(key.to_string(), value),
+    }
+}
+
+/// Get the `top_k` most frequent variants of an [`EventLog`] with their number of traces
+///
+/// Events are classified by their activity (i.e., using the default [`EventLogClassifier`]).
+/// See [`EventLog::variants`] for the ordering of variants.
+#[register_binding]
+pub fn log_variants(log: &EventLog, #[bind(default = 10)] top_k: usize) -> Vec<(Vec<String>, u64)> {
+    let mut variants = log.variants(&EventLogClassifier::default());
+    variants.truncate(top_k);
+    variants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_log;
+
+    #[test]
+    fn test_add_event_and_trace_attributes() {
+        let mut log = event_log!(["a", "b"], ["c"]);
+        log.add_event_attribute("activity_length", |e| {
+            AttributeValue::Int(
+                e.attributes
+                    .get_by_key(ACTIVITY_NAME)
+                    .and_then(|a| a.value.try_as_string())
+                    .map(|s| s.len() as i64)
+                    .unwrap_or_default(),
+            )
+        });
+        log.add_trace_attribute("num_events", |t| AttributeValue::Int(t.events.len() as i64));
+        // Existing attributes are replaced instead of duplicated
+        log.add_trace_attribute("num_events", |t| {
+            AttributeValue::Int(2 * t.events.len() as i64)
+        });
+
+        for t in &log.traces {
+            assert_eq!(
+                t.attributes
+                    .iter()
+                    .filter(|a| a.key == "num_events")
+                    .map(|a| a.value.clone())
+                    .collect::<Vec<_>>(),
+                vec![AttributeValue::Int(2 * t.events.len() as i64)]
+            );
+            for e in &t.events {
+                assert_eq!(
+                    e.attributes
+                        .get_by_key("activity_length")
+                        .and_then(|a| a.value.try_as_int()),
+                    Some(&1)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_variants() {
+        let log = event_log!(
+            ["a", "c"],
+            ["a", "b"],
+            ["b"],
+            ["a", "c"],
+            ["b"],
+            ["a", "b"],
+            ["c", "a"]
+        );
+        let variants = log.variants(&EventLogClassifier::default());
+        let expected = [
+            (vec!["a", "b"], 2),
+            (vec!["a", "c"], 2),
+            (vec!["b"], 2),
+            (vec!["c", "a"], 1),
+        ];
+        assert_eq!(
+            variants,
+            expected
+                .iter()
+                .map(|(v, c)| (v.iter().map(|a| a.to_string()).collect(), *c))
+                .collect::<Vec<(Vec<String>, u64)>>()
+        );
+        assert_eq!(log_variants(&log, 2), variants[..2]);
+    }
+
+    #[test]
+    fn test_attribute_value_from_json_value() {
+        let v = serde_json::json!({
+            "int": 42,
+            "float": 4.2,
+            "bool": "true",
+            "date": "2024-01-01T12:00:00+01:00",
+            "text": "hello",
+            "list": [1, null],
+        });
+        let AttributeValue::Container(attrs) = AttributeValue::from_json_value(&v) else {
+            panic!("Objects should be converted to containers");
+        };
+        let get = |key: &str| attrs.get_by_key(key).unwrap().value.clone();
+        assert_eq!(get("int"), AttributeValue::Int(42));
+        assert_eq!(get("float"), AttributeValue::Float(4.2));
+        assert_eq!(get("bool"), AttributeValue::Boolean(true));
+        assert_eq!(
+            get("date"),
+            AttributeValue::Date(
+                DateTime::parse_from_rfc3339("2024-01-01T12:00:00+01:00").unwrap()
+            )
+        );
+        assert_eq!(get("text"), AttributeValue::String("hello".to_string()));
+        assert_eq!(
+            get("list"),
+            AttributeValue::List(vec![
+                Attribute::new("0".to_string(), AttributeValue::Int(1)),
+                Attribute::new("1".to_string(), AttributeValue::None()),
+            ])
+        );
+
+        let mut log = event_log!(["a"]);
+        set_log_attribute(&mut log, "source", serde_json::json!("erp"));
+        set_log_attribute(&mut log, "source", serde_json::json!(3));
+        assert_eq!(
+            log.attributes.iter().filter(|a| a.key == "source").count(),
+            1
+        );
+        assert_eq!(
+            log.attributes.get_by_key("source").unwrap().value,
+            AttributeValue::Int(3)
+        );
+    }
+
+    #[test]
+    fn test_rename_attribute_and_remap_activities() {
+        let mut log = event_log!(["a", "b"], ["c", "a"]);
+        log.rename_attribute(ACTIVITY_NAME, "Activity");
+        assert!(log.traces.iter().flat_map(|t| &t.events).all(|e| e
+            .attributes
+            .get_by_key(ACTIVITY_NAME)
+            .is_none()
+            && e.attributes.get_by_key("Activity").is_some()));
+
+        log.global_event_attrs = Some(vec![Attribute::new(
+            "Activity".to_string(),
+            AttributeValue::String("UNKNOWN".to_string()),
+        )]);
+        log.classifiers = Some(vec![EventLogClassifier {
+            name: "Activity".to_string(),
+            keys: vec!["Activity".to_string()],
+        }]);
+        log.add_event_attribute(ACTIVITY_NAME, |_| AttributeValue::Int(0));
+        log.rename_attribute("Activity", ACTIVITY_NAME);
+        assert_eq!(
+            log.global_event_attrs.as_ref().unwrap()[0].key,
+            ACTIVITY_NAME
+        );
+        assert_eq!(log.classifiers.as_ref().unwrap()[0].keys, [ACTIVITY_NAME]);
+
+        log.remap_activity_labels(&HashMap::from([
+            ("a".to_string(), "x".to_string()),
+            ("b".to_string(), "a".to_string()),
+        ]));
+        let acts: Vec<Vec<&str>> = log
+            .traces
+            .iter()
+            .map(|t| {
+                t.events
+                    .iter()
+                    .map(|e| {
+                        // Replaced attribute is removed, so each event has exactly one activity
+                        assert_eq!(
+                            e.attributes
+                                .iter()
+                                .filter(|a| a.key == ACTIVITY_NAME)
+                                .count(),
+                            1
+                        );
+                        e.attributes
+                            .get_by_key(ACTIVITY_NAME)
+                            .and_then(|a| a.value.try_as_string())
+                            .unwrap()
+                            .as_str()
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(acts, vec![vec!["x", "a"], vec!["c", "x"]]);
+    }
+}
@@ -20,6 +20,9 @@ pub enum EventLogIOError {
     Json(serde_json::Error),
     /// XML Parsing Error
     Xml(quick_xml::Error),
+    /// Binary (bincode) (De-)Serialization Error
+    #[cfg(feature = "bincode")]
+    Bincode(bincode::Error),
     /// Unsupported Format
     UnsupportedFormat(String),
 }
@@ -31,6 +34,8 @@ impl std::fmt::Display for EventLogIOError {
             EventLogIOError::Xes(e) => write!(f, "XES Error: {}", e),
             EventLogIOError::Json(e) => write!(f, "JSON Error: {}", e),
             EventLogIOError::Xml(e) => write!(f, "XML Error: {}", e),
+            #[cfg(feature = "bincode")]
+            EventLogIOError::Bincode(e) => write!(f, "Bincode Error: {}", e),
             EventLogIOError::UnsupportedFormat(s) => write!(f, "Unsupported Format: {}", s),
         }
     }
@@ -43,6 +48,8 @@ impl std::error::Error for EventLogIOError {
             EventLogIOError::Xes(e) => Some(e),
             EventLogIOError::Json(e) => Some(e),
             EventLogIOError::Xml(e) => Some(e),
+            #[cfg(feature = "bincode")]
+            EventLogIOError::Bincode(e) => Some(e),
             EventLogIOError::UnsupportedFormat(_) => None,
         }
     }
@@ -66,6 +73,13 @@ impl From<serde_json::Error> for EventLogIOError {
     }
 }
 
+#[cfg(feature = "bincode")]
+impl From<bincode::Error> for EventLogIOError {
+    fn from(e: bincode::Error) -> Self {
+        EventLogIOError::Bincode(e)
+    }
+}
+
 impl From<quick_xml::Error> for EventLogIOError {
     fn from(e: quick_xml::Error) -> Self {
         EventLogIOError::Xml(e)
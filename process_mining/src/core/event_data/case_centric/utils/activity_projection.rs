@@ -3,8 +3,11 @@
 //! Only considers traces as sequences of activities.
 //!
 //! Cases with the same activity trace are aggregated as frequencies.
+use crate::diagnostics::warn_diagnostic;
 use std::collections::{HashMap, HashSet};
 
+#[cfg(feature = "bincode")]
+use bincode::Options;
 use macros_process_mining::{register_binding, RegistryEntity};
 use rayon::prelude::*;
 use schemars::JsonSchema;
@@ -14,8 +17,8 @@ use crate::core::event_data::case_centric::io::EventLogIOError;
 use crate::core::io::{Exportable, ExtensionWithMime, Importable};
 use crate::core::{
     event_data::case_centric::{
-        constants::ACTIVITY_NAME, xes::stream_xes::XESParsingTraceStream, Attribute,
-        AttributeValue, Event, XESEditableAttribute,
+        constants::ACTIVITY_NAME, xes::stream_xes::XESParsingTraceStream, AttributeValue, Event,
+        EventLogClassifier, XESEditableAttribute,
     },
     EventLog,
 };
@@ -104,31 +107,79 @@ pub fn log_to_activity_projection(log: &EventLog) -> EventLogActivityProjection
 
 impl From<&EventLog> for EventLogActivityProjection {
     fn from(val: &EventLog) -> Self {
-        let acts_per_trace: Vec<Vec<String>> = val
-            .traces
-            .par_iter()
-            .map(|t| -> Vec<String> {
-                t.events
-                    .iter()
-                    .map(|e| {
-                        match e
-                            .attributes
-                            .get_by_key(ACTIVITY_NAME)
-                            .cloned()
-                            .unwrap_or(Attribute {
-                                key: ACTIVITY_NAME.into(),
-                                value: AttributeValue::String("No Activity".into()),
-                                own_attributes: None,
-                            })
-                            .value
-                        {
-                            AttributeValue::String(s) => s,
-                            _ => "No Activity".into(),
+        let (projection, num_unlabeled) = log_to_activity_projection_with_unlabeled_count(val);
+        if num_unlabeled > 0 {
+            warn_diagnostic!(
+                "{num_unlabeled} events have no activity ({ACTIVITY_NAME}) and are projected to \"No Activity\""
+            );
+        }
+        projection
+    }
+}
+
+/// Convert an [`EventLog`] into an [`EventLogActivityProjection`], also returning the number of unlabeled events
+///
+/// The activity of an event is read from [`ACTIVITY_NAME`], falling back to the global event attributes of the log
+/// (see [`XESEditableAttribute::get_by_key_or_global`]).
+/// Events without a (string) activity in both are projected to `"No Activity"` and counted as unlabeled.
+pub fn log_to_activity_projection_with_unlabeled_count(
+    log: &EventLog,
+) -> (EventLogActivityProjection, usize) {
+    let (acts_per_trace, num_unlabeled): (Vec<Vec<String>>, Vec<usize>) = log
+        .traces
+        .par_iter()
+        .map(|t| {
+            let mut num_unlabeled = 0;
+            let acts = t
+                .events
+                .iter()
+                .map(|e| {
+                    match e
+                        .attributes
+                        .get_by_key_or_global(ACTIVITY_NAME, &log.global_event_attrs)
+                        .map(|a| &a.value)
+                    {
+                        Some(AttributeValue::String(s)) => s.clone(),
+                        _ => {
+                            num_unlabeled += 1;
+                            "No Activity".to_string()
                         }
-                    })
-                    .collect::<Vec<String>>()
-            })
-            .collect();
+                    }
+                })
+                .collect::<Vec<String>>();
+            (acts, num_unlabeled)
+        })
+        .unzip();
+    (
+        EventLogActivityProjection::from_activity_traces(acts_per_trace),
+        num_unlabeled.into_iter().sum(),
+    )
+}
+
+/// Convert an [`EventLog`] into an [`EventLogActivityProjection`] using the passed [`EventLogClassifier`] to derive activities
+///
+/// All traces with the same activity sequence are aggregated into one trace with a frequency count.
+/// Global event attributes of the log are used as fallback (see [`EventLogClassifier::get_class_identity_with_globals`]).
+pub fn log_to_activity_projection_with_classifier(
+    log: &EventLog,
+    classifier: &EventLogClassifier,
+) -> EventLogActivityProjection {
+    let acts_per_trace: Vec<Vec<String>> = log
+        .traces
+        .par_iter()
+        .map(|t| {
+            t.events
+                .iter()
+                .map(|e| classifier.get_class_identity_with_globals(e, &log.global_event_attrs))
+                .collect()
+        })
+        .collect();
+    EventLogActivityProjection::from_activity_traces(acts_per_trace)
+}
+
+impl EventLogActivityProjection {
+    /// Construct an [`EventLogActivityProjection`] from the activity sequences of all traces
+    fn from_activity_traces(acts_per_trace: Vec<Vec<String>>) -> Self {
         let activity_set: HashSet<&String> = acts_per_trace.iter().flatten().collect();
         let activities: Vec<String> = activity_set.into_iter().cloned().collect();
         let act_to_index: HashMap<String, usize> = activities
@@ -154,9 +205,43 @@ impl From<&EventLog> for EventLogActivityProjection {
             traces,
         }
     }
-}
 
-impl EventLogActivityProjection {
+    /// Filter the projection to only contain the activities in `keep`
+    ///
+    /// All other activities are removed from every trace, and `activities`/`act_to_index` are rebuilt
+    /// (retaining the relative order of the kept activities).
+    /// Traces which become identical are merged, summing up their frequencies.
+    /// Traces consisting only of removed activities are kept as empty traces.
+    ///
+    /// The returned traces are sorted by frequency (descending) and then by their activity indices.
+    pub fn filter_activities(&self, keep: &HashSet<String>) -> EventLogActivityProjection {
+        let mut new_index: Vec<Option<usize>> = vec![None; self.activities.len()];
+        let mut activities = Vec::new();
+        for (i, act) in self.activities.iter().enumerate() {
+            if keep.contains(act) {
+                new_index[i] = Some(activities.len());
+                activities.push(act.clone());
+            }
+        }
+        let act_to_index: HashMap<String, usize> = activities
+            .iter()
+            .enumerate()
+            .map(|(i, act)| (act.clone(), i))
+            .collect();
+        let mut traces_map: HashMap<Vec<usize>, u64> = HashMap::new();
+        for (trace, freq) in &self.traces {
+            let filtered: Vec<usize> = trace.iter().filter_map(|a| new_index[*a]).collect();
+            *traces_map.entry(filtered).or_default() += freq;
+        }
+        let mut traces: Vec<_> = traces_map.into_iter().collect();
+        traces.sort_by(|(t1, f1), (t2, f2)| f2.cmp(f1).then_with(|| t1.cmp(t2)));
+        EventLogActivityProjection {
+            activities,
+            act_to_index,
+            traces,
+        }
+    }
+
     /// Reconstructs sorted activity name from a list of indices
     ///
     /// Uses the internal index -> activity mapping.
@@ -190,6 +275,62 @@ pub struct ProcessVariant {
     pub percentage: f64,
 }
 
+/// Default separator between activities in variant strings (see [`encode_variant`])
+pub const DEFAULT_VARIANT_SEPARATOR: char = ',';
+
+/// Escape character used in variant strings (see [`encode_variant`])
+pub const VARIANT_ESCAPE_CHAR: char = '\\';
+
+///
+/// Encode an activity sequence as a single string, separating activities by `separator`
+///
+/// Occurrences of the separator (and of the escape character [`VARIANT_ESCAPE_CHAR`]) in activity names
+/// are escaped by prefixing them with [`VARIANT_ESCAPE_CHAR`], so that activity names containing the separator
+/// are decoded correctly by [`decode_variant`].
+///
+/// Note, that the empty sequence and the sequence consisting only of an empty activity name
+/// are both encoded as the empty string (and decoded as the empty sequence).
+pub fn encode_variant<S: AsRef<str>>(activities: &[S], separator: char) -> String {
+    let mut ret = String::new();
+    for (i, act) in activities.iter().enumerate() {
+        if i > 0 {
+            ret.push(separator);
+        }
+        for c in act.as_ref().chars() {
+            if c == separator || c == VARIANT_ESCAPE_CHAR {
+                ret.push(VARIANT_ESCAPE_CHAR);
+            }
+            ret.push(c);
+        }
+    }
+    ret
+}
+
+///
+/// Decode a variant string created by [`encode_variant`] into the activity sequence
+///
+/// The same `separator` as for encoding has to be used.
+/// A trailing (unmatched) escape character is kept as is.
+pub fn decode_variant(variant: &str, separator: char) -> Vec<String> {
+    if variant.is_empty() {
+        return Vec::new();
+    }
+    let mut ret = Vec::new();
+    let mut current = String::new();
+    let mut chars = variant.chars();
+    while let Some(c) = chars.next() {
+        if c == VARIANT_ESCAPE_CHAR {
+            current.push(chars.next().unwrap_or(VARIANT_ESCAPE_CHAR));
+        } else if c == separator {
+            ret.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    ret.push(current);
+    ret
+}
+
 #[register_binding]
 /// Get the number of distinct trace variants in the projection
 pub fn get_num_variants(projection: &EventLogActivityProjection) -> usize {
@@ -259,39 +400,77 @@ pub fn get_top_n_variants(
     get_variants_iter(projection).take(n).collect()
 }
 
+/// Options for adding artificial start and end activities to traces
+///
+/// See [`add_start_end_acts_proj_with_options`] and [`add_start_end_acts_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StartEndActivityOptions {
+    /// Name of the artificial start activity
+    pub start: String,
+    /// Name of the artificial end activity
+    pub end: String,
+    /// Do not insert the start (end) activity into traces which already begin (end) with it
+    ///
+    /// This makes adding start and end activities idempotent, e.g., when chaining preprocessing steps.
+    pub skip_if_present: bool,
+}
+
+impl Default for StartEndActivityOptions {
+    fn default() -> Self {
+        Self {
+            start: START_ACTIVITY.to_string(),
+            end: END_ACTIVITY.to_string(),
+            skip_if_present: false,
+        }
+    }
+}
+
 ///
 /// Add artificial start and end activities to a given [`EventLogActivityProjection`]
 ///
 /// Mutating the [`EventLogActivityProjection`] in place
 /// Additionally also checks if artificial [`START_ACTIVITY`] or [`END_ACTIVITY`] are already present in log
 ///
+/// See [`add_start_end_acts_proj_named`] for using custom names for the artificial activities.
+///
 pub fn add_start_end_acts_proj(log: &mut EventLogActivityProjection) {
+    add_start_end_acts_proj_named(log, START_ACTIVITY, END_ACTIVITY)
+}
+
+///
+/// Add artificial start and end activities with the given names to a given [`EventLogActivityProjection`]
+///
+/// Mutating the [`EventLogActivityProjection`] in place (including `activities` and `act_to_index`)
+/// Additionally also checks if the `start` or `end` activity are already present in log
+///
+pub fn add_start_end_acts_proj_named(log: &mut EventLogActivityProjection, start: &str, end: &str) {
     let mut should_add_start = true;
-    let start_act = match log.act_to_index.get(START_ACTIVITY) {
+    let start_act = match log.act_to_index.get(start) {
         Some(a) => {
-            eprintln!("Start activity ({START_ACTIVITY}) already present in activity set! Will skip adding a start activity to every trace, which might not be the desired outcome.");
+            warn_diagnostic!("Start activity ({start}) already present in activity set! Will skip adding a start activity to every trace, which might not be the desired outcome.");
             should_add_start = false;
             *a
         }
         None => {
             let a = log.activities.len();
-            log.activities.push(START_ACTIVITY.to_string());
-            log.act_to_index.insert(START_ACTIVITY.to_string(), a);
+            log.activities.push(start.to_string());
+            log.act_to_index.insert(start.to_string(), a);
             a
         }
     };
 
     let mut should_add_end = true;
-    let end_act = match log.act_to_index.get(END_ACTIVITY) {
+    let end_act = match log.act_to_index.get(end) {
         Some(a) => {
-            eprintln!("End activity ({END_ACTIVITY}) already present in activity set! Still adding an end activity to every trace, which might not be the desired outcome.");
+            warn_diagnostic!("End activity ({end}) already present in activity set! Still adding an end activity to every trace, which might not be the desired outcome.");
             should_add_end = false;
             *a
         }
         None => {
             let a = log.activities.len();
-            log.activities.push(END_ACTIVITY.to_string());
-            log.act_to_index.insert(END_ACTIVITY.to_string(), a);
+            log.activities.push(end.to_string());
+            log.act_to_index.insert(end.to_string(), a);
             a
         }
     };
@@ -308,21 +487,98 @@ pub fn add_start_end_acts_proj(log: &mut EventLogActivityProjection) {
     }
 }
 
+///
+/// Add artificial start and end activities to a given [`EventLogActivityProjection`], as configured by the passed options
+///
+/// Without [`StartEndActivityOptions::skip_if_present`], this behaves like [`add_start_end_acts_proj_named`].
+/// Otherwise, the activities are only inserted into traces which do not already begin or end with them.
+/// Traces becoming equal this way are merged (summing up their frequencies).
+///
+pub fn add_start_end_acts_proj_with_options(
+    log: &mut EventLogActivityProjection,
+    options: &StartEndActivityOptions,
+) {
+    if !options.skip_if_present {
+        return add_start_end_acts_proj_named(log, &options.start, &options.end);
+    }
+    let mut get_or_add_act = |name: &str| match log.act_to_index.get(name) {
+        Some(a) => *a,
+        None => {
+            let a = log.activities.len();
+            log.activities.push(name.to_string());
+            log.act_to_index.insert(name.to_string(), a);
+            a
+        }
+    };
+    let start_act = get_or_add_act(&options.start);
+    let end_act = get_or_add_act(&options.end);
+    let mut merged: HashMap<Vec<usize>, u64> = HashMap::new();
+    for (mut t, freq) in std::mem::take(&mut log.traces) {
+        if t.first() != Some(&start_act) {
+            t.insert(0, start_act);
+        }
+        if t.len() < 2 || t.last() != Some(&end_act) {
+            t.push(end_act);
+        }
+        *merged.entry(t).or_default() += freq;
+    }
+    log.traces = merged.into_iter().collect();
+    log.traces
+        .sort_by(|(t1, f1), (t2, f2)| f2.cmp(f1).then_with(|| t1.cmp(t2)));
+}
+
 ///
 /// Add artificial start and end activities to a given [`EventLog`]
 ///
 /// Mutating the [`EventLog`] in place
 /// Caution: Does not check if [`START_ACTIVITY`] or [`END_ACTIVITY`] are already present in the log
 ///
+/// See [`add_start_end_acts_named`] for using custom names for the artificial activities.
+///
 pub fn add_start_end_acts(log: &mut EventLog) {
+    add_start_end_acts_named(log, START_ACTIVITY, END_ACTIVITY)
+}
+
+///
+/// Add artificial start and end activities with the given names to a given [`EventLog`]
+///
+/// Mutating the [`EventLog`] in place
+/// Caution: Does not check if the `start` or `end` activity are already present in the log
+///
+pub fn add_start_end_acts_named(log: &mut EventLog, start: &str, end: &str) {
     log.traces.par_iter_mut().for_each(|t| {
-        let start_event = Event::new(START_ACTIVITY.to_string());
-        let end_event = Event::new(END_ACTIVITY.to_string());
+        let start_event = Event::new(start.to_string());
+        let end_event = Event::new(end.to_string());
         t.events.insert(0, start_event);
         t.events.push(end_event);
     });
 }
 
+///
+/// Add artificial start and end activities to a given [`EventLog`], as configured by the passed options
+///
+/// Without [`StartEndActivityOptions::skip_if_present`], this behaves like [`add_start_end_acts_named`].
+/// Otherwise, the activities are only inserted into traces which do not already begin or end with them.
+///
+pub fn add_start_end_acts_with_options(log: &mut EventLog, options: &StartEndActivityOptions) {
+    if !options.skip_if_present {
+        return add_start_end_acts_named(log, &options.start, &options.end);
+    }
+    let has_activity = |ev: Option<&Event>, name: &str| {
+        ev.and_then(|e| e.attributes.get_by_key(ACTIVITY_NAME))
+            .and_then(|a| a.value.try_as_string())
+            .is_some_and(|a| a == name)
+    };
+    log.traces.par_iter_mut().for_each(|t| {
+        if !has_activity(t.events.first(), &options.start) {
+            t.events.insert(0, Event::new(options.start.clone()));
+        }
+        if t.events.len() < 2 || !has_activity(t.events.last(), &options.end) {
+            t.events.push(Event::new(options.end.clone()));
+        }
+    });
+}
+
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 /// Weighted DFG of [`EventLogActivityProjection`]
 pub struct ActivityProjectionDFG {
@@ -402,12 +658,61 @@ impl ActivityProjectionDFG {
                         m2
                     }
                 })
-                .unwrap(),
+                .unwrap_or_default(),
         };
         dfg
     }
 }
 
+/// Default maximum size (in bytes) of the input accepted by [`import_projection_bincode`] (1 GiB)
+#[cfg(feature = "bincode")]
+pub const DEFAULT_PROJECTION_BINCODE_LIMIT: u64 = 1 << 30;
+
+/// Export an [`EventLogActivityProjection`] in a compact binary format (using `bincode`)
+///
+/// Integers are variable-length encoded, so the result is considerably smaller and faster to (de-)serialize than JSON.
+/// The result can be imported again using [`import_projection_bincode`].
+///
+/// Note: This function is only available if the `bincode` feature is enabled.
+#[cfg(feature = "bincode")]
+pub fn export_projection_bincode<W: Write>(
+    proj: &EventLogActivityProjection,
+    writer: W,
+) -> Result<(), EventLogIOError> {
+    let mut writer = std::io::BufWriter::new(writer);
+    bincode::options().serialize_into(&mut writer, proj)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Import an [`EventLogActivityProjection`] from the compact binary format written by [`export_projection_bincode`]
+///
+/// Reads at most [`DEFAULT_PROJECTION_BINCODE_LIMIT`] bytes (see [`import_projection_bincode_with_limit`]).
+///
+/// Note: This function is only available if the `bincode` feature is enabled.
+#[cfg(feature = "bincode")]
+pub fn import_projection_bincode<R: Read>(
+    reader: R,
+) -> Result<EventLogActivityProjection, EventLogIOError> {
+    import_projection_bincode_with_limit(reader, DEFAULT_PROJECTION_BINCODE_LIMIT)
+}
+
+/// Import an [`EventLogActivityProjection`] from the compact binary format, reading at most `limit` bytes
+///
+/// Inputs exceeding the limit (including malformed inputs with huge length prefixes) are rejected
+/// with an error before allocating memory for them, so this is safe to use on untrusted input.
+///
+/// Note: This function is only available if the `bincode` feature is enabled.
+#[cfg(feature = "bincode")]
+pub fn import_projection_bincode_with_limit<R: Read>(
+    reader: R,
+    limit: u64,
+) -> Result<EventLogActivityProjection, EventLogIOError> {
+    Ok(bincode::options()
+        .with_limit(limit)
+        .deserialize_from(std::io::BufReader::new(reader))?)
+}
+
 impl Importable for EventLogActivityProjection {
     type Error = EventLogIOError;
     type ImportOptions = ();
@@ -417,6 +722,10 @@ impl Importable for EventLogActivityProjection {
         format: &str,
         _: Self::ImportOptions,
     ) -> Result<Self, Self::Error> {
+        #[cfg(feature = "bincode")]
+        if format.ends_with("bin") {
+            return import_projection_bincode(reader);
+        }
         if format.ends_with("json") {
             let reader = std::io::BufReader::new(reader);
             let res: Self = serde_json::from_reader(reader)?;
@@ -433,6 +742,8 @@ impl Importable for EventLogActivityProjection {
         let p = path.to_string_lossy().to_lowercase();
         if p.ends_with(".json") {
             Some("json".to_string())
+        } else if cfg!(feature = "bincode") && p.ends_with(".bin") {
+            Some("bin".to_string())
         } else {
             <EventLog as Importable>::infer_format(path)
         }
@@ -441,6 +752,8 @@ impl Importable for EventLogActivityProjection {
     fn known_import_formats() -> Vec<crate::core::io::ExtensionWithMime> {
         vec![
             ExtensionWithMime::new("json", "application/json"),
+            #[cfg(feature = "bincode")]
+            ExtensionWithMime::new("bin", "application/octet-stream"),
             ExtensionWithMime::new("xes", "application/xml"),
             ExtensionWithMime::new("xes.gz", "application/gzip"),
         ]
@@ -457,6 +770,10 @@ impl Exportable for EventLogActivityProjection {
         format: &str,
         _: Self::ExportOptions,
     ) -> Result<(), Self::Error> {
+        #[cfg(feature = "bincode")]
+        if format.ends_with("bin") {
+            return export_projection_bincode(self, writer);
+        }
         if format.ends_with("json") {
             serde_json::to_writer(writer, self)?;
             Ok(())
@@ -466,14 +783,145 @@ impl Exportable for EventLogActivityProjection {
     }
 
     fn known_export_formats() -> Vec<ExtensionWithMime> {
-        vec![ExtensionWithMime::new("json", "application/json")]
+        vec![
+            ExtensionWithMime::new("json", "application/json"),
+            #[cfg(feature = "bincode")]
+            ExtensionWithMime::new("bin", "application/octet-stream"),
+        ]
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{test_utils::get_test_data_path, EventLog, Importable};
+    use crate::{
+        core::event_data::case_centric::Attribute, event_log, test_utils::get_test_data_path,
+        EventLog, Importable,
+    };
+
+    #[test]
+    fn test_projection_global_activity_fallback() {
+        let mut log = crate::event_log!(["a", "b"], ["a"]);
+        log.traces[0].events[1]
+            .attributes
+            .remove_with_key(ACTIVITY_NAME);
+        log.traces[1].events[0]
+            .attributes
+            .remove_with_key(ACTIVITY_NAME);
+        let (proj, num_unlabeled) = log_to_activity_projection_with_unlabeled_count(&log);
+        assert_eq!(num_unlabeled, 2);
+        assert!(proj.act_to_index.contains_key("No Activity"));
+
+        log.global_event_attrs = Some(vec![Attribute::new(
+            ACTIVITY_NAME.to_string(),
+            AttributeValue::String("default".to_string()),
+        )]);
+        let (proj, num_unlabeled) = log_to_activity_projection_with_unlabeled_count(&log);
+        assert_eq!(num_unlabeled, 0);
+        assert_eq!(
+            proj.acts_to_names_sorted(&(0..proj.activities.len()).collect::<Vec<_>>()),
+            vec!["a", "default"]
+        );
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_projection_bincode_round_trip() {
+        let log = event_log!(["a", "b", "c"], ["a", "c"], ["a", "b", "c"]);
+        let proj: EventLogActivityProjection = (&log).into();
+        let mut bytes = Vec::new();
+        export_projection_bincode(&proj, &mut bytes).unwrap();
+        let json = proj.export_to_bytes("json").unwrap();
+        assert!(bytes.len() < json.len());
+        let imported = import_projection_bincode(bytes.as_slice()).unwrap();
+        assert_eq!(imported.activities, proj.activities);
+        assert_eq!(imported.act_to_index, proj.act_to_index);
+        assert_eq!(imported.traces, proj.traces);
+        let imported = EventLogActivityProjection::import_from_bytes(
+            &proj.export_to_bytes("bin").unwrap(),
+            "bin",
+        )
+        .unwrap();
+        assert_eq!(imported.traces, proj.traces);
+        // Inputs exceeding the limit are rejected
+        assert!(import_projection_bincode_with_limit(bytes.as_slice(), 4).is_err());
+        // A huge length prefix is rejected instead of being allocated
+        let mut malformed = Vec::new();
+        bincode::options()
+            .serialize_into(&mut malformed, &u64::MAX)
+            .unwrap();
+        assert!(import_projection_bincode(malformed.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_add_start_end_acts_named() {
+        let log = event_log!(["__START", "a"], ["a", "b"]);
+        let mut proj: EventLogActivityProjection = (&log).into();
+        add_start_end_acts_proj_named(&mut proj, "Begin", "Finish");
+        let begin = proj.act_to_index["Begin"];
+        let finish = proj.act_to_index["Finish"];
+        assert_eq!(proj.activities[begin], "Begin");
+        assert_eq!(proj.activities[finish], "Finish");
+        assert_eq!(proj.activities.len(), 5);
+        for (t, _) in &proj.traces {
+            assert_eq!(t.first(), Some(&begin));
+            assert_eq!(t.last(), Some(&finish));
+        }
+        let mut traces: Vec<_> = proj
+            .traces
+            .iter()
+            .map(|(t, _)| proj.reconstruct_activities(t))
+            .collect();
+        traces.sort();
+        assert_eq!(
+            traces,
+            vec![
+                vec!["Begin", "__START", "a", "Finish"],
+                vec!["Begin", "a", "b", "Finish"],
+            ]
+        );
+
+        let mut log = log;
+        add_start_end_acts_named(&mut log, "Begin", "Finish");
+        for t in &log.traces {
+            assert_eq!(t.events.len(), 4);
+            for (ev, name) in [(t.events.first(), "Begin"), (t.events.last(), "Finish")] {
+                assert_eq!(
+                    ev.and_then(|e| e.attributes.get_by_key(ACTIVITY_NAME))
+                        .and_then(|a| a.value.try_as_string()),
+                    Some(&name.to_string())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_start_end_acts_skip_if_present() {
+        let options = StartEndActivityOptions {
+            skip_if_present: true,
+            ..Default::default()
+        };
+        let log = event_log!(["__START", "a"], ["a"], ["a", "__END"]);
+        let mut proj: EventLogActivityProjection = (&log).into();
+        add_start_end_acts_proj_with_options(&mut proj, &options);
+        let once = proj.clone();
+        add_start_end_acts_proj_with_options(&mut proj, &options);
+        assert_eq!(proj.traces, once.traces);
+        assert_eq!(proj.activities.len(), 3);
+        assert_eq!(proj.traces.len(), 1);
+        assert_eq!(proj.traces[0].1, 3);
+        assert_eq!(
+            proj.reconstruct_activities(&proj.traces[0].0),
+            vec!["__START", "a", "__END"]
+        );
+
+        let mut log = log;
+        add_start_end_acts_with_options(&mut log, &options);
+        add_start_end_acts_with_options(&mut log, &options);
+        for t in &log.traces {
+            assert_eq!(t.events.len(), 3);
+        }
+    }
 
     #[test]
     fn test_variants_rtfm() {
@@ -508,4 +956,53 @@ mod test {
             ]
         )
     }
+
+    #[test]
+    fn test_variant_encoding() {
+        let variant = vec![
+            "Create Order".to_string(),
+            "Pay, then ship".to_string(),
+            "C:\\orders".to_string(),
+            String::new(),
+        ];
+        let encoded = encode_variant(&variant, DEFAULT_VARIANT_SEPARATOR);
+        assert_eq!(encoded, r"Create Order,Pay\, then ship,C:\\orders,");
+        assert_eq!(decode_variant(&encoded, DEFAULT_VARIANT_SEPARATOR), variant);
+        let encoded = encode_variant(&variant, ';');
+        assert_eq!(encoded, r"Create Order;Pay, then ship;C:\\orders;");
+        assert_eq!(decode_variant(&encoded, ';'), variant);
+        assert!(decode_variant(&encode_variant::<&str>(&[], ','), ',').is_empty());
+    }
+
+    #[test]
+    fn test_filter_activities() {
+        let log: EventLogActivityProjection = (&event_log!(
+            ["a", "n", "b"],
+            ["a", "b"],
+            ["a", "b", "n"],
+            ["n"],
+            ["a", "c"]
+        ))
+            .into();
+        let keep: HashSet<String> = ["a", "b", "x"].into_iter().map(String::from).collect();
+        let filtered = log.filter_activities(&keep);
+        let mut activities = filtered.activities.clone();
+        activities.sort();
+        assert_eq!(activities, ["a", "b"]);
+        assert_eq!(filtered.act_to_index.len(), 2);
+        let variants: Vec<(Vec<String>, u64)> = filtered
+            .traces
+            .iter()
+            .map(|(t, f)| (filtered.reconstruct_activities(t), *f))
+            .collect();
+        assert_eq!(
+            variants,
+            // Ties are broken by the activity indices, so the empty trace comes first
+            vec![
+                (vec!["a".to_string(), "b".to_string()], 3),
+                (vec![], 1),
+                (vec!["a".to_string()], 1),
+            ]
+        );
+    }
 }
@@ -0,0 +1,116 @@
+//! Clustering of Trace Variants
+//!
+//! Groups the variants of an [`EventLogActivityProjection`] based on the edit distance of their activity sequences.
+use macros_process_mining::register_binding;
+
+use super::activity_projection::EventLogActivityProjection;
+
+/// Levenshtein (edit) distance between two activity sequences
+fn edit_distance(a: &[usize], b: &[usize]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, x) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(x != y);
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+/// Cluster the trace variants of an [`EventLogActivityProjection`] into (at most) `k` clusters
+///
+/// Uses agglomerative clustering with average linkage, where the distance between two variants is the
+/// Levenshtein distance between their activity sequences.
+/// Starting with one cluster per variant, the two closest clusters are merged until only `k` clusters remain
+/// (ties are broken by the smallest variant indices, so the result is deterministic).
+///
+/// Returns the indices of the variants (i.e., into [`EventLogActivityProjection::traces`]) per cluster.
+/// Indices within a cluster are sorted, and clusters are sorted by their smallest index.
+/// If `k` is at least the number of variants, every variant forms its own cluster; `k = 0` is treated as `k = 1`.
+///
+/// Note that this requires all pairwise distances and thus quadratic memory in the number of variants.
+#[register_binding]
+pub fn cluster_variants(log: &EventLogActivityProjection, k: usize) -> Vec<Vec<usize>> {
+    let n = log.traces.len();
+    let k = k.max(1);
+    let mut clusters: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    if n <= k {
+        return clusters;
+    }
+    // Sum of all pairwise distances between the variants of two clusters
+    let mut linkage: Vec<Vec<usize>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| edit_distance(&log.traces[i].0, &log.traces[j].0))
+                .collect()
+        })
+        .collect();
+    while clusters.len() > k {
+        let mut best: Option<(f64, usize, usize)> = None;
+        for a in 0..clusters.len() {
+            for b in a + 1..clusters.len() {
+                let avg = linkage[a][b] as f64 / (clusters[a].len() * clusters[b].len()) as f64;
+                if best.is_none_or(|(d, _, _)| avg < d) {
+                    best = Some((avg, a, b));
+                }
+            }
+        }
+        let (_, a, b) = best.expect("At least two clusters remain");
+        let merged = clusters.remove(b);
+        clusters[a].extend(merged);
+        clusters[a].sort_unstable();
+        let removed = linkage.remove(b);
+        for (c, row) in linkage.iter_mut().enumerate() {
+            let d = row.remove(b);
+            if c != a {
+                row[a] += d;
+            }
+        }
+        for (c, d) in removed.into_iter().enumerate().filter(|(c, _)| *c != b) {
+            let c = if c > b { c - 1 } else { c };
+            if c != a {
+                linkage[a][c] += d;
+            }
+        }
+    }
+    clusters.sort_by_key(|c| c[0]);
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projection(traces: &[&[usize]]) -> EventLogActivityProjection {
+        EventLogActivityProjection {
+            activities: (0..5).map(|a| a.to_string()).collect(),
+            act_to_index: (0..5).map(|a| (a.to_string(), a)).collect(),
+            traces: traces.iter().map(|t| (t.to_vec(), 1)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance(&[], &[1, 2]), 2);
+        assert_eq!(edit_distance(&[0, 1, 2], &[0, 2]), 1);
+        assert_eq!(edit_distance(&[0, 1, 2], &[2, 1, 0]), 2);
+    }
+
+    #[test]
+    fn test_cluster_variants() {
+        let log = projection(&[
+            &[0, 1, 2],
+            &[3, 4, 4, 4],
+            &[0, 1, 1, 2],
+            &[3, 4, 4],
+            &[0, 2],
+        ]);
+        assert_eq!(cluster_variants(&log, 2), vec![vec![0, 2, 4], vec![1, 3]]);
+        assert_eq!(cluster_variants(&log, 1), vec![vec![0, 1, 2, 3, 4]]);
+        assert_eq!(cluster_variants(&log, 0), vec![vec![0, 1, 2, 3, 4]]);
+        assert_eq!(cluster_variants(&log, 10).len(), 5);
+    }
+}
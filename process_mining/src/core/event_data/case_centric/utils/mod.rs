@@ -1,5 +1,8 @@
 //! Utilities Related to Case-centric Event Data
 pub mod activity_projection;
+pub mod cluster;
 #[cfg(feature = "log-splitting")]
 pub mod event_log_splitter;
 pub mod partial_orders;
+pub mod sample;
+pub mod to_ocel;
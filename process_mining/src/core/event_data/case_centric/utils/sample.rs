@@ -0,0 +1,202 @@
+//! Sampling of Event Logs
+//!
+//! Randomly selects a subset of the cases of an [`EventLog`], e.g., to quickly try out discovery parameters
+//! on a representative part of a large log.
+//! Sampling is seeded, so the same seed always results in the same sample.
+use macros_process_mining::register_binding;
+
+use crate::{
+    core::{event_data::case_centric::EventLogClassifier, EventLog},
+    utils::{new_uuid, splitmix64},
+};
+
+///
+/// Randomly sample (up to) `n` traces of an [`EventLog`]
+///
+/// All log-level information (attributes, extensions, classifiers, and global attributes) is preserved.
+/// The sampled traces keep their relative order from the original log.
+/// If `n` is at least the number of traces, all traces are kept.
+///
+/// The sample only depends on the `seed` (and the number of traces), i.e., the same seed always results in the same sample.
+///
+#[register_binding]
+pub fn sample_cases(log: &EventLog, n: usize, seed: u64) -> EventLog {
+    let num_traces = log.traces.len();
+    let mut indices: Vec<usize> = (0..num_traces).collect();
+    let n = n.min(num_traces);
+    let mut state = seed;
+    shuffle_prefix(&mut indices, n, &mut state);
+    log_with_traces(log, indices[..n].to_vec())
+}
+
+/// Partial Fisher-Yates shuffle using a `SplitMix64` sequence, moving `n` random elements to the front
+fn shuffle_prefix(indices: &mut [usize], n: usize, state: &mut u64) {
+    for i in 0..n {
+        let j = i + (splitmix64(state) % (indices.len() - i) as u64) as usize;
+        indices.swap(i, j);
+    }
+}
+
+/// Copy of `log` containing only the traces at the given indices (in their original order)
+fn log_with_traces(log: &EventLog, mut sampled: Vec<usize>) -> EventLog {
+    sampled.sort_unstable();
+    EventLog {
+        attributes: log.attributes.clone(),
+        traces: sampled.into_iter().map(|i| log.traces[i].clone()).collect(),
+        extensions: log.extensions.clone(),
+        classifiers: log.classifiers.clone(),
+        global_trace_attrs: log.global_trace_attrs.clone(),
+        global_event_attrs: log.global_event_attrs.clone(),
+    }
+}
+
+///
+/// Randomly sample a fraction `p` (between `0.0` and `1.0`) of the traces of an [`EventLog`]
+///
+/// The number of sampled traces is rounded to the nearest integer; values of `p` outside of `[0, 1]` are clamped.
+/// See [`sample_cases`] for details.
+///
+#[register_binding]
+pub fn sample_fraction(log: &EventLog, p: f64, seed: u64) -> EventLog {
+    let n = (p.clamp(0.0, 1.0) * log.traces.len() as f64).round() as usize;
+    sample_cases(log, n, seed)
+}
+
+impl EventLog {
+    ///
+    /// Randomly sample (up to) `n` traces, covering all variants if possible
+    ///
+    /// If `n` is at least the number of variants (i.e., distinct activity sequences, see [`EventLog::variants`]),
+    /// the sample contains one randomly chosen trace of each variant, filled up with traces selected uniformly at random
+    /// from the remaining ones. Otherwise, `n` traces are selected uniformly at random.
+    ///
+    /// As for [`sample_cases`], log-level information and the relative order of traces are preserved.
+    /// If no `seed` is given, a random seed is used (which can itself be seeded using [`crate::utils::seed_ids`]).
+    ///
+    pub fn sample_traces(&self, n: usize, seed: Option<u64>) -> EventLog {
+        let mut state = seed.unwrap_or_else(|| new_uuid().as_u64_pair().0);
+        let traces_per_variant = self.traces_per_variant(&EventLogClassifier::default());
+        let n = n.min(self.traces.len());
+        if n < traces_per_variant.len() {
+            let mut indices: Vec<usize> = (0..self.traces.len()).collect();
+            shuffle_prefix(&mut indices, n, &mut state);
+            return log_with_traces(self, indices[..n].to_vec());
+        }
+        // Variants are visited in order of their first trace, so the sample only depends on the seed
+        let mut variants: Vec<Vec<usize>> = traces_per_variant.into_values().collect();
+        variants.sort_unstable_by_key(|v| v[0]);
+        let mut representatives = Vec::with_capacity(n);
+        let mut remaining = Vec::with_capacity(self.traces.len() - variants.len());
+        for mut v in variants {
+            shuffle_prefix(&mut v, 1, &mut state);
+            representatives.push(v[0]);
+            remaining.extend_from_slice(&v[1..]);
+        }
+        let num_remaining = n - representatives.len();
+        shuffle_prefix(&mut remaining, num_remaining, &mut state);
+        representatives.extend_from_slice(&remaining[..num_remaining]);
+        log_with_traces(self, representatives)
+    }
+}
+
+///
+/// Randomly sample (up to) `n` traces of an [`EventLog`], covering all variants if possible
+///
+/// See [`EventLog::sample_traces`] for details.
+///
+#[register_binding]
+pub fn sample_log(log: &EventLog, n: usize, #[bind(default)] seed: Option<u64>) -> EventLog {
+    log.sample_traces(n, seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::event_data::case_centric::{
+            constants::ACTIVITY_NAME, Attribute, AttributeValue, EventLogClassifier,
+            XESEditableAttribute,
+        },
+        event_log,
+    };
+
+    fn first_activities(log: &EventLog) -> Vec<String> {
+        log.traces
+            .iter()
+            .map(|t| {
+                t.events[0]
+                    .attributes
+                    .get_by_key(ACTIVITY_NAME)
+                    .and_then(|a| a.value.try_as_string())
+                    .unwrap()
+                    .clone()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sample_cases() {
+        let mut log = event_log!(["a"], ["b"], ["c"], ["d"], ["e"], ["f"]);
+        log.attributes.push(Attribute::new(
+            "name".to_string(),
+            AttributeValue::String("test".to_string()),
+        ));
+        log.classifiers = Some(vec![EventLogClassifier {
+            name: "Activity".to_string(),
+            keys: vec![ACTIVITY_NAME.to_string()],
+        }]);
+
+        let sample = sample_cases(&log, 3, 42);
+        assert_eq!(sample.traces.len(), 3);
+        assert_eq!(sample.attributes, log.attributes);
+        assert_eq!(sample.classifiers, log.classifiers);
+        // Deterministic and order-preserving
+        let acts = first_activities(&sample);
+        assert_eq!(acts, first_activities(&sample_cases(&log, 3, 42)));
+        let mut sorted = acts.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(acts, sorted);
+
+        // Seed 0 does not result in the first `n` traces
+        assert_ne!(first_activities(&sample_cases(&log, 3, 0)), ["a", "b", "c"]);
+        assert_eq!(sample_cases(&log, 10, 1).traces.len(), 6);
+        assert_eq!(sample_fraction(&log, 0.5, 1).traces.len(), 3);
+        assert!(sample_fraction(&log, -1.0, 1).traces.is_empty());
+    }
+
+    #[test]
+    fn test_sample_traces() {
+        let log = event_log!(
+            ["a", "b"],
+            ["a", "b"],
+            ["a", "b"],
+            ["a", "b"],
+            ["a", "c"],
+            ["a", "b"],
+            ["d"],
+            ["a", "b"],
+            ["a", "c"]
+        );
+        // Reproducible with a fixed seed
+        let sample = log.sample_traces(4, Some(7));
+        assert_eq!(sample.traces.len(), 4);
+        assert_eq!(sample.traces, log.sample_traces(4, Some(7)).traces);
+        for seed in 0..20 {
+            // All variants are covered if n is at least the number of variants
+            for n in 3..6 {
+                let sample = log.sample_traces(n, Some(seed));
+                assert_eq!(sample.traces.len(), n);
+                assert_eq!(sample.variants(&EventLogClassifier::default()).len(), 3);
+            }
+            assert_eq!(log.sample_traces(2, Some(seed)).traces.len(), 2);
+        }
+        // Seed 0 does not result in the first trace of each variant
+        let firsts: Vec<_> = [0, 4, 6]
+            .into_iter()
+            .map(|i| log.traces[i].clone())
+            .collect();
+        assert_ne!(log.sample_traces(3, Some(0)).traces, firsts);
+        assert_eq!(sample_log(&log, 20, None).traces.len(), 9);
+    }
+}
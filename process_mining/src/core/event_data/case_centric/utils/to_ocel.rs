@@ -0,0 +1,244 @@
+//! Convert a case-centric [`EventLog`] to an object-centric [`OCEL`]
+use std::collections::{HashMap, HashSet};
+
+use chrono::DateTime;
+use macros_process_mining::register_binding;
+
+use crate::core::{
+    event_data::{
+        case_centric::{
+            constants::{ACTIVITY_NAME, TRACE_ID_NAME},
+            event_log_struct::{AttributeValue, XESEditableAttribute},
+        },
+        object_centric::ocel_struct::{
+            OCELAttributeValue, OCELEvent, OCELEventAttribute, OCELObject, OCELObjectAttribute,
+            OCELRelationship, OCELType, OCELTypeAttribute,
+        },
+    },
+    EventLog, OCEL,
+};
+
+/// Key of the timestamp attribute of events
+const TIMESTAMP_KEY: &str = "time:timestamp";
+
+/// Add the attribute (with the type of the passed value) to the type, if not already present
+fn add_type_attribute(
+    types: &mut HashMap<String, Vec<OCELTypeAttribute>>,
+    type_name: &str,
+    attr_name: &str,
+    value: &OCELAttributeValue,
+) {
+    let attrs = types.entry(type_name.to_string()).or_default();
+    if !attrs.iter().any(|a| a.name == attr_name) {
+        attrs.push(OCELTypeAttribute::new(attr_name, &value.get_type()));
+    }
+}
+
+/// String representation of an attribute value used as ID or type name
+fn value_to_string(value: &AttributeValue) -> String {
+    match value {
+        AttributeValue::String(s) => s.clone(),
+        v => OCELAttributeValue::from(v.clone()).to_string(),
+    }
+}
+
+fn into_ocel_types(types: HashMap<String, Vec<OCELTypeAttribute>>) -> Vec<OCELType> {
+    let mut types: Vec<_> = types
+        .into_iter()
+        .map(|(name, attributes)| OCELType { name, attributes })
+        .collect();
+    types.sort_by(|a, b| a.name.cmp(&b.name));
+    types
+}
+
+///
+/// Convert an [`EventLog`] to an [`OCEL`] with a single object type
+///
+/// - Each trace becomes one object of type `case_object_type`, identified by the trace ID (`concept:name`,
+///   or `case_<trace index>` if it is missing).
+///   If the ID was already used by a previous trace, the trace index is appended (i.e., `<trace ID>_<trace index>`).
+///   All other trace attributes become object attributes (with timestamp `1970-01-01T00:00:00Z`).
+/// - Each event becomes an [`OCELEvent`] with the activity (`concept:name`) as event type
+///   and an E2O relationship to the object of its trace.
+///   The event timestamp is taken from `time:timestamp` (defaulting to `1970-01-01T00:00:00Z` if missing),
+///   and all other event attributes become event attributes.
+///
+/// Event IDs are constructed as `<trace ID>_<event index in trace>`.
+/// Attribute types are derived from the first occurrence of each attribute.
+///
+/// This can be seen as the inverse of [`flatten_ocel_on`](crate::core::event_data::object_centric::utils::flatten::flatten_ocel_on).
+#[register_binding]
+pub fn event_log_to_ocel(log: &EventLog, case_object_type: impl AsRef<str>) -> OCEL {
+    let case_object_type = case_object_type.as_ref();
+    let mut event_types: HashMap<String, Vec<OCELTypeAttribute>> = HashMap::new();
+    let mut object_types: HashMap<String, Vec<OCELTypeAttribute>> = HashMap::new();
+    object_types.insert(case_object_type.to_string(), Vec::new());
+    let mut events = Vec::new();
+    let mut objects = Vec::with_capacity(log.traces.len());
+    let mut used_ids: HashSet<String> = HashSet::with_capacity(log.traces.len());
+
+    for (trace_index, trace) in log.traces.iter().enumerate() {
+        let mut case_id = match trace.attributes.get_by_key(TRACE_ID_NAME) {
+            Some(a) => value_to_string(&a.value),
+            None => format!("case_{trace_index}"),
+        };
+        while !used_ids.insert(case_id.clone()) {
+            case_id = format!("{case_id}_{trace_index}");
+        }
+        let attributes = trace
+            .attributes
+            .iter()
+            .filter(|a| a.key != TRACE_ID_NAME)
+            .map(|a| {
+                let value: OCELAttributeValue = a.value.clone().into();
+                add_type_attribute(&mut object_types, case_object_type, &a.key, &value);
+                OCELObjectAttribute::new(&a.key, value, DateTime::UNIX_EPOCH)
+            })
+            .collect();
+
+        for (event_index, event) in trace.events.iter().enumerate() {
+            let event_type = event
+                .attributes
+                .get_by_key(ACTIVITY_NAME)
+                .map(|a| value_to_string(&a.value))
+                .unwrap_or_default();
+            let time = event
+                .attributes
+                .get_by_key(TIMESTAMP_KEY)
+                .and_then(|a| a.value.try_as_date().copied())
+                .unwrap_or(DateTime::UNIX_EPOCH.into());
+            event_types.entry(event_type.clone()).or_default();
+            let attributes = event
+                .attributes
+                .iter()
+                .filter(|a| a.key != ACTIVITY_NAME && a.key != TIMESTAMP_KEY)
+                .map(|a| {
+                    let value: OCELAttributeValue = a.value.clone().into();
+                    add_type_attribute(&mut event_types, &event_type, &a.key, &value);
+                    OCELEventAttribute {
+                        name: a.key.clone(),
+                        value,
+                    }
+                })
+                .collect();
+            events.push(OCELEvent::new(
+                format!("{case_id}_{event_index}"),
+                event_type,
+                time,
+                attributes,
+                vec![OCELRelationship::new(&case_id, "")],
+            ));
+        }
+
+        objects.push(OCELObject {
+            id: case_id,
+            object_type: case_object_type.to_string(),
+            attributes,
+            relationships: Vec::new(),
+        });
+    }
+
+    OCEL {
+        event_types: into_ocel_types(event_types),
+        object_types: into_ocel_types(object_types),
+        events,
+        objects,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::event_data::object_centric::{
+            linked_ocel::IndexLinkedOCEL, utils::flatten::flatten_ocel_on,
+        },
+        event_log,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_event_log_to_ocel() {
+        let log = event_log!(
+            ["a"; {"org:resource" => "John"}, "b", "c"] {"concept:name" => "order-1", "cost" => 2500.00},
+            ["a", "c"],
+        );
+        let ocel = event_log_to_ocel(&log, "order");
+
+        assert_eq!(ocel.objects.len(), 2);
+        assert_eq!(ocel.events.len(), 5);
+        assert_eq!(
+            ocel.object_types
+                .iter()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["order"]
+        );
+        assert_eq!(
+            ocel.event_types
+                .iter()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+
+        let order_1 = &ocel.objects[0];
+        assert_eq!(order_1.id, "order-1");
+        assert_eq!(
+            order_1.attributes,
+            vec![OCELObjectAttribute::new(
+                "cost",
+                2500.0,
+                DateTime::UNIX_EPOCH
+            )]
+        );
+        // `event_log!` names traces by their index
+        assert_eq!(ocel.objects[1].id, "1");
+
+        let first = &ocel.events[0];
+        assert_eq!(first.id, "order-1_0");
+        assert_eq!(first.event_type, "a");
+        assert_eq!(
+            first.relationships,
+            vec![OCELRelationship::new("order-1", "")]
+        );
+        assert_eq!(first.attributes.len(), 1);
+        assert_eq!(first.attributes[0].name, "org:resource");
+        assert!(ocel
+            .events
+            .iter()
+            .all(|e| e.attributes.iter().all(|a| a.name != TIMESTAMP_KEY)));
+
+        // Flattening on the case object type results in the original traces again
+        let flattened = flatten_ocel_on(&IndexLinkedOCEL::from_ocel(ocel), "order");
+        assert_eq!(flattened.traces.len(), 2);
+        for (t1, t2) in log.traces.iter().zip(&flattened.traces) {
+            let acts = |t: &crate::core::event_data::case_centric::Trace| {
+                t.events
+                    .iter()
+                    .map(|e| e.attributes.get_by_key(ACTIVITY_NAME).cloned())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(acts(t1), acts(t2));
+        }
+    }
+
+    #[test]
+    fn test_event_log_to_ocel_duplicate_trace_ids() {
+        let log = event_log!(
+            ["a", "b"] {"concept:name" => "order-1"},
+            ["a"] {"concept:name" => "order-1"},
+            ["c"] {"concept:name" => "order-1_1"},
+        );
+        let ocel = event_log_to_ocel(&log, "order");
+
+        let object_ids: Vec<_> = ocel.objects.iter().map(|o| o.id.as_str()).collect();
+        assert_eq!(object_ids, vec!["order-1", "order-1_1", "order-1_1_2"]);
+        let event_ids: HashSet<_> = ocel.events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(event_ids.len(), ocel.events.len());
+        assert_eq!(
+            ocel.events[2].relationships,
+            vec![OCELRelationship::new("order-1_1", "")]
+        );
+    }
+}
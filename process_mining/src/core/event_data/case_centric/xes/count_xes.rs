@@ -0,0 +1,116 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+use flate2::read::GzDecoder;
+use quick_xml::{events::Event as XMLEvent, Reader};
+
+use super::import_xes::XESParseError;
+
+///
+/// Count the number of traces and events in XES data from a [`BufRead`]
+///
+/// Only scans the XML tags, without constructing any [`Trace`](crate::core::event_data::case_centric::Trace)s or [`Event`](crate::core::event_data::case_centric::Event)s,
+/// which is much faster than a (streaming) import.
+/// Events outside of traces are not counted.
+///
+/// Returns the tuple `(number of traces, number of events)`.
+pub fn count_xes_traces_bufread(
+    reader: impl BufRead,
+    is_gzipped: bool,
+) -> Result<(usize, usize), XESParseError> {
+    if is_gzipped {
+        count_xml_traces(Reader::from_reader(BufReader::new(GzDecoder::new(reader))))
+    } else {
+        count_xml_traces(Reader::from_reader(reader))
+    }
+}
+
+///
+/// Count the number of traces and events in an XES file (auto-detecting gz compression from file extension)
+///
+/// See [`count_xes_traces_bufread`] for details.
+///
+/// Returns the tuple `(number of traces, number of events)`.
+pub fn count_xes_traces<P: AsRef<Path>>(path: P) -> Result<(usize, usize), XESParseError> {
+    let is_gz = path
+        .as_ref()
+        .as_os_str()
+        .to_str()
+        .is_some_and(|p| p.ends_with(".gz"));
+    let file = File::open(path)?;
+    count_xes_traces_bufread(BufReader::new(file), is_gz)
+}
+
+fn count_xml_traces<R: BufRead>(mut reader: Reader<R>) -> Result<(usize, usize), XESParseError> {
+    let mut buf = Vec::new();
+    let mut num_traces = 0;
+    let mut num_events = 0;
+    let mut in_trace = false;
+    let mut encountered_log = false;
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            XMLEvent::Start(t) => match t.local_name().as_ref() {
+                b"log" => encountered_log = true,
+                b"trace" => {
+                    num_traces += 1;
+                    in_trace = true;
+                }
+                b"event" if in_trace => num_events += 1,
+                _ => {}
+            },
+            XMLEvent::Empty(t) => match t.local_name().as_ref() {
+                b"log" => encountered_log = true,
+                b"trace" => num_traces += 1,
+                b"event" if in_trace => num_events += 1,
+                _ => {}
+            },
+            XMLEvent::End(t) if t.local_name().as_ref() == b"trace" => in_trace = false,
+            XMLEvent::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    if !encountered_log {
+        return Err(XESParseError::NoTopLevelLog);
+    }
+    Ok((num_traces, num_events))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_xes_traces() {
+        let xes = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<log xes.version="1.0">
+  <global scope="event">
+    <string key="concept:name" value="__INVALID__"/>
+  </global>
+  <trace>
+    <string key="concept:name" value="c1"/>
+    <event>
+      <string key="concept:name" value="a"/>
+    </event>
+    <event>
+      <string key="concept:name" value="b"/>
+    </event>
+  </trace>
+  <trace/>
+  <trace>
+    <event/>
+  </trace>
+</log>"#;
+        assert_eq!(
+            count_xes_traces_bufread(xes.as_bytes(), false).unwrap(),
+            (3, 3)
+        );
+        assert!(matches!(
+            count_xes_traces_bufread("<trace></trace>".as_bytes(), false),
+            Err(XESParseError::NoTopLevelLog)
+        ));
+    }
+}
@@ -224,6 +224,29 @@ pub fn export_xes_event_log_to_path<P: AsRef<std::path::Path>>(
     export_xes_event_log_to_file(log, file, is_gz)
 }
 
+/// Export an [`EventLog`] to a byte vector (e.g., to send it over the network)
+///
+/// If `compress_gz` is `true`, the XES data is gz-compressed (i.e., the content of a `.xes.gz` file)
+pub fn export_xes_event_log_to_vec(
+    log: &EventLog,
+    compress_gz: bool,
+) -> Result<Vec<u8>, quick_xml::Error> {
+    if compress_gz {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+        export_xes_event_log(&mut Writer::new(&mut encoder), log)?;
+        return Ok(encoder.finish()?);
+    }
+    let mut bytes = Vec::new();
+    export_xes_event_log(&mut Writer::new(&mut bytes), log)?;
+    Ok(bytes)
+}
+
+/// Export an [`EventLog`] to a XES [`String`]
+pub fn export_xes_event_log_to_string(log: &EventLog) -> Result<String, quick_xml::Error> {
+    let bytes = export_xes_event_log_to_vec(log, false)?;
+    Ok(String::from_utf8(bytes).expect("XES export only writes valid UTF-8 strings"))
+}
+
 /// Export a trace stream (i.e., [`Iterator`] over [`Trace`]) and [`XESOuterLogData`] to a XML [`Writer`]
 pub fn export_xes_trace_stream<'a, W, T: Borrow<Trace>, I>(
     writer: impl Into<XMLWriterWrapper<'a, W>>,
@@ -272,6 +295,41 @@ where
     )
 }
 
+/// Export a trace stream (i.e., [`Iterator`] over [`Trace`]s, e.g., generated on the fly) and [`XESOuterLogData`] to a [`Write`]r
+///
+/// The `<log>` header (log attributes, extensions, global attributes, and classifiers) is written from `log_data`,
+/// followed by each trace as it is yielded by the iterator. Thus, traces are never collected into a full [`EventLog`].
+/// See also [`export_xes_trace_stream`], which this wraps.
+///
+/// If `compress_gz` is `true`, the XES is gz-compressed (i.e., the content of a `.xes.gz` file).
+/// The writer is flushed (and the compression finished) before returning.
+pub fn export_xes_from_trace_iter<T: Borrow<Trace>, I, W>(
+    traces: I,
+    log_data: XESOuterLogData,
+    writer: W,
+    compress_gz: bool,
+) -> Result<(), quick_xml::Error>
+where
+    I: Iterator<Item = T>,
+    W: Write,
+{
+    if compress_gz {
+        let mut xml_writer =
+            Writer::new(BufWriter::new(GzEncoder::new(writer, Compression::fast())));
+        export_xes_trace_stream(&mut xml_writer, traces, log_data)?;
+        xml_writer
+            .into_inner()
+            .into_inner()
+            .map_err(|e| e.into_error())?
+            .finish()?;
+        return Ok(());
+    }
+    let mut xml_writer = Writer::new(BufWriter::new(writer));
+    export_xes_trace_stream(&mut xml_writer, traces, log_data)?;
+    xml_writer.into_inner().flush()?;
+    Ok(())
+}
+
 fn serialize_classifier(classifier_keys: &[String]) -> String {
     let should_quote = classifier_keys.iter().any(|key| key.contains(' '));
     if should_quote {
@@ -293,7 +351,11 @@ mod export_xes_tests {
 
     use crate::{
         core::event_data::case_centric::{
-            event_log_struct::EventLogExtension,
+            constants::ACTIVITY_NAME,
+            event_log_struct::{
+                Attribute, AttributeValue, Event, EventLogClassifier, EventLogExtension, Trace,
+                XESEditableAttribute,
+            },
             xes::{
                 export_xes::{export_xes_event_log, serialize_classifier},
                 import_xes::{import_xes_path, import_xes_slice, XESImportOptions},
@@ -303,7 +365,28 @@ mod export_xes_tests {
         test_utils::get_test_data_path,
     };
 
-    use super::export_xes_trace_stream_to_file;
+    use super::{
+        export_xes_event_log_to_string, export_xes_event_log_to_vec, export_xes_from_trace_iter,
+        export_xes_trace_stream_to_file,
+    };
+
+    #[test]
+    fn test_xes_export_to_string_and_vec() {
+        let log = crate::event_log!(["a", "b"], ["a", "c", "b"]);
+        let xes = export_xes_event_log_to_string(&log).unwrap();
+        assert!(xes.starts_with("<?xml"));
+        let log2 = import_xes_slice(xes.as_bytes(), false, XESImportOptions::default()).unwrap();
+        assert_eq!(log.traces.len(), log2.traces.len());
+
+        let gz = export_xes_event_log_to_vec(&log, true).unwrap();
+        let log3 = import_xes_slice(&gz, true, XESImportOptions::default()).unwrap();
+        assert_eq!(log.traces.len(), log3.traces.len());
+        assert_eq!(log3.traces[1].events.len(), 3);
+        assert_eq!(
+            export_xes_event_log_to_vec(&log, false).unwrap(),
+            xes.into_bytes()
+        );
+    }
 
     #[test]
     fn test_xes_export_std_writer() {
@@ -483,4 +566,68 @@ mod export_xes_tests {
             );
         }
     }
+
+    #[test]
+    fn test_xes_export_from_trace_iter() {
+        let log_data = XESOuterLogData {
+            classifiers: vec![EventLogClassifier {
+                name: "Activity".to_string(),
+                keys: vec![ACTIVITY_NAME.to_string()],
+            }],
+            global_event_attrs: vec![Attribute::new(
+                ACTIVITY_NAME.to_string(),
+                AttributeValue::String("unknown".to_string()),
+            )],
+            ..Default::default()
+        };
+        let traces = || {
+            (0..10_000).map(|i| {
+                let mut trace = Trace::new();
+                trace.attributes.push(Attribute::new(
+                    "concept:name".to_string(),
+                    AttributeValue::String(format!("case-{i}")),
+                ));
+                trace.events = (0..i % 5)
+                    .map(|j| Event::new(format!("activity-{j}")))
+                    .collect();
+                trace
+            })
+        };
+        for compress_gz in [false, true] {
+            let mut bytes = Vec::new();
+            export_xes_from_trace_iter(traces(), log_data.clone(), &mut bytes, compress_gz)
+                .unwrap();
+            let log = import_xes_slice(&bytes, compress_gz, XESImportOptions::default()).unwrap();
+            assert_eq!(log.traces.len(), 10_000);
+            assert_eq!(log.classifiers, Some(log_data.classifiers.clone()));
+            assert_eq!(
+                log.global_event_attrs,
+                Some(log_data.global_event_attrs.clone())
+            );
+            assert_eq!(
+                log.traces.iter().map(|t| t.events.len()).sum::<usize>(),
+                traces().map(|t| t.events.len()).sum::<usize>()
+            );
+            assert_eq!(
+                log.traces[9_999]
+                    .attributes
+                    .get_by_key("concept:name")
+                    .unwrap()
+                    .value,
+                AttributeValue::String("case-9999".to_string())
+            );
+            // Borrowed traces can be exported as well
+            let mut re_exported = Vec::new();
+            export_xes_from_trace_iter(
+                log.traces.iter(),
+                log_data.clone(),
+                &mut re_exported,
+                compress_gz,
+            )
+            .unwrap();
+            let re_imported =
+                import_xes_slice(&re_exported, compress_gz, XESImportOptions::default()).unwrap();
+            assert_eq!(re_imported.traces, log.traces);
+        }
+    }
 }
@@ -1,9 +1,9 @@
 use std::collections::HashSet;
 
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Read};
 
-use flate2::bufread::GzDecoder;
+use flate2::read::GzDecoder;
 
 use quick_xml::Error as QuickXMLError;
 use quick_xml::Reader;
@@ -38,11 +38,43 @@ pub enum XESParseError {
     ExpectedLogData,
     /// Parsing Transformation Error: Expected that Trace would be emitted now
     ExpectedTraceData,
+    /// An event does not have a (valid) timestamp, although one is required
+    /// (i.e., for sorting events in strict mode, see [`XESImportOptions::strict`])
+    ///
+    /// Includes the index of the trace and the index of the event within this trace
+    MissingTimestamp {
+        /// Index of the trace in the log
+        trace: usize,
+        /// Index of the event in the trace
+        event: usize,
+    },
+    /// A date value could not be parsed as a timestamp (with the invalid value included)
+    BadTimestamp {
+        /// The value which could not be parsed
+        value: String,
+    },
+    /// An XML tag was encountered where it is not allowed by the XES standard (with the tag name included)
+    UnexpectedTag {
+        /// Name of the unexpected tag
+        tag: String,
+    },
 }
 
 impl std::fmt::Display for XESParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Failed to parse XES: {self:?}")
+        match self {
+            XESParseError::MissingTimestamp { trace, event } => write!(
+                f,
+                "Failed to parse XES: Missing timestamp for event {event} in trace {trace}"
+            ),
+            XESParseError::BadTimestamp { value } => {
+                write!(f, "Failed to parse XES: Invalid timestamp {value:?}")
+            }
+            XESParseError::UnexpectedTag { tag } => {
+                write!(f, "Failed to parse XES: Unexpected tag <{tag}>")
+            }
+            _ => write!(f, "Failed to parse XES: {self:?}"),
+        }
     }
 }
 
@@ -74,6 +106,9 @@ impl From<QuickXMLError> for XESParseError {
 /// Options for XES Import
 ///
 /// See also [`build_ignore_attributes`] for easy construction of attributes set to not ignore
+///
+/// Entries of the allowlists ending with `*` match all keys starting with the preceding prefix
+/// (e.g., `case:*` keeps all attributes with keys starting with `case:`).
 pub struct XESImportOptions {
     /// If Some: Ignore all top-level log attributes, except attributes with keys in the provided allowlist
     pub ignore_log_attributes_except: Option<HashSet<String>>,
@@ -102,6 +137,19 @@ pub struct XESImportOptions {
     /// * If true: Print warnings and error messages to stdout
     /// * If false: Suppress all warnings and error messages
     pub verbose: bool,
+    /// Strict parsing: Fail on malformed data instead of skipping it
+    /// * If true: Return an error for
+    ///   * date values that cannot be parsed ([`XESParseError::BadTimestamp`]),
+    ///   * tags that are not part of the XES standard ([`XESParseError::UnexpectedTag`]), and
+    ///   * events without a valid timestamp for the `sort_events_with_timestamp_key` (if set) ([`XESParseError::MissingTimestamp`])
+    /// * If false: Skip such values (possibly printing a warning if `verbose` is set)
+    #[serde(default)]
+    pub strict: bool,
+    /// Gather a [`StreamSummary`](super::stream_xes::StreamSummary) of all traces while streaming
+    ///
+    /// Available via `finish_summary` on the trace/event streams (e.g., [`XESParsingTraceStream::finish_summary`](super::stream_xes::XESParsingTraceStream::finish_summary))
+    #[serde(default)]
+    pub collect_summary: bool,
 }
 
 impl Default for XESImportOptions {
@@ -113,13 +161,17 @@ impl Default for XESImportOptions {
             date_format: None,
             sort_events_with_timestamp_key: None,
             verbose: true,
+            strict: false,
+            collect_summary: false,
         }
     }
 }
 ///
-/// Construct a `HashSet<Vec<u8>>` from a _collection_ of String, &str, ...
+/// Construct a `HashSet<String>` from a _collection_ of String, &str, ...
+///
+/// Example usage: `build_ignore_attributes(vec!["concept:name", "case:*"])`
 ///
-/// Example usage: `XESImportOptions::build_ignore_attributes(vec!["concept:name"])`
+/// Entries ending with `*` are matched as prefixes (see [`XESImportOptions`]).
 ///
 pub fn build_ignore_attributes<I, S: AsRef<str>>(keys: I) -> HashSet<String>
 where
@@ -128,6 +180,74 @@ where
     keys.into_iter().map(|s| s.as_ref().to_string()).collect()
 }
 
+///
+/// Attribute allowlist of [`XESImportOptions`], precomputed for fast lookups
+///
+/// Entries ending with `*` match all keys starting with the preceding prefix.
+///
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AttributeAllowlist {
+    /// Keys matched exactly
+    exact: HashSet<String>,
+    /// Sorted prefixes, excluding prefixes which are already covered by a shorter prefix
+    prefixes: Vec<String>,
+}
+
+impl AttributeAllowlist {
+    pub(crate) fn new(allowlist: &HashSet<String>) -> Self {
+        let mut prefixes: Vec<String> = allowlist
+            .iter()
+            .filter_map(|entry| entry.strip_suffix('*').map(str::to_string))
+            .collect();
+        prefixes.sort();
+        // After sorting, a prefix directly follows the (last kept) shorter prefix covering it
+        prefixes.dedup_by(|p, kept| p.starts_with(kept.as_str()));
+        Self {
+            exact: allowlist.clone(),
+            prefixes,
+        }
+    }
+
+    /// Check if the attribute key is contained in the allowlist
+    pub(crate) fn contains(&self, key: &str) -> bool {
+        if self.exact.contains(key) {
+            return true;
+        }
+        // As no prefix covers another, only the greatest prefix not greater than `key` can match
+        let i = self.prefixes.partition_point(|p| p.as_str() <= key);
+        i > 0 && key.starts_with(self.prefixes[i - 1].as_str())
+    }
+}
+
+///
+/// Log, trace, and event attribute allowlists of [`XESImportOptions`], precomputed for fast lookups
+///
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AttributeAllowlists {
+    pub(crate) log: Option<AttributeAllowlist>,
+    pub(crate) trace: Option<AttributeAllowlist>,
+    pub(crate) event: Option<AttributeAllowlist>,
+}
+
+impl AttributeAllowlists {
+    pub(crate) fn new(options: &XESImportOptions) -> Self {
+        Self {
+            log: options
+                .ignore_log_attributes_except
+                .as_ref()
+                .map(AttributeAllowlist::new),
+            trace: options
+                .ignore_trace_attributes_except
+                .as_ref()
+                .map(AttributeAllowlist::new),
+            event: options
+                .ignore_event_attributes_except
+                .as_ref()
+                .map(AttributeAllowlist::new),
+        }
+    }
+}
+
 /// Parse XES from the given reader
 pub fn import_xes<T>(reader: T, options: XESImportOptions) -> Result<EventLog, XESParseError>
 where
@@ -147,6 +267,25 @@ where
     Ok(EventLog::from_traces_and_log_data(traces, log_data))
 }
 
+///
+/// Import a XES [`EventLog`] from any [`Read`] (e.g., a network stream, a zip archive entry, or stdin)
+///
+/// * `is_compressed_gz`: Parse the data from the passed `reader` as a compressed .gz archive
+///
+/// The reader is buffered internally, so there is no need to pass a [`BufReader`].
+///
+pub fn import_xes_reader<R: Read>(
+    reader: R,
+    options: XESImportOptions,
+    is_compressed_gz: bool,
+) -> Result<EventLog, XESParseError> {
+    if is_compressed_gz {
+        import_xes(BufReader::new(GzDecoder::new(reader)), options)
+    } else {
+        import_xes(BufReader::new(reader), options)
+    }
+}
+
 ///
 /// Import a XES [`EventLog`] from a file path
 ///
@@ -154,29 +293,20 @@ pub fn import_xes_path<P: AsRef<std::path::Path>>(
     path: P,
     options: XESImportOptions,
 ) -> Result<EventLog, XESParseError> {
-    if path
+    let is_gz = path
         .as_ref()
         .as_os_str()
         .to_str()
-        .is_some_and(|p| p.ends_with(".gz"))
-    {
-        let file = File::open(path)?;
-        let dec: GzDecoder<BufReader<&File>> = GzDecoder::new(BufReader::new(&file));
-        let reader = BufReader::new(dec);
-        import_xes(reader, options)
-    } else {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        import_xes(reader, options)
-    }
+        .is_some_and(|p| p.ends_with(".gz"));
+    let file = File::open(path)?;
+    import_xes_reader(file, options, is_gz)
 }
 
 ///
 /// Import a XES [`EventLog`] directly from a string
 ///
 pub fn import_xes_str(xes_str: &str, options: XESImportOptions) -> Result<EventLog, XESParseError> {
-    let reader = BufReader::new(xes_str.as_bytes());
-    import_xes(reader, options)
+    import_xes(xes_str.as_bytes(), options)
 }
 
 ///
@@ -189,11 +319,5 @@ pub fn import_xes_slice(
     is_compressed_gz: bool,
     options: XESImportOptions,
 ) -> Result<EventLog, XESParseError> {
-    // let buf_reader = BufReader::new(reader);
-    if is_compressed_gz {
-        let gz: GzDecoder<&[u8]> = GzDecoder::new(xes_data);
-        let reader = BufReader::new(gz);
-        return import_xes(reader, options);
-    }
-    import_xes(BufReader::new(xes_data), options)
+    import_xes_reader(xes_data, options, is_compressed_gz)
 }
@@ -1,8 +1,11 @@
 //! XES Format for Event Data
+pub(crate) mod count_xes;
 pub(crate) mod export_xes;
 pub(crate) mod import_xes;
 pub(crate) mod stream_xes;
 #[doc(inline)]
+pub use count_xes::*;
+#[doc(inline)]
 pub use export_xes::*;
 #[doc(inline)]
 pub use import_xes::*;
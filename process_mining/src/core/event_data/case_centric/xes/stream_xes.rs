@@ -1,17 +1,19 @@
 use crate::core::event_data::case_centric::xes::import_xes::XESImportOptions;
+use crate::diagnostics::warn_diagnostic;
 
 use super::{
     super::event_log_struct::{
         Attribute, AttributeValue, Attributes, Event, EventLogClassifier, EventLogExtension, Trace,
         XESEditableAttribute,
     },
-    import_xes::XESParseError,
+    import_xes::{AttributeAllowlists, XESParseError},
 };
 use chrono::{DateTime, FixedOffset, NaiveDateTime};
 use flate2::read::GzDecoder;
 use quick_xml::{escape::unescape, events::BytesStart, Reader};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashSet,
     fmt::Debug,
     fs::File,
     io::{BufRead, BufReader, Read},
@@ -85,6 +87,8 @@ pub struct StreamingXESParser<'a> {
     current_nested_attributes: Vec<Attribute>,
     /// XES Import options (see [`XESImportOptions`])
     options: XESImportOptions,
+    /// Attribute allowlists of the options, precomputed for fast lookups
+    allowlists: AttributeAllowlists,
     /// Whether a (top-level) log tag was encountered yet (top-level log tag is required for XES files, see [`XESParseError::NoTopLevelLog`])
     encountered_log: bool,
     // [XESOuterLogData] parsed from the log (this will be emitted once the first trace is encountered or the file ends)
@@ -93,6 +97,8 @@ pub struct StreamingXESParser<'a> {
     log_data_emitted: bool,
     /// Whether the parsing was terminated (either by encountering an error or reaching the Eof)
     finished: bool,
+    /// Number of traces emitted so far (i.e., the index of the currently parsed trace)
+    num_traces_emitted: usize,
 }
 
 impl Debug for StreamingXESParser<'_> {
@@ -154,7 +160,7 @@ impl StreamingXESParser<'_> {
         fn emit_trace_data(myself: &mut StreamingXESParser<'_>) -> Option<XESNextStreamElement> {
             if let Some(mut trace) = myself.current_trace.take() {
                 if let Some(event_timestamp_key) = &myself.options.sort_events_with_timestamp_key {
-                    trace.events.sort_by_key(|e| {
+                    let get_timestamp = |e: &Event| {
                         if let Some(dt_attr) = e.attributes.get_by_key(event_timestamp_key) {
                             if let AttributeValue::Date(d) = dt_attr.value {
                                 return Some(d);
@@ -171,7 +177,19 @@ impl StreamingXESParser<'_> {
                         }
 
                         None
-                    });
+                    };
+                    if myself.options.strict {
+                        if let Some(event) =
+                            trace.events.iter().position(|e| get_timestamp(e).is_none())
+                        {
+                            let trace = myself.num_traces_emitted;
+                            return terminate_with_error(
+                                myself,
+                                XESParseError::MissingTimestamp { trace, event },
+                            );
+                        }
+                    }
+                    trace.events.sort_by_key(get_timestamp);
                 }
                 trace.events.shrink_to_fit();
                 trace.attributes.shrink_to_fit();
@@ -179,6 +197,7 @@ impl StreamingXESParser<'_> {
                     .events
                     .iter_mut()
                     .for_each(|e| e.attributes.shrink_to_fit());
+                myself.num_traces_emitted += 1;
                 return Some(XESNextStreamElement::Trace(trace));
             }
             terminate_with_error(myself, XESParseError::MissingLastTrace)
@@ -233,7 +252,9 @@ impl StreamingXESParser<'_> {
                                     }
                                     None => {
                                         if self.options.verbose {
-                                            eprintln!("Invalid XES format: Event without trace")
+                                            warn_diagnostic!(
+                                                "Invalid XES format: Event without trace"
+                                            )
                                         }
                                     }
                                 }
@@ -268,7 +289,7 @@ impl StreamingXESParser<'_> {
                                 // and nested if symbolizes the logic better
                                 if self.encountered_log {
                                     if self.options.verbose {
-                                        eprintln!(
+                                        warn_diagnostic!(
                                         "Encountered two log tags. This is not a valid XES file"
                                     )
                                     }
@@ -294,15 +315,18 @@ impl StreamingXESParser<'_> {
                                     // Nested attribute!
                                     let key = get_attribute_string(&t, "key").unwrap_or_default();
                                     if !should_ignore_attribute(
-                                        &self.options,
+                                        &self.allowlists,
                                         &self.current_mode,
                                         &key,
                                     ) {
-                                        let value = parse_attribute_value_from_tag(
+                                        let value = match parse_attribute_value_from_tag(
                                             &t,
                                             &self.current_mode,
                                             &self.options,
-                                        );
+                                        ) {
+                                            Ok(value) => value,
+                                            Err(e) => return terminate_with_error(self, e),
+                                        };
                                         if !(key.is_empty()
                                             && matches!(value, AttributeValue::None()))
                                         {
@@ -352,18 +376,23 @@ impl StreamingXESParser<'_> {
                                         XESParseError::NoTopLevelLog,
                                     );
                                 }
-                                if !StreamingXESParser::add_attribute_from_tag(
+                                match StreamingXESParser::add_attribute_from_tag(
                                     &self.current_mode,
                                     &mut self.current_trace,
                                     &mut self.log_data,
                                     &mut self.current_nested_attributes,
                                     &self.options,
+                                    &self.allowlists,
                                     &t,
                                 ) {
-                                    return terminate_with_error(
-                                        self,
-                                        XESParseError::AttributeOutsideLog,
-                                    );
+                                    Ok(true) => {}
+                                    Ok(false) => {
+                                        return terminate_with_error(
+                                            self,
+                                            XESParseError::AttributeOutsideLog,
+                                        );
+                                    }
+                                    Err(e) => return terminate_with_error(self, e),
                                 }
                             }
                         },
@@ -466,7 +495,7 @@ impl StreamingXESParser<'_> {
                                                 // This means there was no current nested attribute but the mode indicated otherwise
                                                 // Should thus not happen, but execution can continue.
                                                 if self.options.verbose {
-                                                    eprintln!("Warning: Attribute mode but no open nested attributes!");
+                                                    warn_diagnostic!("Attribute mode but no open nested attributes!");
                                                 }
                                                 self.current_mode = self.last_mode_before_attr;
                                             }
@@ -591,38 +620,16 @@ pub fn parse_classifier_key(t: String, log_data: &XESOuterLogData) -> Vec<String
     }
     ret
 }
-fn should_ignore_attribute(options: &XESImportOptions, mode: &Mode, key: &str) -> bool {
-    if options.ignore_event_attributes_except.is_some()
-        || options.ignore_trace_attributes_except.is_some()
-        || options.ignore_log_attributes_except.is_some()
-    {
-        if matches!(mode, Mode::Event)
-            && options
-                .ignore_event_attributes_except
-                .as_ref()
-                .is_some_and(|not_ignored| !not_ignored.contains(key))
-        {
-            return true;
-        }
-        if matches!(mode, Mode::Trace)
-            && options
-                .ignore_trace_attributes_except
-                .as_ref()
-                .is_some_and(|not_ignored| !not_ignored.contains(key))
-        {
-            return true;
-        }
-
-        if matches!(mode, Mode::Log)
-            && options
-                .ignore_log_attributes_except
-                .as_ref()
-                .is_some_and(|not_ignored| !not_ignored.contains(key))
-        {
-            return true;
-        }
-    }
-    false
+fn should_ignore_attribute(allowlists: &AttributeAllowlists, mode: &Mode, key: &str) -> bool {
+    let allowlist = match mode {
+        Mode::Event => &allowlists.event,
+        Mode::Trace => &allowlists.trace,
+        Mode::Log => &allowlists.log,
+        _ => return false,
+    };
+    allowlist
+        .as_ref()
+        .is_some_and(|not_ignored| !not_ignored.contains(key))
 }
 #[test]
 fn test_classifier_parse() {
@@ -706,20 +713,24 @@ impl StreamingXESParser<'_> {
     ///
     /// Add XES attribute from tag to the currently active element (indicated by `current_mode`)
     ///
+    /// Returns `Ok(false)` if there is no active element to add the attribute to,
+    /// and an error if the attribute value is invalid (in strict mode, see [`XESImportOptions::strict`])
+    ///
     fn add_attribute_from_tag(
         current_mode: &Mode,
         current_trace: &mut Option<Trace>,
         log_data: &mut XESOuterLogData,
         current_nested_attributes: &mut [Attribute],
         options: &XESImportOptions,
+        allowlists: &AttributeAllowlists,
         t: &BytesStart<'_>,
-    ) -> bool {
+    ) -> Result<bool, XESParseError> {
         let key = get_attribute_string(t, "key").unwrap_or_default();
-        if should_ignore_attribute(options, current_mode, &key) {
-            return true;
+        if should_ignore_attribute(allowlists, current_mode, &key) {
+            return Ok(true);
         }
 
-        let val = parse_attribute_value_from_tag(t, current_mode, options);
+        let val = parse_attribute_value_from_tag(t, current_mode, options)?;
         match current_mode {
             Mode::Trace => match current_trace {
                 Some(t) => {
@@ -727,7 +738,7 @@ impl StreamingXESParser<'_> {
                 }
                 None => {
                     if options.verbose {
-                        eprintln!(
+                        warn_diagnostic!(
                         "No current trace when parsing trace attribute: Key {key:?}, Value {val:?}"
                     );
                     }
@@ -740,7 +751,7 @@ impl StreamingXESParser<'_> {
                     }
                     None => {
                         if options.verbose {
-                            eprintln!(
+                            warn_diagnostic!(
                             "No current event when parsing event attribute: Key {key:?}, Value {val:?}"
                         )
                         }
@@ -748,7 +759,7 @@ impl StreamingXESParser<'_> {
                 },
                 None => {
                     if options.verbose {
-                        eprintln!(
+                        warn_diagnostic!(
                         "No current trace when parsing event attribute: Key {key:?}, Value {val:?}"
                     );
                     }
@@ -758,7 +769,7 @@ impl StreamingXESParser<'_> {
             Mode::Log => {
                 log_data.log_attributes.add_to_attributes(key, val);
             }
-            Mode::None => return false,
+            Mode::None => return Ok(false),
             Mode::Attribute => {
                 if let Some(last_attr) = current_nested_attributes.last_mut() {
                     match &mut last_attr.value {
@@ -778,7 +789,7 @@ impl StreamingXESParser<'_> {
                         }
                     }
                 } else {
-                    return false;
+                    return Ok(false);
                 }
             }
             Mode::GlobalTraceAttributes => {
@@ -788,7 +799,7 @@ impl StreamingXESParser<'_> {
                 log_data.global_event_attrs.add_to_attributes(key, val);
             }
         }
-        true
+        Ok(true)
     }
 }
 
@@ -802,6 +813,64 @@ pub struct XESParsingTraceStream<'a> {
     inner: StreamingXESParser<'a>,
     /// Error encountered while parsing XES
     pub error: Option<XESParseError>,
+    summary: StreamSummary,
+}
+
+///
+/// Descriptive statistics on the traces emitted by a [`XESParsingTraceStream`]
+///
+/// Gathered while streaming (if enabled via [`XESImportOptions::collect_summary`]),
+/// so that no second pass over the XES is necessary (see [`XESParsingTraceStream::finish_summary`]).
+///
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StreamSummary {
+    /// Number of traces
+    pub num_traces: usize,
+    /// Number of events (across all traces)
+    pub num_events: usize,
+    /// Distinct activity labels (i.e., `concept:name` values of events)
+    pub activities: HashSet<String>,
+    /// Distinct keys of (top-level) event attributes
+    pub event_attribute_keys: HashSet<String>,
+    /// Earliest event timestamp (`time:timestamp`)
+    pub first_timestamp: Option<DateTime<FixedOffset>>,
+    /// Latest event timestamp (`time:timestamp`)
+    pub last_timestamp: Option<DateTime<FixedOffset>>,
+}
+
+impl StreamSummary {
+    fn add_trace(&mut self, trace: &Trace) {
+        self.num_traces += 1;
+        self.num_events += trace.events.len();
+        for event in &trace.events {
+            for attr in &event.attributes {
+                if !self.event_attribute_keys.contains(&attr.key) {
+                    self.event_attribute_keys.insert(attr.key.clone());
+                }
+                match (attr.key.as_str(), &attr.value) {
+                    ("concept:name", AttributeValue::String(activity))
+                        if !self.activities.contains(activity) =>
+                    {
+                        self.activities.insert(activity.clone());
+                    }
+                    ("time:timestamp", AttributeValue::Date(time)) => {
+                        if self.first_timestamp.is_none_or(|t| *time < t) {
+                            self.first_timestamp = Some(*time);
+                        }
+                        if self.last_timestamp.is_none_or(|t| *time > t) {
+                            self.last_timestamp = Some(*time);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Time span between the first and last event timestamp (if any timestamps were encountered)
+    pub fn time_span(&self) -> Option<chrono::Duration> {
+        Some(self.last_timestamp? - self.first_timestamp?)
+    }
 }
 /// [`XESParsingTraceStream`] and [`XESOuterLogData`]
 ///
@@ -816,7 +885,12 @@ impl Iterator for &mut XESParsingTraceStream<'_> {
             return None;
         }
         match self.inner.next_trace() {
-            Some(XESNextStreamElement::Trace(t)) => Some(t),
+            Some(XESNextStreamElement::Trace(t)) => {
+                if self.inner.options.collect_summary {
+                    self.summary.add_trace(&t);
+                }
+                Some(t)
+            }
             Some(XESNextStreamElement::Error(e)) => {
                 self.error = Some(e);
                 None
@@ -838,8 +912,25 @@ impl<'a> XESParsingTraceStream<'a> {
         self.error.clone()
     }
 
+    ///
+    /// Consume the stream and return a [`StreamSummary`] of all traces emitted so far
+    ///
+    /// Call this after iterating the stream to completion to get statistics on the whole log.
+    /// Note that parsing errors are not part of the summary (see [`XESParsingTraceStream::check_for_errors`]).
+    ///
+    /// Returns `None` if [`XESImportOptions::collect_summary`] is not enabled.
+    ///
+    pub fn finish_summary(self) -> Option<StreamSummary> {
+        if self.inner.options.collect_summary {
+            Some(self.summary)
+        } else {
+            None
+        }
+    }
+
     /// Set or update parsing options
     pub fn set_options(&mut self, options: XESImportOptions) {
+        self.inner.allowlists = AttributeAllowlists::new(&options);
         self.inner.options = options;
     }
 
@@ -865,11 +956,13 @@ impl<'a> XESParsingTraceStream<'a> {
             last_mode_before_attr: Mode::Log,
             encountered_log: false,
             current_nested_attributes: Vec::new(),
+            allowlists: AttributeAllowlists::new(&options),
             options,
             log_data,
             log_data_emitted: false,
             buf: Vec::new(),
             finished: false,
+            num_traces_emitted: 0,
         };
         let next = s.next_trace();
         match next {
@@ -877,7 +970,9 @@ impl<'a> XESParsingTraceStream<'a> {
                 XESNextStreamElement::Error(e) => Err(e),
                 XESNextStreamElement::Trace(_) => {
                     if s.options.verbose {
-                        eprintln!("Encountered trace before LogData; This should not happen!");
+                        warn_diagnostic!(
+                            "Encountered trace before LogData; This should not happen!"
+                        );
                     }
                     Err(XESParseError::ExpectedLogData)
                 }
@@ -885,6 +980,7 @@ impl<'a> XESParsingTraceStream<'a> {
                     (Self {
                         inner: s,
                         error: None,
+                        summary: StreamSummary::default(),
                     }),
                     d,
                 )),
@@ -892,7 +988,7 @@ impl<'a> XESParsingTraceStream<'a> {
             None => {
                 // No log data and no error returned: This should not happen!
                 if s.options.verbose {
-                    eprintln!(
+                    warn_diagnostic!(
                     "Iterator initially empty. Expected log data or error; This should not happen!"
                 );
                 }
@@ -902,6 +998,35 @@ impl<'a> XESParsingTraceStream<'a> {
     }
 }
 
+///
+/// Stream XES [`Trace`]s from any [`Read`] (e.g., a network stream, a zip archive entry, or stdin)
+///
+/// * `is_compressed_gz`: Parse the data from the passed `reader` as a compressed .gz archive
+///
+/// The reader is buffered internally, so there is no need to pass a [`BufReader`].
+///
+/// The returned [`XESParsingStreamAndLogData`] contains the [`XESOuterLogData`] and can be used to iterate over [`Trace`]s
+///
+pub fn stream_xes_reader<'a, R: Read + 'a>(
+    reader: R,
+    options: XESImportOptions,
+    is_compressed_gz: bool,
+) -> Result<XESParsingStreamAndLogData<'a>, XESParseError> {
+    if is_compressed_gz {
+        XESParsingTraceStream::try_new(
+            Box::new(Reader::from_reader(Box::new(BufReader::new(
+                GzDecoder::new(reader),
+            )))),
+            options,
+        )
+    } else {
+        XESParsingTraceStream::try_new(
+            Box::new(Reader::from_reader(Box::new(BufReader::new(reader)))),
+            options,
+        )
+    }
+}
+
 ///
 /// Stream XES [`Trace`]s from a byte slice
 ///
@@ -911,10 +1036,7 @@ pub fn stream_xes_slice(
     xes_data: &[u8],
     options: XESImportOptions,
 ) -> Result<XESParsingStreamAndLogData<'_>, XESParseError> {
-    XESParsingTraceStream::try_new(
-        Box::new(Reader::from_reader(Box::new(BufReader::new(xes_data)))),
-        options,
-    )
+    stream_xes_reader(xes_data, options, false)
 }
 
 ///
@@ -926,9 +1048,7 @@ pub fn stream_xes_slice_gz(
     xes_data: &[u8],
     options: XESImportOptions,
 ) -> Result<XESParsingStreamAndLogData<'_>, XESParseError> {
-    let gz: GzDecoder<&[u8]> = GzDecoder::new(xes_data);
-    let reader = BufReader::new(gz);
-    XESParsingTraceStream::try_new(Box::new(Reader::from_reader(Box::new(reader))), options)
+    stream_xes_reader(xes_data, options, true)
 }
 
 ///
@@ -940,10 +1060,7 @@ pub fn stream_xes_file<'a>(
     file: File,
     options: XESImportOptions,
 ) -> Result<XESParsingStreamAndLogData<'a>, XESParseError> {
-    XESParsingTraceStream::try_new(
-        Box::new(Reader::from_reader(Box::new(BufReader::new(file)))),
-        options,
-    )
+    stream_xes_reader(file, options, false)
 }
 
 ///
@@ -955,11 +1072,7 @@ pub fn stream_xes_file_gz<'a>(
     file: File,
     options: XESImportOptions,
 ) -> Result<XESParsingStreamAndLogData<'a>, XESParseError> {
-    let dec = GzDecoder::new(file);
-    XESParsingTraceStream::try_new(
-        Box::new(Reader::from_reader(Box::new(BufReader::new(dec)))),
-        options,
-    )
+    stream_xes_reader(file, options, true)
 }
 
 ///
@@ -1006,6 +1119,91 @@ pub fn stream_xes_from_path<'a, P: AsRef<std::path::Path>>(
     }
 }
 
+/// XES Parsing Event Stream
+///
+/// Allows iterating over [`Event`]s, together with the ID of the trace they originate from
+///
+/// Wraps an [`XESParsingTraceStream`], so only the events of the current trace are kept in memory.
+/// Events are emitted in the order of the trace (see [`XESImportOptions::sort_events_with_timestamp_key`]).
+#[derive(Debug)]
+pub struct XESParsingEventStream<'a> {
+    traces: XESParsingTraceStream<'a>,
+    current_trace_id: String,
+    current_events: std::vec::IntoIter<Event>,
+    num_traces: usize,
+}
+
+/// [`XESParsingEventStream`] and [`XESOuterLogData`]
+///
+/// First component is event stream lazily parsed, second component provides top-level log information (eagerly parsed at the beginning)
+pub type XESParsingEventStreamAndLogData<'a> = (XESParsingEventStream<'a>, XESOuterLogData);
+
+impl<'a> XESParsingEventStream<'a> {
+    /// Construct a new [`XESParsingEventStream`] from an [`XESParsingTraceStream`]
+    pub fn new(traces: XESParsingTraceStream<'a>) -> Self {
+        Self {
+            traces,
+            current_trace_id: String::new(),
+            current_events: Vec::new().into_iter(),
+            num_traces: 0,
+        }
+    }
+
+    /// Check if any errors occured
+    pub fn check_for_errors(&self) -> Option<XESParseError> {
+        self.traces.check_for_errors()
+    }
+
+    /// Consume the stream and return a [`StreamSummary`] of all traces emitted so far
+    ///
+    /// See [`XESParsingTraceStream::finish_summary`].
+    pub fn finish_summary(self) -> Option<StreamSummary> {
+        self.traces.finish_summary()
+    }
+}
+
+impl Iterator for XESParsingEventStream<'_> {
+    /// Trace ID (`concept:name` of the trace, or the index of the trace if missing) and [`Event`]
+    type Item = (String, Event);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ev) = self.current_events.next() {
+                return Some((self.current_trace_id.clone(), ev));
+            }
+            let trace = (&mut self.traces).next()?;
+            self.current_trace_id = trace
+                .attributes
+                .get_by_key("concept:name")
+                .and_then(|a| a.value.try_as_string().cloned())
+                .unwrap_or_else(|| self.num_traces.to_string());
+            self.num_traces += 1;
+            self.current_events = trace.events.into_iter();
+        }
+    }
+}
+
+impl FusedIterator for XESParsingEventStream<'_> {}
+
+///
+/// Stream XES [`Event`]s from path (auto-detecting gz compression from file extension)
+///
+/// Each event is returned together with the ID of the trace it belongs to (see [`XESParsingEventStream`]).
+/// Events are sorted by `time:timestamp` within each trace, unless [`XESImportOptions::sort_events_with_timestamp_key`] is set to a different key.
+///
+/// The returned [`XESParsingEventStreamAndLogData`] contains the [`XESOuterLogData`] and can be used to iterate over [`Event`]s
+///
+pub fn stream_xes_events_from_path<'a, P: AsRef<std::path::Path>>(
+    path: P,
+    mut options: XESImportOptions,
+) -> Result<XESParsingEventStreamAndLogData<'a>, XESParseError> {
+    if options.sort_events_with_timestamp_key.is_none() {
+        options.sort_events_with_timestamp_key = Some("time:timestamp".to_string());
+    }
+    let (traces, log_data) = stream_xes_from_path(path, options)?;
+    Ok((XESParsingEventStream::new(traces), log_data))
+}
+
 fn get_attribute_string(t: &BytesStart<'_>, key: &'static str) -> Option<String> {
     if let Ok(Some(attr)) = t.try_get_attribute(key) {
         let raw = String::from_utf8_lossy(&attr.value);
@@ -1023,11 +1221,34 @@ fn get_attribute_string(t: &BytesStart<'_>, key: &'static str) -> Option<String>
     None
 }
 
+/// Tag names of XES attributes
+const XES_ATTRIBUTE_TAGS: [&[u8]; 8] = [
+    b"string",
+    b"date",
+    b"int",
+    b"float",
+    b"boolean",
+    b"id",
+    b"list",
+    b"container",
+];
+
+///
+/// Parse the attribute value from an XES attribute tag
+///
+/// Invalid values are skipped (i.e., [`AttributeValue::None`] is returned), except in strict mode (see [`XESImportOptions::strict`]),
+/// where an error is returned for invalid dates and unknown tags.
+///
 fn parse_attribute_value_from_tag(
     t: &BytesStart<'_>,
     mode: &Mode,
     options: &XESImportOptions,
-) -> AttributeValue {
+) -> Result<AttributeValue, XESParseError> {
+    if options.strict && !XES_ATTRIBUTE_TAGS.contains(&t.name().as_ref()) {
+        return Err(XESParseError::UnexpectedTag {
+            tag: String::from_utf8_lossy(t.name().as_ref()).into_owned(),
+        });
+    }
     let attribute_val: Option<AttributeValue> = match t.name().as_ref() {
         b"container" => Some(AttributeValue::Container(Attributes::new())),
         b"list" => Some(AttributeValue::List(Vec::new())),
@@ -1039,8 +1260,11 @@ fn parse_attribute_value_from_tag(
                     b"date" => match parse_date_from_str(&value, &options.date_format) {
                         Some(dt) => Some(AttributeValue::Date(dt)),
                         None => {
+                            if options.strict {
+                                return Err(XESParseError::BadTimestamp { value });
+                            }
                             if options.verbose {
-                                eprintln!("Failed to parse date from {value:?}");
+                                warn_diagnostic!("Failed to parse date from {value:?}");
                             }
                             None
                         }
@@ -1050,7 +1274,9 @@ fn parse_attribute_value_from_tag(
                             Ok(n) => n,
                             Err(e) => {
                                 if options.verbose {
-                                    eprintln!("Could not parse integer {value:?}: Error {e}");
+                                    warn_diagnostic!(
+                                        "Could not parse integer {value:?}: Error {e}"
+                                    );
                                 }
                                 i64::default()
                             }
@@ -1062,7 +1288,7 @@ fn parse_attribute_value_from_tag(
                             Ok(n) => n,
                             Err(e) => {
                                 if options.verbose {
-                                    eprintln!("Could not parse float {value:?}: Error {e}");
+                                    warn_diagnostic!("Could not parse float {value:?}: Error {e}");
                                 }
                                 f64::default()
                             }
@@ -1074,7 +1300,9 @@ fn parse_attribute_value_from_tag(
                             Ok(n) => n,
                             Err(e) => {
                                 if options.verbose {
-                                    eprintln!("Could not parse boolean {value:?}: Error {e}");
+                                    warn_diagnostic!(
+                                        "Could not parse boolean {value:?}: Error {e}"
+                                    );
                                 }
                                 bool::default()
                             }
@@ -1086,7 +1314,7 @@ fn parse_attribute_value_from_tag(
                             Ok(n) => n,
                             Err(e) => {
                                 if options.verbose {
-                                    eprintln!("Could not parse UUID {value:?}: Error {e}");
+                                    warn_diagnostic!("Could not parse UUID {value:?}: Error {e}");
                                 }
                                 Uuid::default()
                             }
@@ -1104,7 +1332,7 @@ fn parse_attribute_value_from_tag(
                                 .read_to_string(&mut name_str)
                                 .unwrap_or_default();
                             if options.verbose {
-                                eprintln!(
+                                warn_diagnostic!(
                                     "Attribute type not implemented '{name_str}' in mode {m:?}"
                                 );
                             }
@@ -1117,7 +1345,7 @@ fn parse_attribute_value_from_tag(
             }
         }
     };
-    attribute_val.unwrap_or(AttributeValue::None())
+    Ok(attribute_val.unwrap_or(AttributeValue::None()))
 }
 
 fn parse_date_from_str(value: &str, date_format: &Option<String>) -> Option<DateTime<FixedOffset>> {
@@ -1154,9 +1382,12 @@ mod stream_test {
     use std::{collections::HashSet, time::Instant};
 
     use crate::{
-        core::event_data::case_centric::xes::{
-            import_xes::{build_ignore_attributes, XESImportOptions},
-            stream_xes::stream_xes_from_path,
+        core::event_data::case_centric::{
+            event_log_struct::XESEditableAttribute,
+            xes::{
+                import_xes::{build_ignore_attributes, XESImportOptions},
+                stream_xes::{stream_xes_events_from_path, stream_xes_from_path},
+            },
         },
         test_utils::get_test_data_path,
     };
@@ -1242,4 +1473,90 @@ mod stream_test {
         println!("{:#?}", log_data.log_attributes);
         assert!(log_data.log_attributes.is_empty());
     }
+
+    #[test]
+    pub fn test_stream_xes_events() {
+        let xes = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<log xes.version="1.0">
+  <trace>
+    <string key="concept:name" value="c1"/>
+    <event>
+      <string key="concept:name" value="b"/>
+      <date key="time:timestamp" value="2024-01-01T11:00:00.000+00:00"/>
+    </event>
+    <event>
+      <string key="concept:name" value="a"/>
+      <date key="time:timestamp" value="2024-01-01T10:00:00.000+00:00"/>
+    </event>
+  </trace>
+  <trace>
+    <event>
+      <string key="concept:name" value="c"/>
+    </event>
+  </trace>
+  <trace>
+    <string key="concept:name" value="c3"/>
+  </trace>
+  <trace>
+    <string key="concept:name" value="c4"/>
+    <event>
+      <string key="concept:name" value="d"/>
+    </event>
+  </trace>
+</log>"#;
+        let path = get_test_data_path()
+            .join("export")
+            .join("stream-events-test.xes");
+        std::fs::write(&path, xes).unwrap();
+        let (mut stream, log_data) =
+            stream_xes_events_from_path(&path, XESImportOptions::default()).unwrap();
+        assert_eq!(log_data.xes_version, Some("1.0".to_string()));
+        let events: Vec<(String, String)> = (&mut stream)
+            .map(|(case, ev)| {
+                let act = ev
+                    .attributes
+                    .get_by_key("concept:name")
+                    .and_then(|a| a.value.try_as_string().cloned())
+                    .unwrap();
+                (case, act)
+            })
+            .collect();
+        assert!(stream.check_for_errors().is_none());
+        assert_eq!(
+            events,
+            vec![
+                ("c1".to_string(), "a".to_string()),
+                ("c1".to_string(), "b".to_string()),
+                ("1".to_string(), "c".to_string()),
+                ("c4".to_string(), "d".to_string()),
+            ]
+        );
+
+        assert!(stream.finish_summary().is_none());
+
+        let (mut stream, _) = stream_xes_events_from_path(
+            &path,
+            XESImportOptions {
+                collect_summary: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!((&mut stream).count(), 4);
+        let summary = stream.finish_summary().unwrap();
+        assert_eq!(summary.num_traces, 4);
+        assert_eq!(summary.num_events, 4);
+        assert_eq!(
+            summary.activities,
+            ["a", "b", "c", "d"].into_iter().map(String::from).collect()
+        );
+        assert_eq!(
+            summary.event_attribute_keys,
+            ["concept:name", "time:timestamp"]
+                .into_iter()
+                .map(String::from)
+                .collect()
+        );
+        assert_eq!(summary.time_span(), Some(chrono::Duration::hours(1)));
+    }
 }
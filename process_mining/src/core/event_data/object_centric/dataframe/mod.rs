@@ -1,25 +1,34 @@
+use crate::diagnostics::warn_diagnostic;
 use std::{
     collections::{HashMap, HashSet},
     fs::File,
     path::Path,
+    sync::Arc,
 };
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Utc};
 use itertools::Itertools;
 use polars::{
     error::{PolarsError, PolarsResult},
     frame::DataFrame,
     io::SerWriter,
     prelude::{
-        AnyValue, CsvWriter, IntoColumn, SortMultipleOptions, StringChunkedBuilder, TimeUnit,
-        TimeZone,
+        AnyValue, CsvReadOptions, CsvWriter, DataType, Field, IntoColumn, PlSmallStr, Schema,
+        SerReader, SortMultipleOptions, StringChunkedBuilder, TimeUnit, TimeZone,
     },
     series::Series,
 };
+use serde::{Deserialize, Serialize};
 
-use crate::core::event_data::object_centric::{
-    linked_ocel::LinkedOCELAccess,
-    ocel_struct::{OCELAttributeValue, OCEL},
+use crate::core::event_data::{
+    object_centric::{
+        linked_ocel::LinkedOCELAccess,
+        ocel_struct::{
+            OCELAttributeType, OCELAttributeValue, OCELEvent, OCELEventAttribute, OCELObject,
+            OCELObjectAttribute, OCELRelationship, OCELType, OCELTypeAttribute, OCEL,
+        },
+    },
+    timestamp_utils::parse_timestamp,
 };
 
 #[cfg(test)]
@@ -299,11 +308,72 @@ pub const OCEL_OBJECT_TYPE_KEY: &str = "ocel:type";
 pub const OCEL_QUALIFIER_KEY: &str = "ocel:qualifier";
 /// Changed Field Key in `DataFrame` (e.g., prices)
 pub const OCEL_CHANGED_FIELD_KEY: &str = "ocel:field";
+/// E2O Validity Key in `DataFrame` (i.e., whether the referenced object exists, see [`OcelDfOptions::e2o_valid_column`])
+pub const OCEL_E2O_VALID_KEY: &str = "ocel:e2o_valid";
+
+/// Options for converting an [`OCEL`] to [`OCELDataFrames`] (see [`ocel_to_dataframes_with_options`])
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OcelDfOptions {
+    /// Drop E2O relationships referring to objects which do not exist in the [`OCEL`]
+    ///
+    /// If `false`, such relationships are kept with a null [`OCEL_OBJECT_TYPE_KEY`] value.
+    pub drop_invalid_e2o: bool,
+    /// Emit a warning diagnostic (see [`crate::diagnostics`]) if invalid E2O relationships are encountered
+    pub warn_invalid_e2o: bool,
+    /// Add a boolean [`OCEL_E2O_VALID_KEY`] column to the E2O `DataFrame`,
+    /// indicating whether the referenced object exists in the [`OCEL`]
+    ///
+    /// This allows detecting invalid E2O relationships without dropping them.
+    /// If `false` (the default), no such column is added.
+    pub e2o_valid_column: bool,
+    /// Tag all datetime columns (timestamps and time-valued attributes) with the UTC timezone
+    ///
+    /// If `false`, datetimes are emitted without timezone information (but still represent UTC instants).
+    pub utc_datetimes: bool,
+}
+
+/// Tag all datetime columns without timezone information with the UTC timezone
+///
+/// The underlying values are not changed, as they already represent UTC instants.
+fn tag_datetimes_utc(df: &mut DataFrame) -> PolarsResult<()> {
+    let naive_cols: Vec<PlSmallStr> = df
+        .get_columns()
+        .iter()
+        .filter(|c| matches!(c.dtype(), DataType::Datetime(_, None)))
+        .map(|c| c.name().clone())
+        .collect();
+    for name in naive_cols {
+        let col = df.column(&name)?;
+        let DataType::Datetime(tu, _) = col.dtype() else {
+            continue;
+        };
+        let tagged = col.cast(&DataType::Datetime(*tu, Some(TimeZone::UTC)))?;
+        df.with_column(tagged)?;
+    }
+    Ok(())
+}
 
 /// Convert an [`OCEL`] to a set of [`DataFrame`]s ([`OCELDataFrames`])
 ///
-/// See [`OCELDataFrames`] for the structure of the Dataframes
+/// See [`OCELDataFrames`] for the structure of the Dataframes.
+///
+/// E2O relationships referring to non-existent objects are kept with a null [`OCEL_OBJECT_TYPE_KEY`] value.
+/// Use [`ocel_to_dataframes_with_options`] to drop them or to mark them in an additional [`OCEL_E2O_VALID_KEY`] column instead.
 pub fn ocel_to_dataframes(ocel: &OCEL) -> OCELDataFrames {
+    ocel_to_dataframes_with_options(ocel, &OcelDfOptions::default()).0
+}
+
+/// Convert an [`OCEL`] to a set of [`DataFrame`]s ([`OCELDataFrames`]) using the given [`OcelDfOptions`]
+///
+/// See [`OCELDataFrames`] for the structure of the Dataframes.
+///
+/// Returns the dataframes together with the number of invalid E2O relationships (i.e., referring to objects
+/// not present in the [`OCEL`]), regardless of whether they were dropped.
+pub fn ocel_to_dataframes_with_options(
+    ocel: &OCEL,
+    options: &OcelDfOptions,
+) -> (OCELDataFrames, usize) {
     let object_attributes: HashSet<String> = ocel
         .object_types
         .iter()
@@ -317,9 +387,7 @@ pub fn ocel_to_dataframes(ocel: &OCEL) -> OCELDataFrames {
         .collect();
     // println!("Object attributes: {:?}; Actual object attributes: {:?}", object_attributes.len(), actual_object_attributes.len());
     if !object_attributes.is_superset(&actual_object_attributes) {
-        eprintln!(
-            "Warning: Global object attributes is not a superset of actual object attributes"
-        );
+        warn_diagnostic!("Global object attributes is not a superset of actual object attributes");
     }
     let object_attributes_initial: HashSet<String> = object_attributes
         .clone()
@@ -332,9 +400,11 @@ pub fn ocel_to_dataframes(ocel: &OCEL) -> OCELDataFrames {
             })
         })
         .collect();
+    // Attribute columns are sorted by name, so that the column order is deterministic
     let objects_df = DataFrame::from_iter(
         object_attributes_initial
             .into_iter()
+            .sorted()
             .map(|name| {
                 Series::from_any_values(
                     (&name).into(),
@@ -381,18 +451,37 @@ pub fn ocel_to_dataframes(ocel: &OCEL) -> OCELDataFrames {
             ]),
     );
 
-    let all_evs_with_rels: Vec<_> = ocel
-        .events
-        .iter()
-        .flat_map(|e| e.relationships.iter().map(move |r| (e, r)))
-        .collect();
-
     let obj_id_to_type_map: HashMap<&String, &String> = ocel
         .objects
         .iter()
         .map(|o| (&o.id, &o.object_type))
         .collect();
 
+    let mut all_evs_with_rels: Vec<_> = ocel
+        .events
+        .iter()
+        .flat_map(|e| e.relationships.iter().map(move |r| (e, r)))
+        .collect();
+    let num_invalid_e2o = all_evs_with_rels
+        .iter()
+        .filter(|(_e, r)| !obj_id_to_type_map.contains_key(&r.object_id))
+        .count();
+    if num_invalid_e2o > 0 {
+        if options.warn_invalid_e2o {
+            warn_diagnostic!(
+                "{num_invalid_e2o} E2O relationships refer to non-existent objects{}",
+                if options.drop_invalid_e2o {
+                    " and were dropped"
+                } else {
+                    ""
+                }
+            );
+        }
+        if options.drop_invalid_e2o {
+            all_evs_with_rels.retain(|(_e, r)| obj_id_to_type_map.contains_key(&r.object_id));
+        }
+    }
+
     let mut e2o_df = DataFrame::from_iter(vec![
         Series::from_any_values(
             OCEL_EVENT_ID_KEY.into(),
@@ -440,10 +529,6 @@ pub fn ocel_to_dataframes(ocel: &OCEL) -> OCELDataFrames {
                     if let Some(obj_type) = obj_id_to_type_map.get(&r.object_id) {
                         AnyValue::StringOwned((*obj_type).into())
                     } else {
-                        // eprintln!(
-                        //     "Invalid object id in E2O reference: Event: {}, Object: {}",
-                        //     _e.id, r.object_id
-                        // );
                         AnyValue::Null
                     }
                 })
@@ -461,6 +546,23 @@ pub fn ocel_to_dataframes(ocel: &OCEL) -> OCELDataFrames {
         )
         .unwrap(),
     ]);
+    if options.e2o_valid_column {
+        e2o_df
+            .with_column(
+                Series::from_any_values(
+                    OCEL_E2O_VALID_KEY.into(),
+                    &all_evs_with_rels
+                        .iter()
+                        .map(|(_e, r)| {
+                            AnyValue::Boolean(obj_id_to_type_map.contains_key(&r.object_id))
+                        })
+                        .collect::<Vec<_>>(),
+                    false,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+    }
 
     let all_obj_with_rels: Vec<_> = ocel
         .objects
@@ -501,6 +603,7 @@ pub fn ocel_to_dataframes(ocel: &OCEL) -> OCELDataFrames {
     let mut object_changes_df = DataFrame::from_iter(
         object_attributes
             .into_iter()
+            .sorted()
             .map(|name| {
                 Series::from_any_values(
                     (&name).into(),
@@ -595,6 +698,7 @@ pub fn ocel_to_dataframes(ocel: &OCEL) -> OCELDataFrames {
     let mut events_df = DataFrame::from_iter(
         event_attributes
             .into_iter()
+            .sorted()
             .map(|name| {
                 Series::from_any_values(
                     (&name).into(),
@@ -673,13 +777,384 @@ pub fn ocel_to_dataframes(ocel: &OCEL) -> OCELDataFrames {
             SortMultipleOptions::default().with_maintain_order(true),
         )
         .unwrap();
-    OCELDataFrames {
+    let mut dfs = OCELDataFrames {
         objects: objects_df,
         events: events_df,
         object_changes: object_changes_df,
         o2o: o2o_df,
         e2o: e2o_df,
+    };
+    if options.utc_datetimes {
+        for df in [
+            &mut dfs.objects,
+            &mut dfs.events,
+            &mut dfs.object_changes,
+            &mut dfs.e2o,
+        ] {
+            tag_datetimes_utc(df).unwrap();
+        }
+    }
+    (dfs, num_invalid_e2o)
+}
+
+fn any_value_to_ocel_attribute_value(val: &AnyValue<'_>) -> OCELAttributeValue {
+    match val {
+        AnyValue::Null => OCELAttributeValue::Null,
+        AnyValue::Boolean(b) => OCELAttributeValue::Boolean(*b),
+        AnyValue::String(s) => OCELAttributeValue::String(s.to_string()),
+        AnyValue::StringOwned(s) => OCELAttributeValue::String(s.to_string()),
+        AnyValue::UInt8(i) => OCELAttributeValue::Integer((*i).into()),
+        AnyValue::UInt16(i) => OCELAttributeValue::Integer((*i).into()),
+        AnyValue::UInt32(i) => OCELAttributeValue::Integer((*i).into()),
+        AnyValue::Int8(i) => OCELAttributeValue::Integer((*i).into()),
+        AnyValue::Int16(i) => OCELAttributeValue::Integer((*i).into()),
+        AnyValue::Int32(i) => OCELAttributeValue::Integer((*i).into()),
+        AnyValue::Int64(i) => OCELAttributeValue::Integer(*i),
+        AnyValue::Float32(f) => OCELAttributeValue::Float((*f).into()),
+        AnyValue::Float64(f) => OCELAttributeValue::Float(*f),
+        AnyValue::Datetime(..) | AnyValue::DatetimeOwned(..) | AnyValue::Date(_) => {
+            match any_value_to_utc_time(val) {
+                Some(t) => OCELAttributeValue::Time(t),
+                None => OCELAttributeValue::Null,
+            }
+        }
+        x => OCELAttributeValue::String(x.to_string()),
+    }
+}
+
+/// Convert a timestamp [`AnyValue`] to a [`DateTime`]
+///
+/// Datetimes without a timezone (as written by [`ocel_to_dataframes`]) are interpreted as UTC.
+/// String values are parsed using [`parse_timestamp`].
+fn any_value_to_utc_time(val: &AnyValue<'_>) -> Option<DateTime<FixedOffset>> {
+    let (v, tu) = match val {
+        AnyValue::Datetime(v, tu, _) | AnyValue::DatetimeOwned(v, tu, _) => (*v, *tu),
+        AnyValue::Date(days) => (i64::from(*days) * 86_400_000, TimeUnit::Milliseconds),
+        AnyValue::String(s) => return parse_timestamp(s, None, false).ok(),
+        AnyValue::StringOwned(s) => return parse_timestamp(s, None, false).ok(),
+        _ => return None,
+    };
+    let d = match tu {
+        TimeUnit::Nanoseconds => Some(DateTime::from_timestamp_nanos(v)),
+        TimeUnit::Microseconds => DateTime::from_timestamp_micros(v),
+        TimeUnit::Milliseconds => DateTime::from_timestamp_millis(v),
+    };
+    d.map(|d| d.fixed_offset())
+}
+
+fn dtype_to_ocel_attribute_type(dtype: &DataType) -> OCELAttributeType {
+    match dtype {
+        DataType::Boolean => OCELAttributeType::Boolean,
+        DataType::String => OCELAttributeType::String,
+        DataType::Datetime(_, _) | DataType::Date => OCELAttributeType::Time,
+        DataType::Null => OCELAttributeType::Null,
+        d if d.is_integer() => OCELAttributeType::Integer,
+        d if d.is_float() => OCELAttributeType::Float,
+        _ => OCELAttributeType::String,
+    }
+}
+
+fn get_str_column<'a>(df: &'a DataFrame, name: &str) -> PolarsResult<Vec<Option<&'a str>>> {
+    let column = df.column(name)?;
+    // Columns without any values (e.g., of an empty data frame) might not have a string type
+    if column.dtype() == &DataType::Null {
+        return Ok(vec![None; column.len()]);
+    }
+    Ok(column.str()?.iter().collect())
+}
+
+fn get_time_column(df: &DataFrame, name: &str) -> PolarsResult<Vec<DateTime<FixedOffset>>> {
+    df.column(name)?
+        .as_materialized_series()
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            any_value_to_utc_time(&v).ok_or_else(|| {
+                PolarsError::ComputeError(
+                    format!("Invalid timestamp in column {name} at row {i}: {v}").into(),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Add the attribute type to the [`OCELType`] with the given name, if not already present
+fn register_type_attribute(
+    types: &mut Vec<OCELType>,
+    type_index: &mut HashMap<String, usize>,
+    type_name: &str,
+    attr_name: &str,
+    dtype: &DataType,
+) {
+    let index = *type_index.entry(type_name.to_string()).or_insert_with(|| {
+        types.push(OCELType {
+            name: type_name.to_string(),
+            attributes: Vec::new(),
+        });
+        types.len() - 1
+    });
+    let t = &mut types[index];
+    if !t.attributes.iter().any(|a| a.name == attr_name) {
+        t.attributes.push(OCELTypeAttribute {
+            name: attr_name.to_string(),
+            value_type: dtype_to_ocel_attribute_type(dtype).to_type_string(),
+        });
+    }
+}
+
+/// Convert a set of [`DataFrame`]s ([`OCELDataFrames`]) back to an [`OCEL`]
+///
+/// This is the inverse of [`ocel_to_dataframes`].
+/// Object attributes are reconstructed from [`OCELDataFrames::object_changes`],
+/// while the attribute columns of [`OCELDataFrames::objects`] are ignored.
+/// Attribute types are derived from the column data types.
+/// Datetime columns without timezone information are interpreted as UTC.
+pub fn dataframes_to_ocel(dfs: &OCELDataFrames) -> PolarsResult<OCEL> {
+    let mut event_types: Vec<OCELType> = Vec::new();
+    let mut event_type_index: HashMap<String, usize> = HashMap::new();
+    let mut object_types: Vec<OCELType> = Vec::new();
+    let mut object_type_index: HashMap<String, usize> = HashMap::new();
+    // Iterating over series values requires contiguous memory (e.g., after reading a CSV in batches)
+    let [objects_df, events_df, e2o_df, o2o_df, changes_df] = [
+        &dfs.objects,
+        &dfs.events,
+        &dfs.e2o,
+        &dfs.o2o,
+        &dfs.object_changes,
+    ]
+    .map(|df| {
+        let mut df = df.clone();
+        df.as_single_chunk();
+        df
+    });
+
+    // Objects
+    let mut objects: Vec<OCELObject> = get_str_column(&objects_df, OCEL_OBJECT_ID_KEY)?
+        .into_iter()
+        .zip(get_str_column(&objects_df, OCEL_OBJECT_TYPE_KEY)?)
+        .map(|(id, ot)| OCELObject {
+            id: id.unwrap_or_default().to_string(),
+            object_type: ot.unwrap_or_default().to_string(),
+            attributes: Vec::new(),
+            relationships: Vec::new(),
+        })
+        .collect();
+    for o in &objects {
+        if !object_type_index.contains_key(&o.object_type) {
+            object_type_index.insert(o.object_type.clone(), object_types.len());
+            object_types.push(OCELType {
+                name: o.object_type.clone(),
+                attributes: Vec::new(),
+            });
+        }
+    }
+    let object_index: HashMap<String, usize> = objects
+        .iter()
+        .enumerate()
+        .map(|(i, o)| (o.id.clone(), i))
+        .collect();
+
+    // Object attribute changes
+    let change_obj_ids = get_str_column(&changes_df, OCEL_OBJECT_ID_KEY)?;
+    let change_fields = get_str_column(&changes_df, OCEL_CHANGED_FIELD_KEY)?;
+    let change_times = get_time_column(&changes_df, OCEL_EVENT_TIMESTAMP_KEY)?;
+    let change_values: HashMap<&str, (&DataType, Vec<AnyValue<'_>>)> = changes_df
+        .get_columns()
+        .iter()
+        .filter(|c| {
+            ![
+                OCEL_OBJECT_ID_KEY,
+                OCEL_OBJECT_TYPE_KEY,
+                OCEL_CHANGED_FIELD_KEY,
+                OCEL_EVENT_TIMESTAMP_KEY,
+            ]
+            .contains(&c.name().as_str())
+        })
+        .map(|c| {
+            (
+                c.name().as_str(),
+                (c.dtype(), c.as_materialized_series().iter().collect()),
+            )
+        })
+        .collect();
+    for (i, (obj_id, field)) in change_obj_ids.into_iter().zip(change_fields).enumerate() {
+        let (Some(obj_id), Some(field)) = (obj_id, field) else {
+            continue;
+        };
+        let Some(obj) = object_index.get(obj_id).map(|&oi| &mut objects[oi]) else {
+            continue;
+        };
+        let (dtype, value) = match change_values.get(field) {
+            Some((dtype, values)) => (*dtype, any_value_to_ocel_attribute_value(&values[i])),
+            None => (&DataType::Null, OCELAttributeValue::Null),
+        };
+        register_type_attribute(
+            &mut object_types,
+            &mut object_type_index,
+            &obj.object_type,
+            field,
+            dtype,
+        );
+        obj.attributes.push(OCELObjectAttribute {
+            name: field.to_string(),
+            value,
+            time: change_times[i],
+        });
+    }
+
+    // O2O relationships
+    for ((from, to), qualifier) in get_str_column(&o2o_df, OCEL_OBJECT_ID_KEY)?
+        .into_iter()
+        .zip(get_str_column(&o2o_df, OCEL_OBJECT_ID_2_KEY)?)
+        .zip(get_str_column(&o2o_df, OCEL_QUALIFIER_KEY)?)
+    {
+        let (Some(from), Some(to)) = (from, to) else {
+            continue;
+        };
+        if let Some(&oi) = object_index.get(from) {
+            objects[oi].relationships.push(OCELRelationship {
+                object_id: to.to_string(),
+                qualifier: qualifier.unwrap_or_default().to_string(),
+            });
+        }
+    }
+
+    // Events
+    let mut events: Vec<OCELEvent> = get_str_column(&events_df, OCEL_EVENT_ID_KEY)?
+        .into_iter()
+        .zip(get_str_column(&events_df, OCEL_EVENT_TYPE_KEY)?)
+        .zip(get_time_column(&events_df, OCEL_EVENT_TIMESTAMP_KEY)?)
+        .map(|((id, et), time)| OCELEvent {
+            id: id.unwrap_or_default().to_string(),
+            event_type: et.unwrap_or_default().to_string(),
+            time,
+            attributes: Vec::new(),
+            relationships: Vec::new(),
+        })
+        .collect();
+    for e in &events {
+        if !event_type_index.contains_key(&e.event_type) {
+            event_type_index.insert(e.event_type.clone(), event_types.len());
+            event_types.push(OCELType {
+                name: e.event_type.clone(),
+                attributes: Vec::new(),
+            });
+        }
+    }
+    for c in events_df.get_columns().iter().filter(|c| {
+        ![
+            OCEL_EVENT_ID_KEY,
+            OCEL_EVENT_TYPE_KEY,
+            OCEL_EVENT_TIMESTAMP_KEY,
+        ]
+        .contains(&c.name().as_str())
+    }) {
+        for (e, v) in events.iter_mut().zip(c.as_materialized_series().iter()) {
+            let value = any_value_to_ocel_attribute_value(&v);
+            if value == OCELAttributeValue::Null {
+                continue;
+            }
+            register_type_attribute(
+                &mut event_types,
+                &mut event_type_index,
+                &e.event_type,
+                c.name(),
+                c.dtype(),
+            );
+            e.attributes.push(OCELEventAttribute {
+                name: c.name().to_string(),
+                value,
+            });
+        }
+    }
+    let event_index: HashMap<String, usize> = events
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (e.id.clone(), i))
+        .collect();
+
+    // E2O relationships
+    for ((ev_id, obj_id), qualifier) in get_str_column(&e2o_df, OCEL_EVENT_ID_KEY)?
+        .into_iter()
+        .zip(get_str_column(&e2o_df, OCEL_OBJECT_ID_KEY)?)
+        .zip(get_str_column(&e2o_df, OCEL_QUALIFIER_KEY)?)
+    {
+        let (Some(ev_id), Some(obj_id)) = (ev_id, obj_id) else {
+            continue;
+        };
+        if let Some(&ei) = event_index.get(ev_id) {
+            events[ei].relationships.push(OCELRelationship {
+                object_id: obj_id.to_string(),
+                qualifier: qualifier.unwrap_or_default().to_string(),
+            });
+        }
     }
+
+    Ok(OCEL {
+        event_types,
+        object_types,
+        events,
+        objects,
+    })
+}
+
+/// Read one of the CSV files written by the [`OCELDataFrames`] export functions
+///
+/// The passed ID/type/qualifier columns are always read as strings, to prevent, e.g., numeric IDs from being parsed as integers.
+fn read_ocel_csv<P: AsRef<Path>>(path: P, string_columns: &[&str]) -> PolarsResult<DataFrame> {
+    let schema_overwrite: Schema = string_columns
+        .iter()
+        .map(|c| Field::new((*c).into(), DataType::String))
+        .collect();
+    CsvReadOptions::default()
+        .with_schema_overwrite(Some(Arc::new(schema_overwrite)))
+        .map_parse_options(|o| o.with_try_parse_dates(true))
+        .try_into_reader_with_file_path(Some(path.as_ref().to_path_buf()))?
+        .finish()
+}
+
+/// Import an [`OCEL`] from the five CSV files written by the [`OCELDataFrames`] export functions
+/// (e.g., [`OCELDataFrames::export_objects_csv`])
+///
+/// All CSV files need to contain the key columns (e.g., [`OCEL_EVENT_ID_KEY`]) documented on [`OCELDataFrames`].
+/// Timestamps are interpreted as UTC.
+/// Attribute types are inferred from the CSV values.
+///
+/// See also [`dataframes_to_ocel`].
+pub fn import_ocel_from_csvs<P: AsRef<Path>>(
+    objects: P,
+    events: P,
+    e2o: P,
+    o2o: P,
+    object_changes: P,
+) -> PolarsResult<OCEL> {
+    let dfs = OCELDataFrames {
+        objects: read_ocel_csv(objects, &[OCEL_OBJECT_ID_KEY, OCEL_OBJECT_TYPE_KEY])?,
+        events: read_ocel_csv(events, &[OCEL_EVENT_ID_KEY, OCEL_EVENT_TYPE_KEY])?,
+        e2o: read_ocel_csv(
+            e2o,
+            &[
+                OCEL_EVENT_ID_KEY,
+                OCEL_EVENT_TYPE_KEY,
+                OCEL_OBJECT_ID_KEY,
+                OCEL_OBJECT_TYPE_KEY,
+                OCEL_QUALIFIER_KEY,
+            ],
+        )?,
+        o2o: read_ocel_csv(
+            o2o,
+            &[OCEL_OBJECT_ID_KEY, OCEL_OBJECT_ID_2_KEY, OCEL_QUALIFIER_KEY],
+        )?,
+        object_changes: read_ocel_csv(
+            object_changes,
+            &[
+                OCEL_OBJECT_ID_KEY,
+                OCEL_OBJECT_TYPE_KEY,
+                OCEL_CHANGED_FIELD_KEY,
+            ],
+        )?,
+    };
+    dataframes_to_ocel(&dfs)
 }
 
 /// Export all events of an type as a [`DataFrame`]
@@ -9,7 +9,7 @@ use crate::{
     test_utils::get_test_data_path,
 };
 
-use super::object_attribute_changes_to_df;
+use super::{import_ocel_from_csvs, object_attribute_changes_to_df};
 
 #[test]
 fn test_ocel2_container_df() {
@@ -85,3 +85,295 @@ fn ocel_object_attribute_changes() {
     let df = object_attribute_changes_to_df(&locel, "products").unwrap();
     println!("{df:#?}");
 }
+
+#[test]
+fn test_ocel_csvs_round_trip() {
+    use chrono::DateTime;
+
+    use crate::{
+        core::event_data::object_centric::ocel_struct::{
+            OCELAttributeType, OCELEvent, OCELEventAttribute, OCELObject, OCELObjectAttribute,
+            OCELRelationship, OCELType, OCELTypeAttribute, OCEL,
+        },
+        test_utils::sort_ocel_for_equality_compare,
+    };
+
+    let t1 = DateTime::parse_from_rfc3339("2024-03-01T10:15:30.123+00:00").unwrap();
+    let t2 = DateTime::parse_from_rfc3339("2024-03-02T08:00:00+00:00").unwrap();
+    let mut ocel = OCEL {
+        event_types: vec![OCELType {
+            name: "place order".to_string(),
+            attributes: vec![
+                OCELTypeAttribute::new("express", &OCELAttributeType::Boolean),
+                OCELTypeAttribute::new("total", &OCELAttributeType::Float),
+            ],
+        }],
+        object_types: vec![
+            OCELType {
+                name: "customer".to_string(),
+                attributes: vec![],
+            },
+            OCELType {
+                name: "order".to_string(),
+                attributes: vec![
+                    OCELTypeAttribute::new("items", &OCELAttributeType::Integer),
+                    OCELTypeAttribute::new("status", &OCELAttributeType::String),
+                ],
+            },
+        ],
+        events: vec![OCELEvent::new(
+            "10",
+            "place order",
+            t1,
+            vec![
+                OCELEventAttribute {
+                    name: "express".to_string(),
+                    value: true.into(),
+                },
+                OCELEventAttribute {
+                    name: "total".to_string(),
+                    value: 1909.04.into(),
+                },
+            ],
+            vec![
+                OCELRelationship::new("1", "order"),
+                OCELRelationship::new("c1", ""),
+            ],
+        )],
+        objects: vec![
+            OCELObject {
+                id: "1".to_string(),
+                object_type: "order".to_string(),
+                attributes: vec![
+                    OCELObjectAttribute::new("items", 3_i64, DateTime::UNIX_EPOCH),
+                    OCELObjectAttribute::new("status", "placed", DateTime::UNIX_EPOCH),
+                    OCELObjectAttribute::new("status", "shipped", t2),
+                ],
+                relationships: vec![OCELRelationship::new("c1", "placed by")],
+            },
+            OCELObject {
+                id: "c1".to_string(),
+                object_type: "customer".to_string(),
+                attributes: vec![],
+                relationships: vec![],
+            },
+        ],
+    };
+    let export_dir = get_test_data_path().join("export");
+    let paths = ["objects", "events", "e2o", "o2o", "object-changes"]
+        .map(|n| export_dir.join(format!("roundtrip-ocel-csvs-{n}.csv")));
+    let mut ocel_dfs = ocel_to_dataframes(&ocel);
+    ocel_dfs.export_objects_csv(&paths[0], &[]).unwrap();
+    ocel_dfs.export_events_csv(&paths[1], &[]).unwrap();
+    ocel_dfs.export_e2o_csv(&paths[2], &[]).unwrap();
+    ocel_dfs.export_o2o_csv(&paths[3], &[]).unwrap();
+    ocel_dfs.export_object_changes_csv(&paths[4], &[]).unwrap();
+    let [objects, events, e2o, o2o, object_changes] = paths;
+    let mut ocel2 = import_ocel_from_csvs(objects, events, e2o, o2o, object_changes).unwrap();
+
+    for o in [&mut ocel, &mut ocel2] {
+        sort_ocel_for_equality_compare(o);
+        for t in o.event_types.iter_mut().chain(o.object_types.iter_mut()) {
+            t.attributes.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        for e in &mut o.events {
+            e.attributes.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+    }
+    assert_eq!(ocel, ocel2);
+}
+
+#[test]
+fn test_ocel_to_dataframes_invalid_e2o() {
+    let mut ocel = crate::ocel![
+        events:
+        ("place", ["o:1", "i:1"]),
+        ("pack", ["o:1"]),
+        o2o:
+    ];
+    ocel.events[0]
+        .relationships
+        .push(crate::core::event_data::object_centric::OCELRelationship::new("i:404", "item"));
+    ocel.events[1]
+        .relationships
+        .push(crate::core::event_data::object_centric::OCELRelationship::new("o:404", "order"));
+
+    let (ocel_dfs, num_invalid) =
+        super::ocel_to_dataframes_with_options(&ocel, &super::OcelDfOptions::default());
+    assert_eq!(num_invalid, 2);
+    assert_eq!(ocel_dfs.e2o.shape().0, 5);
+    assert_eq!(
+        ocel_dfs
+            .e2o
+            .column(super::OCEL_OBJECT_TYPE_KEY)
+            .unwrap()
+            .null_count(),
+        2
+    );
+
+    let ((ocel_dfs, num_invalid), diagnostics) = crate::diagnostics::collect_diagnostics(|| {
+        super::ocel_to_dataframes_with_options(
+            &ocel,
+            &super::OcelDfOptions {
+                drop_invalid_e2o: true,
+                warn_invalid_e2o: true,
+                ..Default::default()
+            },
+        )
+    });
+    assert_eq!(num_invalid, 2);
+    assert_eq!(ocel_dfs.e2o.shape().0, 3);
+    assert_eq!(
+        ocel_dfs
+            .e2o
+            .column(super::OCEL_OBJECT_TYPE_KEY)
+            .unwrap()
+            .null_count(),
+        0
+    );
+    assert_eq!(diagnostics.len(), 1);
+
+    let (ocel_dfs, _) = super::ocel_to_dataframes_with_options(
+        &ocel,
+        &super::OcelDfOptions {
+            e2o_valid_column: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(ocel_dfs.e2o.shape().0, 5);
+    let valid = ocel_dfs
+        .e2o
+        .column(super::OCEL_E2O_VALID_KEY)
+        .unwrap()
+        .bool()
+        .unwrap();
+    assert_eq!(valid.sum(), Some(3));
+    assert!(super::ocel_to_dataframes(&ocel)
+        .e2o
+        .column(super::OCEL_E2O_VALID_KEY)
+        .is_err());
+}
+
+#[test]
+fn test_ocel_to_dataframes_utc() {
+    use polars::prelude::{DataType, TimeUnit, TimeZone};
+    let mut ocel = crate::ocel![
+        events:
+        ("place", ["o:1", "i:1"]),
+        ("pack", ["o:1"]),
+        o2o:
+        ("o:1", "i:1")
+    ];
+    ocel.objects[0].attributes.push(
+        crate::core::event_data::object_centric::OCELObjectAttribute::new(
+            "price",
+            12_i64,
+            ocel.events[1].time,
+        ),
+    );
+    let utc = DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC));
+    let naive = ocel_to_dataframes(&ocel);
+    assert_eq!(
+        naive
+            .events
+            .column(super::OCEL_EVENT_TIMESTAMP_KEY)
+            .unwrap()
+            .dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, None)
+    );
+    let (dfs, _) = super::ocel_to_dataframes_with_options(
+        &ocel,
+        &super::OcelDfOptions {
+            utc_datetimes: true,
+            ..Default::default()
+        },
+    );
+    for df in [&dfs.events, &dfs.e2o, &dfs.object_changes] {
+        let col = df.column(super::OCEL_EVENT_TIMESTAMP_KEY).unwrap();
+        assert_eq!(col.dtype(), &utc);
+    }
+    let ev_times = |df: &polars::prelude::DataFrame| {
+        df.column(super::OCEL_EVENT_TIMESTAMP_KEY)
+            .unwrap()
+            .as_materialized_series()
+            .to_physical_repr()
+            .i64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ev_times(&naive.events), ev_times(&dfs.events));
+    let back = super::dataframes_to_ocel(&dfs).unwrap();
+    assert_eq!(back.events[1].time, ocel.events[1].time);
+}
+
+#[test]
+fn test_ocel_to_dataframes_column_order() {
+    use crate::core::event_data::object_centric::{
+        OCELAttributeType, OCELAttributeValue, OCELEventAttribute, OCELTypeAttribute,
+    };
+    let mut ocel = crate::ocel![
+        events:
+        ("place", ["o:1"]),
+        o2o:
+    ];
+    for name in ["zeta", "alpha", "mu", "beta", "omega"] {
+        ocel.event_types[0]
+            .attributes
+            .push(OCELTypeAttribute::new(name, &OCELAttributeType::Integer));
+        ocel.events[0].attributes.push(OCELEventAttribute {
+            name: name.to_string(),
+            value: OCELAttributeValue::Integer(1),
+        });
+        ocel.object_types[0]
+            .attributes
+            .push(OCELTypeAttribute::new(name, &OCELAttributeType::Integer));
+        ocel.objects[0].attributes.push(
+            crate::core::event_data::object_centric::OCELObjectAttribute::new(
+                name,
+                1_i64,
+                chrono::DateTime::UNIX_EPOCH,
+            ),
+        );
+    }
+    let column_names = |dfs: &super::OCELDataFrames| {
+        [&dfs.objects, &dfs.events, &dfs.object_changes].map(|df| {
+            df.get_column_names()
+                .into_iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+        })
+    };
+    let first = column_names(&ocel_to_dataframes(&ocel));
+    for _ in 0..5 {
+        assert_eq!(first, column_names(&ocel_to_dataframes(&ocel)));
+    }
+    assert_eq!(
+        first[1][..5],
+        ["alpha", "beta", "mu", "omega", "zeta"].map(String::from)
+    );
+}
+
+#[test]
+fn test_slim_linked_ocel_from_dataframes() {
+    use crate::core::event_data::object_centric::linked_ocel::SlimLinkedOCEL;
+    let ocel = crate::ocel![
+        events:
+        ("place", ["o:1", "i:1", "i:2"]),
+        ("pack", ["o:1", "i:2"]),
+        o2o:
+        ("o:1", "i:1")
+    ];
+    let locel = SlimLinkedOCEL::from_dataframes(&ocel_to_dataframes(&ocel)).unwrap();
+    assert_eq!(locel.get_all_evs().count(), 2);
+    assert_eq!(locel.get_all_obs().count(), 3);
+    let pack = locel.get_evs_of_type("pack").next().unwrap();
+    let mut pack_obs: Vec<_> = locel
+        .get_e2o(pack)
+        .map(|(_, o)| locel.get_ob_id(o).to_string())
+        .collect();
+    pack_obs.sort();
+    assert_eq!(pack_obs, ["i:2", "o:1"]);
+    let o1 = locel.get_ob_by_id("o:1").unwrap();
+    assert_eq!(locel.get_o2o(&o1).count(), 1);
+}
@@ -4,6 +4,7 @@ use std::io::{Read, Write};
 use std::path::Path;
 
 use crate::core::event_data::object_centric::ocel_csv::OCELCSVImportError;
+use crate::core::event_data::object_centric::ocel_json::schema::OCELJsonSchemaError;
 #[cfg(feature = "ocel-sqlite")]
 use crate::core::event_data::object_centric::ocel_sql::export_ocel_sqlite_to_vec;
 #[cfg(any(feature = "ocel-duckdb", feature = "ocel-sqlite"))]
@@ -19,6 +20,8 @@ pub enum OCELIOError {
     Io(std::io::Error),
     /// JSON Parsing Error
     Json(serde_json::Error),
+    /// Violations of the OCEL 2.0 JSON schema
+    JsonSchema(Vec<OCELJsonSchemaError>),
     /// XML Parsing Error
     Xml(quick_xml::Error),
     /// CSV Parsing Error
@@ -40,6 +43,16 @@ impl std::fmt::Display for OCELIOError {
         match self {
             OCELIOError::Io(e) => write!(f, "IO Error: {}", e),
             OCELIOError::Json(e) => write!(f, "JSON Error: {}", e),
+            OCELIOError::JsonSchema(errors) => {
+                write!(f, "JSON Schema Error: ")?;
+                for (i, e) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", e)?;
+                }
+                Ok(())
+            }
             OCELIOError::Xml(e) => write!(f, "XML Error: {}", e),
             OCELIOError::Csv(e) => write!(f, "CSV Error: {}", e),
             #[cfg(feature = "ocel-sqlite")]
@@ -57,6 +70,7 @@ impl std::error::Error for OCELIOError {
         match self {
             OCELIOError::Io(e) => Some(e),
             OCELIOError::Json(e) => Some(e),
+            OCELIOError::JsonSchema(_) => None,
             OCELIOError::Xml(e) => Some(e),
             OCELIOError::Csv(e) => Some(e),
             #[cfg(feature = "ocel-sqlite")]
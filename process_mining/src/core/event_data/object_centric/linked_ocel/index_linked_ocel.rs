@@ -7,10 +7,13 @@ use std::{
 };
 
 use macros_process_mining::RegistryEntity;
+use rayon::prelude::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::core::event_data::object_centric::ocel_struct::{OCELEvent, OCELObject, OCELType, OCEL};
+use crate::core::event_data::object_centric::ocel_struct::{
+    OCELEvent, OCELObject, OCELRelationship, OCELType, OCEL,
+};
 use crate::core::io::{Exportable, Importable};
 use crate::core::{event_data::object_centric::io::OCELIOError, io::ExtensionWithMime};
 
@@ -126,11 +129,142 @@ impl IndexLinkedOCEL {
         Self::from(ocel)
     }
 
+    /// Process an [`OCEL`] into a [`IndexLinkedOCEL`], building the indices in parallel
+    ///
+    /// Produces the same result as [`IndexLinkedOCEL::from_ocel`], but the independent
+    /// event/object indices are computed concurrently (using rayon), which is considerably
+    /// faster for larger OCEL on multi-core machines.
+    pub fn from_ocel_parallel(mut ocel: OCEL) -> Self {
+        // Sort events so that the index order corresponds to the timstamp order
+        ocel.events.par_sort_by_key(|e| e.time);
+        let (event_ids_to_index, object_ids_to_index): (HashMap<_, _>, HashMap<_, _>) = rayon::join(
+            || {
+                ocel.events
+                    .par_iter()
+                    .enumerate()
+                    .map(|(ev_index, e)| (e.id.clone(), EventIndex(ev_index)))
+                    .collect()
+            },
+            || {
+                ocel.objects
+                    .par_iter()
+                    .enumerate()
+                    .map(|(ob_index, o)| (o.id.clone(), ObjectIndex(ob_index)))
+                    .collect()
+            },
+        );
+        let event_type_names: HashSet<&str> =
+            ocel.event_types.iter().map(|et| et.name.as_str()).collect();
+
+        let ((e2o_rel, e2o_set), (o2o_rel, (events_per_type, objects_per_type))) = rayon::join(
+            || {
+                rayon::join(
+                    || {
+                        ocel.events
+                            .par_iter()
+                            .map(|e| {
+                                if !event_type_names.contains(e.event_type.as_str()) {
+                                    return Vec::new();
+                                }
+                                linked_relationships(&e.relationships, &object_ids_to_index)
+                            })
+                            .collect::<Vec<_>>()
+                    },
+                    || {
+                        ocel.events
+                            .par_iter()
+                            .map(|e| linked_object_set(&e.relationships, &object_ids_to_index))
+                            .collect::<Vec<_>>()
+                    },
+                )
+            },
+            || {
+                rayon::join(
+                    || {
+                        ocel.objects
+                            .par_iter()
+                            .map(|o| linked_relationships(&o.relationships, &object_ids_to_index))
+                            .collect::<Vec<_>>()
+                    },
+                    || rayon::join(|| events_per_type(&ocel), || objects_per_type(&ocel)),
+                )
+            },
+        );
+
+        // Reverse relationships (derived from the forward relationships computed above)
+        let (e2o_rev_et, (e2o_rel_rev, o2o_rel_rev)) = rayon::join(
+            || e2o_rev_per_event_type(&events_per_type, &e2o_rel),
+            || {
+                rayon::join(
+                    || reverse_relationships(&e2o_rel, ocel.objects.len()),
+                    || reverse_relationships(&o2o_rel, ocel.objects.len()),
+                )
+            },
+        );
+
+        Self {
+            ocel,
+            event_ids_to_index,
+            object_ids_to_index,
+            events_per_type,
+            objects_per_type,
+            e2o_rev_et,
+            e2o_rel,
+            e2o_set,
+            o2o_rel,
+            e2o_rel_rev,
+            o2o_rel_rev,
+        }
+    }
+
     /// Get the inner [`OCEL`] of the [`IndexLinkedOCEL`]
     pub fn into_inner(self) -> OCEL {
         self.ocel
     }
 
+    /// Reconstruct a (serializable) [`OCEL`] from the [`IndexLinkedOCEL`]
+    ///
+    /// Events and objects are returned in index order, with all attributes (including object attribute changes).
+    /// The E2O and O2O relationships (and their qualifiers) are rebuilt from the relationship indices,
+    /// so relationships referencing unknown objects in the original [`OCEL`] are not included.
+    ///
+    /// As long as the event index order corresponds to the timestamp order (i.e., unless events were added out of order),
+    /// [`IndexLinkedOCEL::from_ocel`] on the result yields the same indices again.
+    pub fn to_ocel(&self) -> OCEL {
+        let rels = |rel: &[(String, ObjectIndex)]| -> Vec<OCELRelationship> {
+            rel.iter()
+                .map(|(qualifier, o)| OCELRelationship {
+                    object_id: self.ocel.objects[o.0].id.clone(),
+                    qualifier: qualifier.clone(),
+                })
+                .collect()
+        };
+        OCEL {
+            event_types: self.ocel.event_types.clone(),
+            object_types: self.ocel.object_types.clone(),
+            events: self
+                .ocel
+                .events
+                .iter()
+                .zip(&self.e2o_rel)
+                .map(|(e, e2o)| OCELEvent {
+                    relationships: rels(e2o),
+                    ..e.clone()
+                })
+                .collect(),
+            objects: self
+                .ocel
+                .objects
+                .iter()
+                .zip(&self.o2o_rel)
+                .map(|(o, o2o)| OCELObject {
+                    relationships: rels(o2o),
+                    ..o.clone()
+                })
+                .collect(),
+        }
+    }
+
     /// Get a immutable reference to the inner [`OCEL`]
     ///
     pub fn get_ocel_ref(&self) -> &OCEL {
@@ -153,15 +287,273 @@ impl IndexLinkedOCEL {
     }
 
     /// Get event index by ID
+    ///
+    /// Uses a hash map built during construction (i.e., does not scan all events).
+    /// Useful, e.g., to translate event IDs reported by conformance checking back to an [`EventIndex`].
     pub fn get_ev_index(&self, id: impl AsRef<str>) -> Option<EventIndex> {
         self.event_ids_to_index.get(id.as_ref()).copied()
     }
     /// Get object index by ID
+    ///
+    /// Uses a hash map built during construction (i.e., does not scan all objects).
     pub fn get_ob_index(&self, id: impl AsRef<str>) -> Option<ObjectIndex> {
         self.object_ids_to_index.get(id.as_ref()).copied()
     }
 }
 
+/// Errors returned by the mutating operations on [`IndexLinkedOCEL`] (e.g., [`IndexLinkedOCEL::add_event`])
+///
+/// If an error is returned, the [`IndexLinkedOCEL`] was not modified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexLinkedOCELError {
+    /// Event id already used
+    DuplicateEventId(String),
+    /// Object id already used
+    DuplicateObjectId(String),
+    /// Event type is not declared in the OCEL
+    UnknownEventType(String),
+    /// Object type is not declared in the OCEL
+    UnknownObjectType(String),
+    /// Relationship references an object id that does not exist
+    DanglingObjectReference(String),
+    /// Event index is out of bounds
+    InvalidEventIndex(EventIndex),
+    /// Object index is out of bounds
+    InvalidObjectIndex(ObjectIndex),
+}
+
+impl std::fmt::Display for IndexLinkedOCELError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateEventId(id) => write!(f, "Duplicate event id: {id}"),
+            Self::DuplicateObjectId(id) => write!(f, "Duplicate object id: {id}"),
+            Self::UnknownEventType(t) => write!(f, "Unknown event type: {t}"),
+            Self::UnknownObjectType(t) => write!(f, "Unknown object type: {t}"),
+            Self::DanglingObjectReference(id) => {
+                write!(f, "Relationship references unknown object id: {id}")
+            }
+            Self::InvalidEventIndex(i) => write!(f, "Invalid event index: {}", i.0),
+            Self::InvalidObjectIndex(i) => write!(f, "Invalid object index: {}", i.0),
+        }
+    }
+}
+
+impl std::error::Error for IndexLinkedOCELError {}
+
+/// Incremental updates
+///
+/// The following functions modify the inner [`OCEL`] and keep all relationship indices consistent,
+/// without re-processing the whole [`OCEL`] (as [`IndexLinkedOCEL::from_ocel`] would).
+///
+/// Note: New events are appended at the end, so after modifications the order of event indices
+/// does not necessarily correspond to the timestamp order anymore.
+impl IndexLinkedOCEL {
+    /// Add a new object
+    ///
+    /// The object type has to be declared in the OCEL, and all O2O relationships have to
+    /// reference existing objects (or the object itself).
+    ///
+    /// Returns the [`ObjectIndex`] of the added object
+    pub fn add_object(&mut self, object: OCELObject) -> Result<ObjectIndex, IndexLinkedOCELError> {
+        if self.object_ids_to_index.contains_key(&object.id) {
+            return Err(IndexLinkedOCELError::DuplicateObjectId(object.id));
+        }
+        if !self.objects_per_type.contains_key(&object.object_type) {
+            return Err(IndexLinkedOCELError::UnknownObjectType(object.object_type));
+        }
+        let new_ob_index = ObjectIndex(self.ocel.objects.len());
+        let o2o: Vec<(String, ObjectIndex)> = object
+            .relationships
+            .iter()
+            .map(|rel| {
+                let target = if rel.object_id == object.id {
+                    new_ob_index
+                } else {
+                    self.get_ob_index(&rel.object_id).ok_or_else(|| {
+                        IndexLinkedOCELError::DanglingObjectReference(rel.object_id.clone())
+                    })?
+                };
+                Ok((rel.qualifier.clone(), target))
+            })
+            .collect::<Result<_, _>>()?;
+
+        self.object_ids_to_index
+            .insert(object.id.clone(), new_ob_index);
+        self.objects_per_type
+            .get_mut(&object.object_type)
+            .expect("Object type checked above")
+            .push(new_ob_index);
+        self.e2o_rel_rev.push(Vec::new());
+        self.o2o_rel_rev.push(Vec::new());
+        for (q, target) in &o2o {
+            self.o2o_rel_rev[target.0].push((q.clone(), new_ob_index));
+        }
+        self.o2o_rel.push(o2o);
+        self.ocel.objects.push(object);
+        Ok(new_ob_index)
+    }
+
+    /// Add a new event
+    ///
+    /// The event type has to be declared in the OCEL, and all E2O relationships have to
+    /// reference existing objects.
+    ///
+    /// Returns the [`EventIndex`] of the added event
+    pub fn add_event(&mut self, event: OCELEvent) -> Result<EventIndex, IndexLinkedOCELError> {
+        if self.event_ids_to_index.contains_key(&event.id) {
+            return Err(IndexLinkedOCELError::DuplicateEventId(event.id));
+        }
+        if !self.events_per_type.contains_key(&event.event_type) {
+            return Err(IndexLinkedOCELError::UnknownEventType(event.event_type));
+        }
+        let e2o: Vec<(String, ObjectIndex)> = event
+            .relationships
+            .iter()
+            .map(|rel| {
+                self.get_ob_index(&rel.object_id)
+                    .map(|o| (rel.qualifier.clone(), o))
+                    .ok_or_else(|| {
+                        IndexLinkedOCELError::DanglingObjectReference(rel.object_id.clone())
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let new_ev_index = EventIndex(self.ocel.events.len());
+        self.event_ids_to_index
+            .insert(event.id.clone(), new_ev_index);
+        self.events_per_type
+            .get_mut(&event.event_type)
+            .expect("Event type checked above")
+            .push(new_ev_index);
+        let e2o_rev_et = self.e2o_rev_et.entry(event.event_type.clone()).or_default();
+        for (q, o) in &e2o {
+            e2o_rev_et.entry(*o).or_default().insert(new_ev_index);
+            self.e2o_rel_rev[o.0].push((q.clone(), new_ev_index));
+        }
+        self.e2o_set.push(e2o.iter().map(|(_q, o)| *o).collect());
+        self.e2o_rel.push(e2o);
+        self.ocel.events.push(event);
+        Ok(new_ev_index)
+    }
+
+    /// Remove an event (and all its E2O relationships)
+    ///
+    /// To avoid shifting all following indices, the last event is moved to the index of the removed event.
+    /// Thus, the [`EventIndex`] of the (previously) last event changes to `index`.
+    ///
+    /// Returns the removed [`OCELEvent`]
+    pub fn remove_event(&mut self, index: EventIndex) -> Result<OCELEvent, IndexLinkedOCELError> {
+        if index.0 >= self.ocel.events.len() {
+            return Err(IndexLinkedOCELError::InvalidEventIndex(index));
+        }
+        let last_index = EventIndex(self.ocel.events.len() - 1);
+
+        // Unlink the removed event
+        let ev_type = self.ocel.events[index.0].event_type.clone();
+        for (_q, o) in &self.e2o_rel[index.0] {
+            self.e2o_rel_rev[o.0].retain(|(_q, e)| e != &index);
+            if let Some(evs) = self.e2o_rev_et.get_mut(&ev_type) {
+                if let Some(set) = evs.get_mut(o) {
+                    set.remove(&index);
+                    if set.is_empty() {
+                        evs.remove(o);
+                    }
+                }
+            }
+        }
+        if let Some(evs) = self.events_per_type.get_mut(&ev_type) {
+            evs.retain(|e| e != &index);
+        }
+        let removed = self.ocel.events.swap_remove(index.0);
+        self.event_ids_to_index.remove(&removed.id);
+        self.e2o_rel.swap_remove(index.0);
+        self.e2o_set.swap_remove(index.0);
+
+        // Re-link the moved event (previously at `last_index`) to its new index
+        if index != last_index {
+            let moved = &self.ocel.events[index.0];
+            self.event_ids_to_index.insert(moved.id.clone(), index);
+            if let Some(evs) = self.events_per_type.get_mut(&moved.event_type) {
+                evs.retain(|e| e != &last_index);
+                let pos = evs.partition_point(|e| e < &index);
+                evs.insert(pos, index);
+            }
+            for (_q, o) in &self.e2o_rel[index.0] {
+                for (_q, e) in self.e2o_rel_rev[o.0].iter_mut() {
+                    if e == &last_index {
+                        *e = index;
+                    }
+                }
+                if let Some(set) = self
+                    .e2o_rev_et
+                    .get_mut(&moved.event_type)
+                    .and_then(|evs| evs.get_mut(o))
+                {
+                    set.remove(&last_index);
+                    set.insert(index);
+                }
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Add an E2O relationship between an event and an object, with the specified qualifier
+    pub fn add_e2o(
+        &mut self,
+        event: EventIndex,
+        object: ObjectIndex,
+        qualifier: impl Into<String>,
+    ) -> Result<(), IndexLinkedOCELError> {
+        if event.0 >= self.ocel.events.len() {
+            return Err(IndexLinkedOCELError::InvalidEventIndex(event));
+        }
+        if object.0 >= self.ocel.objects.len() {
+            return Err(IndexLinkedOCELError::InvalidObjectIndex(object));
+        }
+        let qualifier = qualifier.into();
+        let ev = &mut self.ocel.events[event.0];
+        ev.relationships.push(OCELRelationship {
+            object_id: self.ocel.objects[object.0].id.clone(),
+            qualifier: qualifier.clone(),
+        });
+        self.e2o_rev_et
+            .entry(ev.event_type.clone())
+            .or_default()
+            .entry(object)
+            .or_default()
+            .insert(event);
+        self.e2o_rel_rev[object.0].push((qualifier.clone(), event));
+        self.e2o_set[event.0].insert(object);
+        self.e2o_rel[event.0].push((qualifier, object));
+        Ok(())
+    }
+
+    /// Add an O2O relationship from `from_obj` to `to_obj`, with the specified qualifier
+    pub fn add_o2o(
+        &mut self,
+        from_obj: ObjectIndex,
+        to_obj: ObjectIndex,
+        qualifier: impl Into<String>,
+    ) -> Result<(), IndexLinkedOCELError> {
+        for o in [from_obj, to_obj] {
+            if o.0 >= self.ocel.objects.len() {
+                return Err(IndexLinkedOCELError::InvalidObjectIndex(o));
+            }
+        }
+        let qualifier = qualifier.into();
+        let object_id = self.ocel.objects[to_obj.0].id.clone();
+        self.ocel.objects[from_obj.0]
+            .relationships
+            .push(OCELRelationship {
+                object_id,
+                qualifier: qualifier.clone(),
+            });
+        self.o2o_rel_rev[to_obj.0].push((qualifier.clone(), from_obj));
+        self.o2o_rel[from_obj.0].push((qualifier, to_obj));
+        Ok(())
+    }
+}
+
 impl Index<EventIndex> for IndexLinkedOCEL {
     type Output = OCELEvent;
     fn index(&self, index: EventIndex) -> &Self::Output {
@@ -215,6 +607,95 @@ impl Index<&ObjectIndex> for &IndexLinkedOCEL {
     }
 }
 
+/// Resolve the target objects of relationships to their [`ObjectIndex`] (skipping unknown objects)
+fn linked_relationships(
+    relationships: &[OCELRelationship],
+    object_ids_to_index: &HashMap<String, ObjectIndex>,
+) -> Vec<(String, ObjectIndex)> {
+    relationships
+        .iter()
+        .flat_map(|rel| {
+            let obj_id = *object_ids_to_index.get(&rel.object_id)?;
+            Some((rel.qualifier.clone(), obj_id))
+        })
+        .collect()
+}
+
+/// Set of (known) target objects of relationships
+fn linked_object_set(
+    relationships: &[OCELRelationship],
+    object_ids_to_index: &HashMap<String, ObjectIndex>,
+) -> HashSet<ObjectIndex> {
+    relationships
+        .iter()
+        .flat_map(|rel| object_ids_to_index.get(&rel.object_id).copied())
+        .collect()
+}
+
+/// Group item indices by their type in a single pass
+///
+/// Only declared types are included (items of undeclared types are skipped)
+fn indices_per_type<'b, I: From<usize>>(
+    type_names: impl Iterator<Item = &'b str>,
+    item_types: impl Iterator<Item = &'b str>,
+) -> HashMap<String, Vec<I>> {
+    let mut per_type: HashMap<String, Vec<I>> =
+        type_names.map(|t| (t.to_string(), Vec::new())).collect();
+    for (index, item_type) in item_types.enumerate() {
+        if let Some(indices) = per_type.get_mut(item_type) {
+            indices.push(I::from(index));
+        }
+    }
+    per_type
+}
+
+fn events_per_type(ocel: &OCEL) -> HashMap<String, Vec<EventIndex>> {
+    indices_per_type(
+        ocel.event_types.iter().map(|et| et.name.as_str()),
+        ocel.events.iter().map(|e| e.event_type.as_str()),
+    )
+}
+
+fn objects_per_type(ocel: &OCEL) -> HashMap<String, Vec<ObjectIndex>> {
+    indices_per_type(
+        ocel.object_types.iter().map(|ot| ot.name.as_str()),
+        ocel.objects.iter().map(|o| o.object_type.as_str()),
+    )
+}
+
+/// Reverse (index-based) relationships to objects
+fn reverse_relationships<I: From<usize>>(
+    relationships: &[Vec<(String, ObjectIndex)>],
+    num_objects: usize,
+) -> Vec<Vec<(String, I)>> {
+    let mut rev: Vec<Vec<(String, I)>> = (0..num_objects).map(|_| Vec::new()).collect();
+    for (index, rels) in relationships.iter().enumerate() {
+        for (qualifier, obj_id) in rels {
+            rev[obj_id.0].push((qualifier.clone(), I::from(index)));
+        }
+    }
+    rev
+}
+
+/// Reverse event-to-object relationships per event type
+fn e2o_rev_per_event_type(
+    events_per_type: &HashMap<String, Vec<EventIndex>>,
+    e2o_rel: &[Vec<(String, ObjectIndex)>],
+) -> HashMap<String, HashMap<ObjectIndex, HashSet<EventIndex>>> {
+    events_per_type
+        .iter()
+        .map(|(et, evs)| {
+            let mut rev: HashMap<ObjectIndex, HashSet<EventIndex>> = HashMap::new();
+            for e_id in evs {
+                for (_q, obj_id) in &e2o_rel[e_id.0] {
+                    rev.entry(*obj_id).or_default().insert(*e_id);
+                }
+            }
+            (et.clone(), rev)
+        })
+        .collect()
+}
+
 impl From<OCEL> for IndexLinkedOCEL {
     fn from(mut ocel: OCEL) -> Self {
         // Sort events so that the index order corresponds to the timstamp order
@@ -231,109 +712,35 @@ impl From<OCEL> for IndexLinkedOCEL {
             .enumerate()
             .map(|(ob_index, o)| (o.id.clone(), ObjectIndex(ob_index)))
             .collect();
-        let mut e2o_rel_rev: Vec<Vec<(String, EventIndex)>> = vec![Vec::new(); ocel.objects.len()];
+        let event_type_names: HashSet<&str> =
+            ocel.event_types.iter().map(|et| et.name.as_str()).collect();
 
-        let mut e2o_rev_et: HashMap<String, HashMap<ObjectIndex, HashSet<EventIndex>>> = ocel
-            .event_types
-            .iter()
-            .map(|et| (et.name.clone(), HashMap::new()))
-            .collect();
-        let e2o_rel = ocel
+        let e2o_rel: Vec<_> = ocel
             .events
             .iter()
-            .enumerate()
-            .map(|(e_index, e)| {
-                let e_id: EventIndex = EventIndex(e_index);
-                e.relationships
-                    .iter()
-                    .flat_map(|rel| {
-                        let obj_id = *object_ids_to_index.get(&rel.object_id)?;
-                        let qualifier = rel.qualifier.clone();
-                        let ev_type = e2o_rev_et.get_mut(&e.event_type)?;
-                        ev_type.entry(obj_id).or_default().insert(e_id);
-                        e2o_rel_rev[obj_id.0].push((qualifier.clone(), e_id));
-                        Some((qualifier, obj_id))
-                    })
-                    .collect()
+            .map(|e| {
+                if !event_type_names.contains(e.event_type.as_str()) {
+                    return Vec::new();
+                }
+                linked_relationships(&e.relationships, &object_ids_to_index)
             })
-            .collect::<Vec<_>>();
-
+            .collect();
         let e2o_set = ocel
             .events
             .iter()
-            .map(|e| {
-                e.relationships
-                    .iter()
-                    .flat_map(|rel| {
-                        let obj_id = *object_ids_to_index.get(&rel.object_id)?;
-                        // let ob = objects.get(&((&rel.object_id).into()))?;
-                        Some(obj_id)
-                    })
-                    .collect()
-            })
+            .map(|e| linked_object_set(&e.relationships, &object_ids_to_index))
             .collect();
-
-        let mut o2o_rel_rev: Vec<Vec<(String, ObjectIndex)>> = vec![Vec::new(); ocel.objects.len()];
-
-        let o2o_rel = ocel
+        let o2o_rel: Vec<_> = ocel
             .objects
             .iter()
-            .enumerate()
-            .map(|(o_index, o)| {
-                let o_id: ObjectIndex = ObjectIndex(o_index);
-                o.relationships
-                    .iter()
-                    .flat_map(|rel| {
-                        let qualifier = rel.qualifier.clone();
-                        let obj2_id = *object_ids_to_index.get(&rel.object_id)?;
-                        o2o_rel_rev[obj2_id.0].push((qualifier, o_id));
-                        Some(((&rel.qualifier).into(), obj2_id))
-                    })
-                    .collect()
-            })
+            .map(|o| linked_relationships(&o.relationships, &object_ids_to_index))
             .collect();
+        let events_per_type = events_per_type(&ocel);
+        let objects_per_type = objects_per_type(&ocel);
 
-        let events_per_type = ocel
-            .event_types
-            .iter()
-            .map(|et| {
-                (
-                    et.name.clone(),
-                    ocel.events
-                        .iter()
-                        .enumerate()
-                        .filter_map(|(index, e)| {
-                            if e.event_type == et.name {
-                                Some(EventIndex(index))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect(),
-                )
-            })
-            .collect();
-
-        let objects_per_type = ocel
-            .object_types
-            .iter()
-            .map(|et| {
-                (
-                    et.name.clone(),
-                    ocel.objects
-                        .iter()
-                        .enumerate()
-                        .filter_map(|(index, e)| {
-                            if e.object_type == et.name {
-                                Some(ObjectIndex(index))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect(),
-                )
-            })
-            .collect();
+        let e2o_rev_et = e2o_rev_per_event_type(&events_per_type, &e2o_rel);
+        let e2o_rel_rev = reverse_relationships(&e2o_rel, ocel.objects.len());
+        let o2o_rel_rev = reverse_relationships(&o2o_rel, ocel.objects.len());
 
         Self {
             ocel,
@@ -537,6 +944,15 @@ impl Importable for IndexLinkedOCEL {
         Ok(IndexLinkedOCEL::from_ocel(ocel))
     }
 
+    fn import_from_path_with_options<P: AsRef<Path>>(
+        path: P,
+        _: Self::ImportOptions,
+    ) -> Result<Self, Self::Error> {
+        // Delegate to the OCEL importer, which reads database formats (e.g., DuckDB) directly from the path
+        let ocel = OCEL::import_from_path(path)?;
+        Ok(IndexLinkedOCEL::from_ocel(ocel))
+    }
+
     fn infer_format(path: &Path) -> Option<String> {
         <OCEL as Importable>::infer_format(path)
     }
@@ -566,13 +982,56 @@ impl Exportable for IndexLinkedOCEL {
 #[cfg(test)]
 mod tests {
 
+    use chrono::DateTime;
+
     use crate::{
-        core::event_data::object_centric::ocel_xml::xml_ocel_import::import_ocel_xml_path,
+        core::event_data::object_centric::{
+            ocel_struct::{OCELAttributeValue, OCELObjectAttribute},
+            ocel_xml::xml_ocel_import::import_ocel_xml_path,
+        },
         test_utils::get_test_data_path,
     };
 
     use super::*;
 
+    #[test]
+    fn test_from_ocel_parallel() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:1"]),
+            ("ship", ["p:1", "o:1"]),
+            ("pack", ["o:2", "i:3"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("o:1", "i:2"),
+            ("p:1", "o:1")
+        ];
+        let mut ocel = ocel;
+        // Event of an undeclared type and relationship to an unknown object
+        let mut undeclared = ocel.events[0].clone();
+        undeclared.id = "undeclared".to_string();
+        undeclared.event_type = "undeclared".to_string();
+        ocel.events.push(undeclared);
+        ocel.events[0].relationships.push(OCELRelationship {
+            object_id: "unknown".to_string(),
+            qualifier: String::new(),
+        });
+        let seq = IndexLinkedOCEL::from_ocel(ocel.clone());
+        let par = IndexLinkedOCEL::from_ocel_parallel(ocel);
+        assert_eq!(seq.ocel, par.ocel);
+        assert_eq!(seq.event_ids_to_index, par.event_ids_to_index);
+        assert_eq!(seq.object_ids_to_index, par.object_ids_to_index);
+        assert_eq!(seq.events_per_type, par.events_per_type);
+        assert_eq!(seq.objects_per_type, par.objects_per_type);
+        assert_eq!(seq.e2o_rev_et, par.e2o_rev_et);
+        assert_eq!(seq.e2o_rel, par.e2o_rel);
+        assert_eq!(seq.e2o_set, par.e2o_set);
+        assert_eq!(seq.o2o_rel, par.o2o_rel);
+        assert_eq!(seq.e2o_rel_rev, par.e2o_rel_rev);
+        assert_eq!(seq.o2o_rel_rev, par.o2o_rel_rev);
+    }
+
     #[test]
     fn test_indexing() {
         let ocel = import_ocel_xml_path(
@@ -602,4 +1061,241 @@ mod tests {
             assert_eq!(ev1, ev4);
         };
     }
+
+    #[test]
+    fn test_to_ocel_round_trip() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:1"]),
+            ("ship", ["p:1", "o:1"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("p:1", "o:1")
+        ];
+        let mut locel = IndexLinkedOCEL::from_ocel(ocel);
+        let o1 = locel.get_ob_index("o:1").unwrap();
+        let i2 = locel.get_ob_index("i:2").unwrap();
+        locel.add_o2o(o1, i2, "contains").unwrap();
+        let ship = locel.events_per_type["ship"][0];
+        locel.add_e2o(ship, i2, "shipped").unwrap();
+        locel.get_ocel_mut().objects[o1.0]
+            .attributes
+            .push(OCELObjectAttribute {
+                name: "status".to_string(),
+                value: OCELAttributeValue::String("open".to_string()),
+                time: DateTime::UNIX_EPOCH.fixed_offset(),
+            });
+
+        let ocel = locel.to_ocel();
+        assert_eq!(&ocel, locel.get_ocel_ref());
+        let rebuilt = IndexLinkedOCEL::from_ocel(ocel);
+        assert_eq!(rebuilt.event_ids_to_index, locel.event_ids_to_index);
+        assert_eq!(rebuilt.object_ids_to_index, locel.object_ids_to_index);
+        assert_eq!(rebuilt.events_per_type, locel.events_per_type);
+        assert_eq!(rebuilt.objects_per_type, locel.objects_per_type);
+        assert_eq!(rebuilt.e2o_rel, locel.e2o_rel);
+        assert_eq!(rebuilt.o2o_rel, locel.o2o_rel);
+        assert_eq!(rebuilt.e2o_rel_rev, locel.e2o_rel_rev);
+        assert_eq!(rebuilt.o2o_rel_rev, locel.o2o_rel_rev);
+        assert_eq!(rebuilt.ocel.objects[o1.0].attributes.len(), 1);
+    }
+
+    /// Compare all (reverse) relationship indices of `locel` to a freshly built [`IndexLinkedOCEL`], based on IDs
+    fn assert_consistent_with_rebuild(locel: &IndexLinkedOCEL) {
+        fn relation_ids(locel: &IndexLinkedOCEL) -> Vec<Vec<(String, String, String)>> {
+            let mut e2o: Vec<_> = locel
+                .get_all_evs()
+                .flat_map(|e| {
+                    locel.get_e2o(e).map(move |(q, o)| {
+                        (
+                            locel.get_ev_id(e).to_string(),
+                            q.to_string(),
+                            locel.get_ob_id(o).to_string(),
+                        )
+                    })
+                })
+                .collect();
+            let mut e2o_rev: Vec<_> = locel
+                .get_all_obs()
+                .flat_map(|o| {
+                    locel.get_e2o_rev(o).map(move |(q, e)| {
+                        (
+                            locel.get_ev_id(e).to_string(),
+                            q.to_string(),
+                            locel.get_ob_id(o).to_string(),
+                        )
+                    })
+                })
+                .collect();
+            let mut o2o_rev: Vec<_> = locel
+                .get_all_obs()
+                .flat_map(|o| {
+                    locel.get_o2o_rev(o).map(move |(q, o2)| {
+                        (
+                            locel.get_ob_id(o2).to_string(),
+                            q.to_string(),
+                            locel.get_ob_id(o).to_string(),
+                        )
+                    })
+                })
+                .collect();
+            let mut e2o_rev_et: Vec<_> = locel
+                .e2o_rev_et
+                .iter()
+                .flat_map(|(et, rev)| {
+                    rev.iter().flat_map(move |(o, evs)| {
+                        evs.iter().map(move |e| {
+                            (
+                                et.clone(),
+                                locel.get_ev_id(e).to_string(),
+                                locel.get_ob_id(o).to_string(),
+                            )
+                        })
+                    })
+                })
+                .collect();
+            let mut per_type: Vec<_> = locel
+                .events_per_type
+                .iter()
+                .flat_map(|(et, evs)| {
+                    evs.iter()
+                        .map(move |e| (et.clone(), locel.get_ev_id(e).to_string(), String::new()))
+                })
+                .collect();
+            for v in [
+                &mut e2o,
+                &mut e2o_rev,
+                &mut o2o_rev,
+                &mut e2o_rev_et,
+                &mut per_type,
+            ] {
+                v.sort();
+            }
+            vec![e2o, e2o_rev, o2o_rev, e2o_rev_et, per_type]
+        }
+        let rebuilt = IndexLinkedOCEL::from_ocel(locel.get_ocel_ref().clone());
+        assert_eq!(relation_ids(locel), relation_ids(&rebuilt));
+        for e in locel.get_all_evs() {
+            assert_eq!(locel.get_ev_by_id(locel.get_ev_id(e)), Some(e));
+            assert!(locel.events_per_type[locel.get_ev_type_of(e)].is_sorted());
+        }
+        for o in locel.get_all_obs() {
+            assert_eq!(locel.get_ob_by_id(locel.get_ob_id(o)), Some(o));
+        }
+    }
+
+    #[test]
+    fn test_incremental_updates() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["c:1", "o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:2"]),
+            ("place", ["c:1", "o:2", "i:3"]),
+            o2o:
+            ("o:1", "i:1")
+        ];
+        let mut locel = IndexLinkedOCEL::from_ocel(ocel);
+        let time = locel.get_ocel_ref().events[2].time;
+
+        let o3 = locel
+            .add_object(OCELObject {
+                id: "o:3".to_string(),
+                object_type: "o".to_string(),
+                attributes: Vec::new(),
+                relationships: vec![OCELRelationship::new("i:3", "contains")],
+            })
+            .unwrap();
+        let ev = locel
+            .add_event(OCELEvent::new(
+                "ev:new",
+                "pack",
+                time,
+                Vec::new(),
+                vec![
+                    OCELRelationship::new("o:3", "packed"),
+                    OCELRelationship::new("i:3", ""),
+                ],
+            ))
+            .unwrap();
+        assert_consistent_with_rebuild(&locel);
+        assert!(locel.e2o_rev_et["pack"][&o3].contains(&ev));
+
+        let c1 = locel.get_ob_index("c:1").unwrap();
+        locel.add_e2o(ev, c1, "for").unwrap();
+        locel.add_o2o(o3, c1, "placed by").unwrap();
+        assert_consistent_with_rebuild(&locel);
+
+        // Remove the first event (i.e., not the last one, which is moved to its index)
+        let first = locel.get_all_evs().next().unwrap();
+        let first_id = locel.get_ev_id(first).to_string();
+        let removed = locel.remove_event(first).unwrap();
+        assert_eq!(removed.id, first_id);
+        assert_eq!(locel.get_ev_index(&first_id), None);
+        assert_eq!(locel.get_ev_index("ev:new"), Some(first));
+        assert_consistent_with_rebuild(&locel);
+
+        // Remove the last event
+        let last = locel.get_all_evs().last().unwrap();
+        locel.remove_event(last).unwrap();
+        assert_consistent_with_rebuild(&locel);
+        assert_eq!(locel.get_all_evs().count(), 2);
+    }
+
+    #[test]
+    fn test_incremental_updates_errors() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["c:1", "o:1"]),
+            o2o:
+            ("o:1", "c:1")
+        ];
+        let mut locel = IndexLinkedOCEL::from_ocel(ocel);
+        let time = locel.get_ocel_ref().events[0].time;
+        assert_eq!(
+            locel.add_event(OCELEvent::new(
+                "ev:2",
+                "place",
+                time,
+                Vec::new(),
+                vec![OCELRelationship::new("o:404", "")],
+            )),
+            Err(IndexLinkedOCELError::DanglingObjectReference(
+                "o:404".to_string()
+            ))
+        );
+        assert_eq!(
+            locel.add_event(OCELEvent::new(
+                "ev:2",
+                "unknown",
+                time,
+                Vec::new(),
+                Vec::new()
+            )),
+            Err(IndexLinkedOCELError::UnknownEventType(
+                "unknown".to_string()
+            ))
+        );
+        assert_eq!(
+            locel.add_object(OCELObject {
+                id: "c:1".to_string(),
+                object_type: "c".to_string(),
+                attributes: Vec::new(),
+                relationships: Vec::new(),
+            }),
+            Err(IndexLinkedOCELError::DuplicateObjectId("c:1".to_string()))
+        );
+        assert_eq!(
+            locel.add_e2o(EventIndex(0), ObjectIndex(42), ""),
+            Err(IndexLinkedOCELError::InvalidObjectIndex(ObjectIndex(42)))
+        );
+        assert_eq!(
+            locel.remove_event(EventIndex(1)).map(|e| e.id),
+            Err(IndexLinkedOCELError::InvalidEventIndex(EventIndex(1)))
+        );
+        // Failed operations do not modify the OCEL
+        assert_eq!(locel.get_all_evs().count(), 1);
+        assert_eq!(locel.get_all_obs().count(), 2);
+        assert_consistent_with_rebuild(&locel);
+    }
 }
@@ -1,11 +1,24 @@
 //! OCEL Struct for Efficient Usage of Relations
+//!
+//! ## Choosing a Representation
+//!
+//! - [`IndexLinkedOCEL`] wraps a complete [`OCEL`] (which stays accessible, e.g., through [`IndexLinkedOCEL::get_ocel_ref`])
+//!   and additionally stores relationship indices, including per-event-type reverse E2O relationships.
+//!   Qualifiers are stored as owned [`String`]s per relationship, so this representation uses the most memory.
+//! - [`SlimLinkedOCEL`] stores events and objects in a compact form (`u32` indices, interned types and qualifiers)
+//!   and supports adding events/objects incrementally and time-range queries on reverse E2O relationships.
+//!   It typically requires considerably less memory, but full [`OCELEvent`]s/[`OCELObject`]s have to be materialized on access.
+//! - [`IDLinkedOCEL`] references events and objects by their IDs, which is convenient but the slowest option.
+//!
+//! [`IndexLinkedOCEL`] and [`SlimLinkedOCEL`] can be converted into each other using [`From`]/[`Into`],
+//! so that functions written against one representation can be used without re-importing the data.
+//! Such a conversion goes through an intermediate [`OCEL`] and thus temporarily needs memory for both representations.
 use std::borrow::{Borrow, Cow};
 
 use crate::core::event_data::object_centric::OCELAttributeValue;
 
 use super::ocel_struct::{OCELEvent, OCELObject, OCELType};
 
-#[allow(unused_imports)]
 use super::ocel_struct::OCEL;
 
 /// An [`OCEL`] linked based on event and object indices
@@ -210,3 +223,31 @@ pub trait LinkedOCELAccess<'a> {
         }
     }
 }
+
+impl From<&IndexLinkedOCEL> for SlimLinkedOCEL {
+    /// Convert an [`IndexLinkedOCEL`] to a [`SlimLinkedOCEL`] (see [`IndexLinkedOCEL::to_ocel`])
+    fn from(value: &IndexLinkedOCEL) -> Self {
+        SlimLinkedOCEL::from_ocel(value.to_ocel())
+    }
+}
+
+impl From<IndexLinkedOCEL> for SlimLinkedOCEL {
+    /// Convert an [`IndexLinkedOCEL`] to a [`SlimLinkedOCEL`] (see [`IndexLinkedOCEL::to_ocel`])
+    fn from(value: IndexLinkedOCEL) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&SlimLinkedOCEL> for IndexLinkedOCEL {
+    /// Convert a [`SlimLinkedOCEL`] to an [`IndexLinkedOCEL`] (see [`LinkedOCELAccess::construct_ocel`])
+    fn from(value: &SlimLinkedOCEL) -> Self {
+        IndexLinkedOCEL::from_ocel(value.construct_ocel())
+    }
+}
+
+impl From<SlimLinkedOCEL> for IndexLinkedOCEL {
+    /// Convert a [`SlimLinkedOCEL`] to an [`IndexLinkedOCEL`] (see [`LinkedOCELAccess::construct_ocel`])
+    fn from(value: SlimLinkedOCEL) -> Self {
+        (&value).into()
+    }
+}
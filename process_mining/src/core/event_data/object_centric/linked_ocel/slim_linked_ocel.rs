@@ -1,21 +1,27 @@
 //! Linked Slim (i.e., less duplicate fields) OCEL
 //!
 //! Allows easy and efficient access to events, objects, and their relations
+use crate::diagnostics::warn_diagnostic;
 use std::{
     borrow::{Borrow, Cow},
     collections::HashMap,
     hash::BuildHasher,
     io::{Read, Write},
+    ops::Bound,
     path::Path,
+    sync::OnceLock,
 };
 
+use crate::utils::new_id;
 use chrono::{DateTime, FixedOffset};
 use hashbrown::{DefaultHashBuilder, HashTable};
 use macros_process_mining::RegistryEntity;
+use rayon::prelude::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
+#[cfg(feature = "dataframes")]
+use crate::core::event_data::object_centric::dataframe::{dataframes_to_ocel, OCELDataFrames};
 use crate::{
     core::{
         event_data::object_centric::{
@@ -34,6 +40,11 @@ use crate::{
     Exportable, Importable,
 };
 
+/// Reverse E2O entry of the time-sorted index: `(time, event, event type index)`
+///
+/// See [`SlimLinkedOCEL::get_e2o_rev_by_time`].
+pub type TimedEventRef = (DateTime<FixedOffset>, EventIndex, usize);
+
 /// Interned qualifier identifier. Indexes into [`SlimLinkedOCEL::qualifiers`].
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
 #[serde(transparent)]
@@ -150,8 +161,8 @@ fn reconcile_known_value(
     match value.try_coerce_to(declared) {
         Some(coerced) => coerced,
         None => {
-            eprintln!(
-                "[rust4pm] warning: {} {:?} attribute {:?}: value variant {:?} differs from declared type {:?} and cannot be coerced; storing as-is",
+            warn_diagnostic!(
+                "{} {:?} attribute {:?}: value variant {:?} differs from declared type {:?} and cannot be coerced; storing as-is",
                 owner_kind,
                 owner_id,
                 attr_name,
@@ -393,6 +404,37 @@ impl ObjectIndex {
                 .filter(move |ev| locel.events[ev.ix()].event_type == ei)
         })
     }
+    /// Get reverse E2O relationships of all events with the specified event type in the given time range
+    ///
+    /// Binary-searches the time-sorted index (see [`SlimLinkedOCEL::get_e2o_rev_by_time`]) instead of
+    /// scanning all events of the object. Events are returned sorted by time.
+    /// Returns an empty iterator if the event type is unknown or the object index is out of bounds.
+    pub fn get_e2o_rev_of_evtype_in_range<'a>(
+        &self,
+        locel: &'a SlimLinkedOCEL,
+        evtype: &str,
+        range: (Bound<DateTime<FixedOffset>>, Bound<DateTime<FixedOffset>>),
+    ) -> impl Iterator<Item = EventIndex> + use<'a> {
+        let evtype_index = locel.evtype_to_index.get(evtype).copied();
+        let evs = match evtype_index {
+            Some(_) => locel.get_e2o_rev_by_time(*self),
+            None => &[],
+        };
+        let start = match range.0 {
+            Bound::Included(t) => evs.partition_point(|(time, _, _)| *time < t),
+            Bound::Excluded(t) => evs.partition_point(|(time, _, _)| *time <= t),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.1 {
+            Bound::Included(t) => evs.partition_point(|(time, _, _)| *time <= t),
+            Bound::Excluded(t) => evs.partition_point(|(time, _, _)| *time < t),
+            Bound::Unbounded => evs.len(),
+        };
+        evs[start..end.max(start)]
+            .iter()
+            .filter(move |(_, _, et)| Some(*et) == evtype_index)
+            .map(|(_, ev, _)| *ev)
+    }
     /// Get reverse O2O source objects of the specified type, optionally filtered by qualifier.
     ///
     /// When `qualifier` is `None`, iterates `o2o_rev` directly (no relationship re-scan).
@@ -596,6 +638,9 @@ pub struct SlimLinkedOCEL {
     pending_e2o: Vec<(EventIndex, OCELRelationship)>,
     /// Forward O2O references whose target object id was unknown at insert time.
     pending_o2o: Vec<(ObjectIndex, OCELRelationship)>,
+    /// Lazily built per-object reverse E2O events sorted by time (see [`Self::get_e2o_rev_by_time`]).
+    /// Reset whenever events, objects or E2O relationships change.
+    e2o_rev_time_index: OnceLock<Vec<Vec<TimedEventRef>>>,
 }
 impl SlimLinkedOCEL {
     /// Create a new empty `SlimLinkedOCEL`
@@ -604,6 +649,33 @@ impl SlimLinkedOCEL {
     pub fn new() -> Self {
         Self::default()
     }
+    /// Get the reverse E2O events of an object as `(time, event, event type index)`, sorted by time
+    /// (ties are ordered by [`EventIndex`])
+    ///
+    /// The underlying index is built for all objects on first use and kept until the OCEL is modified.
+    /// Returns an empty slice if the object index is out of bounds.
+    pub fn get_e2o_rev_by_time(&self, ob: ObjectIndex) -> &[TimedEventRef] {
+        self.e2o_rev_time_index
+            .get_or_init(|| {
+                self.objects
+                    .par_iter()
+                    .map(|o| {
+                        let mut evs: Vec<TimedEventRef> = o
+                            .e2o_rev
+                            .iter()
+                            .map(|ev| {
+                                let e = &self.events[ev.ix()];
+                                (e.time, *ev, e.event_type)
+                            })
+                            .collect();
+                        evs.sort_unstable_by_key(|(time, ev, _)| (*time, *ev));
+                        evs
+                    })
+                    .collect()
+            })
+            .get(ob.ix())
+            .map_or(&[], Vec::as_slice)
+    }
     /// Convert an unlinked [`OCEL`] to a [`SlimLinkedOCEL`].
     ///
     /// Events are sorted by time before insertion so that `events_per_type` lists are
@@ -627,7 +699,7 @@ impl SlimLinkedOCEL {
                 relationships,
             } = o;
             if let Err(e) = linked.append_object(id, &object_type, attributes, relationships) {
-                eprintln!("[rust4pm] warning: skipping object: {e}");
+                warn_diagnostic!("skipping object: {e}");
             }
         }
         for ev in ocel.events {
@@ -639,12 +711,22 @@ impl SlimLinkedOCEL {
                 relationships,
             } = ev;
             if let Err(e) = linked.append_event(id, &event_type, time, attributes, relationships) {
-                eprintln!("[rust4pm] warning: skipping event: {e}");
+                warn_diagnostic!("skipping event: {e}");
             }
         }
         let _ = linked.finalize();
         linked
     }
+    /// Convert a set of [`DataFrame`](polars::frame::DataFrame)s ([`OCELDataFrames`]) to a [`SlimLinkedOCEL`]
+    ///
+    /// The data frames are interpreted as in [`dataframes_to_ocel`], and the result is linked
+    /// as in [`SlimLinkedOCEL::from_ocel`].
+    ///
+    /// 🔐 Requires the `dataframes` feature to be enabled.
+    #[cfg(feature = "dataframes")]
+    pub fn from_dataframes(dfs: &OCELDataFrames) -> polars::error::PolarsResult<Self> {
+        Ok(Self::from_ocel(dataframes_to_ocel(dfs)?))
+    }
 
     /// Resolve a qualifier index to its string form. Panics if `idx` is out of range, so
     /// only safe for indices read from this OCEL's own relationship lists. Use
@@ -682,6 +764,25 @@ impl SlimLinkedOCEL {
             .flat_map(|et| &self.objects_per_type[*et])
     }
 
+    /// Get the number of objects of the specified object type
+    ///
+    /// In contrast to counting [`LinkedOCELAccess::get_obs_of_type`], this is a constant-time lookup.
+    /// Returns 0 if the object type is unknown.
+    pub fn num_obs_of_type(&self, object_type: &str) -> usize {
+        self.obtype_to_index
+            .get(object_type)
+            .map_or(0, |ot| self.objects_per_type[*ot].len())
+    }
+    /// Get the number of objects per object type
+    ///
+    /// Also includes object types without any objects.
+    pub fn ob_type_counts(&self) -> HashMap<String, usize> {
+        self.object_types
+            .iter()
+            .map(|ot| (ot.name.clone(), self.num_obs_of_type(&ot.name)))
+            .collect()
+    }
+
     /// Get all object types as strings
     fn get_ob_types(&self) -> impl Iterator<Item = &String> {
         self.object_types.iter().map(|ot| &ot.name)
@@ -727,8 +828,9 @@ impl SlimLinkedOCEL {
         mut attributes: Vec<OCELAttributeValue>,
         relationships: Vec<(String, ObjectIndex)>,
     ) -> Option<EventIndex> {
+        self.e2o_rev_time_index.take();
         let etype = self.evtype_to_index.get(event_type)?;
-        let id = id.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let id = id.unwrap_or_else(|| new_id(""));
         let h = self.hasher.hash_one(&id);
         if self
             .event_ids_to_index
@@ -760,8 +862,8 @@ impl SlimLinkedOCEL {
         // Pad (or truncate) attributes to expected length; warn on mismatch
         let expected_attr_len = self.event_types[*etype].attributes.len();
         if attributes.len() != expected_attr_len {
-            eprintln!(
-                "[rust4pm] warning: event_type '{}' expects {} attribute value(s), got {}. \
+            warn_diagnostic!(
+                "event_type '{}' expects {} attribute value(s), got {}. \
                  Padding with Null / truncating. Ensure attribute order matches `add_event_type`.",
                 event_type,
                 expected_attr_len,
@@ -801,8 +903,9 @@ impl SlimLinkedOCEL {
         mut attributes: Vec<Vec<(DateTime<FixedOffset>, OCELAttributeValue)>>,
         relationships: Vec<(String, ObjectIndex)>,
     ) -> Option<ObjectIndex> {
+        self.e2o_rev_time_index.take();
         let otype = self.obtype_to_index.get(object_type)?;
-        let id = id.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let id = id.unwrap_or_else(|| new_id(""));
         let h = self.hasher.hash_one(&id);
         if self
             .object_ids_to_index
@@ -834,8 +937,8 @@ impl SlimLinkedOCEL {
         // Pad (or truncate) attributes to expected length; warn on mismatch
         let expected_attr_len = self.object_types[*otype].attributes.len();
         if attributes.len() != expected_attr_len {
-            eprintln!(
-                "[rust4pm] warning: object_type '{}' expects {} attribute list(s), got {}. \
+            warn_diagnostic!(
+                "object_type '{}' expects {} attribute list(s), got {}. \
                  Padding with empty / truncating. Ensure attribute order matches `add_object_type`.",
                 object_type,
                 expected_attr_len,
@@ -865,10 +968,12 @@ impl SlimLinkedOCEL {
     ///
     /// Returns `true` on success, `false` if either index is out of bounds (with a stderr warning).
     pub fn add_e2o(&mut self, event: EventIndex, object: ObjectIndex, qualifier: String) -> bool {
+        self.e2o_rev_time_index.take();
         if (event.0 as usize) >= self.events.len() || (object.0 as usize) >= self.objects.len() {
-            eprintln!(
-                "[rust4pm] warning: add_e2o called with invalid index(es) (event={}, object={}); ignored",
-                event.0, object.0
+            warn_diagnostic!(
+                "add_e2o called with invalid index(es) (event={}, object={}); ignored",
+                event.0,
+                object.0
             );
             return false;
         }
@@ -901,9 +1006,10 @@ impl SlimLinkedOCEL {
     ) -> bool {
         if (from_obj.0 as usize) >= self.objects.len() || (to_obj.0 as usize) >= self.objects.len()
         {
-            eprintln!(
-                "[rust4pm] warning: add_o2o called with invalid index(es) (from_obj={}, to_obj={}); ignored",
-                from_obj.0, to_obj.0
+            warn_diagnostic!(
+                "add_o2o called with invalid index(es) (from_obj={}, to_obj={}); ignored",
+                from_obj.0,
+                to_obj.0
             );
             return false;
         }
@@ -926,10 +1032,12 @@ impl SlimLinkedOCEL {
     ///
     /// Returns `true` on success, `false` if either index is out of bounds (with a stderr warning).
     pub fn delete_e2o(&mut self, event: &EventIndex, object: &ObjectIndex) -> bool {
+        self.e2o_rev_time_index.take();
         if (event.0 as usize) >= self.events.len() || (object.0 as usize) >= self.objects.len() {
-            eprintln!(
-                "[rust4pm] warning: delete_e2o called with invalid index(es) (event={}, object={}); ignored",
-                event.0, object.0
+            warn_diagnostic!(
+                "delete_e2o called with invalid index(es) (event={}, object={}); ignored",
+                event.0,
+                object.0
             );
             return false;
         }
@@ -949,9 +1057,10 @@ impl SlimLinkedOCEL {
     pub fn delete_o2o(&mut self, from_obj: &ObjectIndex, to_obj: &ObjectIndex) -> bool {
         if (from_obj.0 as usize) >= self.objects.len() || (to_obj.0 as usize) >= self.objects.len()
         {
-            eprintln!(
-                "[rust4pm] warning: delete_o2o called with invalid index(es) (from_obj={}, to_obj={}); ignored",
-                from_obj.0, to_obj.0
+            warn_diagnostic!(
+                "delete_o2o called with invalid index(es) (from_obj={}, to_obj={}); ignored",
+                from_obj.0,
+                to_obj.0
             );
             return false;
         }
@@ -1072,6 +1181,7 @@ impl AppendableOCEL for SlimLinkedOCEL {
         attributes: Vec<OCELEventAttribute>,
         relationships: Vec<OCELRelationship>,
     ) -> Result<(), Self::Error> {
+        self.e2o_rev_time_index.take();
         let h_id = self.hasher.hash_one(&id);
         if self
             .event_ids_to_index
@@ -1093,8 +1203,8 @@ impl AppendableOCEL for SlimLinkedOCEL {
         for a in &attributes {
             if !attrs.iter().any(|d| d.name == a.name) {
                 if !attrs.is_empty() {
-                    eprintln!(
-                        "[rust4pm] warning: event {:?} of type {:?} has attribute {:?} not in the existing type schema; auto-growing the type",
+                    warn_diagnostic!(
+                        "event {:?} of type {:?} has attribute {:?} not in the existing type schema; auto-growing the type",
                         id, event_type, a.name
                     );
                 }
@@ -1167,6 +1277,7 @@ impl AppendableOCEL for SlimLinkedOCEL {
         attributes: Vec<OCELObjectAttribute>,
         relationships: Vec<OCELRelationship>,
     ) -> Result<(), Self::Error> {
+        self.e2o_rev_time_index.take();
         let h_id = self.hasher.hash_one(&id);
         if self
             .object_ids_to_index
@@ -1186,8 +1297,8 @@ impl AppendableOCEL for SlimLinkedOCEL {
         for a in &attributes {
             if !attrs.iter().any(|d| d.name == a.name) {
                 if !attrs.is_empty() {
-                    eprintln!(
-                        "[rust4pm] warning: object {:?} of type {:?} has attribute {:?} not in the existing type schema; auto-growing the type",
+                    warn_diagnostic!(
+                        "object {:?} of type {:?} has attribute {:?} not in the existing type schema; auto-growing the type",
                         id, object_type, a.name
                     );
                 }
@@ -1263,6 +1374,7 @@ impl AppendableOCEL for SlimLinkedOCEL {
     }
 
     fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.e2o_rev_time_index.take();
         // Resolve pending E2O / O2O forward refs and re-sort touched relationship lists.
         // The OCEL spec disallows duplicate (source, target, qualifier) triples; not
         // deduped here, invalid input flows through as-is.
@@ -1289,8 +1401,8 @@ impl AppendableOCEL for SlimLinkedOCEL {
                     ev_dirty.push(ev_idx);
                 }
                 None => {
-                    eprintln!(
-                        "[rust4pm] warning: dropping E2O reference to unknown object id {:?}",
+                    warn_diagnostic!(
+                        "dropping E2O reference to unknown object id {:?}",
                         rel.object_id
                     );
                 }
@@ -1327,8 +1439,8 @@ impl AppendableOCEL for SlimLinkedOCEL {
                     ob_dirty.push(from_idx);
                 }
                 None => {
-                    eprintln!(
-                        "[rust4pm] warning: dropping O2O reference to unknown object id {:?}",
+                    warn_diagnostic!(
+                        "dropping O2O reference to unknown object id {:?}",
                         rel.object_id
                     );
                 }
@@ -1765,6 +1877,26 @@ impl Importable for SlimLinkedOCEL {
         }
     }
 
+    fn import_from_path_with_options<P: AsRef<Path>>(
+        path: P,
+        options: Self::ImportOptions,
+    ) -> Result<Self, Self::Error> {
+        let path = path.as_ref();
+        let format = <Self as Importable>::infer_format(path).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Could not infer format from path",
+            )
+        })?;
+        if format.ends_with("db") || format.ends_with("sqlite") {
+            // Delegate to the OCEL importer, which reads database formats (e.g., DuckDB) directly from the path
+            let ocel = OCEL::import_from_path(path)?;
+            return Ok(SlimLinkedOCEL::from_ocel(ocel));
+        }
+        let file = std::fs::File::open(path)?;
+        Self::import_from_reader_with_options(std::io::BufReader::new(file), &format, options)
+    }
+
     fn infer_format(path: &Path) -> Option<String> {
         <OCEL as Importable>::infer_format(path)
     }
@@ -1896,6 +2028,23 @@ mod tests {
         DateTime::parse_from_rfc3339(s).unwrap()
     }
 
+    #[test]
+    fn object_type_counts() {
+        let mut s: SlimLinkedOCEL = SlimLinkedOCEL::new();
+        s.declare_object_type(empty_type("order")).unwrap();
+        s.declare_object_type(empty_type("item")).unwrap();
+        s.append_object("o1".into(), "order", Vec::new(), Vec::new())
+            .unwrap();
+        s.append_object("o2".into(), "order", Vec::new(), Vec::new())
+            .unwrap();
+        assert_eq!(s.num_obs_of_type("order"), 2);
+        assert_eq!(s.num_obs_of_type("unknown"), 0);
+        assert_eq!(
+            s.ob_type_counts(),
+            [("order".to_string(), 2), ("item".to_string(), 0)].into()
+        );
+    }
+
     #[test]
     fn append_resolves_forward_e2o_on_finalize() {
         let mut s: SlimLinkedOCEL = SlimLinkedOCEL::new();
@@ -2319,4 +2468,41 @@ mod tests {
         assert_eq!(ev.attributes[0], OCELAttributeValue::String("hi".into()));
         assert_eq!(ev.attributes[1], OCELAttributeValue::Integer(42));
     }
+
+    #[test]
+    fn index_slim_conversions() {
+        use crate::core::event_data::object_centric::linked_ocel::IndexLinkedOCEL;
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:2"]),
+            ("place", ["o:2"]),
+            o2o:
+            ("o:1", "i:1")
+        ];
+        let index = IndexLinkedOCEL::from_ocel(ocel);
+        let slim: SlimLinkedOCEL = (&index).into();
+        assert_eq!(slim.get_num_evs(), 3);
+        assert_eq!(slim.get_num_obs(), 4);
+        let back: IndexLinkedOCEL = slim.into();
+        for (a, b) in index.get_all_evs().zip(back.get_all_evs()) {
+            assert_eq!(index.get_ev_id(a), back.get_ev_id(b));
+            let e2o = |l: &IndexLinkedOCEL, e| {
+                let mut ids: Vec<String> = l
+                    .get_e2o(e)
+                    .map(|(_q, o)| l.get_ob_id(o).to_string())
+                    .collect();
+                ids.sort();
+                ids
+            };
+            assert_eq!(e2o(&index, a), e2o(&back, b));
+        }
+        let o1 = back.get_ob_index("o:1").unwrap();
+        assert_eq!(
+            back.get_o2o(o1)
+                .map(|(_q, o)| back.get_ob_id(o))
+                .collect::<Vec<_>>(),
+            ["i:1"]
+        );
+    }
 }
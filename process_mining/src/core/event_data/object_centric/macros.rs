@@ -118,7 +118,7 @@ macro_rules! ocel {
                 object_id_to_object.insert($from_ob.to_string(),
                     OCELObject{
                         id: $from_ob.to_string(),
-                        object_type: object_type,
+                        object_type,
                         attributes: Vec::new(),
                         relationships: vec![o2o_relation]
                     }
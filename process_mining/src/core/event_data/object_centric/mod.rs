@@ -21,6 +21,8 @@ pub mod ocel_sql;
 pub(crate) mod ocel_struct;
 pub mod ocel_xml;
 pub mod readable;
+pub mod stats;
 pub mod utils;
+pub mod validate;
 #[doc(inline)]
 pub use ocel_struct::*;
@@ -1,5 +1,6 @@
 //! CSV Import for OCEL
 
+use crate::diagnostics::warn_diagnostic;
 use std::{collections::HashMap, fmt::Display, io::Read};
 
 use chrono::{DateTime, FixedOffset};
@@ -345,7 +346,7 @@ pub fn import_ocel_csv_with_options(
                     });
                 }
                 if options.verbose {
-                    eprintln!("Warning: Skipping row {row_num} (missing timestamp)");
+                    warn_diagnostic!("Skipping row {row_num} (missing timestamp)");
                 }
                 continue;
             }
@@ -363,7 +364,7 @@ pub fn import_ocel_csv_with_options(
 
         if is_attr_only && timestamp.is_none() {
             if options.verbose {
-                eprintln!("Warning: Row {row_num} (attribute-only without timestamp). Will assume UNIX EPOCH as time.");
+                warn_diagnostic!("Row {row_num} (attribute-only without timestamp). Will assume UNIX EPOCH as time.");
             }
             timestamp = Some(DateTime::UNIX_EPOCH.into());
         }
@@ -448,7 +449,7 @@ pub fn import_ocel_csv_with_options(
                         ),
                     });
                 }
-                eprintln!("Warning: O2O source '{id}' not found at row {row_num}");
+                warn_diagnostic!("O2O source '{id}' not found at row {row_num}");
             }
             continue;
         }
@@ -1,10 +1,14 @@
 //! OCEL 2.0 JSON Format Import/Export
+pub mod schema;
+
 use std::{
     fs::File,
-    io::{BufReader, BufWriter},
+    io::{BufRead, BufReader, BufWriter, Read, Write},
     path::Path,
 };
 
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+
 use serde::{
     de::{DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor},
     Deserializer, Serialize, Serializer,
@@ -14,6 +18,7 @@ use crate::core::event_data::object_centric::{
     appendable::AppendableOCEL,
     io::OCELIOError,
     ocel_struct::{OCELEvent, OCELObject, OCELType, OCEL},
+    ocel_xml::xml_ocel_import::{OCELImportOptions, TypeFilteringAppendable},
     readable::ReadableOCEL,
 };
 
@@ -47,10 +52,37 @@ pub fn json_to_ocel(ocel_json: &str) -> OCEL {
 ///
 /// Import [`OCEL`] from a JSON file given by a filepath
 ///
+/// Gzip-compressed files (e.g., `.json.gz`) are detected by their magic bytes and decompressed transparently.
+///
 /// See also [`import_ocel_json_slice`].
 ///
 pub fn import_ocel_json_path<P: AsRef<std::path::Path>>(path: P) -> Result<OCEL, std::io::Error> {
-    let reader: BufReader<File> = BufReader::new(File::open(path)?);
+    let reader = open_maybe_gz(path)?;
+    Ok(serde_json::from_reader(reader)?)
+}
+
+/// First bytes of gzip-compressed data
+const GZIP_MAGIC_BYTES: [u8; 2] = [0x1f, 0x8b];
+
+/// Open a file for reading, transparently decompressing it if it starts with the gzip magic bytes
+fn open_maybe_gz<P: AsRef<Path>>(path: P) -> Result<Box<dyn BufRead>, std::io::Error> {
+    let mut reader = BufReader::new(File::open(path)?);
+    if reader.fill_buf()?.starts_with(&GZIP_MAGIC_BYTES) {
+        Ok(Box::new(BufReader::new(GzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+///
+/// Import [`OCEL`] from a gzip-compressed JSON file (e.g., `.json.gz`) given by a filepath
+///
+/// Note that [`import_ocel_json_path`] also detects gzip-compressed files automatically.
+///
+pub fn import_ocel_json_gz_from_path<P: AsRef<std::path::Path>>(
+    path: P,
+) -> Result<OCEL, std::io::Error> {
+    let reader = BufReader::new(GzDecoder::new(BufReader::new(File::open(path)?)));
     Ok(serde_json::from_reader(reader)?)
 }
 
@@ -63,6 +95,63 @@ pub fn import_ocel_json_slice(slice: &[u8]) -> Result<OCEL, std::io::Error> {
     Ok(serde_json::from_slice(slice)?)
 }
 
+///
+/// Import [`OCEL`] from a JSON file given by a filepath __with__ _custom options_
+///
+/// Events and objects of ignored types (see [`OCELImportOptions`]) are skipped while parsing.
+/// If [`OCELImportOptions::strict_schema`] is enabled, the input is first validated against the OCEL 2.0 JSON schema
+/// (requiring the whole file to be read into memory).
+/// Other options (e.g., the date format) are not used for JSON.
+/// As for [`import_ocel_json_path`], gzip-compressed files are detected automatically.
+///
+pub fn import_ocel_json_path_with<P: AsRef<std::path::Path>>(
+    path: P,
+    options: &OCELImportOptions,
+) -> Result<OCEL, OCELIOError> {
+    let mut reader = open_maybe_gz(path)?;
+    if options.strict_schema {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        return import_ocel_json_slice_with(&bytes, options);
+    }
+    import_ocel_json_reader_with(reader, options)
+}
+
+///
+/// Import [`OCEL`] from a JSON byte slice __with__ _custom options_
+///
+/// See [`import_ocel_json_path_with`] for details.
+///
+pub fn import_ocel_json_slice_with(
+    slice: &[u8],
+    options: &OCELImportOptions,
+) -> Result<OCEL, OCELIOError> {
+    if options.strict_schema {
+        let value: serde_json::Value = serde_json::from_slice(slice)?;
+        let errors = schema::validate_ocel_json_schema(&value);
+        if !errors.is_empty() {
+            return Err(OCELIOError::JsonSchema(errors));
+        }
+    }
+    import_ocel_json_reader_with(slice, options)
+}
+
+fn import_ocel_json_reader_with<R: std::io::Read>(
+    reader: R,
+    options: &OCELImportOptions,
+) -> Result<OCEL, OCELIOError> {
+    let mut ocel = OCEL {
+        event_types: Vec::new(),
+        object_types: Vec::new(),
+        events: Vec::new(),
+        objects: Vec::new(),
+    };
+    let mut filtered = TypeFilteringAppendable::new(&mut ocel, options);
+    import_ocel_json_into(reader, &mut filtered)?;
+    filtered.finish();
+    Ok(ocel)
+}
+
 /// Export an OCEL backend to a JSON file at the specified path.
 pub fn export_ocel_json_to_path<R, P>(ocel: &R, path: P) -> Result<(), std::io::Error>
 where
@@ -73,6 +162,17 @@ where
     Ok(write_ocel_json(ocel, writer)?)
 }
 
+/// Export an OCEL backend to a gzip-compressed JSON file (e.g., `.json.gz`) at the specified path.
+pub fn export_ocel_json_gz<R, P>(ocel: &R, path: P) -> Result<(), std::io::Error>
+where
+    R: ReadableOCEL + ?Sized,
+    P: AsRef<Path>,
+{
+    let mut encoder = GzEncoder::new(BufWriter::new(File::create(path)?), Compression::default());
+    write_ocel_json(ocel, &mut encoder)?;
+    encoder.finish()?.flush()
+}
+
 /// Export an OCEL backend to JSON in a byte array ([`Vec<u8>`]).
 pub fn export_ocel_json_to_vec<R: ReadableOCEL + ?Sized>(
     ocel: &R,
@@ -93,6 +193,66 @@ where
     Ok(write_ocel_json(ocel, writer)?)
 }
 
+/// Number of bytes after which [`export_ocel_json_streaming`] flushes the underlying writer
+pub const STREAMING_FLUSH_INTERVAL: usize = 1 << 20;
+
+///
+/// Stream an OCEL backend as JSON into the given writer, flushing it periodically.
+///
+/// Types, events, and objects are serialized element by element into a buffer, which is
+/// flushed to `writer` (including a call to [`Write::flush`]) after every [`STREAMING_FLUSH_INTERVAL`] bytes.
+/// Thus, the serialized OCEL is never held in memory completely, and data is handed over to
+/// the writer (e.g., a network socket) continuously instead of only at the end.
+///
+pub fn export_ocel_json_streaming<R, W>(ocel: &R, writer: W) -> Result<(), std::io::Error>
+where
+    R: ReadableOCEL + ?Sized,
+    W: Write,
+{
+    export_ocel_json_flushing(ocel, writer, STREAMING_FLUSH_INTERVAL)
+}
+
+fn export_ocel_json_flushing<R, W>(
+    ocel: &R,
+    writer: W,
+    flush_interval: usize,
+) -> Result<(), std::io::Error>
+where
+    R: ReadableOCEL + ?Sized,
+    W: Write,
+{
+    let mut writer = FlushingWriter {
+        inner: BufWriter::new(writer),
+        flush_interval,
+        since_flush: 0,
+    };
+    write_ocel_json(ocel, &mut writer)?;
+    writer.flush()
+}
+
+/// Buffered writer which flushes after every `flush_interval` written bytes
+struct FlushingWriter<W: Write> {
+    inner: BufWriter<W>,
+    flush_interval: usize,
+    since_flush: usize,
+}
+
+impl<W: Write> Write for FlushingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.since_flush += written;
+        if self.since_flush >= self.flush_interval {
+            self.flush()?;
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.since_flush = 0;
+        self.inner.flush()
+    }
+}
+
 /// Stream an OCEL to `writer` as JSON. Field order matches `OCEL`'s `Serialize` derive
 /// so `&OCEL` output is byte-identical.
 fn write_ocel_json<R, W>(ocel: &R, writer: W) -> Result<(), serde_json::Error>
@@ -314,6 +474,43 @@ mod tests {
         assert_eq!(exported, reference);
     }
 
+    /// Periodically flushing export produces the same bytes as the regular export.
+    #[test]
+    fn export_streaming_flushes() {
+        struct CountingWriter {
+            data: Vec<u8>,
+            flushes: usize,
+        }
+        impl Write for CountingWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.data.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                self.flushes += 1;
+                Ok(())
+            }
+        }
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:2"]),
+            o2o:
+            ("o:1", "i:1")
+        ];
+        let mut writer = CountingWriter {
+            data: Vec::new(),
+            flushes: 0,
+        };
+        export_ocel_json_flushing(&ocel, &mut writer, 64).unwrap();
+        assert_eq!(writer.data, export_ocel_json_to_vec(&ocel).unwrap());
+        assert!(writer.flushes > 2);
+
+        let mut buf = Vec::new();
+        export_ocel_json_streaming(&ocel, &mut buf).unwrap();
+        assert_eq!(buf, writer.data);
+    }
+
     /// Streaming export from `SlimLinkedOCEL` reimports as the original `OCEL`.
     #[test]
     fn export_slim_roundtrip() {
@@ -413,4 +610,74 @@ mod tests {
         let b_obs: HashMap<&str, _> = b.objects.iter().map(|o| (o.id.as_str(), o)).collect();
         assert_eq!(a_obs, b_obs);
     }
+
+    #[test]
+    fn gz_roundtrip() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            ("ship", ["o:1"]),
+            o2o:
+            ("o:1", "i:1")
+        ];
+        let dir = std::env::temp_dir().join(format!("ocel-json-gz-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ocel.json.gz");
+        export_ocel_json_gz(&ocel, &path).unwrap();
+        assert_eq!(fs::read(&path).unwrap()[..2], GZIP_MAGIC_BYTES);
+        assert_eq!(import_ocel_json_gz_from_path(&path).unwrap(), ocel);
+        // Detected automatically by the (uncompressed) path-based importers, independent of the extension
+        let renamed = dir.join("ocel.json");
+        fs::rename(&path, &renamed).unwrap();
+        assert_eq!(import_ocel_json_path(&renamed).unwrap(), ocel);
+        assert_eq!(
+            import_ocel_json_path_with(&renamed, &OCELImportOptions::default()).unwrap(),
+            ocel
+        );
+        export_ocel_json_to_path(&ocel, &renamed).unwrap();
+        assert_eq!(import_ocel_json_path(&renamed).unwrap(), ocel);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn strict_schema_reports_json_pointers() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            o2o:
+        ];
+        let options = OCELImportOptions {
+            strict_schema: true,
+            ..Default::default()
+        };
+        let mut value = serde_json::to_value(&ocel).unwrap();
+        let bytes = serde_json::to_vec(&value).unwrap();
+        assert_eq!(import_ocel_json_slice_with(&bytes, &options).unwrap(), ocel);
+
+        let root = value.as_object_mut().unwrap();
+        root.remove("objectTypes");
+        root["events"][0]["time"] = serde_json::json!("yesterday");
+        root["objects"][0]["relationships"] = serde_json::json!([{ "objectId": 1 }]);
+        let bytes = serde_json::to_vec(&value).unwrap();
+        let Err(OCELIOError::JsonSchema(errors)) = import_ocel_json_slice_with(&bytes, &options)
+        else {
+            panic!("Expected schema errors");
+        };
+        let pointers: Vec<&str> = errors.iter().map(|e| e.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            [
+                "/objectTypes",
+                "/events/0/time",
+                "/objects/0/relationships/0/objectId",
+                "/objects/0/relationships/0/qualifier",
+            ]
+        );
+        assert!(errors[0].to_string().contains("missing required property"));
+        // Without strict validation, only the first deserialization error is reported
+        assert!(matches!(
+            import_ocel_json_slice_with(&bytes, &OCELImportOptions::default()),
+            Err(OCELIOError::Json(_))
+        ));
+    }
 }
@@ -0,0 +1,178 @@
+//! Validation of JSON data against the OCEL 2.0 JSON Schema
+//!
+//! The checks mirror the structure required by the [OCEL 2.0 JSON schema](https://www.ocel-standard.org/):
+//! required keys, the types of all values, and `date-time` timestamps.
+//! In contrast to deserialization errors, all violations are collected and reported with a JSON pointer to their location.
+use chrono::DateTime;
+use serde_json::{Map, Value};
+
+///
+/// Violation of the OCEL 2.0 JSON schema
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OCELJsonSchemaError {
+    /// JSON pointer to the invalid (or missing) value (e.g., `/events/3/time`)
+    pub pointer: String,
+    /// Description of the violation
+    pub message: String,
+}
+
+impl std::fmt::Display for OCELJsonSchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pointer = if self.pointer.is_empty() {
+            "/"
+        } else {
+            &self.pointer
+        };
+        write!(f, "{pointer}: {}", self.message)
+    }
+}
+
+/// Expected JSON type of a value
+#[derive(Debug, Clone, Copy)]
+enum Expected {
+    String,
+    DateTime,
+    Array,
+    /// Attribute value (i.e., a string, number, boolean, or `null`)
+    AttributeValue,
+}
+
+/// Collects schema violations while walking the JSON value
+struct Validator {
+    errors: Vec<OCELJsonSchemaError>,
+}
+
+impl Validator {
+    fn error(&mut self, pointer: &str, message: impl Into<String>) {
+        self.errors.push(OCELJsonSchemaError {
+            pointer: pointer.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// Get the object at `pointer`, reporting an error if `value` is not an object
+    fn object<'a>(&mut self, value: &'a Value, pointer: &str) -> Option<&'a Map<String, Value>> {
+        let obj = value.as_object();
+        if obj.is_none() {
+            self.error(pointer, "expected an object");
+        }
+        obj
+    }
+
+    /// Check the type of the field `key` of `obj`, reporting an error if it is missing but `required`
+    ///
+    /// Returns the value of the field if it is present and has the expected type.
+    fn field<'a>(
+        &mut self,
+        obj: &'a Map<String, Value>,
+        pointer: &str,
+        key: &str,
+        expected: Expected,
+        required: bool,
+    ) -> Option<&'a Value> {
+        let pointer = format!("{pointer}/{key}");
+        let Some(value) = obj.get(key) else {
+            if required {
+                self.error(&pointer, format!("missing required property '{key}'"));
+            }
+            return None;
+        };
+        let valid = match expected {
+            Expected::String => value.is_string(),
+            Expected::DateTime => value
+                .as_str()
+                .is_some_and(|s| DateTime::parse_from_rfc3339(s).is_ok()),
+            Expected::Array => value.is_array(),
+            Expected::AttributeValue => !value.is_array() && !value.is_object(),
+        };
+        if !valid {
+            let message = match expected {
+                Expected::String => "expected a string",
+                Expected::DateTime => "expected a date-time string (RFC 3339)",
+                Expected::Array => "expected an array",
+                Expected::AttributeValue => "expected a string, number, boolean, or null",
+            };
+            self.error(&pointer, message);
+            return None;
+        }
+        Some(value)
+    }
+
+    /// Validate all items of the (optional) array field `key` of `obj` using `validate_item`
+    fn array_items(
+        &mut self,
+        obj: &Map<String, Value>,
+        pointer: &str,
+        key: &str,
+        required: bool,
+        validate_item: impl Fn(&mut Self, &Map<String, Value>, &str),
+    ) {
+        let Some(items) = self
+            .field(obj, pointer, key, Expected::Array, required)
+            .and_then(Value::as_array)
+        else {
+            return;
+        };
+        for (i, item) in items.iter().enumerate() {
+            let item_pointer = format!("{pointer}/{key}/{i}");
+            if let Some(item) = self.object(item, &item_pointer) {
+                validate_item(self, item, &item_pointer);
+            }
+        }
+    }
+
+    fn validate_type(&mut self, ty: &Map<String, Value>, pointer: &str) {
+        self.field(ty, pointer, "name", Expected::String, true);
+        self.array_items(ty, pointer, "attributes", true, |v, attr, pointer| {
+            v.field(attr, pointer, "name", Expected::String, true);
+            v.field(attr, pointer, "type", Expected::String, true);
+        });
+    }
+
+    fn validate_relationships(&mut self, obj: &Map<String, Value>, pointer: &str) {
+        self.array_items(obj, pointer, "relationships", false, |v, rel, pointer| {
+            v.field(rel, pointer, "objectId", Expected::String, true);
+            v.field(rel, pointer, "qualifier", Expected::String, true);
+        });
+    }
+
+    fn validate_event(&mut self, ev: &Map<String, Value>, pointer: &str) {
+        self.field(ev, pointer, "id", Expected::String, true);
+        self.field(ev, pointer, "type", Expected::String, true);
+        self.field(ev, pointer, "time", Expected::DateTime, true);
+        self.array_items(ev, pointer, "attributes", false, |v, attr, pointer| {
+            v.field(attr, pointer, "name", Expected::String, true);
+            v.field(attr, pointer, "value", Expected::AttributeValue, true);
+        });
+        self.validate_relationships(ev, pointer);
+    }
+
+    fn validate_object(&mut self, ob: &Map<String, Value>, pointer: &str) {
+        self.field(ob, pointer, "id", Expected::String, true);
+        self.field(ob, pointer, "type", Expected::String, true);
+        self.array_items(ob, pointer, "attributes", false, |v, attr, pointer| {
+            v.field(attr, pointer, "name", Expected::String, true);
+            v.field(attr, pointer, "value", Expected::AttributeValue, true);
+            v.field(attr, pointer, "time", Expected::DateTime, true);
+        });
+        self.validate_relationships(ob, pointer);
+    }
+}
+
+///
+/// Validate a JSON value against the OCEL 2.0 JSON schema
+///
+/// Returns all schema violations (empty if the value is valid), grouped by the top-level section
+/// (event types, object types, events, objects) they occur in.
+///
+pub fn validate_ocel_json_schema(value: &Value) -> Vec<OCELJsonSchemaError> {
+    let mut validator = Validator { errors: Vec::new() };
+    if let Some(root) = validator.object(value, "") {
+        validator.array_items(root, "", "eventTypes", true, Validator::validate_type);
+        validator.array_items(root, "", "objectTypes", true, Validator::validate_type);
+        validator.array_items(root, "", "events", true, Validator::validate_event);
+        validator.array_items(root, "", "objects", true, Validator::validate_object);
+    }
+    validator.errors
+}
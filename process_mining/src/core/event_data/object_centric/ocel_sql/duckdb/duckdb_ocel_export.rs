@@ -1,6 +1,8 @@
 use crate::core::event_data::object_centric::{ocel_struct::OCEL, readable::ReadableOCEL};
 
-use super::super::export::export_ocel_to_sql_con;
+use super::super::export::{
+    export_ocel_to_sql_con, export_ocel_to_sql_con_with, OCELSqlExportOptions,
+};
 use super::super::*;
 use ::duckdb::Connection;
 use macros_process_mining::register_binding;
@@ -22,6 +24,27 @@ where
     export_ocel_to_sql_con(&con, ocel)
 }
 
+///
+/// Export an OCEL to a `DuckDB` file at the specified path, only including the event/object types selected in the [`OCELSqlExportOptions`]
+///
+/// Note: This function is only available if the `ocel-duckdb` feature is enabled.
+///
+pub fn export_ocel_duckdb_to_path_with<P, O>(
+    ocel: &O,
+    path: P,
+    options: &OCELSqlExportOptions,
+) -> Result<(), DatabaseError>
+where
+    P: AsRef<std::path::Path>,
+    O: ReadableOCEL + ?Sized,
+{
+    if path.as_ref().exists() {
+        let _ = std::fs::remove_file(&path);
+    }
+    let con = Connection::open(path)?;
+    export_ocel_to_sql_con_with(&con, ocel, options)
+}
+
 #[register_binding(name = "export_ocel_duckdb_to_path", stringify_error)]
 fn export_ocel_duckdb_to_path_binding(
     ocel: &OCEL,
@@ -1,9 +1,13 @@
+use crate::diagnostics::warn_diagnostic;
 use std::collections::HashMap;
 
 use crate::core::event_data::{
-    object_centric::ocel_struct::{
-        OCELAttributeValue, OCELEvent, OCELEventAttribute, OCELObject, OCELObjectAttribute,
-        OCELRelationship, OCELTypeAttribute, OCEL,
+    object_centric::{
+        ocel_struct::{
+            OCELAttributeValue, OCELEvent, OCELEventAttribute, OCELObject, OCELObjectAttribute,
+            OCELRelationship, OCELTypeAttribute, OCEL,
+        },
+        ocel_xml::xml_ocel_import::OCELImportOptions,
     },
     timestamp_utils::parse_timestamp,
 };
@@ -270,9 +274,7 @@ pub fn import_ocel_duckdb_from_con(con: Connection) -> Result<OCEL, ::duckdb::Er
                 qualifier,
             });
         } else {
-            eprintln!(
-                "Warning: E2O relationship not added as event with ID {ev_id} was not found."
-            );
+            warn_diagnostic!("E2O relationship not added as event with ID {ev_id} was not found.");
         }
     });
 
@@ -280,23 +282,25 @@ pub fn import_ocel_duckdb_from_con(con: Connection) -> Result<OCEL, ::duckdb::Er
     let mut s = con.prepare("SELECT * FROM object_object".to_string().as_str())?;
     let evs = query_all::<_>(&mut s, [])?;
     evs.and_then(|x| {
-            Ok::<(String, String, String), ::duckdb::Error>((
-                x.get(OCEL_O2O_SOURCE_ID_COLUMN)?,
-                x.get(OCEL_O2O_TARGET_ID_COLUMN)?,
-                x.get(OCEL_REL_QUALIFIER_COLUMN)?,
-            ))
-        })
-        .flatten()
-        .for_each(|(source_ob_id, target_ob_id, qualifier)| {
-            if let Some(ev) = object_map.get_mut(&source_ob_id) {
-                ev.relationships.push(OCELRelationship {
-                    object_id: target_ob_id,
-                    qualifier,
-                });
-            }else{
-                eprintln!("Warning: O2O relationship not added as object with ID {source_ob_id} was not found.");
-            }
-        });
+        Ok::<(String, String, String), ::duckdb::Error>((
+            x.get(OCEL_O2O_SOURCE_ID_COLUMN)?,
+            x.get(OCEL_O2O_TARGET_ID_COLUMN)?,
+            x.get(OCEL_REL_QUALIFIER_COLUMN)?,
+        ))
+    })
+    .flatten()
+    .for_each(|(source_ob_id, target_ob_id, qualifier)| {
+        if let Some(ev) = object_map.get_mut(&source_ob_id) {
+            ev.relationships.push(OCELRelationship {
+                object_id: target_ob_id,
+                qualifier,
+            });
+        } else {
+            warn_diagnostic!(
+                "O2O relationship not added as object with ID {source_ob_id} was not found."
+            );
+        }
+    });
 
     ocel.objects = object_map.into_values().collect();
     ocel.events = event_map.into_values().collect();
@@ -318,3 +322,19 @@ pub fn import_ocel_duckdb_from_path<P: AsRef<std::path::Path>>(
     let con = Connection::open(path)?;
     import_ocel_duckdb_from_con(con)
 }
+
+///
+/// Import an [`OCEL`] `DuckDB` file from the given path __with__ _custom options_
+///
+/// Events and objects of ignored types (see [`OCELImportOptions`]) are removed after reading the database.
+/// Other options (e.g., the date format) are not used for `DuckDB`.
+///
+/// Note: This function is only available if the `ocel-duckdb` feature is enabled.
+pub fn import_ocel_duckdb_from_path_with<P: AsRef<std::path::Path>>(
+    path: P,
+    options: &OCELImportOptions,
+) -> Result<OCEL, ::duckdb::Error> {
+    let mut ocel = import_ocel_duckdb_from_path(path)?;
+    options.apply_type_filters(&mut ocel);
+    Ok(ocel)
+}
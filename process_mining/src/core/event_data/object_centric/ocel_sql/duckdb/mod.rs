@@ -9,11 +9,15 @@ mod duckdb_tests {
 
     use crate::{
         core::event_data::object_centric::{
+            ocel_json::import_ocel_json_path,
             ocel_sql::{export_ocel_duckdb_to_path, import_ocel_duckdb_from_path},
-            ocel_struct::{OCELAttributeValue, OCELRelationship},
+            ocel_struct::{
+                OCELAttributeType, OCELAttributeValue, OCELEvent, OCELEventAttribute, OCELObject,
+                OCELObjectAttribute, OCELRelationship, OCELType, OCELTypeAttribute, OCEL,
+            },
             ocel_xml::xml_ocel_import::import_ocel_xml_path,
         },
-        test_utils::get_test_data_path,
+        test_utils::{get_test_data_path, sort_ocel_for_equality_compare},
     };
 
     #[test]
@@ -100,6 +104,96 @@ mod duckdb_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_duckdb_ocel_round_trip_order() {
+        let path = get_test_data_path();
+        let ocel = import_ocel_json_path(path.join("ocel").join("order-management.json")).unwrap();
+        let export_path = path.join("export").join("roundtrip-duckdb-export.duckdb");
+        export_ocel_duckdb_to_path(&ocel, &export_path).unwrap();
+        let ocel2 = import_ocel_duckdb_from_path(export_path).unwrap();
+        assert_eq!(ocel.event_types.len(), ocel2.event_types.len());
+        assert_eq!(ocel.object_types.len(), ocel2.object_types.len());
+
+        assert_eq!(ocel.objects.len(), ocel2.objects.len());
+        assert_eq!(ocel.events.len(), ocel2.events.len());
+    }
+
+    #[test]
+    fn test_duckdb_ocel_round_trip_attribute_values() {
+        let t1 = DateTime::parse_from_rfc3339("2024-03-01T10:15:30.123+00:00").unwrap();
+        let t2 = DateTime::parse_from_rfc3339("2024-03-02T08:00:00+00:00").unwrap();
+        let mut ocel = OCEL {
+            event_types: vec![OCELType {
+                name: "place order".to_string(),
+                attributes: vec![
+                    OCELTypeAttribute::new("total", &OCELAttributeType::Float),
+                    OCELTypeAttribute::new("quantity", &OCELAttributeType::Integer),
+                    OCELTypeAttribute::new("express", &OCELAttributeType::Boolean),
+                    OCELTypeAttribute::new("due", &OCELAttributeType::Time),
+                    OCELTypeAttribute::new("note", &OCELAttributeType::String),
+                ],
+            }],
+            object_types: vec![OCELType {
+                name: "order".to_string(),
+                attributes: vec![
+                    OCELTypeAttribute::new("price", &OCELAttributeType::Float),
+                    OCELTypeAttribute::new("items", &OCELAttributeType::Integer),
+                    OCELTypeAttribute::new("paid", &OCELAttributeType::Boolean),
+                    // Never set on any object
+                    OCELTypeAttribute::new("discount", &OCELAttributeType::Float),
+                ],
+            }],
+            events: vec![OCELEvent::new(
+                "e1",
+                "place order",
+                t1,
+                vec![
+                    OCELEventAttribute {
+                        name: "total".to_string(),
+                        value: 3.0.into(),
+                    },
+                    OCELEventAttribute {
+                        name: "quantity".to_string(),
+                        value: 5_000_000_000_i64.into(),
+                    },
+                    OCELEventAttribute {
+                        name: "express".to_string(),
+                        value: true.into(),
+                    },
+                    OCELEventAttribute {
+                        name: "due".to_string(),
+                        value: t2.into(),
+                    },
+                    OCELEventAttribute {
+                        name: "note".to_string(),
+                        value: "customer's 'special' request".into(),
+                    },
+                ],
+                vec![OCELRelationship::new("o1", "order")],
+            )],
+            objects: vec![OCELObject {
+                id: "o1".to_string(),
+                object_type: "order".to_string(),
+                attributes: vec![
+                    OCELObjectAttribute::new("price", 0.1 + 0.2, DateTime::UNIX_EPOCH),
+                    OCELObjectAttribute::new("items", 3_i64, DateTime::UNIX_EPOCH),
+                    OCELObjectAttribute::new("paid", false, DateTime::UNIX_EPOCH),
+                    OCELObjectAttribute::new("items", 4_i64, t2),
+                ],
+                relationships: vec![],
+            }],
+        };
+        let export_path = get_test_data_path()
+            .join("export")
+            .join("roundtrip-duckdb-attribute-values.duckdb");
+        export_ocel_duckdb_to_path(&ocel, &export_path).unwrap();
+        let mut ocel2 = import_ocel_duckdb_from_path(export_path).unwrap();
+
+        sort_ocel_for_equality_compare(&mut ocel);
+        sort_ocel_for_equality_compare(&mut ocel2);
+        assert_eq!(ocel, ocel2);
+    }
+
     #[test]
     fn test_duckdb_containers_round_trip_ocel() -> Result<(), ::duckdb::Error> {
         let path = get_test_data_path()
@@ -1,17 +1,145 @@
-use crate::core::event_data::object_centric::readable::ReadableOCEL;
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::event_data::object_centric::{
+    ocel_struct::{OCELEvent, OCELObject},
+    readable::ReadableOCEL,
+};
 
 use super::*;
+
+///
+/// Options for exporting an OCEL to an SQL database
+///
+/// By default, all event and object types are exported.
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OCELSqlExportOptions {
+    /// If set, only events of these types (and their attribute tables) are exported
+    #[serde(default)]
+    pub event_types: Option<HashSet<String>>,
+    /// If set, only objects of these types (and their attribute tables) are exported
+    ///
+    /// E2O and O2O relationships referring to objects of other types are dropped.
+    #[serde(default)]
+    pub object_types: Option<HashSet<String>>,
+}
+
+impl OCELSqlExportOptions {
+    fn keep_event_type(&self, event_type: &str) -> bool {
+        self.event_types
+            .as_ref()
+            .is_none_or(|types| types.contains(event_type))
+    }
+
+    fn keep_object_type(&self, object_type: &str) -> bool {
+        self.object_types
+            .as_ref()
+            .is_none_or(|types| types.contains(object_type))
+    }
+}
+
+/// Remove E2O relationships to objects which are not exported (only cloning the event if needed)
+fn retain_e2o<'b>(
+    event: Cow<'b, OCELEvent>,
+    kept_objects: Option<&HashSet<String>>,
+) -> Cow<'b, OCELEvent> {
+    match kept_objects {
+        Some(kept)
+            if event
+                .relationships
+                .iter()
+                .any(|r| !kept.contains(&r.object_id)) =>
+        {
+            let mut event = event.into_owned();
+            event.relationships.retain(|r| kept.contains(&r.object_id));
+            Cow::Owned(event)
+        }
+        _ => event,
+    }
+}
+
+/// Remove O2O relationships to objects which are not exported (only cloning the object if needed)
+fn retain_o2o<'b>(
+    object: Cow<'b, OCELObject>,
+    kept_objects: Option<&HashSet<String>>,
+) -> Cow<'b, OCELObject> {
+    match kept_objects {
+        Some(kept)
+            if object
+                .relationships
+                .iter()
+                .any(|r| !kept.contains(&r.object_id)) =>
+        {
+            let mut object = object.into_owned();
+            object.relationships.retain(|r| kept.contains(&r.object_id));
+            Cow::Owned(object)
+        }
+        _ => object,
+    }
+}
+
 /// Export an OCEL log to an SQL Database connection.
 ///
 /// Accepts any [`ReadableOCEL`] backend.
 ///
 /// Available with the `ocel-sqlite` or `ocel-duckdb` feature.
 pub fn export_ocel_to_sql_con<'a, DC, O>(con: DC, ocel: &O) -> Result<(), DatabaseError>
+where
+    DC: Into<DatabaseConnection<'a>>,
+    O: ReadableOCEL + ?Sized,
+{
+    export_ocel_to_sql_con_with(con, ocel, &OCELSqlExportOptions::default())
+}
+
+/// Export an OCEL log to an SQL Database connection, only including the event/object types selected in the
+/// passed [`OCELSqlExportOptions`].
+///
+/// Relationships (E2O and O2O) referring to objects which are not exported are omitted,
+/// so that the resulting database stays referentially consistent.
+///
+/// Available with the `ocel-sqlite` or `ocel-duckdb` feature.
+pub fn export_ocel_to_sql_con_with<'a, DC, O>(
+    con: DC,
+    ocel: &O,
+    options: &OCELSqlExportOptions,
+) -> Result<(), DatabaseError>
 where
     DC: Into<DatabaseConnection<'a>>,
     O: ReadableOCEL + ?Sized,
 {
     let con = con.into();
+    let event_types: Vec<_> = ocel
+        .event_types()
+        .iter()
+        .filter(|et| options.keep_event_type(&et.name))
+        .collect();
+    let object_types: Vec<_> = ocel
+        .object_types()
+        .iter()
+        .filter(|ot| options.keep_object_type(&ot.name))
+        .collect();
+    // IDs of exported objects (only needed if some object types are excluded)
+    let kept_object_ids: Option<HashSet<String>> = options.object_types.as_ref().map(|_| {
+        object_types
+            .iter()
+            .flat_map(|ot| ocel.iter_objects_of_type(&ot.name))
+            .map(|o| o.id.clone())
+            .collect()
+    });
+    let kept_object_ids = kept_object_ids.as_ref();
+    let objects = || {
+        ocel.iter_objects()
+            .filter(|o| options.keep_object_type(&o.object_type))
+            .map(move |o| retain_o2o(o, kept_object_ids))
+    };
+    let events = || {
+        ocel.iter_events()
+            .filter(|e| options.keep_event_type(&e.event_type))
+            .map(move |e| retain_e2o(e, kept_object_ids))
+    };
     con.execute_no_params("BEGIN TRANSACTION")?;
     // event map type
     con.execute_no_params(&format!(r#"CREATE TABLE IF NOT EXISTS "event_map_type" ("{OCEL_TYPE_COLUMN}" TEXT, "{OCEL_TYPE_MAP_COLUMN}"	TEXT, PRIMARY KEY("{OCEL_TYPE_COLUMN}"))"#))?;
@@ -47,7 +175,7 @@ where
     con.execute_no_params("COMMIT")?;
     con.execute_no_params("BEGIN TRANSACTION")?;
     // Tables for event types
-    for et in ocel.event_types() {
+    for et in &event_types {
         let mut attr_cols = et
             .attributes
             .iter()
@@ -72,7 +200,7 @@ where
     }
 
     // Tables for object types
-    for ot in ocel.object_types() {
+    for ot in &object_types {
         let mut attr_cols = ot
             .attributes
             .iter()
@@ -96,9 +224,9 @@ where
         )?;
     }
 
-    con.add_objects("object", ocel.iter_objects())?;
+    con.add_objects("object", objects())?;
 
-    for ot in ocel.object_types() {
+    for ot in &object_types {
         con.add_object_changes_for_type(
             &clean_sql_name(&format!("object_{}", ot.name)),
             ot,
@@ -108,13 +236,13 @@ where
 
     con.execute_no_params("COMMIT")?;
     con.execute_no_params("BEGIN TRANSACTION")?;
-    con.add_o2o_relationships("object_object", ocel.iter_objects())?;
+    con.add_o2o_relationships("object_object", objects())?;
     con.execute_no_params("COMMIT")?;
     con.execute_no_params("BEGIN TRANSACTION")?;
 
-    con.add_events("event", ocel.iter_events())?;
+    con.add_events("event", events())?;
 
-    for et in ocel.event_types() {
+    for et in &event_types {
         con.add_event_attributes_for_type(
             &clean_sql_name(&format!("event_{}", et.name)),
             et,
@@ -123,19 +251,19 @@ where
     }
     con.execute_no_params("COMMIT")?;
     con.execute_no_params("BEGIN TRANSACTION")?;
-    con.add_e2o_relationships("event_object", ocel.iter_events())?;
+    con.add_e2o_relationships("event_object", events())?;
 
     con.execute_no_params("COMMIT")?;
     con.execute_no_params("BEGIN TRANSACTION")?;
 
-    for ot in ocel.object_types() {
+    for ot in &object_types {
         con.execute_no_params(&format!(
             r#"CREATE INDEX IF NOT EXISTS "{}_obid" ON "object_{}" ("{OCEL_ID_COLUMN}" ASC)"#,
             clean_sql_name(&ot.name),
             clean_sql_name(&ot.name)
         ))?;
     }
-    for et in ocel.event_types() {
+    for et in &event_types {
         con.execute_no_params(&format!(
             r#"CREATE INDEX IF NOT EXISTS "{}_evid" ON "event_{}" ("{OCEL_ID_COLUMN}" ASC)"#,
             clean_sql_name(&et.name),
@@ -22,26 +22,37 @@ pub(crate) const OCEL_REL_QUALIFIER_COLUMN: &str = "ocel_qualifier";
 #[cfg(feature = "ocel-duckdb")]
 pub(crate) mod duckdb;
 pub(crate) mod export;
+pub use export::OCELSqlExportOptions;
 #[cfg(feature = "ocel-sqlite")]
 pub(crate) mod sqlite;
 
 #[cfg(feature = "ocel-duckdb")]
 pub use duckdb::duckdb_ocel_export::export_ocel_duckdb_to_path;
 #[cfg(feature = "ocel-duckdb")]
+pub use duckdb::duckdb_ocel_export::export_ocel_duckdb_to_path_with;
+#[cfg(feature = "ocel-duckdb")]
 pub use duckdb::duckdb_ocel_import::import_ocel_duckdb_from_con;
 #[cfg(feature = "ocel-duckdb")]
 pub use duckdb::duckdb_ocel_import::import_ocel_duckdb_from_path;
+#[cfg(feature = "ocel-duckdb")]
+pub use duckdb::duckdb_ocel_import::import_ocel_duckdb_from_path_with;
 
 #[cfg(feature = "ocel-sqlite")]
 pub use sqlite::sqlite_ocel_export::export_ocel_sqlite_to_path;
 #[cfg(feature = "ocel-sqlite")]
+pub use sqlite::sqlite_ocel_export::export_ocel_sqlite_to_path_with;
+#[cfg(feature = "ocel-sqlite")]
 pub use sqlite::sqlite_ocel_export::export_ocel_sqlite_to_vec;
+#[cfg(feature = "ocel-sqlite")]
+pub use sqlite::sqlite_ocel_export::export_ocel_sqlite_to_vec_with;
 
 #[cfg(feature = "ocel-sqlite")]
 pub use sqlite::sqlite_ocel_import::import_ocel_sqlite_from_con;
 #[cfg(feature = "ocel-sqlite")]
 pub use sqlite::sqlite_ocel_import::import_ocel_sqlite_from_path;
 #[cfg(feature = "ocel-sqlite")]
+pub use sqlite::sqlite_ocel_import::import_ocel_sqlite_from_path_with;
+#[cfg(feature = "ocel-sqlite")]
 pub use sqlite::sqlite_ocel_import::import_ocel_sqlite_from_slice;
 
 use crate::core::event_data::object_centric::ocel_struct::OCELAttributeType;
@@ -55,7 +66,10 @@ pub(crate) fn sql_type_to_ocel(s: &str) -> OCELAttributeType {
         "FLOAT" => OCELAttributeType::Float,
         // SQL type written for floats (see `ocel_type_to_sql`); DuckDB PRAGMA reports it as "DOUBLE"
         "DOUBLE" | "DOUBLE PRECISION" => OCELAttributeType::Float,
-        "INTEGER" => OCELAttributeType::Integer,
+        // SQL type written for integers (see `ocel_type_to_sql`) and other common integer types
+        "INTEGER" | "BIGINT" | "INT" | "INT8" | "INT64" | "HUGEINT" | "SMALLINT" => {
+            OCELAttributeType::Integer
+        }
         "BOOLEAN" => OCELAttributeType::Boolean,
         "TIMESTAMP" => OCELAttributeType::Time,
         _ => OCELAttributeType::String,
@@ -67,7 +81,8 @@ pub(crate) fn ocel_type_to_sql(attr: &OCELAttributeType) -> &'static str {
         OCELAttributeType::String => "TEXT",
         // DOUBLE PRECISION instead of REAL for full f64 float support in both DuckDB and SQLite
         OCELAttributeType::Float => "DOUBLE PRECISION",
-        OCELAttributeType::Integer => "INTEGER",
+        // BIGINT instead of INTEGER, as INTEGER only has 32 bits in DuckDB
+        OCELAttributeType::Integer => "BIGINT",
         OCELAttributeType::Boolean => "BOOLEAN",
         OCELAttributeType::Time => "TIMESTAMP",
         _ => "TEXT",
@@ -363,6 +378,32 @@ impl<'a> DatabaseConnection<'a> {
     }
 }
 
+#[cfg(feature = "ocel-sqlite")]
+impl rusqlite::ToSql for super::ocel_struct::OCELAttributeValue {
+    /// Bind attribute values with their native SQL type (instead of as a quoted string literal),
+    /// so that they can be read back as the same [`OCELAttributeType`].
+    ///
+    /// Times are stored as RFC 3339 (ISO-8601) strings, which preserves the original offset.
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        use super::ocel_struct::OCELAttributeValue;
+        use rusqlite::types::{ToSqlOutput, Value, ValueRef};
+        Ok(match self {
+            OCELAttributeValue::Integer(i) => ToSqlOutput::Owned(Value::Integer(*i)),
+            OCELAttributeValue::Float(f) => ToSqlOutput::Owned(Value::Real(*f)),
+            OCELAttributeValue::Boolean(b) => ToSqlOutput::Owned(Value::Integer(*b as i64)),
+            OCELAttributeValue::Time(dt) => ToSqlOutput::Owned(Value::Text(dt.to_rfc3339())),
+            OCELAttributeValue::String(s) => ToSqlOutput::Borrowed(ValueRef::Text(s.as_bytes())),
+            OCELAttributeValue::Null => ToSqlOutput::Owned(Value::Null),
+        })
+    }
+}
+
+/// Comma-prefixed list of `n` SQL parameter placeholders (e.g., `", ?, ?"`), or an empty string for `n = 0`
+#[cfg(feature = "ocel-sqlite")]
+fn sql_placeholders(n: usize) -> String {
+    ", ?".repeat(n)
+}
+
 #[cfg(feature = "ocel-sqlite")]
 fn write_object_changes_sqlite(
     connection: &rusqlite::Connection,
@@ -370,6 +411,7 @@ fn write_object_changes_sqlite(
     object_type: &OCELType,
     o: &super::ocel_struct::OCELObject,
 ) -> Result<(), DatabaseError> {
+    use rusqlite::ToSql;
     let initial_vals: Vec<_> = object_type
         .attributes
         .iter()
@@ -377,17 +419,20 @@ fn write_object_changes_sqlite(
             o.attributes
                 .iter()
                 .find(|oa| oa.name == a.name && oa.time == DateTime::UNIX_EPOCH)
-                .map(|v| format!("'{}'", v.value))
-                .unwrap_or_else(|| "NULL".to_string())
+                .map(|v| &v.value)
         })
         .collect();
-    let mut attr_vals = initial_vals.join(", ");
-    if !attr_vals.is_empty() {
-        attr_vals.insert_str(0, ", ");
-    }
+    let unix_time = DateTime::UNIX_EPOCH.to_rfc3339();
+    let params: Vec<&dyn ToSql> = [&o.id as &dyn ToSql, &unix_time]
+        .into_iter()
+        .chain(initial_vals.iter().map(|v| v as &dyn ToSql))
+        .collect();
     connection.execute(
-        &format!(r#"INSERT INTO "{table_name}" VALUES (?,?,NULL{attr_vals})"#),
-        [&o.id, &DateTime::UNIX_EPOCH.to_rfc3339()],
+        &format!(
+            r#"INSERT INTO "{table_name}" VALUES (?,?,NULL{})"#,
+            sql_placeholders(initial_vals.len())
+        ),
+        rusqlite::params_from_iter(params),
     )?;
 
     for a in o
@@ -398,24 +443,19 @@ fn write_object_changes_sqlite(
         let vals: Vec<_> = object_type
             .attributes
             .iter()
-            .map(|ot_attr| {
-                if a.name == ot_attr.name {
-                    format!("'{}'", a.value)
-                } else {
-                    "NULL".to_string()
-                }
-            })
+            .map(|ot_attr| (a.name == ot_attr.name).then_some(&a.value))
+            .collect();
+        let time = a.time.to_rfc3339();
+        let params: Vec<&dyn ToSql> = [&o.id as &dyn ToSql, &time, &a.name]
+            .into_iter()
+            .chain(vals.iter().map(|v| v as &dyn ToSql))
             .collect();
-        let mut attr_vals = vals.join(", ");
-        if !attr_vals.is_empty() {
-            attr_vals.insert_str(0, ", ");
-        }
         connection.execute(
             &format!(
-                r#"INSERT INTO "{table_name}" VALUES (?,?,'{}'{attr_vals})"#,
-                a.name
+                r#"INSERT INTO "{table_name}" VALUES (?,?,?{})"#,
+                sql_placeholders(vals.len())
             ),
-            [&o.id, &a.time.to_rfc3339()],
+            rusqlite::params_from_iter(params),
         )?;
     }
     Ok(())
@@ -487,6 +527,7 @@ fn write_event_attrs_sqlite(
     event_type: &OCELType,
     e: &super::ocel_struct::OCELEvent,
 ) -> Result<(), DatabaseError> {
+    use rusqlite::ToSql;
     let vals: Vec<_> = event_type
         .attributes
         .iter()
@@ -494,17 +535,20 @@ fn write_event_attrs_sqlite(
             e.attributes
                 .iter()
                 .find(|ea| ea.name == a.name)
-                .map(|v| format!("'{}'", v.value))
-                .unwrap_or_else(|| "NULL".to_string())
+                .map(|v| &v.value)
         })
         .collect();
-    let mut attr_vals = vals.join(", ");
-    if !attr_vals.is_empty() {
-        attr_vals.insert_str(0, ", ");
-    }
+    let time = e.time.to_rfc3339();
+    let params: Vec<&dyn ToSql> = [&e.id as &dyn ToSql, &time]
+        .into_iter()
+        .chain(vals.iter().map(|v| v as &dyn ToSql))
+        .collect();
     connection.execute(
-        &format!(r#"INSERT INTO "{table_name}" VALUES (?,?{attr_vals})"#),
-        [&e.id, &e.time.to_rfc3339()],
+        &format!(
+            r#"INSERT INTO "{table_name}" VALUES (?,?{})"#,
+            sql_placeholders(vals.len())
+        ),
+        rusqlite::params_from_iter(params),
     )?;
     Ok(())
 }
@@ -564,4 +608,84 @@ mod test {
         assert_eq!(ocel.objects.len(), ocel2.objects.len());
         assert_eq!(ocel.events.len(), ocel2.events.len());
     }
+
+    #[test]
+    fn test_sqlite_ocel_round_trip_attribute_values() {
+        use chrono::DateTime;
+
+        use crate::core::event_data::object_centric::ocel_struct::{
+            OCELAttributeType, OCELEvent, OCELEventAttribute, OCELObject, OCELObjectAttribute,
+            OCELRelationship, OCELType, OCELTypeAttribute, OCEL,
+        };
+
+        let t1 = DateTime::parse_from_rfc3339("2024-03-01T10:15:30.123+02:00").unwrap();
+        let t2 = DateTime::parse_from_rfc3339("2024-03-02T08:00:00-05:00").unwrap();
+        let mut ocel = OCEL {
+            event_types: vec![OCELType {
+                name: "place order".to_string(),
+                attributes: vec![
+                    OCELTypeAttribute::new("total", &OCELAttributeType::Float),
+                    OCELTypeAttribute::new("express", &OCELAttributeType::Boolean),
+                    OCELTypeAttribute::new("due", &OCELAttributeType::Time),
+                    OCELTypeAttribute::new("note", &OCELAttributeType::String),
+                ],
+            }],
+            object_types: vec![OCELType {
+                name: "order".to_string(),
+                attributes: vec![
+                    OCELTypeAttribute::new("price", &OCELAttributeType::Float),
+                    OCELTypeAttribute::new("paid", &OCELAttributeType::Boolean),
+                    OCELTypeAttribute::new("items", &OCELAttributeType::Integer),
+                ],
+            }],
+            events: vec![OCELEvent::new(
+                "e1",
+                "place order",
+                t1,
+                vec![
+                    OCELEventAttribute {
+                        name: "total".to_string(),
+                        value: 1909.04.into(),
+                    },
+                    OCELEventAttribute {
+                        name: "express".to_string(),
+                        value: true.into(),
+                    },
+                    OCELEventAttribute {
+                        name: "due".to_string(),
+                        value: t2.into(),
+                    },
+                    OCELEventAttribute {
+                        name: "note".to_string(),
+                        value: "customer's 'special' request".into(),
+                    },
+                ],
+                vec![OCELRelationship::new("o1", "order")],
+            )],
+            objects: vec![OCELObject {
+                id: "o1".to_string(),
+                object_type: "order".to_string(),
+                attributes: vec![
+                    OCELObjectAttribute::new("price", 0.1 + 0.2, DateTime::UNIX_EPOCH),
+                    OCELObjectAttribute::new("paid", false, DateTime::UNIX_EPOCH),
+                    OCELObjectAttribute::new("items", 3_i64, DateTime::UNIX_EPOCH),
+                    OCELObjectAttribute::new("paid", true, t2),
+                ],
+                relationships: vec![],
+            }],
+        };
+        let export_path = test_utils::get_test_data_path()
+            .join("export")
+            .join("roundtrip-sqlite-attribute-values.sqlite");
+        let _ = remove_file(&export_path);
+        let conn = rusqlite::Connection::open(&export_path).unwrap();
+        export_ocel_to_sql_con(&conn, &ocel).unwrap();
+        let mut ocel2 = import_ocel_sqlite_from_path(export_path).unwrap();
+
+        test_utils::sort_ocel_for_equality_compare(&mut ocel);
+        test_utils::sort_ocel_for_equality_compare(&mut ocel2);
+        assert_eq!(ocel, ocel2);
+        // `PartialEq` on `DateTime` ignores the offset, so also check it explicitly
+        assert_eq!(ocel2.events[0].time.offset(), t1.offset());
+    }
 }
@@ -1,6 +1,8 @@
 use crate::core::event_data::object_centric::readable::ReadableOCEL;
 
-use super::super::export::export_ocel_to_sql_con;
+use super::super::export::{
+    export_ocel_to_sql_con, export_ocel_to_sql_con_with, OCELSqlExportOptions,
+};
 use super::super::*;
 use rusqlite::Connection;
 
@@ -21,6 +23,27 @@ where
     export_ocel_to_sql_con(&con, ocel)
 }
 
+///
+/// Export an OCEL to an `SQLite` file at the specified path, only including the event/object types selected in the [`OCELSqlExportOptions`]
+///
+/// Note: This function is only available if the `ocel-sqlite` feature is enabled.
+///
+pub fn export_ocel_sqlite_to_path_with<P, O>(
+    ocel: &O,
+    path: P,
+    options: &OCELSqlExportOptions,
+) -> Result<(), DatabaseError>
+where
+    P: AsRef<std::path::Path>,
+    O: ReadableOCEL + ?Sized,
+{
+    if path.as_ref().exists() {
+        let _ = std::fs::remove_file(&path);
+    }
+    let con = Connection::open(path)?;
+    export_ocel_to_sql_con_with(&con, ocel, options)
+}
+
 ///
 /// Export an OCEL to a `SQLite` byte array
 ///
@@ -35,6 +58,23 @@ where
     Ok((*data).to_vec())
 }
 
+///
+/// Export an OCEL to a `SQLite` byte array, only including the event/object types selected in the [`OCELSqlExportOptions`]
+///
+/// Note: This function is only available if the `ocel-sqlite` feature is enabled.
+pub fn export_ocel_sqlite_to_vec_with<O>(
+    ocel: &O,
+    options: &OCELSqlExportOptions,
+) -> Result<Vec<u8>, DatabaseError>
+where
+    O: ReadableOCEL + ?Sized,
+{
+    let con = Connection::open_in_memory()?;
+    export_ocel_to_sql_con_with(&con, ocel, options)?;
+    let data = con.serialize(rusqlite::MAIN_DB)?;
+    Ok((*data).to_vec())
+}
+
 #[cfg(test)]
 mod sqlite_export_tests {
     use std::fs::{self};
@@ -48,6 +88,35 @@ mod sqlite_export_tests {
 
     use super::export_ocel_to_sql_con;
 
+    #[test]
+    fn test_sqlite_export_type_subset() {
+        use crate::core::event_data::object_centric::ocel_sql::{
+            export_ocel_sqlite_to_vec_with, import_ocel_sqlite_from_slice, OCELSqlExportOptions,
+        };
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "c:1"]),
+            ("pack", ["o:1", "i:1"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("o:1", "c:1")
+        ];
+        let options = OCELSqlExportOptions {
+            event_types: Some(["place".to_string()].into()),
+            object_types: Some(["o".to_string(), "i".to_string()].into()),
+        };
+        let bytes = export_ocel_sqlite_to_vec_with(&ocel, &options).unwrap();
+        let exported = import_ocel_sqlite_from_slice(&bytes).unwrap();
+        assert_eq!(exported.event_types.len(), 1);
+        assert_eq!(exported.object_types.len(), 2);
+        assert_eq!(exported.events.len(), 1);
+        assert_eq!(exported.objects.len(), 2);
+        assert_eq!(exported.events[0].relationships.len(), 2);
+        let order = exported.objects.iter().find(|o| o.id == "o:1").unwrap();
+        assert_eq!(order.relationships.len(), 1);
+        assert_eq!(order.relationships[0].object_id, "i:1");
+    }
+
     #[test]
     fn test_sqlite_export_order_management() {
         let path = get_test_data_path()
@@ -3,7 +3,9 @@ use crate::core::event_data::object_centric::ocel_struct::{
     OCELAttributeValue, OCELEvent, OCELEventAttribute, OCELObject, OCELObjectAttribute,
     OCELRelationship, OCELTypeAttribute,
 };
+use crate::core::event_data::object_centric::ocel_xml::xml_ocel_import::OCELImportOptions;
 use crate::core::event_data::timestamp_utils::parse_timestamp;
+use crate::diagnostics::warn_diagnostic;
 use std::{collections::HashMap, ffi::CString};
 
 use super::super::*;
@@ -262,9 +264,7 @@ pub fn import_ocel_sqlite_from_con(con: Connection) -> Result<OCEL, rusqlite::Er
                 qualifier,
             });
         } else {
-            eprintln!(
-                "Warning: E2O relationship not added as event with ID {ev_id} was not found."
-            );
+            warn_diagnostic!("E2O relationship not added as event with ID {ev_id} was not found.");
         }
     });
 
@@ -272,23 +272,25 @@ pub fn import_ocel_sqlite_from_con(con: Connection) -> Result<OCEL, rusqlite::Er
     let mut s = con.prepare("SELECT * FROM object_object".to_string().as_str())?;
     let evs = query_all::<_>(&mut s, [])?;
     evs.and_then(|x| {
-            Ok::<(String, String, String), rusqlite::Error>((
-                x.get(OCEL_O2O_SOURCE_ID_COLUMN)?,
-                x.get(OCEL_O2O_TARGET_ID_COLUMN)?,
-                x.get(OCEL_REL_QUALIFIER_COLUMN)?,
-            ))
-        })
-        .flatten()
-        .for_each(|(source_ob_id, target_ob_id, qualifier)| {
-            if let Some(ev) = object_map.get_mut(&source_ob_id) {
-                ev.relationships.push(OCELRelationship {
-                    object_id: target_ob_id,
-                    qualifier,
-                });
-            }else{
-                eprintln!("Warning: O2O relationship not added as object with ID {source_ob_id} was not found.");
-            }
-        });
+        Ok::<(String, String, String), rusqlite::Error>((
+            x.get(OCEL_O2O_SOURCE_ID_COLUMN)?,
+            x.get(OCEL_O2O_TARGET_ID_COLUMN)?,
+            x.get(OCEL_REL_QUALIFIER_COLUMN)?,
+        ))
+    })
+    .flatten()
+    .for_each(|(source_ob_id, target_ob_id, qualifier)| {
+        if let Some(ev) = object_map.get_mut(&source_ob_id) {
+            ev.relationships.push(OCELRelationship {
+                object_id: target_ob_id,
+                qualifier,
+            });
+        } else {
+            warn_diagnostic!(
+                "O2O relationship not added as object with ID {source_ob_id} was not found."
+            );
+        }
+    });
 
     ocel.objects = object_map.into_values().collect();
     ocel.events = event_map.into_values().collect();
@@ -311,6 +313,22 @@ pub fn import_ocel_sqlite_from_path<P: AsRef<std::path::Path>>(
     import_ocel_sqlite_from_con(con)
 }
 
+///
+/// Import an [`OCEL`] `SQLite` file from the given path __with__ _custom options_
+///
+/// Events and objects of ignored types (see [`OCELImportOptions`]) are removed after reading the database.
+/// Other options (e.g., the date format) are not used for `SQLite`.
+///
+/// Note: This function is only available if the `ocel-sqlite` feature is enabled.
+pub fn import_ocel_sqlite_from_path_with<P: AsRef<std::path::Path>>(
+    path: P,
+    options: &OCELImportOptions,
+) -> Result<OCEL, rusqlite::Error> {
+    let mut ocel = import_ocel_sqlite_from_path(path)?;
+    options.apply_type_filters(&mut ocel);
+    Ok(ocel)
+}
+
 ///
 /// Import an [`OCEL`] `SQLite` file from the given byte slice
 ///
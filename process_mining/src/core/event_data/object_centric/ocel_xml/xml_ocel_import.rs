@@ -1,5 +1,6 @@
+use crate::diagnostics::warn_diagnostic;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     convert::Infallible,
     io::{BufRead, BufReader},
 };
@@ -36,6 +37,24 @@ pub struct OCELImportOptions {
     ///
     /// Will fall back to default formats (e.g., rfc3339) if parsing fails using passed `date_format`
     pub date_format: Option<String>,
+    /// Event types to ignore during import
+    ///
+    /// Events of these types (as well as the type declarations) are dropped.
+    #[serde(default)]
+    pub ignore_event_types: HashSet<String>,
+    /// Object types to ignore during import
+    ///
+    /// Objects of these types (as well as the type declarations) are dropped,
+    /// together with all E2O and O2O relationships referring to them.
+    #[serde(default)]
+    pub ignore_object_types: HashSet<String>,
+    /// Validate OCEL 2.0 JSON input against the OCEL 2.0 JSON schema before importing it
+    ///
+    /// If enabled, schema violations are reported as [`OCELIOError::JsonSchema`], listing all violations with
+    /// JSON pointers to their location (instead of only the first deserialization error).
+    /// Only used for JSON imports.
+    #[serde(default)]
+    pub strict_schema: bool,
 }
 
 impl Default for OCELImportOptions {
@@ -43,7 +62,139 @@ impl Default for OCELImportOptions {
         Self {
             verbose: true,
             date_format: None,
+            ignore_event_types: HashSet::new(),
+            ignore_object_types: HashSet::new(),
+            strict_schema: false,
+        }
+    }
+}
+
+impl OCELImportOptions {
+    /// Remove all events and objects of ignored types (as well as relationships referring to removed objects) from an [`OCEL`]
+    ///
+    /// Used by importers which cannot skip ignored types while parsing (e.g., `SQLite`),
+    /// but can also be used to filter an already imported OCEL.
+    pub fn apply_type_filters(&self, ocel: &mut OCEL) {
+        if self.ignore_event_types.is_empty() && self.ignore_object_types.is_empty() {
+            return;
+        }
+        ocel.event_types
+            .retain(|et| !self.ignore_event_types.contains(&et.name));
+        ocel.object_types
+            .retain(|ot| !self.ignore_object_types.contains(&ot.name));
+        ocel.events
+            .retain(|e| !self.ignore_event_types.contains(&e.event_type));
+        let ignored_object_ids: HashSet<String> = ocel
+            .objects
+            .iter()
+            .filter(|o| self.ignore_object_types.contains(&o.object_type))
+            .map(|o| o.id.clone())
+            .collect();
+        ocel.objects
+            .retain(|o| !self.ignore_object_types.contains(&o.object_type));
+        remove_relationships_to(ocel, &ignored_object_ids);
+    }
+}
+
+fn remove_relationships_to(ocel: &mut OCEL, object_ids: &HashSet<String>) {
+    if object_ids.is_empty() {
+        return;
+    }
+    for rels in ocel
+        .events
+        .iter_mut()
+        .map(|e| &mut e.relationships)
+        .chain(ocel.objects.iter_mut().map(|o| &mut o.relationships))
+    {
+        rels.retain(|r| !object_ids.contains(&r.object_id));
+    }
+}
+
+///
+/// [`AppendableOCEL`] wrapper, dropping events and objects of ignored types (see [`OCELImportOptions`])
+///
+/// Relationships referring to ignored objects are dropped, if the object was appended before.
+/// For forward references, see [`TypeFilteringAppendable::finish`].
+pub(crate) struct TypeFilteringAppendable<'a, A> {
+    inner: &'a mut A,
+    options: &'a OCELImportOptions,
+    ignored_object_ids: HashSet<String>,
+}
+
+impl<'a, A: AppendableOCEL> TypeFilteringAppendable<'a, A> {
+    pub(crate) fn new(inner: &'a mut A, options: &'a OCELImportOptions) -> Self {
+        Self {
+            inner,
+            options,
+            ignored_object_ids: HashSet::new(),
+        }
+    }
+
+    fn filter_relationships(&self, relationships: &mut Vec<OCELRelationship>) {
+        if !self.ignored_object_ids.is_empty() {
+            relationships.retain(|r| !self.ignored_object_ids.contains(&r.object_id));
+        }
+    }
+}
+
+impl TypeFilteringAppendable<'_, OCEL> {
+    /// Also remove relationships referring to ignored objects which were appended before the referenced object
+    pub(crate) fn finish(self) {
+        remove_relationships_to(self.inner, &self.ignored_object_ids);
+    }
+}
+
+impl<A: AppendableOCEL> AppendableOCEL for TypeFilteringAppendable<'_, A> {
+    type Error = A::Error;
+
+    fn declare_event_type(&mut self, event_type: OCELType) -> Result<(), Self::Error> {
+        if self.options.ignore_event_types.contains(&event_type.name) {
+            return Ok(());
+        }
+        self.inner.declare_event_type(event_type)
+    }
+
+    fn declare_object_type(&mut self, object_type: OCELType) -> Result<(), Self::Error> {
+        if self.options.ignore_object_types.contains(&object_type.name) {
+            return Ok(());
+        }
+        self.inner.declare_object_type(object_type)
+    }
+
+    fn append_event(
+        &mut self,
+        id: String,
+        event_type: &str,
+        time: DateTime<FixedOffset>,
+        attributes: Vec<OCELEventAttribute>,
+        mut relationships: Vec<OCELRelationship>,
+    ) -> Result<(), Self::Error> {
+        if self.options.ignore_event_types.contains(event_type) {
+            return Ok(());
         }
+        self.filter_relationships(&mut relationships);
+        self.inner
+            .append_event(id, event_type, time, attributes, relationships)
+    }
+
+    fn append_object(
+        &mut self,
+        id: String,
+        object_type: &str,
+        attributes: Vec<OCELObjectAttribute>,
+        mut relationships: Vec<OCELRelationship>,
+    ) -> Result<(), Self::Error> {
+        if self.options.ignore_object_types.contains(object_type) {
+            self.ignored_object_ids.insert(id);
+            return Ok(());
+        }
+        self.filter_relationships(&mut relationships);
+        self.inner
+            .append_object(id, object_type, attributes, relationships)
+    }
+
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.inner.finalize()
     }
 }
 
@@ -110,7 +261,7 @@ fn append_object_attr_decl(
         }
         Err(e) => {
             if options.verbose {
-                eprintln!("Failed to parse time value of attribute: {e}. Will skip this attribute completely for now.");
+                warn_diagnostic!("Failed to parse time value of attribute: {e}. Will skip this attribute completely for now.");
             }
         }
     }
@@ -183,7 +334,7 @@ fn parse_attribute_value(
         Ok(attribute_val) => attribute_val,
         Err(e) => {
             if options.verbose {
-                eprintln!(
+                warn_diagnostic!(
                     "Failed to parse attribute value {value:?} with supposed type {attribute_type:?}\n{e}"
                 );
             }
@@ -199,11 +350,28 @@ fn parse_attribute_value(
 /// parsed. The caller is responsible for invoking [`AppendableOCEL::finalize`]
 /// afterwards if the implementation requires it.
 ///
+/// Events and objects of ignored types (see [`OCELImportOptions::ignore_event_types`] and [`OCELImportOptions::ignore_object_types`])
+/// are not appended. Relationships referring to ignored objects are only dropped if the object appears before the relationship.
+///
 pub fn import_ocel_xml_into<R, A>(
     reader: &mut Reader<R>,
     ocel: &mut A,
     options: OCELImportOptions,
 ) -> Result<(), OCELIOError>
+where
+    R: BufRead,
+    A: AppendableOCEL,
+    A::Error: Into<OCELIOError>,
+{
+    let mut filtered = TypeFilteringAppendable::new(ocel, &options);
+    import_ocel_xml_into_filtered(reader, &mut filtered, &options)
+}
+
+fn import_ocel_xml_into_filtered<R, A>(
+    reader: &mut Reader<R>,
+    ocel: &mut TypeFilteringAppendable<'_, A>,
+    options: &OCELImportOptions,
+) -> Result<(), OCELIOError>
 where
     R: BufRead,
     A: AppendableOCEL,
@@ -279,7 +447,7 @@ where
                         Mode::Object => match t.name().as_ref() {
                             b"attributes" | b"objects" => {}
                             b"attribute" => {
-                                append_object_attr_decl(&t, &mut current_object, &options)?;
+                                append_object_attr_decl(&t, &mut current_object, options)?;
                             }
                             _ => {}
                         },
@@ -403,7 +571,7 @@ where
                             }
                             b"attributes" | b"objects" => {}
                             b"attribute" => {
-                                append_object_attr_decl(&t, &mut current_object, &options)?;
+                                append_object_attr_decl(&t, &mut current_object, options)?;
                             }
                             _ => {}
                         },
@@ -449,7 +617,7 @@ where
                                     .get(&(o.object_type.clone(), attr.name.clone()))
                                     .unwrap_or(&OCELAttributeType::String),
                                 str_val,
-                                &options,
+                                options,
                             );
                         }
                         Mode::Event => {
@@ -461,7 +629,7 @@ where
                                     .get(&(e.event_type.clone(), attr.name.clone()))
                                     .unwrap_or(&OCELAttributeType::String),
                                 str_val,
-                                &options,
+                                options,
                             );
                         }
                         _ => {}
@@ -496,10 +664,25 @@ where
         events: Vec::new(),
         objects: Vec::new(),
     };
-    import_ocel_xml_into(reader, &mut ocel, options)?;
+    let mut filtered = TypeFilteringAppendable::new(&mut ocel, &options);
+    import_ocel_xml_into_filtered(reader, &mut filtered, &options)?;
+    filtered.finish();
     Ok(ocel)
 }
 
+///
+/// Import an [`OCEL`] XML from a buffered reader __with__ _custom options_
+///
+/// The XML is parsed incrementally, i.e., the input does not have to be held in memory completely
+/// (e.g., when passing a [`BufReader`] over a large file).
+///
+pub fn import_ocel_xml_reader_with<R: BufRead>(
+    reader: R,
+    options: OCELImportOptions,
+) -> Result<OCEL, OCELIOError> {
+    import_ocel_xml(&mut Reader::from_reader(reader), options)
+}
+
 ///
 /// Import an [`OCEL`] XML from a byte slice __with__ _custom options_
 ///
@@ -507,7 +690,7 @@ pub fn import_ocel_xml_slice_with(
     xes_data: &[u8],
     options: OCELImportOptions,
 ) -> Result<OCEL, OCELIOError> {
-    import_ocel_xml(&mut Reader::from_reader(BufReader::new(xes_data)), options)
+    import_ocel_xml_reader_with(xes_data, options)
 }
 
 ///
@@ -521,6 +704,15 @@ pub fn import_ocel_xml_path_with<P: AsRef<std::path::Path>>(
     import_ocel_xml(&mut reader, options)
 }
 
+///
+/// Import an [`OCEL`] XML from a buffered reader with default options
+///
+/// See [`import_ocel_xml_reader_with`]
+///
+pub fn import_ocel_xml_reader<R: BufRead>(reader: R) -> Result<OCEL, OCELIOError> {
+    import_ocel_xml_reader_with(reader, OCELImportOptions::default())
+}
+
 ///
 /// Import an [`OCEL`] XML from a byte slice with default options
 ///
@@ -0,0 +1,297 @@
+//! Statistics of Object-centric Event Data
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Display,
+};
+
+use chrono::{DateTime, FixedOffset};
+use macros_process_mining::register_binding;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    event_data::object_centric::linked_ocel::{LinkedOCELAccess, SlimLinkedOCEL},
+    OCEL,
+};
+
+/// Summary of an [`OCEL`] (see [`ocel_summary`] and [`locel_summary`])
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OcelSummary {
+    /// Total number of events
+    pub num_events: usize,
+    /// Total number of objects
+    pub num_objects: usize,
+    /// Number of events per event type
+    ///
+    /// Also includes declared event types without any events
+    pub event_type_counts: BTreeMap<String, usize>,
+    /// Number of objects per object type
+    ///
+    /// Also includes declared object types without any objects
+    pub object_type_counts: BTreeMap<String, usize>,
+    /// Total number of E2O (event-to-object) relationships
+    pub num_e2o: usize,
+    /// Total number of O2O (object-to-object) relationships
+    pub num_o2o: usize,
+    /// Timestamp of the earliest event (`None` if there are no events)
+    pub first_event_time: Option<DateTime<FixedOffset>>,
+    /// Timestamp of the latest event (`None` if there are no events)
+    pub last_event_time: Option<DateTime<FixedOffset>>,
+    /// Distinct qualifiers used in E2O relationships
+    pub e2o_qualifiers: BTreeSet<String>,
+    /// Distinct qualifiers used in O2O relationships
+    pub o2o_qualifiers: BTreeSet<String>,
+}
+
+///
+/// Compute an [`OcelSummary`] of an [`OCEL`]
+///
+/// Contains the number of events and objects (per type), the number of E2O and O2O relationships,
+/// the time span of all events and the distinct qualifiers used.
+pub fn ocel_summary(ocel: &OCEL) -> OcelSummary {
+    let mut event_type_counts: BTreeMap<String, usize> = ocel
+        .event_types
+        .iter()
+        .map(|et| (et.name.clone(), 0))
+        .collect();
+    let mut object_type_counts: BTreeMap<String, usize> = ocel
+        .object_types
+        .iter()
+        .map(|ot| (ot.name.clone(), 0))
+        .collect();
+    let mut e2o_qualifiers = BTreeSet::new();
+    let mut o2o_qualifiers = BTreeSet::new();
+    let mut num_e2o = 0;
+    let mut num_o2o = 0;
+    for ev in &ocel.events {
+        *event_type_counts.entry(ev.event_type.clone()).or_default() += 1;
+        num_e2o += ev.relationships.len();
+        for rel in &ev.relationships {
+            e2o_qualifiers.insert(rel.qualifier.clone());
+        }
+    }
+    for ob in &ocel.objects {
+        *object_type_counts
+            .entry(ob.object_type.clone())
+            .or_default() += 1;
+        num_o2o += ob.relationships.len();
+        for rel in &ob.relationships {
+            o2o_qualifiers.insert(rel.qualifier.clone());
+        }
+    }
+    OcelSummary {
+        num_events: ocel.events.len(),
+        num_objects: ocel.objects.len(),
+        event_type_counts,
+        object_type_counts,
+        num_e2o,
+        num_o2o,
+        first_event_time: ocel.events.iter().map(|ev| ev.time).min(),
+        last_event_time: ocel.events.iter().map(|ev| ev.time).max(),
+        e2o_qualifiers,
+        o2o_qualifiers,
+    }
+}
+
+///
+/// Compute an [`OcelSummary`] of a [`SlimLinkedOCEL`]
+///
+/// Computes the same statistics as [`ocel_summary`], but on the linked representation (e.g., to get a quick overview of a loaded OCEL).
+///
+/// Note that [`SlimLinkedOCEL`] drops relationships referring to non-existing objects.
+/// Thus, `num_e2o` and `num_o2o` (as well as the qualifiers) only cover valid relationships
+/// and can be lower than for the original [`OCEL`].
+#[register_binding(name = "ocel_summary")]
+pub fn locel_summary(locel: &SlimLinkedOCEL) -> OcelSummary {
+    let event_type_counts = locel
+        .get_ev_types()
+        .map(|et| (et.to_string(), locel.get_evs_of_type(et).count()))
+        .collect();
+    let object_type_counts = locel
+        .get_ob_types()
+        .map(|ot| (ot.to_string(), locel.get_obs_of_type(ot).count()))
+        .collect();
+    let mut e2o_qualifiers = BTreeSet::new();
+    let mut o2o_qualifiers = BTreeSet::new();
+    let mut num_e2o = 0;
+    let mut num_o2o = 0;
+    for ev in locel.get_all_evs() {
+        for (q, _ob) in locel.get_e2o(ev) {
+            num_e2o += 1;
+            e2o_qualifiers.insert(q.to_string());
+        }
+    }
+    for ob in locel.get_all_obs() {
+        for (q, _ob2) in locel.get_o2o(ob) {
+            num_o2o += 1;
+            o2o_qualifiers.insert(q.to_string());
+        }
+    }
+    let first_event_time = locel.get_all_evs().map(|ev| *locel.get_ev_time(ev)).min();
+    let last_event_time = locel.get_all_evs().map(|ev| *locel.get_ev_time(ev)).max();
+    OcelSummary {
+        num_events: locel.get_num_evs(),
+        num_objects: locel.get_num_obs(),
+        event_type_counts,
+        object_type_counts,
+        num_e2o,
+        num_o2o,
+        first_event_time,
+        last_event_time,
+        e2o_qualifiers,
+        o2o_qualifiers,
+    }
+}
+
+///
+/// Count how often each qualifier is used in the relationships of an [`OCEL`]
+///
+/// Returns the qualifier counts of E2O (event-to-object) and O2O (object-to-object) relationships, respectively.
+pub fn qualifier_usage(ocel: &OCEL) -> (HashMap<String, usize>, HashMap<String, usize>) {
+    let mut e2o: HashMap<String, usize> = HashMap::new();
+    let mut o2o: HashMap<String, usize> = HashMap::new();
+    for rel in ocel.events.iter().flat_map(|ev| &ev.relationships) {
+        *e2o.entry(rel.qualifier.clone()).or_default() += 1;
+    }
+    for rel in ocel.objects.iter().flat_map(|ob| &ob.relationships) {
+        *o2o.entry(rel.qualifier.clone()).or_default() += 1;
+    }
+    (e2o, o2o)
+}
+
+impl Display for OcelSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Events: {} ({} event types)",
+            self.num_events,
+            self.event_type_counts.len()
+        )?;
+        for (et, count) in &self.event_type_counts {
+            writeln!(f, "  {et}: {count}")?;
+        }
+        writeln!(
+            f,
+            "Objects: {} ({} object types)",
+            self.num_objects,
+            self.object_type_counts.len()
+        )?;
+        for (ot, count) in &self.object_type_counts {
+            writeln!(f, "  {ot}: {count}")?;
+        }
+        writeln!(f, "E2O relationships: {}", self.num_e2o)?;
+        writeln!(f, "O2O relationships: {}", self.num_o2o)?;
+        if let (Some(first), Some(last)) = (self.first_event_time, self.last_event_time) {
+            writeln!(
+                f,
+                "Time span: {} to {}",
+                first.to_rfc3339(),
+                last.to_rfc3339()
+            )?;
+        }
+        writeln!(f, "E2O qualifiers: {:?}", self.e2o_qualifiers)?;
+        write!(f, "O2O qualifiers: {:?}", self.o2o_qualifiers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ocel;
+
+    #[test]
+    fn test_ocel_summary() {
+        let ocel = ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:2"]),
+            ("place", ["o:2"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("o:1", "i:2")
+        ];
+        let summary = ocel_summary(&ocel);
+        assert_eq!(summary.num_events, 3);
+        assert_eq!(summary.num_objects, 4);
+        assert_eq!(
+            summary.event_type_counts,
+            [("pack".to_string(), 1), ("place".to_string(), 2)].into()
+        );
+        assert_eq!(
+            summary.object_type_counts,
+            [("i".to_string(), 2), ("o".to_string(), 2)].into()
+        );
+        assert_eq!(summary.num_e2o, 6);
+        assert_eq!(summary.num_o2o, 2);
+        assert_eq!(
+            summary.last_event_time.unwrap() - summary.first_event_time.unwrap(),
+            chrono::TimeDelta::seconds(2)
+        );
+        // The ocel! macro uses the object type as qualifier
+        assert_eq!(
+            summary.e2o_qualifiers,
+            ["i".to_string(), "o".to_string()].into()
+        );
+        let printed = summary.to_string();
+        assert!(printed.starts_with("Events: 3 (2 event types)\n  pack: 1\n  place: 2\n"));
+        assert!(
+            printed.contains("Time span: 2020-01-01T00:00:00+00:00 to 2020-01-01T00:00:02+00:00")
+        );
+    }
+
+    #[test]
+    fn test_locel_summary() {
+        let ocel = ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:2"]),
+            ("place", ["o:2"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("o:1", "i:2")
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel.clone());
+        assert_eq!(locel_summary(&locel), ocel_summary(&ocel));
+    }
+
+    #[test]
+    fn test_locel_summary_order_management() {
+        use crate::core::event_data::object_centric::ocel_xml::xml_ocel_import::import_ocel_xml_path;
+        let path = crate::test_utils::get_test_data_path()
+            .join("ocel")
+            .join("order-management.xml");
+        let locel = SlimLinkedOCEL::from_ocel(import_ocel_xml_path(&path).unwrap());
+        let summary = locel_summary(&locel);
+        assert_eq!(summary.num_events, 21008);
+        assert_eq!(summary.num_objects, 10840);
+        assert_eq!(summary.event_type_counts.len(), 11);
+        assert_eq!(summary.object_type_counts.len(), 6);
+        assert_eq!(summary.event_type_counts.values().sum::<usize>(), 21008);
+        assert_eq!(summary.object_type_counts.values().sum::<usize>(), 10840);
+    }
+
+    #[test]
+    fn test_qualifier_usage() {
+        let mut ocel = ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:2"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("o:1", "i:2")
+        ];
+        ocel.events[0].relationships[0].qualifier = "customer order".to_string();
+        let (e2o, o2o) = qualifier_usage(&ocel);
+        assert_eq!(
+            e2o,
+            HashMap::from([
+                ("customer order".to_string(), 1),
+                ("o".to_string(), 1),
+                ("i".to_string(), 3)
+            ])
+        );
+        assert_eq!(o2o.values().sum::<usize>(), 2);
+        assert_eq!(o2o.len(), 1);
+    }
+}
@@ -0,0 +1,357 @@
+//! Filtering of OCEL Events and Objects
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, FixedOffset};
+use macros_process_mining::register_binding;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::event_data::object_centric::{linked_ocel::IndexLinkedOCEL, ocel_struct::OCEL};
+
+///
+/// Filter for [`filter_ocel`]
+///
+/// All restrictions are optional: Unset restrictions keep all events/objects.
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct OcelFilter {
+    /// Event types to keep (or all if `None`)
+    #[serde(default)]
+    pub event_types: Option<HashSet<String>>,
+    /// Object types to keep (or all if `None`)
+    #[serde(default)]
+    pub object_types: Option<HashSet<String>>,
+    /// Start of the time window (inclusive): Earlier events are removed
+    #[serde(default)]
+    pub start: Option<DateTime<FixedOffset>>,
+    /// End of the time window (inclusive): Later events are removed
+    #[serde(default)]
+    pub end: Option<DateTime<FixedOffset>>,
+}
+
+impl OcelFilter {
+    fn keep_event_type(&self, event_type: &str) -> bool {
+        self.event_types
+            .as_ref()
+            .is_none_or(|ets| ets.contains(event_type))
+    }
+
+    fn keep_event(&self, event_type: &str, time: &DateTime<FixedOffset>) -> bool {
+        self.keep_event_type(event_type)
+            && self.start.is_none_or(|start| *time >= start)
+            && self.end.is_none_or(|end| *time <= end)
+    }
+
+    fn keep_object_type(&self, object_type: &str) -> bool {
+        self.object_types
+            .as_ref()
+            .is_none_or(|ots| ots.contains(object_type))
+    }
+}
+
+///
+/// Filter an [`OCEL`] by event types, object types, and a time window (see [`OcelFilter`])
+///
+/// Events are removed if their type is not selected or if they occur outside of the time window.
+/// Objects are removed if their type is not selected, or if they were related to events (via E2O)
+/// but are not related to any remaining event.
+/// Events which were only related to removed objects are removed as well.
+///
+/// All E2O and O2O relationships referring to removed objects are pruned, so that the result stays
+/// consistent (see [`validate_ocel`](crate::core::event_data::object_centric::validate::validate_ocel)).
+/// Only the selected event and object types are declared in the result.
+///
+pub fn filter_ocel(ocel: &OCEL, filter: OcelFilter) -> OCEL {
+    let of_kept_type: HashSet<&str> = ocel
+        .objects
+        .iter()
+        .filter(|o| filter.keep_object_type(&o.object_type))
+        .map(|o| o.id.as_str())
+        .collect();
+    let events: Vec<_> = ocel
+        .events
+        .iter()
+        .filter(|e| filter.keep_event(&e.event_type, &e.time))
+        .filter_map(|e| {
+            let mut e = e.clone();
+            let had_relationships = !e.relationships.is_empty();
+            e.relationships
+                .retain(|r| of_kept_type.contains(r.object_id.as_str()));
+            (!had_relationships || !e.relationships.is_empty()).then_some(e)
+        })
+        .collect();
+    let referenced_before: HashSet<&str> = ocel
+        .events
+        .iter()
+        .flat_map(|e| e.relationships.iter().map(|r| r.object_id.as_str()))
+        .collect();
+    let referenced_after: HashSet<&str> = events
+        .iter()
+        .flat_map(|e| e.relationships.iter().map(|r| r.object_id.as_str()))
+        .collect();
+    let kept_objects: HashSet<&str> = of_kept_type
+        .into_iter()
+        .filter(|o| !referenced_before.contains(o) || referenced_after.contains(o))
+        .collect();
+    let objects = ocel
+        .objects
+        .iter()
+        .filter(|o| kept_objects.contains(o.id.as_str()))
+        .map(|o| {
+            let mut o = o.clone();
+            o.relationships
+                .retain(|r| kept_objects.contains(r.object_id.as_str()));
+            o
+        })
+        .collect();
+    OCEL {
+        event_types: ocel
+            .event_types
+            .iter()
+            .filter(|et| filter.keep_event_type(&et.name))
+            .cloned()
+            .collect(),
+        object_types: ocel
+            .object_types
+            .iter()
+            .filter(|ot| filter.keep_object_type(&ot.name))
+            .cloned()
+            .collect(),
+        events,
+        objects,
+    }
+}
+
+///
+/// Filter an [`IndexLinkedOCEL`] by event types, object types, and a time window
+///
+/// The [`OCEL`] is reconstructed from the relationship indices (see [`IndexLinkedOCEL::to_ocel`]),
+/// so edits to the [`IndexLinkedOCEL`] (e.g., added E2O or O2O relationships) are taken into account.
+/// See [`filter_ocel`] for details.
+///
+#[register_binding(name = "filter_ocel")]
+pub fn filter_index_linked_ocel(locel: &IndexLinkedOCEL, filter: OcelFilter) -> IndexLinkedOCEL {
+    IndexLinkedOCEL::from_ocel(filter_ocel(&locel.to_ocel(), filter))
+}
+
+/// Filter an [`OCEL`] to the events of `event_type` which are related (via E2O) to at least one
+/// object of `required_object_type`
+///
+/// If a `qualifier` is passed, only E2O relationships with exactly this qualifier are considered.
+/// All other events are removed, while all objects and types are kept.
+pub fn filter_events_by_object_type(
+    ocel: &OCEL,
+    event_type: &str,
+    required_object_type: &str,
+    qualifier: Option<&str>,
+) -> OCEL {
+    let object_types: HashMap<&str, &str> = ocel
+        .objects
+        .iter()
+        .map(|o| (o.id.as_str(), o.object_type.as_str()))
+        .collect();
+    let events = ocel
+        .events
+        .iter()
+        .filter(|e| {
+            e.event_type == event_type
+                && e.relationships.iter().any(|r| {
+                    qualifier.is_none_or(|q| r.qualifier == q)
+                        && object_types.get(r.object_id.as_str()) == Some(&required_object_type)
+                })
+        })
+        .cloned()
+        .collect();
+    OCEL {
+        event_types: ocel.event_types.clone(),
+        object_types: ocel.object_types.clone(),
+        events,
+        objects: ocel.objects.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::event_data::object_centric::{
+            ocel_json::import_ocel_json_path, validate::validate_ocel,
+        },
+        test_utils::get_test_data_path,
+    };
+
+    fn sorted_ids<'a>(ids: impl Iterator<Item = &'a String>) -> Vec<&'a str> {
+        let mut ids: Vec<_> = ids.map(String::as_str).collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_filter_ocel() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "c:1"]),
+            ("pick", ["i:1"]),
+            ("pay", ["o:1"]),
+            ("place", ["o:2", "i:2"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("o:2", "c:1")
+        ];
+
+        let filtered = filter_ocel(
+            &ocel,
+            OcelFilter {
+                object_types: Some(HashSet::from(["o".to_string()])),
+                ..Default::default()
+            },
+        );
+        assert!(validate_ocel(&filtered).is_valid());
+        assert_eq!(
+            sorted_ids(filtered.events.iter().map(|e| &e.id)),
+            ["ev:1", "ev:3", "ev:4"]
+        );
+        assert_eq!(
+            sorted_ids(filtered.objects.iter().map(|o| &o.id)),
+            ["o:1", "o:2"]
+        );
+        assert!(filtered.objects.iter().all(|o| o.relationships.is_empty()));
+        assert!(filtered
+            .events
+            .iter()
+            .flat_map(|e| &e.relationships)
+            .all(|r| r.object_id.starts_with("o:")));
+        assert_eq!(
+            sorted_ids(filtered.object_types.iter().map(|t| &t.name)),
+            ["o"]
+        );
+
+        // Time window: Only keep the second and third event
+        let filtered = filter_ocel(
+            &ocel,
+            OcelFilter {
+                start: Some(ocel.events[1].time),
+                end: Some(ocel.events[2].time),
+                ..Default::default()
+            },
+        );
+        assert!(validate_ocel(&filtered).is_valid());
+        assert_eq!(
+            sorted_ids(filtered.events.iter().map(|e| &e.id)),
+            ["ev:2", "ev:3"]
+        );
+        assert_eq!(
+            sorted_ids(filtered.objects.iter().map(|o| &o.id)),
+            ["i:1", "o:1"]
+        );
+
+        let filtered = filter_ocel(
+            &ocel,
+            OcelFilter {
+                event_types: Some(HashSet::from(["pay".to_string()])),
+                ..Default::default()
+            },
+        );
+        assert_eq!(sorted_ids(filtered.events.iter().map(|e| &e.id)), ["ev:3"]);
+        assert_eq!(
+            sorted_ids(filtered.event_types.iter().map(|t| &t.name)),
+            ["pay"]
+        );
+    }
+
+    #[test]
+    fn test_filter_edited_index_linked_ocel() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1"]),
+            ("pick", ["i:1"]),
+            o2o:
+        ];
+        let mut locel = IndexLinkedOCEL::from_ocel(ocel);
+        let place = locel.get_ev_index("ev:1").unwrap();
+        let o1 = locel.get_ob_index("o:1").unwrap();
+        let i1 = locel.get_ob_index("i:1").unwrap();
+        locel.add_e2o(place, i1, "item").unwrap();
+        locel.add_o2o(o1, i1, "contains").unwrap();
+
+        let filtered = filter_index_linked_ocel(
+            &locel,
+            OcelFilter {
+                event_types: Some(HashSet::from(["place".to_string()])),
+                ..Default::default()
+            },
+        );
+        let filtered = filtered.get_ocel_ref();
+        assert!(validate_ocel(filtered).is_valid());
+        // `i:1` is kept, as it is related to the remaining event by the added E2O relationship
+        assert_eq!(
+            sorted_ids(filtered.objects.iter().map(|o| &o.id)),
+            ["i:1", "o:1"]
+        );
+        let place = &filtered.events[0];
+        assert!(place
+            .relationships
+            .iter()
+            .any(|r| r.object_id == "i:1" && r.qualifier == "item"));
+        let o1 = filtered.objects.iter().find(|o| o.id == "o:1").unwrap();
+        assert_eq!(o1.relationships.len(), 1);
+        assert_eq!(o1.relationships[0].object_id, "i:1");
+    }
+
+    #[test]
+    fn test_filter_ocel_order_management_object_type() {
+        let ocel = import_ocel_json_path(
+            get_test_data_path()
+                .join("ocel")
+                .join("order-management.json"),
+        )
+        .unwrap();
+        let filtered = filter_ocel(
+            &ocel,
+            OcelFilter {
+                object_types: Some(HashSet::from(["orders".to_string()])),
+                ..Default::default()
+            },
+        );
+        assert!(validate_ocel(&filtered).is_valid());
+        assert!(!filtered.events.is_empty());
+        assert!(filtered.objects.iter().all(|o| o.object_type == "orders"));
+        // Only E2O relationships to orders remain (as all remaining objects are orders)
+        assert!(filtered.events.iter().all(|e| !e.relationships.is_empty()));
+        // Orders are not related to other orders via O2O
+        assert!(filtered.objects.iter().all(|o| o.relationships.is_empty()));
+    }
+
+    #[test]
+    fn test_filter_events_by_object_type() {
+        let mut ocel = crate::ocel![
+            events:
+            ("pack", ["o:1", "i:1"]),
+            ("pack", ["o:2"]),
+            ("ship", ["i:2"]),
+            o2o:
+        ];
+        let ids = |ocel: &OCEL| ocel.events.iter().map(|e| e.id.clone()).collect::<Vec<_>>();
+        let filtered = filter_events_by_object_type(&ocel, "pack", "i", None);
+        assert_eq!(ids(&filtered), vec![ocel.events[0].id.clone()]);
+        assert_eq!(filtered.objects.len(), ocel.objects.len());
+
+        ocel.events[0]
+            .relationships
+            .iter_mut()
+            .find(|r| r.object_id == "i:1")
+            .unwrap()
+            .qualifier = "packed item".to_string();
+        assert_eq!(
+            filter_events_by_object_type(&ocel, "pack", "i", Some("packed item"))
+                .events
+                .len(),
+            1
+        );
+        assert!(
+            filter_events_by_object_type(&ocel, "pack", "i", Some("other"))
+                .events
+                .is_empty()
+        );
+    }
+}
@@ -1,4 +1,9 @@
 //!  Functionality to Flatten OCEL on an Object Type
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+};
+
 use macros_process_mining::register_binding;
 
 use crate::core::{
@@ -6,9 +11,12 @@ use crate::core::{
         case_centric::event_log_struct::{
             Attribute, AttributeValue, Event, Trace, XESEditableAttribute,
         },
-        object_centric::{linked_ocel::LinkedOCELAccess, ocel_struct::OCELAttributeValue},
+        object_centric::{
+            linked_ocel::LinkedOCELAccess,
+            ocel_struct::{OCELAttributeValue, OCELEvent, OCELObject},
+        },
     },
-    EventLog,
+    EventLog, OCEL,
 };
 
 #[register_binding]
@@ -20,73 +28,60 @@ pub fn flatten_ocel_on<'a>(
     ocel: &'a impl LinkedOCELAccess<'a>,
     object_type: impl AsRef<str>,
 ) -> EventLog {
-    let mut traces: Vec<_> = ocel
+    let traces = ocel
         .get_obs_of_type(object_type.as_ref())
         .map(|ob| {
-            let ob_val = ocel.get_full_ob(ob);
-            let mut events: Vec<_> = ocel
-                .get_e2o_rev(ob)
-                .map(|(_q, ev)| {
-                    let ev_val = ocel.get_full_ev(ev);
-                    let mut xes_ev = Event {
-                        attributes: vec![
-                            Attribute::new(
-                                "concept:name".to_string(),
-                                AttributeValue::String(ev_val.event_type.clone()),
-                            ),
-                            Attribute::new(
-                                "time:timestamp".to_string(),
-                                AttributeValue::Date(ev_val.time),
-                            ),
-                        ],
-                    };
-
-                    xes_ev.attributes.extend(ev_val.attributes.iter().map(|at| {
-                        let xes_attr_val: AttributeValue = at.value.clone().into();
-                        Attribute {
-                            key: at.name.clone(),
-                            value: xes_attr_val,
-                            own_attributes: None,
-                        }
-                    }));
-
-                    xes_ev
-                })
-                .collect();
-            events.sort_by_cached_key(|ev| {
-                ev.attributes
-                    .get_by_key("time:timestamp")
-                    .and_then(|a| a.value.try_as_date().cloned())
-            });
-            let mut xes_t = Trace {
-                attributes: vec![Attribute::new(
-                    "concept:name".to_string(),
-                    AttributeValue::String(ob_val.id.clone()),
-                )],
-                events,
-            };
-            xes_t
-                .attributes
-                .extend(ob_val.attributes.iter().flat_map(|at| {
-                    let xes_attr_val: Option<AttributeValue> = match &at.value {
-                        OCELAttributeValue::Integer(i) => Some(AttributeValue::Int(*i)),
-                        OCELAttributeValue::Float(f) => Some(AttributeValue::Float(*f)),
-                        OCELAttributeValue::String(s) => Some(AttributeValue::String(s.clone())),
-                        // OCELAttributeValue::Time(date_time) => None,
-                        // OCELAttributeValue::Boolean(_) => todo!(),
-                        // OCELAttributeValue::Null => todo!(),
-                        _ => None,
-                    };
-                    xes_attr_val.map(|v| Attribute {
-                        key: at.name.clone(),
-                        value: v,
-                        own_attributes: None,
-                    })
-                }));
-            xes_t
+            let events = ocel.get_e2o_rev(ob).map(|(_q, ev)| ocel.get_full_ev(ev));
+            object_to_xes_trace(&ocel.get_full_ob(ob), events)
         })
         .collect();
-    let mut ret = EventLog::new();
+    event_log_from_traces(traces)
+}
+
+#[register_binding]
+/// Flatten an [`OCEL`] on a specific object type, resulting in a case-centric Event Log
+///
+/// For each object of the specified type, a trace is created containing all events related to that object
+/// (including their attributes), sorted chronologically.
+/// Events related to multiple objects of that type are duplicated into each of their traces (convergence).
+/// An event related to the same object multiple times (e.g., with different qualifiers) occurs only once in its trace.
+///
+/// Traces are ordered by the timestamp of their first event; events and traces with equal timestamps
+/// keep their order in the [`OCEL`].
+///
+pub fn flatten_ocel_to_event_log(ocel: &OCEL, object_type: impl AsRef<str>) -> EventLog {
+    let object_type = object_type.as_ref();
+    let objects: Vec<&OCELObject> = ocel
+        .objects
+        .iter()
+        .filter(|o| o.object_type == object_type)
+        .collect();
+    let ob_index: HashMap<&str, usize> = objects
+        .iter()
+        .enumerate()
+        .map(|(i, o)| (o.id.as_str(), i))
+        .collect();
+    let mut events_per_object: Vec<Vec<&OCELEvent>> = vec![Vec::new(); objects.len()];
+    for ev in &ocel.events {
+        let related: HashSet<usize> = ev
+            .relationships
+            .iter()
+            .filter_map(|rel| ob_index.get(rel.object_id.as_str()).copied())
+            .collect();
+        for i in related {
+            events_per_object[i].push(ev);
+        }
+    }
+    let traces = objects
+        .into_iter()
+        .zip(events_per_object)
+        .map(|(ob, events)| object_to_xes_trace(ob, events))
+        .collect();
+    event_log_from_traces(traces)
+}
+
+/// Create an [`EventLog`] of the passed traces, ordered by the timestamp of their first event (stable)
+fn event_log_from_traces(mut traces: Vec<Trace>) -> EventLog {
     traces.sort_by_cached_key(|t| {
         t.events.first().map(|e| {
             e.attributes
@@ -95,6 +90,150 @@ pub fn flatten_ocel_on<'a>(
                 .cloned()
         })
     });
+    let mut ret = EventLog::new();
     ret.traces = traces;
     ret
 }
+
+/// Convert an [`OCELEvent`] to an XES [`Event`], copying its type, timestamp, and attributes
+fn ocel_event_to_xes(ev: &OCELEvent) -> Event {
+    let mut xes_ev = Event {
+        attributes: vec![
+            Attribute::new(
+                "concept:name".to_string(),
+                AttributeValue::String(ev.event_type.clone()),
+            ),
+            Attribute::new("time:timestamp".to_string(), AttributeValue::Date(ev.time)),
+        ],
+    };
+    xes_ev.attributes.extend(ev.attributes.iter().map(|at| {
+        let xes_attr_val: AttributeValue = at.value.clone().into();
+        Attribute {
+            key: at.name.clone(),
+            value: xes_attr_val,
+            own_attributes: None,
+        }
+    }));
+    xes_ev
+}
+
+/// Create the XES [`Trace`] of an [`OCELObject`], named by its ID and with its (supported) attributes
+///
+/// The passed events are sorted chronologically (stable) and converted using [`ocel_event_to_xes`].
+fn object_to_xes_trace(
+    ob: &OCELObject,
+    events: impl IntoIterator<Item = impl Borrow<OCELEvent>>,
+) -> Trace {
+    let mut events: Vec<_> = events.into_iter().collect();
+    events.sort_by_key(|ev| ev.borrow().time);
+    let mut xes_t = Trace {
+        attributes: vec![Attribute::new(
+            "concept:name".to_string(),
+            AttributeValue::String(ob.id.clone()),
+        )],
+        events: events
+            .iter()
+            .map(|ev| ocel_event_to_xes(ev.borrow()))
+            .collect(),
+    };
+    xes_t.attributes.extend(ob.attributes.iter().flat_map(|at| {
+        let xes_attr_val: Option<AttributeValue> = match &at.value {
+            OCELAttributeValue::Integer(i) => Some(AttributeValue::Int(*i)),
+            OCELAttributeValue::Float(f) => Some(AttributeValue::Float(*f)),
+            OCELAttributeValue::String(s) => Some(AttributeValue::String(s.clone())),
+            _ => None,
+        };
+        xes_attr_val.map(|v| Attribute {
+            key: at.name.clone(),
+            value: v,
+            own_attributes: None,
+        })
+    }));
+    xes_t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event_data::object_centric::{
+        linked_ocel::IndexLinkedOCEL,
+        ocel_struct::{OCELEventAttribute, OCELRelationship},
+    };
+
+    #[test]
+    fn test_flatten_ocel_to_event_log() {
+        let mut ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pick", ["i:2"]),
+            ("ship", ["o:1", "o:2"]),
+            o2o:
+        ];
+        ocel.events[0].attributes.push(OCELEventAttribute {
+            name: "price".to_string(),
+            value: OCELAttributeValue::Float(4.2),
+        });
+        // Relating the same object twice (with another qualifier) does not duplicate the event
+        ocel.events[2]
+            .relationships
+            .push(OCELRelationship::new("o:1", "shipped"));
+
+        let log = flatten_ocel_to_event_log(&ocel, "o");
+        let activities = |id: &str| {
+            let trace = log
+                .traces
+                .iter()
+                .find(|t| {
+                    t.attributes.get_by_key("concept:name").unwrap().value
+                        == AttributeValue::String(id.to_string())
+                })
+                .unwrap();
+            trace
+                .events
+                .iter()
+                .map(|e| {
+                    e.attributes
+                        .get_by_key("concept:name")
+                        .unwrap()
+                        .value
+                        .try_as_string()
+                        .unwrap()
+                        .clone()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(log.traces.len(), 2);
+        assert_eq!(activities("o:1"), ["place", "ship"]);
+        // The shared "ship" event is duplicated into both order traces
+        assert_eq!(activities("o:2"), ["ship"]);
+        assert_eq!(
+            log.traces[0].events[0]
+                .attributes
+                .get_by_key("price")
+                .map(|a| &a.value),
+            Some(&AttributeValue::Float(4.2))
+        );
+
+        let log = flatten_ocel_to_event_log(&ocel, "i");
+        assert_eq!(log.traces.len(), 2);
+        assert_eq!(log.traces.iter().map(|t| t.events.len()).sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn test_flatten_ocel_on_matches_flatten_ocel_to_event_log() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pick", ["i:2"]),
+            ("ship", ["o:1", "o:2"]),
+            o2o:
+        ];
+        let locel = IndexLinkedOCEL::from_ocel(ocel.clone());
+        for object_type in ["o", "i"] {
+            assert_eq!(
+                flatten_ocel_on(&locel, object_type).traces,
+                flatten_ocel_to_event_log(&ocel, object_type).traces
+            );
+        }
+    }
+}
@@ -1,3 +1,6 @@
 //! Utilities Related to Object-centric Event Data
+pub mod filter;
 pub mod flatten;
 pub mod init_exit_events;
+pub mod privacy;
+pub mod transform;
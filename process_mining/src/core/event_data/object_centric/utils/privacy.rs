@@ -0,0 +1,208 @@
+//! Privacy-preserving Transformations of OCEL Data
+//!
+//! Pseudonyms are computed using a keyed cryptographic hash (keyed BLAKE3) of the original values.
+//! Without the key, pseudonyms can neither be reversed nor linked to the original values, e.g., by hashing
+//! guessed IDs (which would be easily possible with an unkeyed or non-cryptographic hash).
+//!
+//! The key thus has to be treated like a password:
+//! - It should be 32 uniformly random bytes, e.g., generated by a cryptographically secure random number generator
+//!   (and not derived from a guessable value such as a small number or a name).
+//! - It must be kept secret, i.e., it should not be shared together with the pseudonymized data.
+//! - The same key always yields the same pseudonyms, so re-using a key across data sets allows linking them.
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::event_data::object_centric::ocel_struct::{OCELAttributeValue, OCEL};
+
+/// Options for [`pseudonymize_with_options`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PseudonymizationOptions {
+    /// Secret key for the keyed hash (the same key always yields the same pseudonyms)
+    ///
+    /// See the [module documentation](self) for the requirements on the key.
+    pub key: [u8; 32],
+    /// Names of (event or object) attributes, whose string values should also be replaced by pseudonyms
+    pub hashed_attributes: HashSet<String>,
+}
+
+/// Mapping from pseudonyms back to the original values, as returned by [`pseudonymize`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PseudonymizationMapping {
+    /// Pseudonymized event ID to original event ID
+    pub event_ids: HashMap<String, String>,
+    /// Pseudonymized object ID to original object ID
+    pub object_ids: HashMap<String, String>,
+    /// Pseudonymized attribute value to original (string) attribute value
+    pub attribute_values: HashMap<String, String>,
+}
+
+/// Keyed BLAKE3 hash of `value` (truncated to 128 bit), including `round` (to re-hash on collisions)
+fn keyed_hash(key: &[u8; 32], value: &str, round: u32) -> u128 {
+    let mut hasher = blake3::Hasher::new_keyed(key);
+    hasher.update(value.as_bytes());
+    if round > 0 {
+        hasher.update(&[0]);
+        hasher.update(&round.to_le_bytes());
+    }
+    let mut bytes = [0; 16];
+    hasher.finalize_xof().fill(&mut bytes);
+    u128::from_le_bytes(bytes)
+}
+
+/// Assigns unique pseudonyms to values, remembering the original values
+struct Pseudonymizer<'a> {
+    key: &'a [u8; 32],
+    prefix: &'static str,
+    mapping: &'a mut HashMap<String, String>,
+    assigned: HashMap<String, String>,
+}
+
+impl<'a> Pseudonymizer<'a> {
+    fn new(
+        key: &'a [u8; 32],
+        prefix: &'static str,
+        mapping: &'a mut HashMap<String, String>,
+    ) -> Self {
+        Self {
+            key,
+            prefix,
+            mapping,
+            assigned: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, value: &str) -> String {
+        if let Some(pseudonym) = self.assigned.get(value) {
+            return pseudonym.clone();
+        }
+        let mut round = 0;
+        let mut pseudonym = format!("{}{:032x}", self.prefix, keyed_hash(self.key, value, round));
+        // Resolve (unlikely) hash collisions by re-hashing
+        while self.mapping.contains_key(&pseudonym) {
+            round += 1;
+            pseudonym = format!("{}{:032x}", self.prefix, keyed_hash(self.key, value, round));
+        }
+        self.mapping.insert(pseudonym.clone(), value.to_string());
+        self.assigned.insert(value.to_string(), pseudonym.clone());
+        pseudonym
+    }
+}
+
+/// Replace all event and object IDs of an [`OCEL`] by opaque, deterministic (keyed) pseudonyms
+///
+/// See [`pseudonymize_with_options`] for details and the [module documentation](self) for the requirements on the `key`.
+pub fn pseudonymize(ocel: &mut OCEL, key: [u8; 32]) -> PseudonymizationMapping {
+    pseudonymize_with_options(
+        ocel,
+        &PseudonymizationOptions {
+            key,
+            ..Default::default()
+        },
+    )
+}
+
+/// Replace all event and object IDs of an [`OCEL`] by opaque, deterministic (keyed) pseudonyms
+///
+/// All E2O and O2O relationships are updated accordingly.
+/// Additionally, string values of the attributes listed in [`PseudonymizationOptions::hashed_attributes`]
+/// are replaced (equal values get equal pseudonyms).
+///
+/// Returns the mapping from pseudonyms to the original values, which allows re-identification
+/// (and thus should not be shared together with the pseudonymized OCEL).
+pub fn pseudonymize_with_options(
+    ocel: &mut OCEL,
+    options: &PseudonymizationOptions,
+) -> PseudonymizationMapping {
+    let mut mapping = PseudonymizationMapping::default();
+    let mut events = Pseudonymizer::new(&options.key, "e-", &mut mapping.event_ids);
+    for e in &mut ocel.events {
+        e.id = events.get(&e.id);
+    }
+    let mut objects = Pseudonymizer::new(&options.key, "o-", &mut mapping.object_ids);
+    for o in &mut ocel.objects {
+        o.id = objects.get(&o.id);
+        for r in &mut o.relationships {
+            r.object_id = objects.get(&r.object_id);
+        }
+    }
+    for e in &mut ocel.events {
+        for r in &mut e.relationships {
+            r.object_id = objects.get(&r.object_id);
+        }
+    }
+    if !options.hashed_attributes.is_empty() {
+        let mut values = Pseudonymizer::new(&options.key, "v-", &mut mapping.attribute_values);
+        let attr_values = ocel
+            .events
+            .iter_mut()
+            .flat_map(|e| e.attributes.iter_mut().map(|a| (&a.name, &mut a.value)))
+            .chain(
+                ocel.objects
+                    .iter_mut()
+                    .flat_map(|o| o.attributes.iter_mut().map(|a| (&a.name, &mut a.value))),
+            );
+        for (name, value) in attr_values {
+            if let OCELAttributeValue::String(s) = value {
+                if options.hashed_attributes.contains(name) {
+                    *s = values.get(s);
+                }
+            }
+        }
+    }
+    mapping
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event_data::object_centric::ocel_struct::OCELEventAttribute;
+
+    #[test]
+    fn test_pseudonymize() {
+        let mut ocel = crate::ocel![
+            events:
+            ("admission", ["patient:1", "doctor:1"]),
+            ("diagnosis", ["patient:1"]),
+            o2o:
+            ("patient:1", "doctor:1")
+        ];
+        ocel.events[0].attributes.push(OCELEventAttribute {
+            name: "name".to_string(),
+            value: OCELAttributeValue::String("Alice".to_string()),
+        });
+        let original = ocel.clone();
+        let options = PseudonymizationOptions {
+            key: [42; 32],
+            hashed_attributes: HashSet::from(["name".to_string()]),
+        };
+        let mapping = pseudonymize_with_options(&mut ocel, &options);
+        assert_eq!(mapping.event_ids.len(), 2);
+        assert_eq!(mapping.object_ids.len(), 2);
+        for (e, orig) in ocel.events.iter().zip(&original.events) {
+            assert_eq!(mapping.event_ids[&e.id], orig.id);
+            for (r, orig_r) in e.relationships.iter().zip(&orig.relationships) {
+                assert_eq!(mapping.object_ids[&r.object_id], orig_r.object_id);
+            }
+        }
+        for (o, orig) in ocel.objects.iter().zip(&original.objects) {
+            assert!(!o.id.contains("patient") && !o.id.contains("doctor"));
+            assert_eq!(mapping.object_ids[&o.id], orig.id);
+            for (r, orig_r) in o.relationships.iter().zip(&orig.relationships) {
+                assert_eq!(mapping.object_ids[&r.object_id], orig_r.object_id);
+            }
+        }
+        let OCELAttributeValue::String(name) = &ocel.events[0].attributes[0].value else {
+            panic!("Attribute should still be a string");
+        };
+        assert_eq!(mapping.attribute_values[name], "Alice");
+
+        // Deterministic for the same key, different for other keys
+        let mut again = original.clone();
+        assert_eq!(pseudonymize_with_options(&mut again, &options), mapping);
+        let mut other = original.clone();
+        pseudonymize(&mut other, [7; 32]);
+        assert_ne!(other.events[0].id, ocel.events[0].id);
+        assert_eq!(other.events[0].attributes, original.events[0].attributes);
+    }
+}
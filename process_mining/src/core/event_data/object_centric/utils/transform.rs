@@ -0,0 +1,89 @@
+//! Transformations of OCEL Data
+use std::collections::HashSet;
+
+use crate::core::event_data::object_centric::ocel_struct::OCEL;
+
+/// Only keep the event and object attributes of an [`OCEL`] with a name in the passed sets
+///
+/// All other attributes are removed from the events and objects (including all attribute value changes of objects),
+/// as well as from the attribute declarations of the event and object types.
+pub fn project_attributes(
+    ocel: &mut OCEL,
+    keep_event_attrs: &HashSet<String>,
+    keep_object_attrs: &HashSet<String>,
+) {
+    for et in &mut ocel.event_types {
+        et.attributes.retain(|a| keep_event_attrs.contains(&a.name));
+    }
+    for ot in &mut ocel.object_types {
+        ot.attributes
+            .retain(|a| keep_object_attrs.contains(&a.name));
+    }
+    for e in &mut ocel.events {
+        e.attributes.retain(|a| keep_event_attrs.contains(&a.name));
+    }
+    for o in &mut ocel.objects {
+        o.attributes.retain(|a| keep_object_attrs.contains(&a.name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use super::*;
+    use crate::core::event_data::object_centric::ocel_struct::{
+        OCELAttributeType, OCELAttributeValue, OCELEventAttribute, OCELObjectAttribute,
+        OCELTypeAttribute,
+    };
+
+    #[test]
+    fn test_project_attributes() {
+        let mut ocel = crate::ocel![
+            events:
+            ("pay", ["o:1"]),
+            o2o:
+        ];
+        let time = DateTime::UNIX_EPOCH.fixed_offset();
+        for name in ["amount", "customer"] {
+            ocel.event_types[0]
+                .attributes
+                .push(OCELTypeAttribute::new(name, &OCELAttributeType::String));
+            ocel.events[0].attributes.push(OCELEventAttribute {
+                name: name.to_string(),
+                value: OCELAttributeValue::String("x".to_string()),
+            });
+        }
+        for name in ["price", "name", "price"] {
+            if !ocel.object_types[0]
+                .attributes
+                .iter()
+                .any(|a| a.name == name)
+            {
+                ocel.object_types[0]
+                    .attributes
+                    .push(OCELTypeAttribute::new(name, &OCELAttributeType::Integer));
+            }
+            ocel.objects[0].attributes.push(OCELObjectAttribute {
+                name: name.to_string(),
+                value: OCELAttributeValue::Integer(1),
+                time,
+            });
+        }
+        project_attributes(
+            &mut ocel,
+            &HashSet::from(["amount".to_string()]),
+            &HashSet::from(["price".to_string()]),
+        );
+        let names =
+            |attrs: &[OCELTypeAttribute]| attrs.iter().map(|a| a.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&ocel.event_types[0].attributes), vec!["amount"]);
+        assert_eq!(names(&ocel.object_types[0].attributes), vec!["price"]);
+        assert_eq!(ocel.events[0].attributes.len(), 1);
+        assert_eq!(ocel.events[0].attributes[0].name, "amount");
+        // Both value changes of the kept attribute remain
+        assert_eq!(ocel.objects[0].attributes.len(), 2);
+        assert!(ocel.objects[0].attributes.iter().all(|a| a.name == "price"));
+        assert_eq!(ocel.events[0].relationships.len(), 1);
+    }
+}
@@ -0,0 +1,548 @@
+//! Validation of Object-centric Event Data
+//!
+//! Detects inconsistencies in an [`OCEL`] which are not caught during import,
+//! but can lead to unexpected results in later analysis steps.
+//!
+//! See [`validate_ocel`] for a check against the OCEL 2.0 metamodel.
+use std::collections::{HashMap, HashSet};
+
+use macros_process_mining::register_binding;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    event_data::object_centric::ocel_struct::{OCELAttributeType, OCELAttributeValue, OCELType},
+    OCEL,
+};
+
+/// Timestamp-related issue detected by [`check_timestamp_consistency`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TimestampIssue {
+    /// The UTC offset of an event timestamp differs from the most common offset in the OCEL
+    #[serde(rename_all = "camelCase")]
+    UnusualOffset {
+        /// ID of the event
+        event_id: String,
+        /// UTC offset of the event timestamp (in seconds)
+        offset_seconds: i32,
+        /// Most common UTC offset of all event timestamps (in seconds)
+        expected_offset_seconds: i32,
+    },
+    /// An event occurs before the previous event (in OCEL order) involving the same object
+    #[serde(rename_all = "camelCase")]
+    OutOfOrder {
+        /// ID of the event
+        event_id: String,
+        /// ID of the involved object
+        object_id: String,
+        /// ID of the previous event involving the object, which has a later timestamp
+        previous_event_id: String,
+    },
+}
+
+impl std::fmt::Display for TimestampIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimestampIssue::UnusualOffset {
+                event_id,
+                offset_seconds,
+                expected_offset_seconds,
+            } => write!(
+                f,
+                "Event {event_id} has UTC offset of {offset_seconds}s (most common offset: {expected_offset_seconds}s)"
+            ),
+            TimestampIssue::OutOfOrder {
+                event_id,
+                object_id,
+                previous_event_id,
+            } => write!(
+                f,
+                "Event {event_id} occurs before previous event {previous_event_id} of object {object_id}"
+            ),
+        }
+    }
+}
+
+///
+/// Check the event timestamps of an [`OCEL`] for consistency
+///
+/// Reports:
+/// - [`TimestampIssue::UnusualOffset`] for all events with a UTC offset different from the most common offset in the OCEL.
+///   Mixed offsets are not wrong per se, but often indicate a timezone problem in the source data.
+/// - [`TimestampIssue::OutOfOrder`] for events with an earlier timestamp than the previous event (in the order of
+///   [`OCEL::events`]) involving the same object.
+///
+/// Issues are returned in the order of the events in the OCEL.
+pub fn check_timestamp_consistency(ocel: &OCEL) -> Vec<TimestampIssue> {
+    let mut offset_counts: HashMap<i32, usize> = HashMap::new();
+    for ev in &ocel.events {
+        *offset_counts
+            .entry(ev.time.offset().local_minus_utc())
+            .or_default() += 1;
+    }
+    // Ties are broken by the smaller offset to keep the result deterministic
+    let expected_offset = offset_counts
+        .into_iter()
+        .max_by(|(o1, c1), (o2, c2)| c1.cmp(c2).then(o2.cmp(o1)))
+        .map(|(o, _)| o);
+
+    let mut issues = Vec::new();
+    let mut last_event_of_object = HashMap::new();
+    for ev in &ocel.events {
+        let offset = ev.time.offset().local_minus_utc();
+        if let Some(expected_offset) = expected_offset {
+            if offset != expected_offset {
+                issues.push(TimestampIssue::UnusualOffset {
+                    event_id: ev.id.clone(),
+                    offset_seconds: offset,
+                    expected_offset_seconds: expected_offset,
+                });
+            }
+        }
+        let mut seen_objects = HashSet::new();
+        for rel in &ev.relationships {
+            if !seen_objects.insert(rel.object_id.as_str()) {
+                continue;
+            }
+            if let Some((prev_time, prev_id)) =
+                last_event_of_object.insert(rel.object_id.as_str(), (ev.time, ev.id.as_str()))
+            {
+                if prev_time > ev.time {
+                    issues.push(TimestampIssue::OutOfOrder {
+                        event_id: ev.id.clone(),
+                        object_id: rel.object_id.clone(),
+                        previous_event_id: prev_id.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    issues
+}
+
+/// Severity of an [`OCELValidationIssue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum OCELValidationSeverity {
+    /// The OCEL violates the OCEL 2.0 metamodel
+    Error,
+    /// The OCEL is valid, but likely contains unintended data
+    Warning,
+}
+
+/// Issue detected by [`validate_ocel`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum OCELValidationIssue {
+    /// Multiple events share the same ID
+    #[serde(rename_all = "camelCase")]
+    DuplicateEventId {
+        /// ID of the events
+        event_id: String,
+    },
+    /// Multiple objects share the same ID
+    #[serde(rename_all = "camelCase")]
+    DuplicateObjectId {
+        /// ID of the objects
+        object_id: String,
+    },
+    /// The type of an event is not declared in [`OCEL::event_types`]
+    #[serde(rename_all = "camelCase")]
+    UndeclaredEventType {
+        /// ID of the event
+        event_id: String,
+        /// Event type
+        event_type: String,
+    },
+    /// The type of an object is not declared in [`OCEL::object_types`]
+    #[serde(rename_all = "camelCase")]
+    UndeclaredObjectType {
+        /// ID of the object
+        object_id: String,
+        /// Object type
+        object_type: String,
+    },
+    /// An event-to-object relationship references an object which does not exist
+    #[serde(rename_all = "camelCase")]
+    UnknownE2OObject {
+        /// ID of the event
+        event_id: String,
+        /// ID of the referenced (missing) object
+        object_id: String,
+    },
+    /// An object-to-object relationship references an object which does not exist
+    #[serde(rename_all = "camelCase")]
+    UnknownO2OObject {
+        /// ID of the (source) object
+        object_id: String,
+        /// ID of the referenced (missing) object
+        target_object_id: String,
+    },
+    /// An event or object attribute is not declared for the event/object type
+    #[serde(rename_all = "camelCase")]
+    UndeclaredAttribute {
+        /// ID of the event or object
+        id: String,
+        /// Event or object type
+        type_name: String,
+        /// Attribute name
+        attribute: String,
+    },
+    /// The value of an event or object attribute does not match its declared type
+    ///
+    /// For instance, a `time` attribute with a string value (i.e., a timestamp which could not be parsed).
+    #[serde(rename_all = "camelCase")]
+    AttributeTypeMismatch {
+        /// ID of the event or object
+        id: String,
+        /// Attribute name
+        attribute: String,
+        /// Declared attribute type (e.g., `time`)
+        expected_type: String,
+        /// Type of the attribute value
+        found_type: String,
+    },
+}
+
+impl OCELValidationIssue {
+    /// Get the [`OCELValidationSeverity`] of the issue
+    pub fn severity(&self) -> OCELValidationSeverity {
+        match self {
+            OCELValidationIssue::UndeclaredAttribute { .. } => OCELValidationSeverity::Warning,
+            _ => OCELValidationSeverity::Error,
+        }
+    }
+}
+
+impl std::fmt::Display for OCELValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OCELValidationIssue::DuplicateEventId { event_id } => {
+                write!(f, "Duplicate event ID {event_id}")
+            }
+            OCELValidationIssue::DuplicateObjectId { object_id } => {
+                write!(f, "Duplicate object ID {object_id}")
+            }
+            OCELValidationIssue::UndeclaredEventType {
+                event_id,
+                event_type,
+            } => write!(f, "Event {event_id} has undeclared event type {event_type}"),
+            OCELValidationIssue::UndeclaredObjectType {
+                object_id,
+                object_type,
+            } => write!(
+                f,
+                "Object {object_id} has undeclared object type {object_type}"
+            ),
+            OCELValidationIssue::UnknownE2OObject {
+                event_id,
+                object_id,
+            } => write!(f, "Event {event_id} is related to unknown object {object_id}"),
+            OCELValidationIssue::UnknownO2OObject {
+                object_id,
+                target_object_id,
+            } => write!(
+                f,
+                "Object {object_id} is related to unknown object {target_object_id}"
+            ),
+            OCELValidationIssue::UndeclaredAttribute {
+                id,
+                type_name,
+                attribute,
+            } => write!(
+                f,
+                "Attribute {attribute} of {id} is not declared for type {type_name}"
+            ),
+            OCELValidationIssue::AttributeTypeMismatch {
+                id,
+                attribute,
+                expected_type,
+                found_type,
+            } => write!(
+                f,
+                "Attribute {attribute} of {id} has a {found_type} value (declared type: {expected_type})"
+            ),
+        }
+    }
+}
+
+/// Result of [`validate_ocel`], with all detected issues categorized by severity
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct OCELValidationReport {
+    /// Issues of severity [`OCELValidationSeverity::Error`]
+    pub errors: Vec<OCELValidationIssue>,
+    /// Issues of severity [`OCELValidationSeverity::Warning`]
+    pub warnings: Vec<OCELValidationIssue>,
+}
+
+impl OCELValidationReport {
+    /// Returns `true` if no errors were detected (warnings are allowed)
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn push(&mut self, issue: OCELValidationIssue) {
+        match issue.severity() {
+            OCELValidationSeverity::Error => self.errors.push(issue),
+            OCELValidationSeverity::Warning => self.warnings.push(issue),
+        }
+    }
+}
+
+/// Check the attributes of an event/object against the declared attributes of its type
+fn validate_attributes<'a>(
+    report: &mut OCELValidationReport,
+    id: &str,
+    type_name: &str,
+    declared: Option<&HashMap<&str, OCELAttributeType>>,
+    attributes: impl Iterator<Item = (&'a str, &'a OCELAttributeValue)>,
+) {
+    // Attributes of undeclared types are not reported individually
+    let Some(declared) = declared else {
+        return;
+    };
+    for (name, value) in attributes {
+        match declared.get(name) {
+            None => report.push(OCELValidationIssue::UndeclaredAttribute {
+                id: id.to_string(),
+                type_name: type_name.to_string(),
+                attribute: name.to_string(),
+            }),
+            Some(expected) => {
+                let found = value.get_type();
+                if found != OCELAttributeType::Null && found != *expected {
+                    report.push(OCELValidationIssue::AttributeTypeMismatch {
+                        id: id.to_string(),
+                        attribute: name.to_string(),
+                        expected_type: expected.to_type_string(),
+                        found_type: found.to_type_string(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Collect the declared attribute types per event/object type
+fn declared_attributes(types: &[OCELType]) -> HashMap<&str, HashMap<&str, OCELAttributeType>> {
+    types
+        .iter()
+        .map(|t| {
+            (
+                t.name.as_str(),
+                t.attributes
+                    .iter()
+                    .map(|a| {
+                        (
+                            a.name.as_str(),
+                            OCELAttributeType::from_type_str(&a.value_type),
+                        )
+                    })
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+///
+/// Validate an [`OCEL`] against the OCEL 2.0 metamodel
+///
+/// Checks that
+/// - event and object IDs are unique,
+/// - all used event and object types are declared,
+/// - all objects referenced in E2O and O2O relationships exist, and
+/// - all attributes are declared for their event/object type, with a value of the declared type
+///   (e.g., that `time` attributes hold parsed timestamps).
+///
+/// Undeclared attributes are reported as warnings, all other issues as errors.
+/// Issues are reported in the order of [`OCEL::events`] and [`OCEL::objects`].
+///
+#[register_binding]
+pub fn validate_ocel(ocel: &OCEL) -> OCELValidationReport {
+    let mut report = OCELValidationReport::default();
+    let event_types = declared_attributes(&ocel.event_types);
+    let object_types = declared_attributes(&ocel.object_types);
+
+    let mut seen_objects: HashSet<&str> = HashSet::with_capacity(ocel.objects.len());
+    let mut reported_objects: HashSet<&str> = HashSet::new();
+    for ob in &ocel.objects {
+        if !seen_objects.insert(ob.id.as_str()) && reported_objects.insert(ob.id.as_str()) {
+            report.push(OCELValidationIssue::DuplicateObjectId {
+                object_id: ob.id.clone(),
+            });
+        }
+    }
+
+    let mut seen_events: HashSet<&str> = HashSet::with_capacity(ocel.events.len());
+    let mut reported_events: HashSet<&str> = HashSet::new();
+    for ev in &ocel.events {
+        if !seen_events.insert(ev.id.as_str()) && reported_events.insert(ev.id.as_str()) {
+            report.push(OCELValidationIssue::DuplicateEventId {
+                event_id: ev.id.clone(),
+            });
+        }
+        let declared = event_types.get(ev.event_type.as_str());
+        if declared.is_none() {
+            report.push(OCELValidationIssue::UndeclaredEventType {
+                event_id: ev.id.clone(),
+                event_type: ev.event_type.clone(),
+            });
+        }
+        validate_attributes(
+            &mut report,
+            &ev.id,
+            &ev.event_type,
+            declared,
+            ev.attributes.iter().map(|a| (a.name.as_str(), &a.value)),
+        );
+        for rel in &ev.relationships {
+            if !seen_objects.contains(rel.object_id.as_str()) {
+                report.push(OCELValidationIssue::UnknownE2OObject {
+                    event_id: ev.id.clone(),
+                    object_id: rel.object_id.clone(),
+                });
+            }
+        }
+    }
+
+    for ob in &ocel.objects {
+        let declared = object_types.get(ob.object_type.as_str());
+        if declared.is_none() {
+            report.push(OCELValidationIssue::UndeclaredObjectType {
+                object_id: ob.id.clone(),
+                object_type: ob.object_type.clone(),
+            });
+        }
+        validate_attributes(
+            &mut report,
+            &ob.id,
+            &ob.object_type,
+            declared,
+            ob.attributes.iter().map(|a| (a.name.as_str(), &a.value)),
+        );
+        for rel in &ob.relationships {
+            if !seen_objects.contains(rel.object_id.as_str()) {
+                report.push(OCELValidationIssue::UnknownO2OObject {
+                    object_id: ob.id.clone(),
+                    target_object_id: rel.object_id.clone(),
+                });
+            }
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, TimeZone};
+
+    use super::*;
+    use crate::core::event_data::object_centric::ocel_struct::{
+        OCELEventAttribute, OCELRelationship, OCELTypeAttribute,
+    };
+    use crate::ocel;
+
+    #[test]
+    fn test_check_timestamp_consistency() {
+        let mut ocel = ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            ("pack", ["o:1", "i:1"]),
+            ("ship", ["o:1"]),
+            ("place", ["o:2"]),
+            o2o:
+        ];
+        assert!(check_timestamp_consistency(&ocel).is_empty());
+
+        // Same instant, but different offset
+        let plus_two = FixedOffset::east_opt(2 * 3600).unwrap();
+        ocel.events[3].time = ocel.events[3].time.with_timezone(&plus_two);
+        // Event before the previous event of o:1
+        ocel.events[2].time = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2019, 12, 31, 0, 0, 0)
+            .unwrap();
+
+        assert_eq!(
+            check_timestamp_consistency(&ocel),
+            vec![
+                TimestampIssue::OutOfOrder {
+                    event_id: "ev:3".to_string(),
+                    object_id: "o:1".to_string(),
+                    previous_event_id: "ev:2".to_string()
+                },
+                TimestampIssue::UnusualOffset {
+                    event_id: "ev:4".to_string(),
+                    offset_seconds: 7200,
+                    expected_offset_seconds: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_ocel() {
+        let mut ocel = ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            ("pack", ["o:1", "i:1"]),
+            o2o:
+            ("o:1", "i:1")
+        ];
+        assert_eq!(validate_ocel(&ocel), OCELValidationReport::default());
+
+        ocel.event_types
+            .iter_mut()
+            .find(|et| et.name == "place")
+            .unwrap()
+            .attributes
+            .push(OCELTypeAttribute::new("deadline", &OCELAttributeType::Time));
+        let ev = &mut ocel.events[0];
+        ev.attributes.push(OCELEventAttribute {
+            name: "deadline".to_string(),
+            value: OCELAttributeValue::String("tomorrow".to_string()),
+        });
+        ev.attributes.push(OCELEventAttribute {
+            name: "note".to_string(),
+            value: OCELAttributeValue::String("-".to_string()),
+        });
+        let ev_id = ev.id.clone();
+        ocel.events[1].id = ocel.events[0].id.clone();
+        ocel.events[1].event_type = "unpack".to_string();
+        ocel.events[1]
+            .relationships
+            .push(OCELRelationship::new("o:42", "order"));
+
+        let report = validate_ocel(&ocel);
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.warnings,
+            vec![OCELValidationIssue::UndeclaredAttribute {
+                id: ev_id.clone(),
+                type_name: "place".to_string(),
+                attribute: "note".to_string(),
+            }]
+        );
+        assert_eq!(report.errors.len(), 4);
+        assert!(report
+            .errors
+            .contains(&OCELValidationIssue::AttributeTypeMismatch {
+                id: ev_id.clone(),
+                attribute: "deadline".to_string(),
+                expected_type: "time".to_string(),
+                found_type: "string".to_string(),
+            }));
+        assert!(report
+            .errors
+            .contains(&OCELValidationIssue::DuplicateEventId { event_id: ev_id }));
+        assert!(report.errors.iter().any(|e| matches!(
+            e,
+            OCELValidationIssue::UndeclaredEventType { event_type, .. } if event_type == "unpack"
+        )));
+        assert!(report.errors.iter().any(|e| matches!(
+            e,
+            OCELValidationIssue::UnknownE2OObject { object_id, .. } if object_id == "o:42"
+        )));
+    }
+}
@@ -325,3 +325,78 @@ fn test_ocel_failing_xml() {
         now.elapsed()
     );
 }
+
+#[test]
+fn test_ocel_import_ignore_types() {
+    use crate::core::{
+        event_data::object_centric::{
+            ocel_json::import_ocel_json_slice_with,
+            ocel_xml::xml_ocel_import::{import_ocel_xml_slice_with, OCELImportOptions},
+        },
+        io::Exportable,
+    };
+    let ocel = crate::ocel![
+        events:
+        ("place", ["o:1", "i:1"]),
+        ("pack", ["o:1", "i:1"]),
+        ("ship", ["o:1"]),
+        o2o:
+        ("o:1", "i:1")
+    ];
+    let options = OCELImportOptions {
+        ignore_event_types: ["pack".to_string()].into(),
+        ignore_object_types: ["i".to_string()].into(),
+        ..Default::default()
+    };
+    let check = |ocel: crate::OCEL| {
+        assert_eq!(ocel.event_types.len(), 2);
+        assert_eq!(ocel.object_types.len(), 1);
+        assert_eq!(
+            ocel.events
+                .iter()
+                .map(|e| e.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["ev:1", "ev:3"]
+        );
+        assert_eq!(ocel.objects.len(), 1);
+        assert!(ocel.objects[0].relationships.is_empty());
+        assert!(ocel
+            .events
+            .iter()
+            .all(|e| e.relationships.iter().all(|r| r.object_id == "o:1")));
+    };
+    check(
+        import_ocel_xml_slice_with(&ocel.export_to_bytes("xml").unwrap(), options.clone()).unwrap(),
+    );
+    check(import_ocel_json_slice_with(&ocel.export_to_bytes("json").unwrap(), &options).unwrap());
+    let mut filtered = ocel;
+    options.apply_type_filters(&mut filtered);
+    check(filtered);
+}
+
+#[test]
+fn test_ocel_import_from_reader() {
+    use crate::core::{
+        event_data::object_centric::ocel_xml::xml_ocel_import::import_ocel_xml_reader,
+        io::Exportable,
+    };
+    let ocel = crate::ocel![
+        events:
+        ("place", ["o:1", "i:1"]),
+        ("ship", ["o:1"]),
+        o2o:
+        ("o:1", "i:1")
+    ];
+    let bytes = ocel.export_to_bytes("xml").unwrap();
+    let imported = import_ocel_xml_reader(std::io::BufReader::new(bytes.as_slice())).unwrap();
+    assert_eq!(imported.events.len(), 2);
+    assert_eq!(imported.objects.len(), 2);
+    assert_eq!(
+        imported
+            .objects
+            .iter()
+            .map(|o| o.relationships.len())
+            .sum::<usize>(),
+        1
+    );
+}
@@ -207,6 +207,90 @@ pub fn test_invalid_xes_file_empty() {
     assert!(matches!(res, Err(XESParseError::NoTopLevelLog)));
 }
 
+#[test]
+pub fn test_xes_parse_error_display() {
+    // Display output of existing variants must stay stable
+    assert_eq!(
+        XESParseError::NoTopLevelLog.to_string(),
+        "Failed to parse XES: NoTopLevelLog"
+    );
+    assert_eq!(
+        XESParseError::MissingTimestamp { trace: 2, event: 5 }.to_string(),
+        "Failed to parse XES: Missing timestamp for event 5 in trace 2"
+    );
+    assert_eq!(
+        XESParseError::BadTimestamp {
+            value: "yesterday".to_string()
+        }
+        .to_string(),
+        "Failed to parse XES: Invalid timestamp \"yesterday\""
+    );
+    assert_eq!(
+        XESParseError::UnexpectedTag {
+            tag: "foo".to_string()
+        }
+        .to_string(),
+        "Failed to parse XES: Unexpected tag <foo>"
+    );
+}
+
+#[test]
+pub fn test_strict_xes_import_errors() {
+    fn xes(events: &str) -> Vec<u8> {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<log xes.version="1.0">
+<trace><string key="concept:name" value="0"/>
+<event><string key="concept:name" value="a"/><date key="time:timestamp" value="2020-01-01T00:00:00+00:00"/></event>
+</trace>
+<trace><string key="concept:name" value="1"/>
+<event><string key="concept:name" value="a"/><date key="time:timestamp" value="2020-01-01T00:00:00+00:00"/></event>
+{events}
+</trace>
+</log>"#
+        )
+        .into_bytes()
+    }
+    let strict = || XESImportOptions {
+        strict: true,
+        verbose: false,
+        sort_events_with_timestamp_key: Some("time:timestamp".to_string()),
+        ..Default::default()
+    };
+    let lenient = || XESImportOptions {
+        strict: false,
+        ..strict()
+    };
+
+    let bad_timestamp = xes(r#"<event><date key="time:timestamp" value="yesterday"/></event>"#);
+    let res = import_xes_slice(&bad_timestamp, false, strict());
+    assert!(matches!(res, Err(XESParseError::BadTimestamp { value }) if value == "yesterday"));
+    assert!(import_xes_slice(&bad_timestamp, false, lenient()).is_ok());
+
+    let unexpected_tag = xes(r#"<event><timestamp key="time:timestamp" value="2020"/></event>"#);
+    let res = import_xes_slice(&unexpected_tag, false, strict());
+    assert!(matches!(res, Err(XESParseError::UnexpectedTag { tag }) if tag == "timestamp"));
+    assert!(import_xes_slice(&unexpected_tag, false, lenient()).is_ok());
+
+    let missing_timestamp = xes(r#"<event><string key="concept:name" value="b"/></event>"#);
+    let res = import_xes_slice(&missing_timestamp, false, strict());
+    assert!(matches!(
+        res,
+        Err(XESParseError::MissingTimestamp { trace: 1, event: 1 })
+    ));
+    assert!(import_xes_slice(&missing_timestamp, false, lenient()).is_ok());
+    // Timestamps are only required for sorting events
+    let res = import_xes_slice(
+        &missing_timestamp,
+        false,
+        XESImportOptions {
+            sort_events_with_timestamp_key: None,
+            ..strict()
+        },
+    );
+    assert_eq!(res.unwrap().traces[1].events.len(), 2);
+}
+
 #[test]
 pub fn test_nested_global_event_attr() {
     let path = get_test_data_path().join("xes").join("small-example.xes");
@@ -314,6 +398,107 @@ pub fn test_xes_nested_attrs() {
     assert_eq!(xes, out_xes);
 }
 
+#[test]
+pub fn test_xes_import_from_reader() {
+    use crate::core::event_data::case_centric::xes::{
+        import_xes::import_xes_reader, stream_xes::stream_xes_reader,
+    };
+    use std::io::Write;
+    let xes = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<log xes.version="1.0">
+  <trace>
+    <string key="concept:name" value="c1"/>
+    <event><string key="concept:name" value="a"/></event>
+    <event><string key="concept:name" value="b"/></event>
+  </trace>
+  <trace>
+    <string key="concept:name" value="c2"/>
+    <event><string key="concept:name" value="c"/></event>
+  </trace>
+</log>"#;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(xes.as_bytes()).unwrap();
+    let gz_bytes = encoder.finish().unwrap();
+
+    // Use a non-slice reader (i.e., not implementing `BufRead`)
+    let log = import_xes_reader(
+        std::io::Cursor::new(gz_bytes).take(u64::MAX),
+        XESImportOptions::default(),
+        true,
+    )
+    .unwrap();
+    assert_eq!(log.traces.len(), 2);
+    assert_eq!(log.traces[0].events.len(), 2);
+
+    let (mut stream, _log_data) =
+        stream_xes_reader(xes.as_bytes(), XESImportOptions::default(), false).unwrap();
+    assert_eq!(
+        stream.map(|t| t.events.len()).collect::<Vec<_>>(),
+        vec![2, 1]
+    );
+}
+
+#[test]
+pub fn test_attribute_allowlist() {
+    use crate::core::event_data::case_centric::xes::import_xes::{
+        build_ignore_attributes, AttributeAllowlist,
+    };
+    let allowlist = AttributeAllowlist::new(&build_ignore_attributes([
+        "concept:name",
+        "org:*",
+        "org:role*",
+        "case:a*",
+        "cost",
+    ]));
+    for key in [
+        "concept:name",
+        "org:",
+        "org:role",
+        "org:resource",
+        "case:ab",
+        "cost",
+    ] {
+        assert!(allowlist.contains(key), "{key}");
+    }
+    for key in ["concept:names", "org", "case:", "case:b", "costs", "a"] {
+        assert!(!allowlist.contains(key), "{key}");
+    }
+}
+
+#[test]
+pub fn test_xes_import_attribute_prefixes() {
+    use crate::core::event_data::case_centric::{
+        xes::import_xes::build_ignore_attributes, XESEditableAttribute,
+    };
+    let xes = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<log xes.version="1.0">
+  <trace>
+    <string key="concept:name" value="c1"/>
+    <event>
+      <string key="concept:name" value="a"/>
+      <string key="org:resource" value="Alice"/>
+      <string key="org:role" value="Clerk"/>
+      <int key="cost" value="5"/>
+    </event>
+  </trace>
+</log>"#;
+    // Keep-list computed at runtime (i.e., owned strings)
+    let keep: Vec<String> = vec!["concept:name".to_string(), "org:*".to_string()];
+    let log = import_xes_slice(
+        xes.as_bytes(),
+        false,
+        XESImportOptions {
+            ignore_event_attributes_except: Some(build_ignore_attributes(keep)),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let attrs = &log.traces[0].events[0].attributes;
+    assert_eq!(attrs.len(), 3);
+    assert!(attrs.get_by_key("org:role").is_some());
+    assert!(attrs.get_by_key("cost").is_none());
+}
+
 // #[test]
 // pub fn test_2017bpic_log() {
 
@@ -1,5 +1,6 @@
 //! Shared timestamp parsing utilities for event data importers
 
+use crate::diagnostics::warn_diagnostic;
 use chrono::{DateTime, FixedOffset, NaiveDateTime};
 
 /// Parse a timestamp string to `DateTime<FixedOffset>`, trying multiple formats.
@@ -89,7 +90,7 @@ pub fn parse_timestamp<'a>(
     }
 
     if verbose {
-        eprintln!("Failed to parse timestamp: {time}");
+        warn_diagnostic!("Failed to parse timestamp: {time}");
     }
     Err("Unexpected timestamp format")
 }
@@ -0,0 +1,228 @@
+//! Behavioral analysis of Petri nets
+//!
+//! Based on a bounded exploration of the reachable markings (see [`reachable_markings`]).
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use uuid::Uuid;
+
+use super::{ArcType, Marking, PetriNet, PlaceID, TransitionID};
+
+/// Default maximum number of markings explored by [`concurrent_transition_pairs`]
+pub const DEFAULT_MAX_MARKINGS: usize = 10_000;
+
+/// Marking with places in a fixed order and without empty places (so it can be hashed)
+type NormalizedMarking = BTreeMap<Uuid, u64>;
+
+/// Weighted pre- and postset of a transition
+type TransitionEffect = (HashMap<Uuid, u64>, HashMap<Uuid, u64>);
+
+/// Result of a bounded exploration of the reachable markings of a [`PetriNet`]
+#[derive(Debug, Clone, Default)]
+pub struct ReachableMarkings {
+    /// Reachable markings, in breadth-first order (starting with the initial marking)
+    pub markings: Vec<Marking>,
+    /// Whether the exploration stopped early because the bound was reached
+    ///
+    /// If `true`, there are reachable markings not included in `markings`
+    /// (e.g., because the net is unbounded).
+    pub truncated: bool,
+}
+
+fn transition_effects(net: &PetriNet) -> HashMap<Uuid, TransitionEffect> {
+    let mut effects: HashMap<Uuid, TransitionEffect> = net
+        .transitions
+        .keys()
+        .map(|id| (*id, Default::default()))
+        .collect();
+    for arc in &net.arcs {
+        match arc.from_to {
+            ArcType::PlaceTransition(p, t) => {
+                *effects.entry(t).or_default().0.entry(p).or_default() += arc.weight;
+            }
+            ArcType::TransitionPlace(t, p) => {
+                *effects.entry(t).or_default().1.entry(p).or_default() += arc.weight;
+            }
+        }
+    }
+    effects
+}
+
+fn covers(marking: &NormalizedMarking, required: &HashMap<Uuid, u64>) -> bool {
+    required
+        .iter()
+        .all(|(p, w)| marking.get(p).is_some_and(|tokens| tokens >= w))
+}
+
+fn explore(
+    net: &PetriNet,
+    effects: &HashMap<Uuid, TransitionEffect>,
+    max_markings: usize,
+) -> (Vec<NormalizedMarking>, bool) {
+    let Some(initial) = net.initial_marking.as_ref() else {
+        return (Vec::new(), false);
+    };
+    let initial: NormalizedMarking = initial
+        .iter()
+        .filter(|(_, tokens)| **tokens > 0)
+        .map(|(p, tokens)| (p.0, *tokens))
+        .collect();
+    let mut visited: HashSet<NormalizedMarking> = HashSet::new();
+    let mut ret = Vec::new();
+    let mut queue = VecDeque::new();
+    visited.insert(initial.clone());
+    queue.push_back(initial);
+    while let Some(marking) = queue.pop_front() {
+        if ret.len() >= max_markings {
+            return (ret, true);
+        }
+        for (pre, post) in effects.values() {
+            if !covers(&marking, pre) {
+                continue;
+            }
+            let mut new_marking = marking.clone();
+            for (p, w) in pre {
+                let tokens = new_marking.get_mut(p).unwrap();
+                *tokens -= w;
+                if *tokens == 0 {
+                    new_marking.remove(p);
+                }
+            }
+            for (p, w) in post {
+                *new_marking.entry(*p).or_default() += w;
+            }
+            if !visited.contains(&new_marking) {
+                visited.insert(new_marking.clone());
+                queue.push_back(new_marking);
+            }
+        }
+        ret.push(marking);
+    }
+    (ret, false)
+}
+
+/// Explore the markings reachable from the initial marking of a [`PetriNet`] (breadth-first)
+///
+/// At most `max_markings` markings are explored; if there are more, [`ReachableMarkings::truncated`] is set.
+/// Returns no markings if the net has no initial marking.
+pub fn reachable_markings(net: &PetriNet, max_markings: usize) -> ReachableMarkings {
+    let (markings, truncated) = explore(net, &transition_effects(net), max_markings);
+    ReachableMarkings {
+        markings: markings
+            .into_iter()
+            .map(|m| m.into_iter().map(|(p, w)| (PlaceID(p), w)).collect())
+            .collect(),
+        truncated,
+    }
+}
+
+/// Get all pairs of transitions which can be enabled concurrently in some reachable marking
+///
+/// Explores at most [`DEFAULT_MAX_MARKINGS`] markings, see [`concurrent_transition_pairs_bounded`] for details.
+pub fn concurrent_transition_pairs(net: &PetriNet) -> HashSet<(TransitionID, TransitionID)> {
+    concurrent_transition_pairs_bounded(net, DEFAULT_MAX_MARKINGS).0
+}
+
+/// Get all pairs of transitions which can be enabled concurrently in some reachable marking,
+/// exploring at most `max_markings` markings
+///
+/// Two (distinct) transitions are enabled concurrently in a marking if the marking holds enough tokens
+/// to fire both at the same time, i.e., if it covers the sum of their (weighted) presets.
+/// In particular, transitions in conflict over a single token are not concurrent.
+/// Each pair is only included once, with the smaller [`TransitionID`] first.
+///
+/// Additionally returns whether the exploration was truncated (see [`ReachableMarkings::truncated`]).
+/// In that case, the returned pairs are an under-approximation: Pairs only enabled concurrently in
+/// unexplored markings are missing.
+/// If the net has no initial marking, no pairs are returned.
+pub fn concurrent_transition_pairs_bounded(
+    net: &PetriNet,
+    max_markings: usize,
+) -> (HashSet<(TransitionID, TransitionID)>, bool) {
+    let effects = transition_effects(net);
+    let (markings, truncated) = explore(net, &effects, max_markings);
+    let mut transitions: Vec<(&Uuid, &HashMap<Uuid, u64>)> =
+        effects.iter().map(|(t, (pre, _))| (t, pre)).collect();
+    transitions.sort_by_key(|(t, _)| **t);
+    let mut ret = HashSet::new();
+    for marking in &markings {
+        let enabled: Vec<_> = transitions
+            .iter()
+            .filter(|(_, pre)| covers(marking, pre))
+            .collect();
+        for (i, (a, pre_a)) in enabled.iter().enumerate() {
+            for (b, pre_b) in &enabled[i + 1..] {
+                let pair = (TransitionID(**a), TransitionID(**b));
+                if ret.contains(&pair) {
+                    continue;
+                }
+                let mut combined = (*pre_a).clone();
+                for (p, w) in pre_b.iter() {
+                    *combined.entry(*p).or_default() += w;
+                }
+                if covers(marking, &combined) {
+                    ret.insert(pair);
+                }
+            }
+        }
+    }
+    (ret, truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concurrent_transition_pairs() {
+        // start -> (a || b) -> end, and a choice between c and d afterwards
+        let mut net = PetriNet::new();
+        let p0 = net.add_place(None);
+        let (p1, p2, p3, p4, p5, p6) = (
+            net.add_place(None),
+            net.add_place(None),
+            net.add_place(None),
+            net.add_place(None),
+            net.add_place(None),
+            net.add_place(None),
+        );
+        let start = net.add_transition(Some("start".into()), None);
+        let a = net.add_transition(Some("a".into()), None);
+        let b = net.add_transition(Some("b".into()), None);
+        let end = net.add_transition(Some("end".into()), None);
+        let c = net.add_transition(Some("c".into()), None);
+        let d = net.add_transition(Some("d".into()), None);
+        net.add_arc(ArcType::place_to_transition(p0, start), None);
+        net.add_arc(ArcType::transition_to_place(start, p1), None);
+        net.add_arc(ArcType::transition_to_place(start, p2), None);
+        net.add_arc(ArcType::place_to_transition(p1, a), None);
+        net.add_arc(ArcType::place_to_transition(p2, b), None);
+        net.add_arc(ArcType::transition_to_place(a, p3), None);
+        net.add_arc(ArcType::transition_to_place(b, p4), None);
+        net.add_arc(ArcType::place_to_transition(p3, end), None);
+        net.add_arc(ArcType::place_to_transition(p4, end), None);
+        net.add_arc(ArcType::transition_to_place(end, p5), None);
+        net.add_arc(ArcType::place_to_transition(p5, c), None);
+        net.add_arc(ArcType::place_to_transition(p5, d), None);
+        net.add_arc(ArcType::transition_to_place(c, p6), None);
+        net.add_arc(ArcType::transition_to_place(d, p6), None);
+
+        assert!(concurrent_transition_pairs(&net).is_empty());
+
+        net.initial_marking = Some(Marking::from([(p0, 1)]));
+        let reachable = reachable_markings(&net, DEFAULT_MAX_MARKINGS);
+        assert_eq!(reachable.markings.len(), 7);
+        assert!(!reachable.truncated);
+        let expected = if a < b { (a, b) } else { (b, a) };
+        assert_eq!(concurrent_transition_pairs(&net), HashSet::from([expected]));
+
+        // Only the initial marking is explored
+        let (pairs, truncated) = concurrent_transition_pairs_bounded(&net, 1);
+        assert!(pairs.is_empty());
+        assert!(truncated);
+
+        // Two tokens in p5 allow c and d to fire concurrently
+        net.initial_marking = Some(Marking::from([(p5, 2)]));
+        let expected = if c < d { (c, d) } else { (d, c) };
+        assert_eq!(concurrent_transition_pairs(&net), HashSet::from([expected]));
+    }
+}
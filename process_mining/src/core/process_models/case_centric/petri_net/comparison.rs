@@ -0,0 +1,474 @@
+//! Comparison of Petri nets
+//!
+//! Structural comparison (up to isomorphism, i.e., ignoring the concrete IDs of places and transitions)
+//! and bounded language comparison (based on playout).
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use uuid::Uuid;
+
+use super::{ArcType, Marking, PetriNet, PlaceID};
+
+/// Marking with places in a fixed order and without empty places (so it can be compared and hashed)
+type NormalizedMarking = BTreeMap<Uuid, u64>;
+
+/// Adjacent arcs of a node as (is outgoing, weight, color of neighbor)
+type NeighborSignature = Vec<(bool, u64, usize)>;
+
+/// Weighted places of a transition's pre- or postset
+type WeightedPlaces = Vec<(Uuid, u64)>;
+
+fn normalize_marking(marking: &Marking) -> NormalizedMarking {
+    marking
+        .iter()
+        .filter(|(_, tokens)| **tokens > 0)
+        .map(|(p, tokens)| (p.0, *tokens))
+        .collect()
+}
+
+/// Arc weights of a [`PetriNet`] by (source node, target node)
+///
+/// Weights of multiple arcs between the same nodes are summed up.
+fn arc_weights(net: &PetriNet) -> HashMap<(Uuid, Uuid), u64> {
+    let mut ret: HashMap<(Uuid, Uuid), u64> = HashMap::new();
+    for arc in &net.arcs {
+        let key = match arc.from_to {
+            ArcType::PlaceTransition(from, to) | ArcType::TransitionPlace(from, to) => (from, to),
+        };
+        *ret.entry(key).or_default() += arc.weight;
+    }
+    ret
+}
+
+/// Initial color of a node, used for the color refinement in [`PetriNet::structurally_equal`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum InitialColor {
+    Place {
+        initial_tokens: u64,
+        final_tokens: Vec<u64>,
+    },
+    Transition {
+        label: Option<String>,
+    },
+}
+
+/// Helper for checking structural equality (isomorphism) of two Petri nets
+struct IsomorphismChecker<'a> {
+    nodes: [Vec<Uuid>; 2],
+    colors: [HashMap<Uuid, usize>; 2],
+    arcs: [HashMap<(Uuid, Uuid), u64>; 2],
+    neighbors: [HashMap<Uuid, HashSet<Uuid>>; 2],
+    nets: [&'a PetriNet; 2],
+}
+
+impl<'a> IsomorphismChecker<'a> {
+    fn new(a: &'a PetriNet, b: &'a PetriNet) -> Self {
+        let nets = [a, b];
+        let nodes = nets.map(|net| {
+            let mut nodes: Vec<Uuid> = net
+                .places
+                .keys()
+                .chain(net.transitions.keys())
+                .copied()
+                .collect();
+            nodes.sort();
+            nodes
+        });
+        let arcs = nets.map(arc_weights);
+        let neighbors = arcs.clone().map(|arcs| {
+            let mut neighbors: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+            for (from, to) in arcs.keys() {
+                neighbors.entry(*from).or_default().insert(*to);
+                neighbors.entry(*to).or_default().insert(*from);
+            }
+            neighbors
+        });
+        let mut checker = Self {
+            nodes,
+            colors: [HashMap::new(), HashMap::new()],
+            arcs,
+            neighbors,
+            nets,
+        };
+        checker.refine_colors();
+        checker
+    }
+
+    fn initial_color(net: &PetriNet, node: &Uuid) -> InitialColor {
+        if let Some(t) = net.transitions.get(node) {
+            return InitialColor::Transition {
+                label: t.label.clone(),
+            };
+        }
+        let p = PlaceID(*node);
+        let initial_tokens = net
+            .initial_marking
+            .as_ref()
+            .and_then(|m| m.get(&p).copied())
+            .unwrap_or_default();
+        let mut final_tokens: Vec<u64> = net
+            .final_markings
+            .iter()
+            .flatten()
+            .map(|m| m.get(&p).copied().unwrap_or_default())
+            .collect();
+        final_tokens.sort();
+        InitialColor::Place {
+            initial_tokens,
+            final_tokens,
+        }
+    }
+
+    /// Iterative color refinement (1-dimensional Weisfeiler-Leman) on both nets at once
+    ///
+    /// Colors are interned using a shared map, so that they are comparable between both nets.
+    fn refine_colors(&mut self) {
+        let mut initial_interner: HashMap<InitialColor, usize> = HashMap::new();
+        for i in 0..2 {
+            for node in &self.nodes[i] {
+                let color = Self::initial_color(self.nets[i], node);
+                let next = initial_interner.len();
+                let c = *initial_interner.entry(color).or_insert(next);
+                self.colors[i].insert(*node, c);
+            }
+        }
+        let mut num_colors = initial_interner.len();
+        loop {
+            let mut interner: HashMap<(usize, NeighborSignature), usize> = HashMap::new();
+            let mut new_colors: [HashMap<Uuid, usize>; 2] = [HashMap::new(), HashMap::new()];
+            for (i, new_colors_i) in new_colors.iter_mut().enumerate() {
+                for node in &self.nodes[i] {
+                    let mut signature: NeighborSignature = self.neighbors[i]
+                        .get(node)
+                        .into_iter()
+                        .flatten()
+                        .flat_map(|n| {
+                            let outgoing = self.arcs[i]
+                                .get(&(*node, *n))
+                                .map(|w| (true, *w, self.colors[i][n]));
+                            let incoming = self.arcs[i]
+                                .get(&(*n, *node))
+                                .map(|w| (false, *w, self.colors[i][n]));
+                            outgoing.into_iter().chain(incoming)
+                        })
+                        .collect();
+                    signature.sort();
+                    let next = interner.len();
+                    let c = *interner
+                        .entry((self.colors[i][node], signature))
+                        .or_insert(next);
+                    new_colors_i.insert(*node, c);
+                }
+            }
+            self.colors = new_colors;
+            if interner.len() == num_colors {
+                break;
+            }
+            num_colors = interner.len();
+        }
+    }
+
+    fn color_histogram(&self, i: usize) -> HashMap<usize, usize> {
+        let mut ret = HashMap::new();
+        for c in self.colors[i].values() {
+            *ret.entry(*c).or_default() += 1;
+        }
+        ret
+    }
+
+    /// Check if mapping `a` (of the first net) to `b` (of the second net) is consistent with the partial `mapping`
+    fn is_consistent(
+        &self,
+        a: &Uuid,
+        b: &Uuid,
+        mapping: &HashMap<Uuid, Uuid>,
+        rev_mapping: &HashMap<Uuid, Uuid>,
+    ) -> bool {
+        let neighbors_a = self.neighbors[0].get(a).into_iter().flatten();
+        let neighbors_b = self.neighbors[1].get(b).into_iter().flatten();
+        neighbors_a
+            .filter(|n| mapping.contains_key(*n) || *n == a)
+            .all(|n| {
+                let m = if n == a { b } else { &mapping[n] };
+                self.arcs[0].get(&(*a, *n)) == self.arcs[1].get(&(*b, *m))
+                    && self.arcs[0].get(&(*n, *a)) == self.arcs[1].get(&(*m, *b))
+            })
+            && neighbors_b
+                .filter(|m| rev_mapping.contains_key(*m) || *m == b)
+                .all(|m| {
+                    let n = if m == b { a } else { &rev_mapping[m] };
+                    self.arcs[0].get(&(*a, *n)) == self.arcs[1].get(&(*b, *m))
+                        && self.arcs[0].get(&(*n, *a)) == self.arcs[1].get(&(*m, *b))
+                })
+    }
+
+    /// Backtracking search for a color-preserving bijection, which also preserves arcs and final markings
+    fn find_mapping(
+        &self,
+        order: &[Uuid],
+        mapping: &mut HashMap<Uuid, Uuid>,
+        rev_mapping: &mut HashMap<Uuid, Uuid>,
+        candidates: &HashMap<usize, Vec<Uuid>>,
+    ) -> bool {
+        let Some((a, rest)) = order.split_first() else {
+            return self.final_markings_match(mapping);
+        };
+        let color = self.colors[0][a];
+        for b in candidates.get(&color).into_iter().flatten() {
+            if rev_mapping.contains_key(b) || !self.is_consistent(a, b, mapping, rev_mapping) {
+                continue;
+            }
+            mapping.insert(*a, *b);
+            rev_mapping.insert(*b, *a);
+            if self.find_mapping(rest, mapping, rev_mapping, candidates) {
+                return true;
+            }
+            mapping.remove(a);
+            rev_mapping.remove(b);
+        }
+        false
+    }
+
+    /// Places which are not part of the net (and thus not mapped) never match
+    fn final_markings_match(&self, mapping: &HashMap<Uuid, Uuid>) -> bool {
+        let Some(mapped) = self.nets[0]
+            .final_markings
+            .iter()
+            .flatten()
+            .map(|m| {
+                normalize_marking(m)
+                    .into_iter()
+                    .map(|(p, tokens)| Some((*mapping.get(&p)?, tokens)))
+                    .collect::<Option<NormalizedMarking>>()
+            })
+            .collect::<Option<HashSet<NormalizedMarking>>>()
+        else {
+            return false;
+        };
+        let other: HashSet<NormalizedMarking> = self.nets[1]
+            .final_markings
+            .iter()
+            .flatten()
+            .map(normalize_marking)
+            .collect();
+        mapped == other
+    }
+
+    fn check(&self) -> bool {
+        if self.nodes[0].len() != self.nodes[1].len()
+            || self.nets[0].places.len() != self.nets[1].places.len()
+            || self.arcs[0].len() != self.arcs[1].len()
+            || self.nets[0].initial_marking.is_some() != self.nets[1].initial_marking.is_some()
+            || self.nets[0].final_markings.is_some() != self.nets[1].final_markings.is_some()
+            || self.color_histogram(0) != self.color_histogram(1)
+        {
+            return false;
+        }
+        let mut candidates: HashMap<usize, Vec<Uuid>> = HashMap::new();
+        for node in &self.nodes[1] {
+            candidates
+                .entry(self.colors[1][node])
+                .or_default()
+                .push(*node);
+        }
+        // Map nodes with rare colors first, to reduce backtracking
+        let mut order = self.nodes[0].clone();
+        order.sort_by_key(|n| candidates[&self.colors[0][n]].len());
+        self.find_mapping(
+            &order,
+            &mut HashMap::new(),
+            &mut HashMap::new(),
+            &candidates,
+        )
+    }
+}
+
+/// Collect all visible label sequences (with at most `max_len` visible labels) of the passed [`PetriNet`]
+///
+/// If the net has final markings, only sequences reaching one of them are included.
+/// Otherwise, all sequences reachable from the initial marking are included.
+fn bounded_language(net: &PetriNet, max_len: usize) -> HashSet<Vec<String>> {
+    let arcs = arc_weights(net);
+    let mut transitions: Vec<(Option<&String>, WeightedPlaces, WeightedPlaces)> = net
+        .transitions
+        .iter()
+        .map(|(id, t)| {
+            let pre = arcs
+                .iter()
+                .filter(|((_, to), _)| to == id)
+                .map(|((from, _), w)| (*from, *w))
+                .collect();
+            let post = arcs
+                .iter()
+                .filter(|((from, _), _)| from == id)
+                .map(|((_, to), w)| (*to, *w))
+                .collect();
+            (t.label.as_ref(), pre, post)
+        })
+        .collect();
+    transitions.sort();
+    let final_markings: Option<HashSet<NormalizedMarking>> = net
+        .final_markings
+        .as_ref()
+        .map(|fms| fms.iter().map(normalize_marking).collect());
+    let initial: NormalizedMarking = net
+        .initial_marking
+        .as_ref()
+        .map(normalize_marking)
+        .unwrap_or_default();
+
+    let mut ret = HashSet::new();
+    let mut visited: HashSet<(NormalizedMarking, Vec<String>)> = HashSet::new();
+    let mut queue: VecDeque<(NormalizedMarking, Vec<String>)> = VecDeque::new();
+    visited.insert((initial.clone(), Vec::new()));
+    queue.push_back((initial, Vec::new()));
+    while let Some((marking, trace)) = queue.pop_front() {
+        if final_markings
+            .as_ref()
+            .is_none_or(|fms| fms.contains(&marking))
+        {
+            ret.insert(trace.clone());
+        }
+        for (label, pre, post) in &transitions {
+            if label.is_some() && trace.len() >= max_len {
+                continue;
+            }
+            if !pre
+                .iter()
+                .all(|(p, w)| marking.get(p).is_some_and(|tokens| tokens >= w))
+            {
+                continue;
+            }
+            let mut new_marking = marking.clone();
+            for (p, w) in pre {
+                let tokens = new_marking.get_mut(p).unwrap();
+                *tokens -= w;
+                if *tokens == 0 {
+                    new_marking.remove(p);
+                }
+            }
+            for (p, w) in post {
+                *new_marking.entry(*p).or_default() += w;
+            }
+            let mut new_trace = trace.clone();
+            if let Some(label) = label {
+                new_trace.push((*label).clone());
+            }
+            let state = (new_marking, new_trace);
+            if !visited.contains(&state) {
+                visited.insert(state.clone());
+                queue.push_back(state);
+            }
+        }
+    }
+    ret
+}
+
+impl PetriNet {
+    /// Check if this [`PetriNet`] is structurally equal to another one
+    ///
+    /// Two nets are structurally equal if they are isomorphic, i.e., if there is a bijection between
+    /// their places and transitions (ignoring their IDs), which preserves transition labels, arcs (including weights),
+    /// as well as the initial and final markings.
+    ///
+    /// Uses color refinement followed by a backtracking search, which is fast for typical (discovered) nets,
+    /// but can take exponential time in the worst case (e.g., for highly symmetric nets).
+    pub fn structurally_equal(&self, other: &PetriNet) -> bool {
+        IsomorphismChecker::new(self, other).check()
+    }
+
+    /// Check if this [`PetriNet`] has the same language as another one, up to sequences of `max_len` visible labels
+    ///
+    /// Languages are computed using a bounded playout from the initial marking, where silent transitions
+    /// do not count towards the length.
+    /// If a net has final markings, only sequences reaching one of them are part of its language.
+    /// Otherwise, all sequences reachable from the initial marking are part of its language.
+    ///
+    /// _Note_: Playout does not terminate for nets in which silent transitions can produce unboundedly many tokens.
+    pub fn language_equivalent_up_to(&self, other: &PetriNet, max_len: usize) -> bool {
+        bounded_language(self, max_len) == bounded_language(other, max_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::process_models::case_centric::petri_net::{
+        ArcType, Marking, PetriNet, PlaceID,
+    };
+
+    /// Sequence `a`, then choice between `b` and a silent transition
+    fn sample_net(with_tau: bool) -> PetriNet {
+        let mut net = PetriNet::new();
+        let p1 = net.add_place(None);
+        let p2 = net.add_place(None);
+        let p3 = net.add_place(None);
+        let a = net.add_transition(Some("a".to_string()), None);
+        let b = net.add_transition(Some("b".to_string()), None);
+        net.add_arc(ArcType::place_to_transition(p1, a), None);
+        net.add_arc(ArcType::transition_to_place(a, p2), None);
+        net.add_arc(ArcType::place_to_transition(p2, b), None);
+        net.add_arc(ArcType::transition_to_place(b, p3), None);
+        if with_tau {
+            let tau = net.add_transition(None, None);
+            net.add_arc(ArcType::place_to_transition(p2, tau), None);
+            net.add_arc(ArcType::transition_to_place(tau, p3), None);
+        }
+        net.initial_marking = Some(Marking::from([(p1, 1)]));
+        net.final_markings = Some(vec![Marking::from([(p3, 1)])]);
+        net
+    }
+
+    #[test]
+    fn structural_equality_ignores_ids() {
+        let net = sample_net(true);
+        assert!(net.structurally_equal(&sample_net(true)));
+        assert!(net.structurally_equal(&net));
+        assert!(!net.structurally_equal(&sample_net(false)));
+
+        let mut other = sample_net(true);
+        let t = other
+            .transitions
+            .values_mut()
+            .find(|t| t.label.as_deref() == Some("b"))
+            .unwrap();
+        t.label = Some("c".to_string());
+        assert!(!net.structurally_equal(&other));
+
+        let mut other = sample_net(true);
+        other.final_markings = Some(vec![]);
+        assert!(!net.structurally_equal(&other));
+    }
+
+    #[test]
+    fn structural_equality_with_dangling_final_marking_place() {
+        let mut net = sample_net(true);
+        let dangling = PlaceID(uuid::Uuid::new_v4());
+        net.final_markings
+            .as_mut()
+            .unwrap()
+            .push(Marking::from([(dangling, 1)]));
+        let mut other = sample_net(true);
+        other
+            .final_markings
+            .as_mut()
+            .unwrap()
+            .push(Marking::from([(PlaceID(uuid::Uuid::new_v4()), 1)]));
+        assert!(!net.structurally_equal(&other));
+        assert!(!net.structurally_equal(&net));
+        assert!(!sample_net(true).structurally_equal(&net));
+    }
+
+    #[test]
+    fn language_equivalence() {
+        let net = sample_net(true);
+        assert!(net.language_equivalent_up_to(&sample_net(true), 5));
+        assert!(!net.language_equivalent_up_to(&sample_net(false), 5));
+        // Without final markings, all reachable prefixes (including the empty one) are part of the language
+        let mut only_a = sample_net(false);
+        only_a.final_markings = None;
+        assert!(!net.language_equivalent_up_to(&only_a, 1));
+        assert_eq!(
+            super::bounded_language(&only_a, 1),
+            [vec![], vec!["a".to_string()]].into()
+        );
+    }
+}
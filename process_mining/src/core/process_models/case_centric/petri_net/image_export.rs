@@ -1,7 +1,7 @@
 //! Image Export of Petri nets
 //!
 //! 🔐 Requires the `graphviz-export` feature to be enabled
-use std::{fs::File, io::Write};
+use std::{collections::HashSet, fs::File, io::Write};
 
 use graphviz_rust::{
     cmd::Format,
@@ -10,9 +10,43 @@ use graphviz_rust::{
     printer::{DotPrinter, PrinterContext},
 };
 use macros_process_mining::register_binding;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::core::PetriNet;
+use crate::core::{process_models::case_centric::petri_net::TransitionID, PetriNet};
+
+/// Styling options for the DOT (Graphviz) export of a [`PetriNet`]
+///
+/// The [`Default`] options correspond to the styling used by [`export_petri_net_to_dot_graph`].
+/// All fields are optional when deserializing (e.g., from a JSON options string).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PetriNetDotOptions {
+    /// Graphviz layout direction (e.g., `LR` for left-to-right or `TB` for top-to-bottom)
+    pub rankdir: String,
+    /// Show the ID of each place next to it
+    pub show_place_ids: bool,
+    /// Fill color of visible (labeled) transitions
+    ///
+    /// Silent transitions are always filled black.
+    pub transition_fill: String,
+    /// Transitions to highlight (e.g., transitions with low fitness in a replay)
+    pub highlight_transitions: HashSet<TransitionID>,
+    /// Label arcs with a weight other than 1 with their weight
+    pub show_arc_weights: bool,
+}
+
+impl Default for PetriNetDotOptions {
+    fn default() -> Self {
+        Self {
+            rankdir: "LR".to_string(),
+            show_place_ids: false,
+            transition_fill: "white".to_string(),
+            highlight_transitions: HashSet::new(),
+            show_arc_weights: true,
+        }
+    }
+}
 
 ///
 /// Export the image of a [`PetriNet`]
@@ -25,7 +59,28 @@ pub fn export_petri_net_image<P: AsRef<std::path::Path>>(
     format: Format,
     dpi_factor: Option<f32>,
 ) -> Result<(), std::io::Error> {
-    let g = export_petri_net_to_dot_graph(net, dpi_factor);
+    export_petri_net_image_with_options(
+        net,
+        path,
+        format,
+        dpi_factor,
+        &PetriNetDotOptions::default(),
+    )
+}
+
+///
+/// Export the image of a [`PetriNet`], styled using the passed [`PetriNetDotOptions`]
+///
+/// Also see [`export_petri_net_image`]
+///
+pub fn export_petri_net_image_with_options<P: AsRef<std::path::Path>>(
+    net: &PetriNet,
+    path: P,
+    format: Format,
+    dpi_factor: Option<f32>,
+    options: &PetriNetDotOptions,
+) -> Result<(), std::io::Error> {
+    let g = export_petri_net_to_dot_graph_with_options(net, dpi_factor, options);
 
     g.print(&mut PrinterContext::default());
 
@@ -41,6 +96,19 @@ pub fn export_petri_net_image<P: AsRef<std::path::Path>>(
 /// Also see [`export_petri_net_image`], as well as [`export_petri_net_image_svg`] and [`export_petri_net_image_png`]
 ///
 pub fn export_petri_net_to_dot_graph(net: &PetriNet, dpi_factor: Option<f32>) -> Graph {
+    export_petri_net_to_dot_graph_with_options(net, dpi_factor, &PetriNetDotOptions::default())
+}
+
+///
+/// Export the a [`PetriNet`] to a DOT graph (used in Graphviz), styled using the passed [`PetriNetDotOptions`]
+///
+/// Also see [`export_petri_net_to_dot_graph`]
+///
+pub fn export_petri_net_to_dot_graph_with_options(
+    net: &PetriNet,
+    dpi_factor: Option<f32>,
+    options: &PetriNetDotOptions,
+) -> Graph {
     let place_nodes: Vec<_> = net
         .places
         .iter()
@@ -50,20 +118,57 @@ pub fn export_petri_net_to_dot_graph(net: &PetriNet, dpi_factor: Option<f32>) ->
             } else {
                 ""
             };
-            let shape = if net.is_in_a_final_marking(&p.into()) {"doublecircle"} else {"circle"};
-            let size = if net.is_in_a_final_marking(&p.into()) {0.4} else {0.5};
-            stmt!(node!(esc p_id; attr!("label", esc symbol), attr!("shape",shape), attr!("fixedsize",true), attr!("width",size), attr!("height",size)))
-        }).collect();
+            let shape = if net.is_in_a_final_marking(&p.into()) {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            let size = if net.is_in_a_final_marking(&p.into()) {
+                0.4
+            } else {
+                0.5
+            };
+            let mut attrs = vec![
+                attr!("label", esc symbol),
+                attr!("shape", shape),
+                attr!("fixedsize", true),
+                attr!("width", size),
+                attr!("height", size),
+            ];
+            if options.show_place_ids {
+                attrs.push(attr!("xlabel", esc p_id));
+            }
+            stmt!(node!(esc p_id, attrs))
+        })
+        .collect();
 
     let transition_nodes: Vec<_> = net
         .transitions
         .iter()
         .map(|(t_id, t)| {
             let label = t.label.as_ref().cloned().unwrap_or_default();
-            let (font_size,width) = (12,1);
-            let fill_color = if t.label.is_none() { "black" } else { "white" };
-            stmt!(node!(esc t_id; attr!("label", esc label), attr!("shape","box"), attr!("fontsize",font_size),attr!("style","filled"), attr!("fillcolor",fill_color), attr!("width",width), attr!("height",0.5)))
-        }).collect();
+            let (font_size, width) = (12, 1);
+            let fill_color = if t.label.is_none() {
+                "black"
+            } else {
+                options.transition_fill.as_str()
+            };
+            let mut attrs = vec![
+                attr!("label", esc label),
+                attr!("shape", "box"),
+                attr!("fontsize", font_size),
+                attr!("style", "filled"),
+                attr!("fillcolor",esc fill_color),
+                attr!("width", width),
+                attr!("height", 0.5),
+            ];
+            if options.highlight_transitions.contains(&t.into()) {
+                attrs.push(attr!("color", esc "#D4001F"));
+                attrs.push(attr!("penwidth", 3));
+            }
+            stmt!(node!(esc t_id, attrs))
+        })
+        .collect();
 
     let arcs: Vec<_> = net
         .arcs
@@ -77,7 +182,7 @@ pub fn export_petri_net_to_dot_graph(net: &PetriNet, dpi_factor: Option<f32>) ->
                     (transition_id, place_id)
                 }
             };
-            let attrs = if arc.weight == 1 {
+            let attrs = if arc.weight == 1 || !options.show_arc_weights {
                 Vec::default()
             } else {
                 vec![attr!("label", (format!("{}", arc.weight)))]
@@ -86,7 +191,7 @@ pub fn export_petri_net_to_dot_graph(net: &PetriNet, dpi_factor: Option<f32>) ->
         })
         .collect();
 
-    let mut global_graph_options = vec![stmt!(attr!("rankdir", "LR"))];
+    let mut global_graph_options = vec![stmt!(attr!("rankdir", esc options.rankdir))];
     if let Some(dpi_fac) = dpi_factor {
         global_graph_options.push(stmt!(attr!("dpi", (dpi_fac * 96.0))))
     }
@@ -135,7 +240,11 @@ mod test {
         test_utils::get_test_data_path,
     };
 
-    use super::{export_petri_net_image_png, export_petri_net_image_svg};
+    use super::{
+        export_petri_net_image_png, export_petri_net_image_svg,
+        export_petri_net_to_dot_graph_with_options, graph_to_dot, PetriNetDotOptions,
+    };
+    use crate::core::{process_models::case_centric::petri_net::ArcType, PetriNet};
 
     #[test]
     pub fn test_petri_net_png_export() {
@@ -158,4 +267,38 @@ mod test {
             .join("petri-net-export-test.svg");
         export_petri_net_image_svg(&pn, export_path).unwrap();
     }
+
+    #[test]
+    pub fn test_petri_net_dot_options() {
+        let mut pn = PetriNet::new();
+        let p = pn.add_place(None);
+        let t_a = pn.add_transition(Some("a".to_string()), None);
+        let t_b = pn.add_transition(Some("b".to_string()), None);
+        pn.add_arc(ArcType::place_to_transition(p, t_a), Some(3));
+        pn.add_arc(ArcType::transition_to_place(t_b, p), None);
+
+        let default_dot = graph_to_dot(&export_petri_net_to_dot_graph_with_options(
+            &pn,
+            None,
+            &PetriNetDotOptions::default(),
+        ));
+        assert!(default_dot.contains(r#"rankdir="LR""#));
+        assert!(default_dot.contains("label=3"));
+        assert!(!default_dot.contains("xlabel"));
+        assert!(!default_dot.contains("penwidth"));
+
+        let options: PetriNetDotOptions = serde_json::from_str(&format!(
+            r#"{{"rankdir": "TB", "showPlaceIds": true, "transitionFill": "lightblue", "highlightTransitions": ["{}"], "showArcWeights": false}}"#,
+            t_a.get_uuid()
+        ))
+        .unwrap();
+        let dot = graph_to_dot(&export_petri_net_to_dot_graph_with_options(
+            &pn, None, &options,
+        ));
+        assert!(dot.contains(r#"rankdir="TB""#));
+        assert!(dot.contains(&format!(r#"xlabel="{}""#, p.get_uuid())));
+        assert!(dot.contains(r#"fillcolor="lightblue""#));
+        assert!(!dot.contains("label=3"));
+        assert_eq!(dot.matches("penwidth").count(), 1);
+    }
 }
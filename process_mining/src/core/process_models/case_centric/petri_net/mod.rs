@@ -1,7 +1,10 @@
 //! Petri net
+pub mod analysis;
+pub mod comparison;
 #[cfg(feature = "graphviz-export")]
 pub mod image_export;
 pub(crate) mod petri_net_struct;
 pub use petri_net_struct::*;
 pub mod io;
 pub mod pnml;
+pub mod reduction;
@@ -1,5 +1,6 @@
 #[cfg(feature = "token-based-replay")]
 use itertools::Itertools;
+use macros_process_mining::RegistryEntity;
 #[cfg(feature = "token-based-replay")]
 use nalgebra::{DMatrix, Dyn, OMatrix};
 use schemars::JsonSchema;
@@ -7,6 +8,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
+use crate::utils::new_uuid;
+
 use crate::core::process_models::case_centric::petri_net::pnml::{
     export_pnml,
     import_pnml::{self, PNMLParseError},
@@ -82,7 +85,39 @@ pub struct Arc {
     /// Source and target of Arc
     pub from_to: ArcType,
     /// Weight (i.e., how many tokens this arc moves)
-    pub weight: u32,
+    ///
+    /// Defaults to 1 if absent when deserializing.
+    #[serde(default = "default_arc_weight")]
+    pub weight: u64,
+}
+
+fn default_arc_weight() -> u64 {
+    1
+}
+
+impl Arc {
+    /// Create new arc from place to transition with weight 1
+    pub fn place_to_transition(from: PlaceID, to: TransitionID) -> Arc {
+        Self::place_to_transition_weighted(from, to, 1)
+    }
+    /// Create new arc from transition to place with weight 1
+    pub fn transition_to_place(from: TransitionID, to: PlaceID) -> Arc {
+        Self::transition_to_place_weighted(from, to, 1)
+    }
+    /// Create new arc from place to transition with the given weight
+    pub fn place_to_transition_weighted(from: PlaceID, to: TransitionID, weight: u64) -> Arc {
+        Arc {
+            from_to: ArcType::place_to_transition(from, to),
+            weight,
+        }
+    }
+    /// Create new arc from transition to place with the given weight
+    pub fn transition_to_place_weighted(from: TransitionID, to: PlaceID, weight: u64) -> Arc {
+        Arc {
+            from_to: ArcType::transition_to_place(from, to),
+            weight,
+        }
+    }
 }
 
 #[derive(
@@ -121,7 +156,7 @@ impl TransitionID {
 /// Marking of a Petri net: Assigning [`PlaceID`]s to a number of tokens
 pub type Marking = HashMap<PlaceID, u64>;
 
-#[derive(Debug, Deserialize, Serialize, Clone, JsonSchema)]
+#[derive(Debug, Deserialize, Serialize, Clone, JsonSchema, RegistryEntity)]
 ///
 /// A Petri net of [`Place`]s and [`Transition`]s
 ///
@@ -163,7 +198,7 @@ impl PetriNet {
     ///
     /// If no ID is passed, a new UUID will be generated
     pub fn add_place(&mut self, place_id: Option<Uuid>) -> PlaceID {
-        let place_id = place_id.unwrap_or(Uuid::new_v4());
+        let place_id = place_id.unwrap_or_else(new_uuid);
         let place = Place { id: place_id };
         self.places.insert(place_id, place);
         PlaceID(place_id)
@@ -177,7 +212,7 @@ impl PetriNet {
         label: Option<String>,
         transition_id: Option<Uuid>,
     ) -> TransitionID {
-        let transition_id = transition_id.unwrap_or(Uuid::new_v4());
+        let transition_id = transition_id.unwrap_or_else(new_uuid);
         let transition = Transition {
             id: transition_id,
             label,
@@ -186,7 +221,7 @@ impl PetriNet {
         TransitionID(transition_id)
     }
     /// Add an arc
-    pub fn add_arc(&mut self, from_to: ArcType, weight: Option<u32>) {
+    pub fn add_arc(&mut self, from_to: ArcType, weight: Option<u64>) {
         self.arcs.push(Arc {
             from_to,
             weight: weight.unwrap_or(1),
@@ -314,12 +349,13 @@ impl PetriNet {
         let mut activities = HashSet::new();
 
         for transition in self.transitions.values() {
-            if let Some(label) = &transition.label {
-                if activities.contains(label) {
-                    return true;
-                } else {
-                    activities.insert(label.clone());
+            match &transition.label {
+                Some(label) => {
+                    if !activities.insert(label) {
+                        return true;
+                    }
                 }
+                None => return true,
             }
         }
 
@@ -352,11 +388,13 @@ impl PetriNet {
 
     #[cfg(feature = "token-based-replay")]
     /// Creates the pre-incidence matrix of the Petri net
+    ///
+    /// Each entry holds the weight of the arc from the place to the transition (or 0).
     pub fn create_pre_incidence_matrix(
         &self,
         vector_dictionary: &HashMap<Uuid, usize>,
-    ) -> DMatrix<u8> {
-        let mut result: OMatrix<u8, Dyn, Dyn> =
+    ) -> DMatrix<u64> {
+        let mut result: OMatrix<u64, Dyn, Dyn> =
             DMatrix::zeros(self.places.len(), self.transitions.len());
 
         self.arcs.iter().for_each(|arc| match arc.from_to {
@@ -364,7 +402,7 @@ impl PetriNet {
                 result[(
                     *vector_dictionary.get(&place_id).unwrap(),
                     *vector_dictionary.get(&transition_id).unwrap(),
-                )] += 1;
+                )] += arc.weight;
             }
             ArcType::TransitionPlace(_, _) => {}
         });
@@ -374,11 +412,13 @@ impl PetriNet {
 
     #[cfg(feature = "token-based-replay")]
     /// Creates the post-incidence matrix of the Petri net
+    ///
+    /// Each entry holds the weight of the arc from the transition to the place (or 0).
     pub fn create_post_incidence_matrix(
         &self,
         vector_dictionary: &HashMap<Uuid, usize>,
-    ) -> DMatrix<u8> {
-        let mut result: OMatrix<u8, Dyn, Dyn> =
+    ) -> DMatrix<u64> {
+        let mut result: OMatrix<u64, Dyn, Dyn> =
             DMatrix::zeros(self.places.len(), self.transitions.len());
 
         self.arcs.iter().for_each(|arc| match arc.from_to {
@@ -387,7 +427,7 @@ impl PetriNet {
                 result[(
                     *vector_dictionary.get(&place_id).unwrap(),
                     *vector_dictionary.get(&transition_id).unwrap(),
-                )] += 1;
+                )] += arc.weight;
             }
         });
 
@@ -396,12 +436,15 @@ impl PetriNet {
 
     #[cfg(feature = "token-based-replay")]
     /// Creates the incidence matrix of the Petri net
-    pub fn create_incidence_matrix(&self, vector_dictionary: &HashMap<Uuid, usize>) -> DMatrix<i8> {
+    pub fn create_incidence_matrix(
+        &self,
+        vector_dictionary: &HashMap<Uuid, usize>,
+    ) -> DMatrix<i64> {
         self.create_post_incidence_matrix(vector_dictionary)
-            .cast::<i8>()
+            .map(|w| w as i64)
             - self
                 .create_pre_incidence_matrix(vector_dictionary)
-                .cast::<i8>()
+                .map(|w| w as i64)
     }
 
     #[cfg(feature = "graphviz-export")]
@@ -448,6 +491,101 @@ impl PetriNet {
     pub fn import_pnml<P: AsRef<std::path::Path>>(path: P) -> Result<PetriNet, PNMLParseError> {
         import_pnml::import_pnml_from_path(path)
     }
+
+    /// Extract the subnet induced by the transitions labeled with one of the given activities
+    ///
+    /// The subnet contains
+    /// - all transitions with a label in `activities`,
+    /// - all silent transitions on a path between two of these transitions, which only passes through
+    ///   places and silent transitions (e.g., silent skips or loops between the selected activities),
+    /// - all places adjacent to any included transition, and
+    /// - all arcs between included places and transitions.
+    ///
+    /// Places shared with excluded transitions are kept as interface places, but their arcs to excluded transitions are removed.
+    /// The initial and final markings are restricted to the included places.
+    /// All IDs of places and transitions are preserved.
+    pub fn subnet_for_activities(&self, activities: &HashSet<String>) -> PetriNet {
+        let selected: HashSet<Uuid> = self
+            .transitions
+            .values()
+            .filter(|t| t.label.as_ref().is_some_and(|l| activities.contains(l)))
+            .map(|t| t.id)
+            .collect();
+        let is_silent = |id: &Uuid| self.transitions.get(id).is_some_and(|t| t.label.is_none());
+        let mut successors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        let mut predecessors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for arc in &self.arcs {
+            let (from, to) = match arc.from_to {
+                ArcType::PlaceTransition(from, to) | ArcType::TransitionPlace(from, to) => {
+                    (from, to)
+                }
+            };
+            successors.entry(from).or_default().push(to);
+            predecessors.entry(to).or_default().push(from);
+        }
+        // Nodes reachable from a selected transition (only through places and silent transitions)
+        let reachable_from_selected = |adjacency: &HashMap<Uuid, Vec<Uuid>>| {
+            let mut visited: HashSet<Uuid> = HashSet::new();
+            let mut stack: Vec<Uuid> = selected.iter().copied().collect();
+            while let Some(node) = stack.pop() {
+                for next in adjacency.get(&node).into_iter().flatten() {
+                    if (self.places.contains_key(next) || is_silent(next)) && visited.insert(*next)
+                    {
+                        stack.push(*next);
+                    }
+                }
+            }
+            visited
+        };
+        let forward = reachable_from_selected(&successors);
+        let backward = reachable_from_selected(&predecessors);
+        let included_transitions: HashSet<Uuid> = selected
+            .iter()
+            .copied()
+            .chain(
+                forward
+                    .intersection(&backward)
+                    .filter(|id| is_silent(id))
+                    .copied(),
+            )
+            .collect();
+        let arcs: Vec<Arc> = self
+            .arcs
+            .iter()
+            .filter(|arc| match arc.from_to {
+                ArcType::PlaceTransition(_, t) | ArcType::TransitionPlace(t, _) => {
+                    included_transitions.contains(&t)
+                }
+            })
+            .cloned()
+            .collect();
+        let places: HashMap<Uuid, Place> = arcs
+            .iter()
+            .map(|arc| match arc.from_to {
+                ArcType::PlaceTransition(p, _) | ArcType::TransitionPlace(_, p) => p,
+            })
+            .filter_map(|p| self.places.get(&p).map(|place| (p, place.clone())))
+            .collect();
+        let restrict = |m: &Marking| -> Marking {
+            m.iter()
+                .filter(|(p, _)| places.contains_key(&p.0))
+                .map(|(p, tokens)| (*p, *tokens))
+                .collect()
+        };
+        PetriNet {
+            transitions: included_transitions
+                .iter()
+                .map(|id| (*id, self.transitions[id].clone()))
+                .collect(),
+            initial_marking: self.initial_marking.as_ref().map(restrict),
+            final_markings: self
+                .final_markings
+                .as_ref()
+                .map(|fms| fms.iter().map(restrict).collect()),
+            places,
+            arcs,
+        }
+    }
 }
 
 /// Creates a [`PetriNet`] from a [`ProcessTree`]
@@ -583,6 +721,58 @@ mod tests {
         assert!(net.preset_of_transition(t2).is_empty());
     }
 
+    #[test]
+    fn subnet_for_activities() {
+        // a -> (tau skip | b) -> c -> d
+        let mut net = PetriNet::new();
+        let places: Vec<PlaceID> = (0..5).map(|_| net.add_place(None)).collect();
+        let a = net.add_transition(Some("a".into()), None);
+        let b = net.add_transition(Some("b".into()), None);
+        let skip = net.add_transition(None, None);
+        let c = net.add_transition(Some("c".into()), None);
+        let d = net.add_transition(Some("d".into()), None);
+        net.add_arc(ArcType::place_to_transition(places[0], a), None);
+        net.add_arc(ArcType::transition_to_place(a, places[1]), None);
+        net.add_arc(ArcType::place_to_transition(places[1], b), None);
+        net.add_arc(ArcType::place_to_transition(places[1], skip), None);
+        net.add_arc(ArcType::transition_to_place(b, places[2]), None);
+        net.add_arc(ArcType::transition_to_place(skip, places[2]), None);
+        net.add_arc(ArcType::place_to_transition(places[2], c), None);
+        net.add_arc(ArcType::transition_to_place(c, places[3]), None);
+        net.add_arc(ArcType::place_to_transition(places[3], d), None);
+        net.add_arc(ArcType::transition_to_place(d, places[4]), None);
+        net.initial_marking = Some(Marking::from([(places[0], 1)]));
+        net.final_markings = Some(vec![Marking::from([(places[4], 1)])]);
+
+        let sub = net.subnet_for_activities(&HashSet::from(["a".to_string(), "c".to_string()]));
+        let ids = |ids: Vec<Uuid>| ids.into_iter().collect::<HashSet<_>>();
+        assert_eq!(
+            ids(sub.transitions.keys().copied().collect()),
+            ids(vec![a.0, skip.0, c.0])
+        );
+        // p1 and p2 are interface places shared with the excluded transition b
+        assert_eq!(
+            ids(sub.places.keys().copied().collect()),
+            ids(places[..4].iter().map(|p| p.0).collect())
+        );
+        assert_eq!(sub.arcs.len(), 6);
+        assert_eq!(sub.initial_marking, net.initial_marking);
+        assert_eq!(sub.final_markings, Some(vec![Marking::new()]));
+
+        // Silent transitions not connecting selected transitions are excluded
+        let sub = net.subnet_for_activities(&HashSet::from(["d".to_string()]));
+        assert_eq!(sub.transitions.len(), 1);
+        assert_eq!(sub.places.len(), 2);
+        assert!(net
+            .subnet_for_activities(&HashSet::new())
+            .transitions
+            .is_empty());
+        // Arcs referring to places missing from the net are tolerated
+        net.places.remove(&places[4].0);
+        let sub = net.subnet_for_activities(&HashSet::from(["d".to_string()]));
+        assert_eq!(sub.places.len(), 1);
+    }
+
     #[test]
     fn deserialize_petri_net_test() {
         let pn: PetriNet = serde_json::from_str(SAMPLE_JSON_NET).unwrap();
@@ -618,6 +808,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn arc_weight_defaults_to_one_when_deserializing() {
+        let p = PlaceID(Uuid::new_v4());
+        let t = TransitionID(Uuid::new_v4());
+        let arc = Arc::place_to_transition_weighted(p, t, 3);
+        let mut json = serde_json::to_value(&arc).unwrap();
+        assert_eq!(serde_json::from_value::<Arc>(json.clone()).unwrap(), arc);
+
+        json.as_object_mut().unwrap().remove("weight");
+        let arc: Arc = serde_json::from_value(json).unwrap();
+        assert_eq!(arc, Arc::place_to_transition(p, t));
+        assert_eq!(arc.weight, 1);
+    }
+
     #[cfg(feature = "token-based-replay")]
     #[test]
     fn create_incidence_matrix_test() {
@@ -1,9 +1,12 @@
 use quick_xml::{events::BytesText, Writer};
 use std::{fs::File, io::Write};
-use uuid::Uuid;
 
 use crate::{
-    core::{process_models::case_centric::petri_net::petri_net_struct::ArcType, PetriNet},
+    core::{
+        process_models::case_centric::petri_net::petri_net_struct::{ArcType, Marking},
+        PetriNet,
+    },
+    utils::new_uuid,
     XMLWriterWrapper,
 };
 const OK: Result<(), std::io::Error> = Ok(());
@@ -11,10 +14,45 @@ const OK: Result<(), std::io::Error> = Ok(());
 ///
 /// Export a [`PetriNet`] to the PNML format and write the result to the provided writer which implements into [`quick_xml::Writer`] / [`std::io::Write`]
 ///
+/// The initial marking is exported per place (`initialMarking`) and all final markings of the net are exported as `finalmarkings`.
+///
 pub fn export_petri_net_to_pnml<'a, W>(
     pn: &PetriNet,
     into_writer: impl Into<XMLWriterWrapper<'a, W>>,
 ) -> Result<(), quick_xml::Error>
+where
+    W: Write + 'a,
+{
+    export_petri_net_to_pnml_with_final_markings(pn, pn.final_markings.as_deref(), into_writer)
+}
+
+///
+/// Export a [`PetriNet`] to the PNML format, declaring only the passed final marking (instead of the final markings of the net)
+///
+/// This is useful if the net has multiple (or no) final markings, but the consuming tool (e.g., for conformance checking)
+/// expects a single declared final marking.
+/// See [`export_petri_net_to_pnml`] for details.
+///
+pub fn export_petri_net_to_pnml_with_final_marking<'a, W>(
+    pn: &PetriNet,
+    final_marking: &Marking,
+    into_writer: impl Into<XMLWriterWrapper<'a, W>>,
+) -> Result<(), quick_xml::Error>
+where
+    W: Write + 'a,
+{
+    export_petri_net_to_pnml_with_final_markings(
+        pn,
+        Some(std::slice::from_ref(final_marking)),
+        into_writer,
+    )
+}
+
+fn export_petri_net_to_pnml_with_final_markings<'a, W>(
+    pn: &PetriNet,
+    final_markings: Option<&[Marking]>,
+    into_writer: impl Into<XMLWriterWrapper<'a, W>>,
+) -> Result<(), quick_xml::Error>
 where
     W: Write + 'a,
 {
@@ -99,7 +137,7 @@ where
                                                     ("activity", "$invisible$"),
                                                     (
                                                         "localNodeID",
-                                                        Uuid::new_v4().to_string().as_str(),
+                                                        new_uuid().to_string().as_str(),
                                                     ),
                                                 ])
                                                 .write_empty()?;
@@ -136,11 +174,11 @@ where
                             OK
                         })?;
 
-                    if let Some(final_markings) = pn.final_markings.clone() {
+                    if let Some(final_markings) = final_markings {
                         writer
                             .create_element("finalmarkings")
                             .write_inner_content(|writer| {
-                                for marking in &final_markings {
+                                for marking in final_markings {
                                     writer.create_element("marking").write_inner_content(
                                         |writer| {
                                             for (place_id, tokens) in marking {
@@ -187,6 +225,18 @@ pub fn export_petri_net_to_pnml_path<P: AsRef<std::path::Path>>(
     export_petri_net_to_pnml(pn, &mut writer)
 }
 
+/// Export a [`PetriNet`] to a PNML [`String`]
+///
+/// Useful for passing the net to other tools (e.g., via language bindings) without writing a file.
+pub fn export_petri_net_to_pnml_string(pn: &PetriNet) -> Result<String, quick_xml::Error> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 4);
+    export_petri_net_to_pnml(pn, &mut writer)?;
+    Ok(
+        String::from_utf8(writer.into_inner())
+            .expect("PNML export only writes valid UTF-8 strings"),
+    )
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -198,7 +248,17 @@ mod test {
         test_utils::get_test_data_path,
     };
 
-    use super::export_petri_net_to_pnml_path;
+    use super::{
+        export_petri_net_to_pnml_path, export_petri_net_to_pnml_string,
+        export_petri_net_to_pnml_with_final_marking,
+    };
+    use crate::core::{
+        process_models::case_centric::petri_net::{
+            petri_net_struct::{ArcType, Marking},
+            pnml::import_pnml::import_pnml_reader,
+        },
+        PetriNet,
+    };
     use std::{fs::File, io::BufWriter};
 
     #[test]
@@ -225,4 +285,90 @@ mod test {
         println!("file:///{}", export_path.to_string_lossy());
         Ok(())
     }
+
+    #[test]
+    fn test_export_pnml_to_string() {
+        let mut pn = PetriNet::new();
+        let p1 = pn.add_place(None);
+        let t = pn.add_transition(Some("a".to_string()), None);
+        pn.add_arc(ArcType::place_to_transition(p1, t), None);
+        let pnml = export_petri_net_to_pnml_string(&pn).unwrap();
+        assert!(pnml.starts_with("<pnml>"));
+        let pn2 = import_pnml_reader(&mut pnml.as_bytes()).unwrap();
+        assert_eq!(pn2.places.len(), 1);
+        assert_eq!(pn2.transitions.len(), 1);
+        assert_eq!(pn2.arcs.len(), 1);
+    }
+
+    #[test]
+    fn test_export_pnml_markings_round_trip() {
+        let mut pn = PetriNet::new();
+        let p1 = pn.add_place(None);
+        let p2 = pn.add_place(None);
+        let p3 = pn.add_place(None);
+        let t = pn.add_transition(Some("a".to_string()), None);
+        pn.add_arc(ArcType::place_to_transition(p1, t), None);
+        pn.add_arc(ArcType::transition_to_place(t, p2), None);
+        pn.add_arc(ArcType::transition_to_place(t, p3), None);
+        let t2 = pn.add_transition(Some("b".to_string()), None);
+        pn.add_arc(ArcType::place_to_transition(p3, t2), None);
+        pn.initial_marking = Some(Marking::from([(p1, 2)]));
+        pn.final_markings = Some(vec![
+            Marking::from([(p2, 2), (p3, 2)]),
+            Marking::from([(p3, 1)]),
+        ]);
+
+        // Place IDs are not preserved by the import, so places are identified by their adjacent transitions
+        let place_key = |net: &PetriNet, m: &Marking| -> Vec<(Vec<String>, Vec<String>, u64)> {
+            let mut ret: Vec<_> = m
+                .iter()
+                .map(|(p, tokens)| {
+                    let labels = |ts: Vec<_>| -> Vec<String> {
+                        ts.into_iter()
+                            .map(|t| net.transitions[&t].label.clone().unwrap())
+                            .collect()
+                    };
+                    (
+                        labels(
+                            net.preset_of_place(*p)
+                                .into_iter()
+                                .map(|t| t.get_uuid())
+                                .collect(),
+                        ),
+                        labels(
+                            net.postset_of_place(*p)
+                                .into_iter()
+                                .map(|t| t.get_uuid())
+                                .collect(),
+                        ),
+                        *tokens,
+                    )
+                })
+                .collect();
+            ret.sort();
+            ret
+        };
+        let markings_key = |net: &PetriNet| {
+            (
+                place_key(net, net.initial_marking.as_ref().unwrap()),
+                net.final_markings
+                    .iter()
+                    .flatten()
+                    .map(|m| place_key(net, m))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let pnml = export_petri_net_to_pnml_string(&pn).unwrap();
+        let pn2 = import_pnml_reader(&mut pnml.as_bytes()).unwrap();
+        assert_eq!(markings_key(&pn2), markings_key(&pn));
+        assert_eq!(pn2.final_markings.as_ref().unwrap().len(), 2);
+
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        export_petri_net_to_pnml_with_final_marking(&pn, &Marking::from([(p2, 1)]), &mut writer)
+            .unwrap();
+        let pn3 = import_pnml_reader(&mut writer.into_inner().as_slice()).unwrap();
+        pn.final_markings = Some(vec![Marking::from([(p2, 1)])]);
+        assert_eq!(markings_key(&pn3), markings_key(&pn));
+    }
 }
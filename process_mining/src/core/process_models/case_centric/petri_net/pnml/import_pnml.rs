@@ -1,3 +1,4 @@
+use crate::diagnostics::warn_diagnostic;
 use quick_xml::{Error as QuickXMLError, Reader};
 use std::{collections::HashMap, io::BufRead};
 use uuid::Uuid;
@@ -7,6 +8,7 @@ use crate::core::{
     process_models::case_centric::petri_net::petri_net_struct::{ArcType, Marking, PlaceID},
     PetriNet,
 };
+use crate::utils::new_uuid;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Mode {
@@ -115,7 +117,7 @@ where
 
     let mut current_id: Option<Uuid> = None;
 
-    let mut arcs: Vec<(String, String, u32)> = Vec::new();
+    let mut arcs: Vec<(String, String, u64)> = Vec::new();
 
     loop {
         match reader.read_event_into(&mut buf)? {
@@ -126,13 +128,13 @@ where
                 }
                 b"net" => {
                     if current_mode != Mode::Pnml {
-                        eprintln!("Expected to be in Mode::PNML when encountering net");
+                        warn_diagnostic!("Expected to be in Mode::PNML when encountering net");
                     }
                     current_mode = Mode::Net;
                 }
                 b"page" => {
                     if current_mode != Mode::Net {
-                        eprintln!("Expected to be in Mode::Net when encountering page");
+                        warn_diagnostic!("Expected to be in Mode::Net when encountering page");
                     }
                     current_mode = Mode::Net;
                 }
@@ -158,7 +160,7 @@ where
                             .unwrap_or_default()
                             .ok_or(PNMLParseError::MissingKey("id"))?;
                         let place_id_str = read_xml_text_unescaped(&mut place_id.value.as_ref());
-                        let uuid = Uuid::new_v4();
+                        let uuid = new_uuid();
                         current_id = Some(uuid);
                         id_map.insert(place_id_str, uuid);
                         pn.add_place(Some(uuid));
@@ -171,7 +173,7 @@ where
                         .unwrap_or_default()
                         .ok_or(PNMLParseError::MissingKey("id"))?;
                     let trans_id_str = read_xml_text_unescaped(&mut trans_id.value.as_ref());
-                    let uuid = Uuid::new_v4();
+                    let uuid = new_uuid();
                     current_id = Some(uuid);
                     id_map.insert(trans_id_str, uuid);
                     pn.add_transition(Some(String::new()), Some(uuid));
@@ -213,7 +215,7 @@ where
                                 // Set label to None (silent)
                                 trans.label = None;
                             } else {
-                                eprintln!(
+                                warn_diagnostic!(
                                     "Can't find current transition when adding toolspecific!"
                                 );
                             }
@@ -279,7 +281,7 @@ where
                                 trans.label = Some(text);
                             }
                         } else {
-                            eprintln!("Can't find current transition when adding text!");
+                            warn_diagnostic!("Can't find current transition when adding text!");
                         }
                     }
                     Mode::InitialMarking => {
@@ -300,7 +302,7 @@ where
                     }
                     Mode::ArcInscription => {
                         if let Some(arc) = arcs.last_mut() {
-                            arc.2 = text.parse::<u32>().unwrap_or(1);
+                            arc.2 = text.parse::<u64>().unwrap_or(1);
                         }
                     }
                     _ => {}
@@ -0,0 +1,438 @@
+//! Structural Reduction of Petri nets
+//!
+//! Implements behavior-preserving reduction rules (following Murata, _Petri nets: Properties, analysis and applications_, 1989).
+//!
+//! Only silent transitions (i.e., transitions without a label) are removed by the series/self-loop rules,
+//! so the observable language of the net (wrt. its initial and final markings) is preserved.
+use std::collections::{BTreeMap, HashMap};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Arc, ArcType, Marking, PetriNet, PlaceID};
+
+/// Configuration of which reduction rules to apply in [`reduce`]
+///
+/// By default, all rules are enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReductionRules {
+    /// Fusion of Series Places (FSP)
+    ///
+    /// Removes a silent transition with a single input place `p1` and a single output place `p2`
+    /// (where the transition is the only consumer of `p1`) by merging `p1` into `p2`.
+    pub fuse_series_places: bool,
+    /// Fusion of Series Transitions (FST)
+    ///
+    /// Removes a place `p` with a single input transition `t1` and a single silent output transition `t2`
+    /// (where `p` is the only input of `t2`) by merging `t2` into `t1`.
+    pub fuse_series_transitions: bool,
+    /// Fusion of Parallel Places (FPP)
+    ///
+    /// Removes places with the same pre- and postset (and the same tokens in all markings) as another place.
+    pub fuse_parallel_places: bool,
+    /// Fusion of Parallel Transitions (FPT)
+    ///
+    /// Removes transitions with the same label and the same pre- and postset as another transition.
+    pub fuse_parallel_transitions: bool,
+    /// Elimination of Self-loop Places (ESP)
+    ///
+    /// Removes places which are only connected through self-loops and always hold enough tokens.
+    pub eliminate_self_loop_places: bool,
+    /// Elimination of Self-loop Transitions (EST)
+    ///
+    /// Removes silent transitions which do not change the marking when fired.
+    pub eliminate_self_loop_transitions: bool,
+}
+
+impl Default for ReductionRules {
+    fn default() -> Self {
+        Self {
+            fuse_series_places: true,
+            fuse_series_transitions: true,
+            fuse_parallel_places: true,
+            fuse_parallel_transitions: true,
+            eliminate_self_loop_places: true,
+            eliminate_self_loop_transitions: true,
+        }
+    }
+}
+
+/// Report of a [`reduce`] run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReductionReport {
+    /// Number of removed places
+    pub removed_places: usize,
+    /// Number of removed transitions
+    pub removed_transitions: usize,
+}
+
+///
+/// Reduce a [`PetriNet`] in place by repeatedly applying the enabled [`ReductionRules`] until none is applicable anymore
+///
+/// Markings are updated accordingly.
+/// Rules are only applied if the involved arcs and markings allow it without changing the observable language
+/// (e.g., places part of a final marking are not fused away).
+///
+/// Returns a [`ReductionReport`] with the number of removed nodes.
+pub fn reduce(net: &mut PetriNet, rules: ReductionRules) -> ReductionReport {
+    let mut report = ReductionReport::default();
+    loop {
+        let adj = Adjacency::new(net);
+        let applied = (rules.eliminate_self_loop_transitions
+            && eliminate_self_loop_transition(net, &adj, &mut report))
+            || (rules.eliminate_self_loop_places
+                && eliminate_self_loop_place(net, &adj, &mut report))
+            || (rules.fuse_parallel_transitions
+                && fuse_parallel_transitions(net, &adj, &mut report))
+            || (rules.fuse_parallel_places && fuse_parallel_places(net, &adj, &mut report))
+            || (rules.fuse_series_places && fuse_series_places(net, &adj, &mut report))
+            || (rules.fuse_series_transitions && fuse_series_transitions(net, &adj, &mut report));
+        if !applied {
+            break;
+        }
+    }
+    report
+}
+
+/// Weighted pre- and postsets of all nodes (sorted for deterministic comparison)
+struct Adjacency {
+    pre: HashMap<Uuid, BTreeMap<Uuid, u64>>,
+    post: HashMap<Uuid, BTreeMap<Uuid, u64>>,
+}
+
+impl Adjacency {
+    fn new(net: &PetriNet) -> Self {
+        let mut pre: HashMap<Uuid, BTreeMap<Uuid, u64>> = HashMap::new();
+        let mut post: HashMap<Uuid, BTreeMap<Uuid, u64>> = HashMap::new();
+        for arc in &net.arcs {
+            let (from, to) = match arc.from_to {
+                ArcType::PlaceTransition(from, to) | ArcType::TransitionPlace(from, to) => {
+                    (from, to)
+                }
+            };
+            *post.entry(from).or_default().entry(to).or_default() += arc.weight;
+            *pre.entry(to).or_default().entry(from).or_default() += arc.weight;
+        }
+        Self { pre, post }
+    }
+
+    fn pre(&self, id: &Uuid) -> BTreeMap<Uuid, u64> {
+        self.pre.get(id).cloned().unwrap_or_default()
+    }
+
+    fn post(&self, id: &Uuid) -> BTreeMap<Uuid, u64> {
+        self.post.get(id).cloned().unwrap_or_default()
+    }
+}
+
+fn tokens(marking: &Marking, p: Uuid) -> u64 {
+    marking.get(&PlaceID(p)).copied().unwrap_or_default()
+}
+
+fn initial_tokens(net: &PetriNet, p: Uuid) -> u64 {
+    net.initial_marking
+        .as_ref()
+        .map(|m| tokens(m, p))
+        .unwrap_or_default()
+}
+
+/// Check if `p1` and `p2` hold the same number of tokens in the initial and all final markings
+fn same_tokens_in_markings(net: &PetriNet, p1: Uuid, p2: Uuid) -> bool {
+    initial_tokens(net, p1) == initial_tokens(net, p2)
+        && net
+            .final_markings
+            .iter()
+            .flatten()
+            .all(|m| tokens(m, p1) == tokens(m, p2))
+}
+
+fn is_in_a_final_marking(net: &PetriNet, p: Uuid) -> bool {
+    net.final_markings
+        .iter()
+        .flatten()
+        .any(|m| tokens(m, p) > 0)
+}
+
+fn sorted_ids<T>(nodes: &HashMap<Uuid, T>) -> Vec<Uuid> {
+    let mut ids: Vec<_> = nodes.keys().copied().collect();
+    ids.sort();
+    ids
+}
+
+fn is_silent(net: &PetriNet, t: &Uuid) -> bool {
+    net.transitions.get(t).is_some_and(|t| t.label.is_none())
+}
+
+/// Merge duplicate arcs (i.e., with the same source and target) by summing up their weights
+fn merge_duplicate_arcs(net: &mut PetriNet) {
+    let mut weights: BTreeMap<ArcType, u64> = BTreeMap::new();
+    for arc in net.arcs.drain(..) {
+        *weights.entry(arc.from_to).or_default() += arc.weight;
+    }
+    net.arcs = weights
+        .into_iter()
+        .map(|(from_to, weight)| Arc { from_to, weight })
+        .collect();
+}
+
+/// Elimination of Self-loop Transitions (EST)
+fn eliminate_self_loop_transition(
+    net: &mut PetriNet,
+    adj: &Adjacency,
+    report: &mut ReductionReport,
+) -> bool {
+    let candidate = sorted_ids(&net.transitions)
+        .into_iter()
+        .find(|t| is_silent(net, t) && adj.pre(t) == adj.post(t));
+    if let Some(t) = candidate {
+        net.remove_transition(&t);
+        report.removed_transitions += 1;
+        return true;
+    }
+    false
+}
+
+/// Elimination of Self-loop Places (ESP)
+fn eliminate_self_loop_place(
+    net: &mut PetriNet,
+    adj: &Adjacency,
+    report: &mut ReductionReport,
+) -> bool {
+    let candidate = sorted_ids(&net.places).into_iter().find(|p| {
+        let pre = adj.pre(p);
+        let initial = initial_tokens(net, *p);
+        pre == adj.post(p)
+            && pre.values().all(|w| *w <= initial)
+            && net
+                .final_markings
+                .iter()
+                .flatten()
+                .all(|m| tokens(m, *p) == initial)
+    });
+    if let Some(p) = candidate {
+        net.remove_place(&p);
+        report.removed_places += 1;
+        return true;
+    }
+    false
+}
+
+/// Fusion of Parallel Transitions (FPT)
+fn fuse_parallel_transitions(
+    net: &mut PetriNet,
+    adj: &Adjacency,
+    report: &mut ReductionReport,
+) -> bool {
+    let ids = sorted_ids(&net.transitions);
+    for (i, t1) in ids.iter().enumerate() {
+        let (pre, post) = (adj.pre(t1), adj.post(t1));
+        let label = &net.transitions[t1].label;
+        if let Some(t2) = ids[i + 1..].iter().find(|t2| {
+            &net.transitions[t2].label == label && adj.pre(t2) == pre && adj.post(t2) == post
+        }) {
+            net.remove_transition(t2);
+            report.removed_transitions += 1;
+            return true;
+        }
+    }
+    false
+}
+
+/// Fusion of Parallel Places (FPP)
+fn fuse_parallel_places(net: &mut PetriNet, adj: &Adjacency, report: &mut ReductionReport) -> bool {
+    let ids = sorted_ids(&net.places);
+    for (i, p1) in ids.iter().enumerate() {
+        let (pre, post) = (adj.pre(p1), adj.post(p1));
+        if let Some(p2) = ids[i + 1..].iter().find(|p2| {
+            adj.pre(p2) == pre && adj.post(p2) == post && same_tokens_in_markings(net, *p1, **p2)
+        }) {
+            net.remove_place(p2);
+            report.removed_places += 1;
+            return true;
+        }
+    }
+    false
+}
+
+/// Fusion of Series Places (FSP)
+fn fuse_series_places(net: &mut PetriNet, adj: &Adjacency, report: &mut ReductionReport) -> bool {
+    let candidate = sorted_ids(&net.transitions).into_iter().find_map(|t| {
+        if !is_silent(net, &t) {
+            return None;
+        }
+        let (pre, post) = (adj.pre(&t), adj.post(&t));
+        if pre.len() != 1 || post.len() != 1 {
+            return None;
+        }
+        let (p1, w1) = pre.into_iter().next()?;
+        let (p2, w2) = post.into_iter().next()?;
+        let p1_post = adj.post(&p1);
+        (w1 == 1 && w2 == 1 && p1 != p2 && p1_post.len() == 1 && !is_in_a_final_marking(net, p1))
+            .then_some((t, p1, p2))
+    });
+    let Some((t, p1, p2)) = candidate else {
+        return false;
+    };
+    // Tokens in p1 can always be moved to p2 silently
+    let p1_tokens = initial_tokens(net, p1);
+    if p1_tokens > 0 {
+        if let Some(im) = &mut net.initial_marking {
+            *im.entry(PlaceID(p2)).or_default() += p1_tokens;
+        }
+    }
+    for arc in &mut net.arcs {
+        if let ArcType::TransitionPlace(_, to) = &mut arc.from_to {
+            if *to == p1 {
+                *to = p2;
+            }
+        }
+    }
+    net.remove_transition(&t);
+    net.remove_place(&p1);
+    merge_duplicate_arcs(net);
+    report.removed_places += 1;
+    report.removed_transitions += 1;
+    true
+}
+
+/// Fusion of Series Transitions (FST)
+fn fuse_series_transitions(
+    net: &mut PetriNet,
+    adj: &Adjacency,
+    report: &mut ReductionReport,
+) -> bool {
+    let candidate = sorted_ids(&net.places).into_iter().find_map(|p| {
+        let (pre, post) = (adj.pre(&p), adj.post(&p));
+        if pre.len() != 1 || post.len() != 1 {
+            return None;
+        }
+        let (t1, w1) = pre.into_iter().next()?;
+        let (t2, w2) = post.into_iter().next()?;
+        (w1 == 1
+            && w2 == 1
+            && t1 != t2
+            && is_silent(net, &t2)
+            && adj.pre(&t2).len() == 1
+            && initial_tokens(net, p) == 0
+            && !is_in_a_final_marking(net, p))
+        .then_some((p, t1, t2))
+    });
+    let Some((p, t1, t2)) = candidate else {
+        return false;
+    };
+    // Firing t2 directly after t1 is always possible, so t1 can directly produce the output of t2
+    for (q, w) in adj.post(&t2) {
+        net.arcs.push(Arc {
+            from_to: ArcType::TransitionPlace(t1, q),
+            weight: w,
+        });
+    }
+    net.remove_transition(&t2);
+    net.remove_place(&p);
+    merge_duplicate_arcs(net);
+    report.removed_places += 1;
+    report.removed_transitions += 1;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::process_models::case_centric::petri_net::TransitionID;
+
+    /// Construct a sequence net `a -> tau -> b` with a redundant parallel place between `a` and `tau`
+    fn sequence_net() -> (PetriNet, PlaceID, PlaceID) {
+        let mut net = PetriNet::new();
+        let start = net.add_place(None);
+        let p1 = net.add_place(None);
+        let p1_copy = net.add_place(None);
+        let p2 = net.add_place(None);
+        let end = net.add_place(None);
+        let a = net.add_transition(Some("a".to_string()), None);
+        let tau = net.add_transition(None, None);
+        let b = net.add_transition(Some("b".to_string()), None);
+        let arcs: [(Option<PlaceID>, TransitionID, Option<PlaceID>); 4] = [
+            (Some(start), a, None),
+            (None, a, Some(p1)),
+            (None, a, Some(p1_copy)),
+            (Some(p2), b, None),
+        ];
+        for (from, t, to) in arcs {
+            if let Some(p) = from {
+                net.add_arc(ArcType::place_to_transition(p, t), None);
+            }
+            if let Some(p) = to {
+                net.add_arc(ArcType::transition_to_place(t, p), None);
+            }
+        }
+        net.add_arc(ArcType::place_to_transition(p1, tau), None);
+        net.add_arc(ArcType::place_to_transition(p1_copy, tau), None);
+        net.add_arc(ArcType::transition_to_place(tau, p2), None);
+        net.add_arc(ArcType::transition_to_place(b, end), None);
+        net.initial_marking = Some([(start, 1)].into_iter().collect());
+        net.final_markings = Some(vec![[(end, 1)].into_iter().collect()]);
+        (net, start, end)
+    }
+
+    #[test]
+    fn test_reduce_sequence() {
+        let (mut net, start, end) = sequence_net();
+        let report = reduce(&mut net, ReductionRules::default());
+        // Parallel place and tau (with its input place) are removed
+        assert_eq!(
+            report,
+            ReductionReport {
+                removed_places: 2,
+                removed_transitions: 1
+            }
+        );
+        assert_eq!(net.places.len(), 3);
+        assert_eq!(net.transitions.len(), 2);
+        assert!(net.transitions.values().all(|t| t.label.is_some()));
+        assert_eq!(net.arcs.len(), 4);
+        assert!(net.places.contains_key(&start.0) && net.places.contains_key(&end.0));
+        assert_eq!(net.initial_marking.as_ref().unwrap().get(&start), Some(&1));
+    }
+
+    #[test]
+    fn test_reduce_with_disabled_rules() {
+        let (mut net, _, _) = sequence_net();
+        let report = reduce(
+            &mut net,
+            ReductionRules {
+                fuse_parallel_places: false,
+                fuse_series_places: false,
+                ..Default::default()
+            },
+        );
+        // Tau has two input places and its output place has labeled postset, so no rule applies
+        assert_eq!(report, ReductionReport::default());
+        assert_eq!(net.transitions.len(), 3);
+
+        // Self-loop places and transitions
+        let mut net = PetriNet::new();
+        let p = net.add_place(None);
+        let res = net.add_place(None);
+        let a = net.add_transition(Some("a".to_string()), None);
+        let tau = net.add_transition(None, None);
+        net.add_arc(ArcType::place_to_transition(p, a), None);
+        net.add_arc(ArcType::place_to_transition(res, a), None);
+        net.add_arc(ArcType::transition_to_place(a, res), None);
+        net.add_arc(ArcType::place_to_transition(p, tau), None);
+        net.add_arc(ArcType::transition_to_place(tau, p), None);
+        net.initial_marking = Some([(p, 1), (res, 1)].into_iter().collect());
+        let report = reduce(&mut net, ReductionRules::default());
+        assert_eq!(
+            report,
+            ReductionReport {
+                removed_places: 1,
+                removed_transitions: 1
+            }
+        );
+        assert_eq!(net.places.keys().collect::<Vec<_>>(), vec![&p.0]);
+        assert_eq!(net.arcs, vec![Arc::place_to_transition(p, a)]);
+    }
+}
@@ -2,6 +2,7 @@
 #[cfg(feature = "graphviz-export")]
 pub mod image_export;
 pub(crate) mod process_tree_struct;
+pub mod ptml_export;
 
 #[doc(inline)]
 pub use process_tree_struct::*;
@@ -90,6 +90,39 @@ impl Node {
     }
 }
 
+///
+/// Textual representation of a (sub)tree, e.g., `->( a, X( b, c ) )`
+///
+/// Operators are written as `->` (sequence), `X` (exclusive choice), `+` (concurrency) and `*` (loop),
+/// silent leaves as `tau`.
+///
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Node::Leaf(leaf) => match &leaf.activity_label {
+                LeafLabel::Activity(label) => write!(f, "{label}"),
+                LeafLabel::Tau => write!(f, "tau"),
+            },
+            Node::Operator(op) => {
+                let symbol = match op.operator_type {
+                    OperatorType::Sequence => "->",
+                    OperatorType::ExclusiveChoice => "X",
+                    OperatorType::Concurrency => "+",
+                    OperatorType::Loop => "*",
+                };
+                write!(f, "{symbol}(")?;
+                for (i, child) in op.children.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, " {child}")?;
+                }
+                write!(f, " )")
+            }
+        }
+    }
+}
+
 ///
 /// Operator type enum for [`Operator`]
 ///
@@ -126,6 +159,15 @@ impl fmt::Display for OperatorType {
         }
     }
 }
+///
+/// Textual representation of the process tree (see the [`fmt::Display`] implementation of [`Node`])
+///
+impl fmt::Display for ProcessTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.root)
+    }
+}
+
 ///
 /// Object-centric process tree struct that contains [`Node`] as root
 ///
@@ -234,6 +276,15 @@ impl ProcessTree {
         petri_net
     }
 
+    ///
+    /// Export the process tree to the PTML format (used by `ProM`)
+    ///
+    /// See [`super::ptml_export`] for exporting to a file or writer.
+    ///
+    pub fn to_ptml(&self) -> Result<String, quick_xml::Error> {
+        super::ptml_export::export_process_tree_to_ptml_string(self)
+    }
+
     #[cfg(feature = "graphviz-export")]
     /// Export a process tree as a PNG image
     ///
@@ -726,6 +777,10 @@ mod tests {
         seq.children.push(Node::Operator(loop_op));
         seq.children.push(Node::Operator(choice));
         let tree = ProcessTree::new(Node::Operator(seq));
+        assert_eq!(
+            tree.to_string(),
+            "->( a, *( e, +( a, b ), f, tau ), X( b, c, d ) )"
+        );
 
         let net = tree.to_petri_net();
 
@@ -0,0 +1,219 @@
+//! Export of [`ProcessTree`]s to the PTML format (used by `ProM`)
+use std::{fs::File, io::Write};
+
+use quick_xml::{events::BytesDecl, events::Event, Writer};
+
+use super::process_tree_struct::{LeafLabel, Node, OperatorType, ProcessTree};
+use crate::{utils::new_uuid, XMLWriterWrapper};
+
+/// Flat PTML node (element name, id, label)
+struct PtmlNode {
+    element: &'static str,
+    id: String,
+    name: String,
+}
+
+/// Collects the PTML nodes and parent-child edges of a process tree (returns the ID of the added node)
+///
+/// Loops with more than one redo child are translated to a loop with an exclusive choice as redo part,
+/// and a silent exit child (as `ProM`'s `xorLoop` expects exactly do, redo and exit children).
+/// Loops without redo children get a silent redo child.
+fn collect_ptml_nodes(
+    node: &Node,
+    nodes: &mut Vec<PtmlNode>,
+    edges: &mut Vec<(String, String)>,
+) -> String {
+    let id = new_uuid().to_string();
+    match node {
+        Node::Leaf(leaf) => {
+            let (element, name) = match &leaf.activity_label {
+                LeafLabel::Activity(label) => ("manualTask", label.clone()),
+                LeafLabel::Tau => ("automaticTask", "tau".to_string()),
+            };
+            nodes.push(PtmlNode {
+                element,
+                id: id.clone(),
+                name,
+            });
+        }
+        Node::Operator(op) => {
+            let element = match op.operator_type {
+                OperatorType::Sequence => "sequence",
+                OperatorType::ExclusiveChoice => "xor",
+                OperatorType::Concurrency => "and",
+                OperatorType::Loop => "xorLoop",
+            };
+            nodes.push(PtmlNode {
+                element,
+                id: id.clone(),
+                name: element.to_string(),
+            });
+            let mut child_ids: Vec<String> = Vec::new();
+            match (&op.operator_type, op.children.as_slice()) {
+                (OperatorType::Loop, [do_child, redo_children @ ..]) => {
+                    child_ids.push(collect_ptml_nodes(do_child, nodes, edges));
+                    match redo_children {
+                        [] => child_ids.push(push_ptml_tau(nodes)),
+                        [redo_child] => {
+                            child_ids.push(collect_ptml_nodes(redo_child, nodes, edges));
+                        }
+                        _ => {
+                            let redo_id = new_uuid().to_string();
+                            nodes.push(PtmlNode {
+                                element: "xor",
+                                id: redo_id.clone(),
+                                name: "xor".to_string(),
+                            });
+                            for redo_child in redo_children {
+                                let child_id = collect_ptml_nodes(redo_child, nodes, edges);
+                                edges.push((redo_id.clone(), child_id));
+                            }
+                            child_ids.push(redo_id);
+                        }
+                    }
+                    child_ids.push(push_ptml_tau(nodes));
+                }
+                (_, children) => {
+                    for child in children {
+                        child_ids.push(collect_ptml_nodes(child, nodes, edges));
+                    }
+                }
+            }
+            for child_id in child_ids {
+                edges.push((id.clone(), child_id));
+            }
+        }
+    }
+    id
+}
+
+/// Adds a silent (`tau`) PTML node without children (returns its ID)
+fn push_ptml_tau(nodes: &mut Vec<PtmlNode>) -> String {
+    let id = new_uuid().to_string();
+    nodes.push(PtmlNode {
+        element: "automaticTask",
+        id: id.clone(),
+        name: "tau".to_string(),
+    });
+    id
+}
+
+///
+/// Export a [`ProcessTree`] to the PTML format (used by `ProM`) and write the result to the provided writer which implements into [`quick_xml::Writer`] / [`std::io::Write`]
+///
+pub fn export_process_tree_to_ptml<'a, W>(
+    tree: &ProcessTree,
+    into_writer: impl Into<XMLWriterWrapper<'a, W>>,
+) -> Result<(), quick_xml::Error>
+where
+    W: Write + 'a,
+{
+    let mut xml_writer: XMLWriterWrapper<'_, W> = into_writer.into();
+    let writer = xml_writer.to_xml_writer();
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let root_id = collect_ptml_nodes(&tree.root, &mut nodes, &mut edges);
+
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer
+        .create_element("ptml")
+        .write_inner_content(|writer| {
+            writer
+                .create_element("processTree")
+                .with_attributes([
+                    ("id", new_uuid().to_string().as_str()),
+                    ("name", "Rust Process Tree Export"),
+                    ("root", root_id.as_str()),
+                ])
+                .write_inner_content(|writer| {
+                    for node in &nodes {
+                        writer
+                            .create_element(node.element)
+                            .with_attributes([
+                                ("id", node.id.as_str()),
+                                ("name", node.name.as_str()),
+                            ])
+                            .write_empty()?;
+                    }
+                    for (parent, child) in &edges {
+                        writer
+                            .create_element("parentsNode")
+                            .with_attributes([
+                                ("id", new_uuid().to_string().as_str()),
+                                ("sourceId", parent.as_str()),
+                                ("targetId", child.as_str()),
+                            ])
+                            .write_empty()?;
+                    }
+                    Ok::<(), std::io::Error>(())
+                })?;
+            Ok::<(), std::io::Error>(())
+        })?;
+    Ok(())
+}
+
+/// Export a [`ProcessTree`] to a `.ptml` file (specified through path)
+///
+/// Also consider using [`ProcessTree::to_ptml`] to get the PTML as a [`String`].
+pub fn export_process_tree_to_ptml_path<P: AsRef<std::path::Path>>(
+    tree: &ProcessTree,
+    path: P,
+) -> Result<(), quick_xml::Error> {
+    let file = File::create(path)?;
+    let mut writer = Writer::new_with_indent(file, b' ', 4);
+    export_process_tree_to_ptml(tree, &mut writer)
+}
+
+/// Export a [`ProcessTree`] to a PTML [`String`]
+pub fn export_process_tree_to_ptml_string(tree: &ProcessTree) -> Result<String, quick_xml::Error> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 4);
+    export_process_tree_to_ptml(tree, &mut writer)?;
+    Ok(
+        String::from_utf8(writer.into_inner())
+            .expect("PTML export only writes valid UTF-8 strings"),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::export_process_tree_to_ptml_string;
+    use crate::core::process_models::case_centric::process_tree::{
+        Leaf, Node, Operator, OperatorType, ProcessTree,
+    };
+
+    #[test]
+    fn test_export_ptml_loop() {
+        let mut loop_op = Operator::new(OperatorType::Loop);
+        for label in ["a", "b", "c"] {
+            loop_op
+                .children
+                .push(Node::Leaf(Leaf::new(Some(label.to_string()))));
+        }
+        let tree = ProcessTree::new(Node::Operator(loop_op));
+        let ptml = export_process_tree_to_ptml_string(&tree).unwrap();
+        assert!(ptml.contains("<ptml>"));
+        assert_eq!(ptml.matches("<xorLoop ").count(), 1);
+        // b and c are wrapped in an exclusive choice
+        assert_eq!(ptml.matches("<xor ").count(), 1);
+        assert_eq!(ptml.matches("<manualTask ").count(), 3);
+        // Silent exit of the loop
+        assert_eq!(ptml.matches("<automaticTask ").count(), 1);
+        assert_eq!(ptml.matches("<parentsNode ").count(), 5);
+    }
+
+    #[test]
+    fn test_export_ptml_single_child_loop() {
+        let mut loop_op = Operator::new(OperatorType::Loop);
+        loop_op
+            .children
+            .push(Node::Leaf(Leaf::new(Some("a".to_string()))));
+        let tree = ProcessTree::new(Node::Operator(loop_op));
+        let ptml = export_process_tree_to_ptml_string(&tree).unwrap();
+        assert_eq!(ptml.matches("<xorLoop ").count(), 1);
+        assert_eq!(ptml.matches("<xor ").count(), 0);
+        assert_eq!(ptml.matches("<manualTask ").count(), 1);
+        // Silent redo and exit of the loop
+        assert_eq!(ptml.matches("<automaticTask ").count(), 2);
+        assert_eq!(ptml.matches("<parentsNode ").count(), 3);
+    }
+}
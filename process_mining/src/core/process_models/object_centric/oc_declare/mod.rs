@@ -6,12 +6,18 @@ use chrono::{DateTime, Duration, FixedOffset};
 use schemars::JsonSchema;
 
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::hash::Hash;
+use std::ops::{Bound, RangeBounds};
 
 use itertools::Itertools;
+use macros_process_mining::register_binding;
 use serde::{Deserialize, Serialize};
 
-use crate::conformance::oc_declare::{get_for_all_evs_perf, get_for_all_evs_perf_thresh};
+use crate::conformance::oc_declare::{
+    explain_unsatisfied, get_for_all_evs_perf, get_for_all_evs_perf_counts,
+    get_for_all_evs_perf_thresh, get_unary_constraint_perf, ViolationInfo,
+};
 use crate::core::event_data::object_centric::linked_ocel::slim_linked_ocel::{
     EventIndex, ObjectIndex,
 };
@@ -66,8 +72,28 @@ impl OCDeclareArc {
         ret
     }
 
+    /// Check if this is a "never" constraint arc, i.e., its max count is 0
+    ///
+    /// For example, an [`OCDeclareArcType::EF`] arc with max count 0 requires that the target never eventually follows the source
+    /// (considering the involved objects).
+    pub fn is_never(&self) -> bool {
+        self.counts.1 == Some(0)
+    }
+
     /// Generate template string representation
+    ///
+    /// "Never" constraint arcs (see [`OCDeclareArc::is_never`]) are labeled distinctly,
+    /// e.g., `NeverFollows(a, b, o)` instead of `EF(a, b, o,0,0)`.
     pub fn as_template_string(&self) -> String {
+        if self.is_never() {
+            return format!(
+                "{}({}, {}, {})",
+                self.arc_type.get_never_name(),
+                self.from.0,
+                self.to.0,
+                self.label.as_template_string(),
+            );
+        }
         format!(
             "{}({}, {}, {},{},{})",
             self.arc_type.get_name(),
@@ -114,6 +140,144 @@ impl OCDeclareArc {
             noise_thresh,
         )
     }
+
+    /// Get up to `max_examples` source events violating this constraint arc (see [`explain_unsatisfied`])
+    pub fn explain_unsatisfied(
+        &self,
+        linked_ocel: &SlimLinkedOCEL,
+        max_examples: usize,
+    ) -> Vec<ViolationInfo> {
+        explain_unsatisfied(linked_ocel, self, max_examples)
+    }
+}
+
+impl fmt::Display for OCDeclareArc {
+    /// Formats the arc using its template string (see [`OCDeclareArc::as_template_string`])
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.as_template_string())
+    }
+}
+
+/// Render OC-DECLARE arcs as a human-readable text model
+///
+/// Arcs are grouped by their source activity (sorted by name), with one arc per line.
+/// If an OCEL is provided, each arc is annotated with the number and fraction of its violating source events.
+pub fn format_oc_declare_model(
+    arcs: &[OCDeclareArc],
+    linked_ocel: Option<&SlimLinkedOCEL>,
+) -> String {
+    let mut ret = String::new();
+    for (from, group) in &arcs
+        .iter()
+        .sorted_by(|a, b| a.from.cmp(&b.from))
+        .chunk_by(|arc| &arc.from)
+    {
+        ret.push_str(from.as_str());
+        ret.push('\n');
+        for arc in group {
+            ret.push_str("  ");
+            ret.push_str(&arc.as_template_string());
+            if let Some(locel) = linked_ocel {
+                let (violated, total) = get_for_all_evs_perf_counts(
+                    arc.from.as_str(),
+                    arc.to.as_str(),
+                    &arc.label,
+                    &arc.arc_type,
+                    &arc.counts,
+                    locel,
+                );
+                let fraction = if total == 0 {
+                    0.0
+                } else {
+                    violated as f64 / total as f64
+                };
+                ret.push_str(&format!(
+                    "  [{violated}/{total} violated, {:.1}%]",
+                    fraction * 100.0
+                ));
+            }
+            ret.push('\n');
+        }
+    }
+    ret
+}
+
+/// Render OC-DECLARE arcs as a human-readable text model, annotated with violation statistics on the given OCEL
+///
+/// See [`format_oc_declare_model`].
+#[register_binding]
+pub fn oc_declare_model_to_string(locel: &SlimLinkedOCEL, arcs: Vec<OCDeclareArc>) -> String {
+    format_oc_declare_model(&arcs, Some(locel))
+}
+
+/// OC-DECLARE unary constraint, concerning a single node (i.e., activity)
+///
+/// In contrast to [`OCDeclareArc`]s, unary constraints do not relate two activities,
+/// but restrict how often a single activity occurs.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash, PartialOrd, Ord, JsonSchema,
+)]
+#[serde(tag = "type")]
+pub enum OCDeclareUnaryConstraint {
+    /// Existence: Each object of the object type must be involved in a number of events of the activity
+    /// within the given bounds
+    ///
+    /// e.g., every order object must be involved in at least one `pay order` event
+    Existence {
+        /// The activity
+        activity: OCDeclareNode,
+        /// The object type
+        object_type: String,
+        /// First tuple element: min count (optional), Second: max count (optional)
+        counts: (Option<usize>, Option<usize>),
+    },
+    /// Absence: The activity must not occur at all
+    Absence {
+        /// The activity
+        activity: OCDeclareNode,
+    },
+}
+
+impl OCDeclareUnaryConstraint {
+    /// Get the activity node of this constraint
+    pub fn activity(&self) -> &OCDeclareNode {
+        match self {
+            OCDeclareUnaryConstraint::Existence { activity, .. } => activity,
+            OCDeclareUnaryConstraint::Absence { activity } => activity,
+        }
+    }
+
+    /// Generate template string representation
+    pub fn as_template_string(&self) -> String {
+        match self {
+            OCDeclareUnaryConstraint::Existence {
+                activity,
+                object_type,
+                counts,
+            } => format!(
+                "Existence({}, {}, {},{})",
+                activity.0,
+                object_type,
+                counts.0.unwrap_or_default(),
+                counts.1.map(|x| x.to_string()).unwrap_or(String::from("∞"))
+            ),
+            OCDeclareUnaryConstraint::Absence { activity } => format!("Absence({})", activity.0),
+        }
+    }
+
+    /// Get fraction of instances violating this constraint
+    ///
+    /// See [`get_unary_constraint_perf`] for details.
+    pub fn get_perf(&self, linked_ocel: &SlimLinkedOCEL) -> f64 {
+        get_unary_constraint_perf(self, linked_ocel)
+    }
+}
+
+impl fmt::Display for OCDeclareUnaryConstraint {
+    /// Formats the constraint using its template string (see [`OCDeclareUnaryConstraint::as_template_string`])
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.as_template_string())
+    }
 }
 
 /// OC-DECLARE Arc Direction/Type
@@ -171,6 +335,19 @@ impl OCDeclareArcType {
         }
     }
 
+    /// Get the name of "never" constraints of this arc type (see [`OCDeclareArc::is_never`])
+    ///
+    /// e.g., [`OCDeclareArcType::EF`] -> `"NeverFollows"`
+    pub fn get_never_name(&self) -> &'static str {
+        match self {
+            OCDeclareArcType::AS => "NeverAssociated",
+            OCDeclareArcType::EF => "NeverFollows",
+            OCDeclareArcType::EP => "NeverPrecedes",
+            OCDeclareArcType::DF => "NeverDirectlyFollows",
+            OCDeclareArcType::DP => "NeverDirectlyPrecedes",
+        }
+    }
+
     /// Check if this arc type is dominated by other arc type
     pub fn is_dominated_by_or_eq(&self, arc_type: &OCDeclareArcType) -> bool {
         if *self == OCDeclareArcType::AS || self == arc_type {
@@ -197,6 +374,9 @@ pub enum ObjectTypeAssociation {
     Simple {
         /// The object type
         object_type: String,
+        /// If set, only objects related to the event with this E2O qualifier are considered
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        qualifier: Option<String>,
     },
     /// Indirect: Object Association through an O2O relationship
     O2O {
@@ -208,6 +388,9 @@ pub enum ObjectTypeAssociation {
         ///
         /// If reversed is `False`, `(first,second)` is considered
         reversed: bool,
+        /// If set, only O2O relationships with this qualifier are considered
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        qualifier: Option<String>,
     },
 }
 
@@ -216,6 +399,7 @@ impl ObjectTypeAssociation {
     pub fn new_simple<T: Into<String>>(ot: T) -> Self {
         Self::Simple {
             object_type: ot.into(),
+            qualifier: None,
         }
     }
     /// Create indirect (i.e., O2O) object type association
@@ -226,6 +410,7 @@ impl ObjectTypeAssociation {
             first: ot1.into(),
             second: ot2.into(),
             reversed: false,
+            qualifier: None,
         }
     }
     /// Create reversed indirect (i.e., O2O) object type association
@@ -236,50 +421,114 @@ impl ObjectTypeAssociation {
             first: ot1.into(),
             second: ot2.into(),
             reversed: true,
+            qualifier: None,
+        }
+    }
+
+    /// Restrict this association to relationships with the given qualifier
+    ///
+    /// For [`ObjectTypeAssociation::Simple`], this refers to the E2O qualifier, for [`ObjectTypeAssociation::O2O`] to the O2O qualifier.
+    pub fn with_qualifier<T: Into<String>>(mut self, q: T) -> Self {
+        match &mut self {
+            Self::Simple { qualifier, .. } | Self::O2O { qualifier, .. } => {
+                *qualifier = Some(q.into())
+            }
+        }
+        self
+    }
+
+    /// Get the qualifier this association is restricted to (if any)
+    pub fn qualifier(&self) -> Option<&str> {
+        match self {
+            Self::Simple { qualifier, .. } | Self::O2O { qualifier, .. } => qualifier.as_deref(),
         }
     }
 
     /// Format as string
+    ///
+    /// Qualifiers are appended in square brackets, e.g., `item[packed]`.
     pub fn as_template_string(&self) -> String {
-        match self {
-            ObjectTypeAssociation::Simple { object_type } => object_type.clone(),
+        let ret = match self {
+            ObjectTypeAssociation::Simple { object_type, .. } => object_type.clone(),
             ObjectTypeAssociation::O2O {
                 first,
                 second,
                 reversed,
+                ..
             } => format!("{}{}{}", first, if !reversed { ">" } else { "<" }, second),
+        };
+        match self.qualifier() {
+            Some(q) => format!("{ret}[{q}]"),
+            None => ret,
         }
     }
 
     /// Get the object index for all objects specified by the association for a specified event
+    ///
+    /// If a qualifier is set, only relationships with that qualifier are considered.
+    /// Synthetic (init/exit) events have no E2O qualifiers, so an E2O qualifier is ignored for them.
     pub fn get_for_ev<'a>(
         &'a self,
         ev: &'a EventOrSynthetic,
         linked_ocel: &'a SlimLinkedOCEL,
     ) -> Vec<&'a ObjectIndex> {
         match self {
-            ObjectTypeAssociation::Simple { object_type } => ev
-                .get_e2o(linked_ocel)
-                .filter(|o| {
-                    let ot = o.get_ob_type(linked_ocel);
-                    ot == object_type
-                })
-                .collect(),
+            ObjectTypeAssociation::Simple {
+                object_type,
+                qualifier,
+            } => {
+                let objects: Box<dyn Iterator<Item = &'a ObjectIndex>> = match (ev, qualifier) {
+                    (EventOrSynthetic::Event(ev_index), Some(q)) => Box::new(
+                        ev_index
+                            .get_ev(linked_ocel)
+                            .relationships
+                            .iter()
+                            .filter(move |(q_idx, _)| linked_ocel.qualifier_str(*q_idx) == q)
+                            .map(|(_, o)| o),
+                    ),
+                    _ => ev.get_e2o(linked_ocel),
+                };
+                objects
+                    .filter(|o| {
+                        let ot = o.get_ob_type(linked_ocel);
+                        ot == object_type
+                    })
+                    .collect()
+            }
             ObjectTypeAssociation::O2O {
                 first,
                 second,
                 reversed,
+                qualifier,
             } => ev
                 .get_e2o(linked_ocel)
                 .filter(|o| o.get_ob_type(linked_ocel) == first)
                 .flat_map(|o| {
                     if !reversed {
-                        o.get_o2o(linked_ocel)
-                            .filter(|o2| o2.get_ob_type(linked_ocel) == second)
+                        o.get_ob(linked_ocel)
+                            .relationships
+                            .iter()
+                            .filter(|(q_idx, o2)| {
+                                o2.get_ob_type(linked_ocel) == second
+                                    && qualifier
+                                        .as_ref()
+                                        .is_none_or(|q| linked_ocel.qualifier_str(*q_idx) == q)
+                            })
+                            .map(|(_, o2)| o2)
                             .collect_vec()
                     } else {
                         o.get_o2o_rev(linked_ocel)
-                            .filter(|o2| o2.get_ob_type(linked_ocel) == second)
+                            .filter(|o2| {
+                                o2.get_ob_type(linked_ocel) == second
+                                    && qualifier.as_ref().is_none_or(|q| {
+                                        o2.get_ob(linked_ocel).relationships.iter().any(
+                                            |(q_idx, target)| {
+                                                target == o
+                                                    && linked_ocel.qualifier_str(*q_idx) == q
+                                            },
+                                        )
+                                    })
+                            })
                             .collect_vec()
                     }
                 })
@@ -386,7 +635,7 @@ impl OCDeclareArcLabel {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type")]
 /// Set filter modeling the predicate that all or any of the included elements must be present
 pub enum SetFilter<T: Eq + Hash> {
@@ -406,6 +655,16 @@ impl<T: Eq + Hash + Ord> SetFilter<T> {
     }
 }
 
+/// Sort key for object type associations in bindings: Associations with more objects (of the target type) first
+fn binding_sort_key(ot: &ObjectTypeAssociation, linked_ocel: &SlimLinkedOCEL) -> i32 {
+    match ot {
+        ObjectTypeAssociation::Simple { object_type, .. } => {
+            -(linked_ocel.num_obs_of_type(object_type) as i32)
+        }
+        ObjectTypeAssociation::O2O { second, .. } => -(linked_ocel.num_obs_of_type(second) as i32),
+    }
+}
+
 impl<'b> OCDeclareArcLabel {
     /// Get all bindings for an OC-DECLARE arc label for a specified event.
     ///
@@ -418,27 +677,13 @@ impl<'b> OCDeclareArcLabel {
     ) -> impl Iterator<Item = Vec<SetFilter<&'a ObjectIndex>>> + use<'a, 'b> {
         self.each
             .iter()
-            .sorted_by_key(|ot| match ot {
-                ObjectTypeAssociation::Simple { object_type } => {
-                    -(linked_ocel.get_obs_of_type(object_type).count() as i32)
-                }
-                ObjectTypeAssociation::O2O { second, .. } => {
-                    -(linked_ocel.get_obs_of_type(second).count() as i32)
-                }
-            })
+            .sorted_by_key(|ot| binding_sort_key(ot, linked_ocel))
             .map(|otass| otass.get_for_ev(ev, linked_ocel))
             .multi_cartesian_product()
             .map(|product| {
                 self.all
                     .iter()
-                    .sorted_by_key(|ot| match ot {
-                        ObjectTypeAssociation::Simple { object_type } => {
-                            -(linked_ocel.get_obs_of_type(object_type).count() as i32)
-                        }
-                        ObjectTypeAssociation::O2O { second, .. } => {
-                            -(linked_ocel.get_obs_of_type(second).count() as i32)
-                        }
-                    })
+                    .sorted_by_key(|ot| binding_sort_key(ot, linked_ocel))
                     .map(|otass| SetFilter::All(otass.get_for_ev(ev, linked_ocel)))
                     .chain(if product.is_empty() {
                         Vec::default()
@@ -448,14 +693,7 @@ impl<'b> OCDeclareArcLabel {
                     .chain(
                         self.any
                             .iter()
-                            .sorted_by_key(|ot| match ot {
-                                ObjectTypeAssociation::Simple { object_type } => {
-                                    -(linked_ocel.get_obs_of_type(object_type).count() as i32)
-                                }
-                                ObjectTypeAssociation::O2O { second, .. } => {
-                                    -(linked_ocel.get_obs_of_type(second).count() as i32)
-                                }
-                            })
+                            .sorted_by_key(|ot| binding_sort_key(ot, linked_ocel))
                             .map(|otass| {
                                 let x = otass.get_for_ev(ev, linked_ocel);
                                 if x.len() == 1 {
@@ -477,20 +715,49 @@ pub struct ObjectInvolvementCounts {
     pub min: usize,
     /// The maximum number of objects of a given type involved in a single instance.
     pub max: usize,
-    // mean: usize,
+    /// The number of instances involving at least one object of the given type.
+    #[serde(default)]
+    pub num_instances: usize,
+    /// The total number of objects of the given type involved, summed over all instances.
+    #[serde(default)]
+    pub total: usize,
 }
 impl Default for ObjectInvolvementCounts {
     fn default() -> Self {
         Self {
             min: usize::MAX,
             max: Default::default(),
+            num_instances: 0,
+            total: 0,
+        }
+    }
+}
+
+impl ObjectInvolvementCounts {
+    /// Record a single instance involving `count` objects of the given type
+    pub fn add_instance(&mut self, count: usize) {
+        self.min = self.min.min(count);
+        self.max = self.max.max(count);
+        self.num_instances += 1;
+        self.total += count;
+    }
+
+    /// The mean number of objects of a given type involved in a single instance
+    ///
+    /// Only instances involving at least one object of the type are considered (as for [`ObjectInvolvementCounts::min`]).
+    /// Returns `None` if no such instances exist.
+    pub fn mean(&self) -> Option<f64> {
+        if self.num_instances == 0 {
+            None
+        } else {
+            Some(self.total as f64 / self.num_instances as f64)
         }
     }
 }
 
 /// Get the object type involvements for an activity
 ///
-/// Produces the min and max counts for objects per object type and activity
+/// Produces the min, max, and mean counts for objects per object type and activity
 ///
 /// The result is a mapping: Activity -> (Object Type -> Counts)
 pub fn get_activity_object_involvements(
@@ -510,14 +777,10 @@ pub fn get_activity_object_involvements(
                     *num_of_objects_for_ev.entry(ot).or_default() += 1;
                 }
                 for (ot, count) in num_of_objects_for_ev {
-                    let num_ob_per_type = nums_of_objects_per_type.get_mut(ot).unwrap();
-
-                    if count < num_ob_per_type.min {
-                        num_ob_per_type.min = count
-                    }
-                    if count > num_ob_per_type.max {
-                        num_ob_per_type.max = count;
-                    }
+                    nums_of_objects_per_type
+                        .get_mut(ot)
+                        .unwrap()
+                        .add_instance(count);
                 }
             }
             (
@@ -531,6 +794,39 @@ pub fn get_activity_object_involvements(
         .collect()
 }
 
+/// Get the qualifier-specific object type involvements for an activity
+///
+/// Like [`get_activity_object_involvements`], but counting objects per object type and E2O qualifier.
+///
+/// The result is a mapping: Activity -> ((Object Type, Qualifier) -> Counts)
+pub fn get_activity_object_qualifier_involvements(
+    locel: &SlimLinkedOCEL,
+) -> HashMap<String, HashMap<(String, String), ObjectInvolvementCounts>> {
+    locel
+        .get_ev_types()
+        .map(|et| {
+            let mut nums_of_objects: HashMap<(String, String), ObjectInvolvementCounts> =
+                HashMap::new();
+            for ev in locel.get_evs_of_type(et) {
+                let mut num_of_objects_for_ev: HashMap<(&str, &str), usize> = HashMap::new();
+                for (q, oi) in &ev.get_ev(locel).relationships {
+                    let ot = oi.get_ob_type(locel);
+                    *num_of_objects_for_ev
+                        .entry((ot, locel.qualifier_str(*q)))
+                        .or_default() += 1;
+                }
+                for ((ot, q), count) in num_of_objects_for_ev {
+                    nums_of_objects
+                        .entry((ot.to_string(), q.to_string()))
+                        .or_default()
+                        .add_instance(count);
+                }
+            }
+            (et.to_string(), nums_of_objects)
+        })
+        .collect()
+}
+
 /// Get Object-to-Object Involvements in the passed OCEL
 ///
 /// Returns a mapping Object Type -> (Object Type -> Count)
@@ -553,14 +849,10 @@ pub fn get_object_to_object_involvements(
                     *num_of_objects_for_ob.entry(ot).or_default() += 1;
                 }
                 for (ot, count) in num_of_objects_for_ob {
-                    let num_ob_per_type = nums_of_objects_per_type.get_mut(ot).unwrap();
-
-                    if count < num_ob_per_type.min {
-                        num_ob_per_type.min = count
-                    }
-                    if count > num_ob_per_type.max {
-                        num_ob_per_type.max = count;
-                    }
+                    nums_of_objects_per_type
+                        .get_mut(ot)
+                        .unwrap()
+                        .add_instance(count);
                 }
             }
             (
@@ -596,14 +888,10 @@ pub fn get_rev_object_to_object_involvements(
                     *num_of_objects_for_ob.entry(ot).or_default() += 1;
                 }
                 for (ot, count) in num_of_objects_for_ob {
-                    let num_ob_per_type = nums_of_objects_per_type.get_mut(ot).unwrap();
-
-                    if count < num_ob_per_type.min {
-                        num_ob_per_type.min = count
-                    }
-                    if count > num_ob_per_type.max {
-                        num_ob_per_type.max = count;
-                    }
+                    nums_of_objects_per_type
+                        .get_mut(ot)
+                        .unwrap()
+                        .add_instance(count);
                 }
             }
             (
@@ -746,6 +1034,28 @@ impl EventOrSynthetic {
             // .collect()
         }
     }
+    /// Get all events (regular or synthetic) of a specific event type involving a specific object,
+    /// which occurred in the given time range
+    ///
+    /// Regular events are looked up in the time-sorted E2O index (see [`ObjectIndex::get_e2o_rev_of_evtype_in_range`]).
+    pub fn get_all_of_et_for_ob_in_range<'a>(
+        locel: &'a SlimLinkedOCEL,
+        ev_type: &'a str,
+        ob: ObjectIndex,
+        range: (Bound<DateTime<FixedOffset>>, Bound<DateTime<FixedOffset>>),
+    ) -> Box<dyn Iterator<Item = Self> + 'a> {
+        if ev_type.starts_with(INIT_EVENT_PREFIX) || ev_type.starts_with(EXIT_EVENT_PREFIX) {
+            Box::new(
+                Self::get_all_of_et_for_ob(locel, ev_type, ob)
+                    .filter(move |e| range.contains(&e.get_timestamp(locel))),
+            )
+        } else {
+            Box::new(
+                ob.get_e2o_rev_of_evtype_in_range(locel, ev_type, range)
+                    .map(Self::Event),
+            )
+        }
+    }
     /// Get all events (regular or synthetic) involving a specific object
     pub fn get_all_for_ob(locel: &SlimLinkedOCEL, ob: ObjectIndex) -> Vec<Self> {
         ob.get_e2o_rev(locel)
@@ -754,3 +1064,269 @@ impl EventOrSynthetic {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ocel;
+
+    #[test]
+    fn test_activity_object_involvements_mean() {
+        let ocel = ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2", "i:3"]),
+            ("place", ["o:2", "i:4"]),
+            ("pay", ["o:1"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let inv = get_activity_object_involvements(&locel);
+        let items = &inv["place"]["i"];
+        assert_eq!((items.min, items.max), (1, 3));
+        assert_eq!((items.num_instances, items.total), (2, 4));
+        assert_eq!(items.mean(), Some(2.0));
+        assert!(!inv["pay"].contains_key("i"));
+        assert_eq!(ObjectInvolvementCounts::default().mean(), None);
+    }
+
+    #[test]
+    fn test_unary_constraints() {
+        let ocel = ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            ("place", ["o:2", "i:2"]),
+            ("pay", ["o:1"]),
+            ("pay", ["o:2"]),
+            ("pay", ["o:2"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let pay_once = OCDeclareUnaryConstraint::Existence {
+            activity: OCDeclareNode::new("pay"),
+            object_type: "o".to_string(),
+            counts: (Some(1), Some(1)),
+        };
+        assert_eq!(pay_once.get_perf(&locel), 0.5);
+        assert_eq!(pay_once.as_template_string(), "Existence(pay, o, 1,1)");
+        let absence = OCDeclareUnaryConstraint::Absence {
+            activity: OCDeclareNode::new("pay"),
+        };
+        assert_eq!(absence.get_perf(&locel), 0.6);
+    }
+
+    #[test]
+    fn test_qualified_object_type_association() {
+        let mut ocel = ocel![
+            events:
+            ("pack", ["o:1", "i:1", "i:2"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("o:1", "i:2")
+        ];
+        for rel in &mut ocel.events[0].relationships {
+            rel.qualifier = if rel.object_id == "i:1" {
+                "packed"
+            } else {
+                "shipped"
+            }
+            .to_string();
+        }
+        let order = ocel.objects.iter_mut().find(|o| o.id == "o:1").unwrap();
+        for rel in &mut order.relationships {
+            rel.qualifier = if rel.object_id == "i:1" {
+                "contains"
+            } else {
+                "other"
+            }
+            .to_string();
+        }
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let ev = EventOrSynthetic::Event(0.into());
+        let ids = |assoc: &ObjectTypeAssociation| {
+            assoc
+                .get_for_ev(&ev, &locel)
+                .into_iter()
+                .map(|o| locel.get_ob_id(o).to_string())
+                .sorted()
+                .collect_vec()
+        };
+        assert_eq!(ids(&ObjectTypeAssociation::new_simple("i")), ["i:1", "i:2"]);
+        let packed = ObjectTypeAssociation::new_simple("i").with_qualifier("packed");
+        assert_eq!(ids(&packed), ["i:1"]);
+        assert_eq!(packed.as_template_string(), "i[packed]");
+        assert_eq!(
+            ids(&ObjectTypeAssociation::new_o2o("o", "i").with_qualifier("contains")),
+            ["i:1"]
+        );
+        assert_eq!(
+            ids(&ObjectTypeAssociation::new_o2o_rev("i", "o").with_qualifier("contains")),
+            ["o:1"]
+        );
+        assert_eq!(
+            serde_json::to_string(&ObjectTypeAssociation::new_simple("i")).unwrap(),
+            r#"{"type":"Simple","object_type":"i"}"#
+        );
+    }
+
+    #[test]
+    fn test_explain_unsatisfied() {
+        let ocel = ocel![
+            events:
+            ("place", ["o:1"]),
+            ("place", ["o:2"]),
+            ("ship", ["o:1"]),
+            ("place", ["o:3"]),
+            ("ship", ["o:3"]),
+            ("place", ["o:4"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let arc = OCDeclareArc {
+            from: OCDeclareNode::new("place"),
+            to: OCDeclareNode::new("ship"),
+            arc_type: OCDeclareArcType::EF,
+            label: OCDeclareArcLabel {
+                each: vec![ObjectTypeAssociation::new_simple("o")],
+                ..Default::default()
+            },
+            counts: (Some(1), None),
+        };
+        let violations = arc.explain_unsatisfied(&locel, 10);
+        let violating_obs: Vec<_> = violations.iter().map(|v| v.binding.clone()).collect();
+        assert_eq!(
+            violating_obs,
+            vec![
+                vec![SetFilter::All(vec!["o:2".to_string()])],
+                vec![SetFilter::All(vec!["o:4".to_string()])],
+            ]
+        );
+        assert!(violations
+            .iter()
+            .all(|v| v.num_targets == 0 && v.source_event_type == "place"));
+        let place_o2 = locel.get_evs_of_type("place").nth(1).unwrap();
+        assert_eq!(violations[0].source_id, locel.get_ev_id(place_o2));
+        assert_eq!(violations[0].time, *locel.get_ev_time(place_o2));
+        // Stops after the requested number of examples
+        assert_eq!(arc.explain_unsatisfied(&locel, 1), violations[..1]);
+        assert!(arc.explain_unsatisfied(&locel, 0).is_empty());
+        // Synthetic source events are reported with the object ID
+        let init_arc = OCDeclareArc {
+            from: OCDeclareNode::new(format!("{INIT_EVENT_PREFIX} o")),
+            ..arc
+        };
+        let ids: HashSet<_> = init_arc
+            .explain_unsatisfied(&locel, 10)
+            .into_iter()
+            .map(|v| v.source_id)
+            .collect();
+        assert_eq!(ids, ["o:2".to_string(), "o:4".to_string()].into());
+    }
+
+    #[test]
+    fn test_ranged_target_events() {
+        let ocel = ocel![
+            events:
+            ("pay", ["o:1"]),
+            ("place", ["o:1", "o:2"]),
+            ("pay", ["o:1"]),
+            ("pay", ["o:2"]),
+            ("place", ["o:1"]),
+            ("pay", ["o:1", "o:2"]),
+            o2o:
+        ];
+        let mut locel = SlimLinkedOCEL::from_ocel(ocel);
+        let times: Vec<_> = locel.get_all_evs().map(|e| *e.get_time(&locel)).collect();
+        let init_o = format!("{INIT_EVENT_PREFIX} o");
+        for ob in locel.get_all_obs() {
+            for t in &times {
+                for range in [
+                    (Bound::Excluded(*t), Bound::Unbounded),
+                    (Bound::Unbounded, Bound::Excluded(*t)),
+                    (Bound::Included(*t), Bound::Included(*t)),
+                ] {
+                    for et in ["pay", "place", init_o.as_str()] {
+                        let scanned: HashSet<_> =
+                            EventOrSynthetic::get_all_of_et_for_ob(&locel, et, ob)
+                                .filter(|e| range.contains(&e.get_timestamp(&locel)))
+                                .collect();
+                        let ranged: HashSet<_> =
+                            EventOrSynthetic::get_all_of_et_for_ob_in_range(&locel, et, ob, range)
+                                .collect();
+                        assert_eq!(scanned, ranged);
+                    }
+                }
+            }
+        }
+
+        let place_pay = |arc_type| OCDeclareArc {
+            from: OCDeclareNode::new("place"),
+            to: OCDeclareNode::new("pay"),
+            arc_type,
+            label: OCDeclareArcLabel {
+                each: vec![ObjectTypeAssociation::new_simple("o")],
+                ..Default::default()
+            },
+            counts: (Some(1), None),
+        };
+        assert_eq!(
+            place_pay(OCDeclareArcType::EF).get_for_all_evs_perf(&locel),
+            0.0
+        );
+        assert_eq!(
+            place_pay(OCDeclareArcType::EP).get_for_all_evs_perf(&locel),
+            0.5
+        );
+
+        // The time-sorted index is rebuilt after modifications
+        let o2 = locel.get_ob_by_id("o:2").unwrap();
+        let last = times.last().unwrap();
+        locel
+            .add_event(
+                "place",
+                *last + Duration::hours(1),
+                None,
+                vec![],
+                vec![("".to_string(), o2)],
+            )
+            .unwrap();
+        assert!(
+            (place_pay(OCDeclareArcType::EF).get_for_all_evs_perf(&locel) - 1.0 / 3.0).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_format_oc_declare_model() {
+        let ocel = ocel![
+            events:
+            ("place", ["o:1"]),
+            ("pay", ["o:1"]),
+            ("place", ["o:2"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let arc = |from: &str, to: &str| OCDeclareArc {
+            from: OCDeclareNode::new(from),
+            to: OCDeclareNode::new(to),
+            arc_type: OCDeclareArcType::EF,
+            label: OCDeclareArcLabel {
+                each: vec![ObjectTypeAssociation::new_simple("o")],
+                ..Default::default()
+            },
+            counts: (Some(1), None),
+        };
+        let arcs = vec![arc("place", "pay"), arc("pay", "place")];
+        assert_eq!(arcs[0].to_string(), arcs[0].as_template_string());
+        assert_eq!(
+            format_oc_declare_model(&arcs, None),
+            format!("pay\n  {}\nplace\n  {}\n", arcs[1], arcs[0])
+        );
+        let with_stats = oc_declare_model_to_string(&locel, arcs.clone());
+        assert_eq!(
+            with_stats,
+            format!(
+                "pay\n  {}  [1/1 violated, 100.0%]\nplace\n  {}  [1/2 violated, 50.0%]\n",
+                arcs[1], arcs[0]
+            )
+        );
+    }
+}
@@ -5,6 +5,7 @@ use uuid::Uuid;
 use crate::core::process_models::object_centric::ocpt::{
     add_all_dfr_from_to_alphabets, compute_shuffle_dfr_language, EventType, ObjectType,
 };
+use crate::utils::new_uuid;
 
 ///
 /// Leaf in an object-centric process tree
@@ -362,7 +363,7 @@ impl OCPTOperator {
     ///
     pub fn new(operator_type: OCPTOperatorType) -> Self {
         Self {
-            uuid: Uuid::new_v4(),
+            uuid: new_uuid(),
             operator_type,
             children: Vec::new(),
         }
@@ -1278,7 +1279,7 @@ impl OCPTLeaf {
     pub fn new(leaf_label: Option<EventType>) -> Self {
         if let Some(leaf_label) = leaf_label {
             Self {
-                uuid: Uuid::new_v4(),
+                uuid: new_uuid(),
                 activity_label: OCPTLeafLabel::Activity(leaf_label),
                 related_ob_types: HashSet::new(),
                 divergent_ob_types: HashSet::new(),
@@ -1287,7 +1288,7 @@ impl OCPTLeaf {
             }
         } else {
             Self {
-                uuid: Uuid::new_v4(),
+                uuid: new_uuid(),
                 activity_label: OCPTLeafLabel::Tau,
                 related_ob_types: HashSet::new(),
                 divergent_ob_types: HashSet::new(),
@@ -0,0 +1,166 @@
+//! Diagnostics (i.e., warnings) emitted by the library
+//!
+//! Non-fatal problems (e.g., skipped invalid relationships during import) are reported as [`Diagnostic`]s
+//! instead of being printed directly.
+//! By default, they are printed to stderr.
+//!
+//! Embedders can capture them programmatically:
+//! - [`set_diagnostic_handler`] registers a global handler receiving all diagnostics
+//! - [`collect_diagnostics`] collects all diagnostics emitted on the current thread while running a closure
+//!
+//! ```rust
+//! use process_mining::diagnostics::collect_diagnostics;
+//! use process_mining::core::event_data::case_centric::utils::activity_projection::{
+//!     add_start_end_acts_proj, EventLogActivityProjection,
+//! };
+//!
+//! let mut proj = EventLogActivityProjection::default();
+//! add_start_end_acts_proj(&mut proj);
+//! let (_, diagnostics) = collect_diagnostics(|| add_start_end_acts_proj(&mut proj));
+//! assert_eq!(diagnostics.len(), 2);
+//! ```
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    sync::{Arc, RwLock},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A diagnostic (warning) emitted by the library
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// Module path of the code emitting the diagnostic (e.g., `process_mining::core::event_data::case_centric::xes::stream_xes`)
+    pub source: String,
+    /// Human-readable message
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[rust4pm] warning: {}", self.message)
+    }
+}
+
+type DiagnosticHandler = Arc<dyn Fn(&Diagnostic) + Send + Sync>;
+
+static HANDLER: RwLock<Option<DiagnosticHandler>> = RwLock::new(None);
+
+thread_local! {
+    static COLLECTOR: RefCell<Option<Vec<Diagnostic>>> = const { RefCell::new(None) };
+}
+
+///
+/// Set a global handler, which is called for every emitted [`Diagnostic`]
+///
+/// Replaces the default handler (printing to stderr) or a previously set handler.
+/// Diagnostics emitted inside of [`collect_diagnostics`] are not passed to the handler.
+pub fn set_diagnostic_handler(handler: impl Fn(&Diagnostic) + Send + Sync + 'static) {
+    let mut h = HANDLER.write().unwrap_or_else(|e| e.into_inner());
+    *h = Some(Arc::new(handler));
+}
+
+/// Reset the global diagnostic handler to the default (printing to stderr)
+pub fn reset_diagnostic_handler() {
+    let mut h = HANDLER.write().unwrap_or_else(|e| e.into_inner());
+    *h = None;
+}
+
+///
+/// Run `f` and collect all [`Diagnostic`]s emitted on the current thread in the meantime
+///
+/// Collected diagnostics are not passed to the global handler.
+/// Note that diagnostics emitted on other threads (e.g., by parallelized code) are not collected.
+///
+/// Returns the result of `f` together with the collected diagnostics.
+pub fn collect_diagnostics<T>(f: impl FnOnce() -> T) -> (T, Vec<Diagnostic>) {
+    let previous = COLLECTOR.with(|c| c.borrow_mut().replace(Vec::new()));
+    let res = f();
+    let collected = COLLECTOR.with(|c| {
+        let mut c = c.borrow_mut();
+        let collected = c.take().unwrap_or_default();
+        *c = previous;
+        collected
+    });
+    (res, collected)
+}
+
+/// Emit a [`Diagnostic`]
+///
+/// Passed to the active collector of the current thread (see [`collect_diagnostics`]),
+/// the global handler (see [`set_diagnostic_handler`]), or printed to stderr.
+pub fn emit(diagnostic: Diagnostic) {
+    let diagnostic = match COLLECTOR.with(|c| match c.borrow_mut().as_mut() {
+        Some(collected) => {
+            collected.push(diagnostic);
+            None
+        }
+        None => Some(diagnostic),
+    }) {
+        Some(d) => d,
+        None => return,
+    };
+    let handler = HANDLER.read().unwrap_or_else(|e| e.into_inner()).clone();
+    match handler {
+        Some(handler) => handler(&diagnostic),
+        None => eprintln!("{diagnostic}"),
+    }
+}
+
+/// Emit a warning [`Diagnostic`] using [`format!`]-style arguments
+macro_rules! warn_diagnostic {
+    ($($arg:tt)*) => {
+        $crate::diagnostics::emit($crate::diagnostics::Diagnostic {
+            source: module_path!().to_string(),
+            message: format!($($arg)*),
+        })
+    };
+}
+pub(crate) use warn_diagnostic;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn test_collect_diagnostics() {
+        let (res, diagnostics) = collect_diagnostics(|| {
+            warn_diagnostic!("first {}", 1);
+            let (_, inner) = collect_diagnostics(|| warn_diagnostic!("inner"));
+            assert_eq!(inner.len(), 1);
+            warn_diagnostic!("second");
+            42
+        });
+        assert_eq!(res, 42);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| d.message.as_str())
+                .collect::<Vec<_>>(),
+            vec!["first 1", "second"]
+        );
+        assert_eq!(diagnostics[0].source, module_path!());
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "[rust4pm] warning: first 1".to_string()
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_handler() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let r = Arc::clone(&received);
+        set_diagnostic_handler(move |d| r.lock().unwrap().push(d.message.clone()));
+        // The handler is global, so it also receives diagnostics emitted on other threads
+        std::thread::spawn(|| warn_diagnostic!("from handler test"))
+            .join()
+            .unwrap();
+        reset_diagnostic_handler();
+        assert!(received
+            .lock()
+            .unwrap()
+            .contains(&"from handler test".to_string()));
+    }
+}
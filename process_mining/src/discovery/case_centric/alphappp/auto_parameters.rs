@@ -13,6 +13,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 4.0,
         absolute_df_clean_thresh: 1,
         relative_df_clean_thresh: 0.01,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.6,
@@ -22,6 +23,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 2.0,
         absolute_df_clean_thresh: 1,
         relative_df_clean_thresh: 0.01,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.4,
@@ -31,6 +33,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 4.0,
         absolute_df_clean_thresh: 1,
         relative_df_clean_thresh: 0.01,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.4,
@@ -40,6 +43,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 2.0,
         absolute_df_clean_thresh: 1,
         relative_df_clean_thresh: 0.01,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.4,
@@ -49,6 +53,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 2.0,
         absolute_df_clean_thresh: 5,
         relative_df_clean_thresh: 0.05,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.1,
@@ -58,6 +63,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 2.0,
         absolute_df_clean_thresh: 5,
         relative_df_clean_thresh: 0.05,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.25,
@@ -67,6 +73,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 2.0,
         absolute_df_clean_thresh: 25,
         relative_df_clean_thresh: 0.1,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.1,
@@ -76,6 +83,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 4.0,
         absolute_df_clean_thresh: 1,
         relative_df_clean_thresh: 0.01,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.1,
@@ -85,6 +93,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 2.0,
         absolute_df_clean_thresh: 1,
         relative_df_clean_thresh: 0.01,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.1,
@@ -94,6 +103,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 4.0,
         absolute_df_clean_thresh: 1,
         relative_df_clean_thresh: 0.01,
+        max_candidates: None,
     },
     AlphaPPPConfig {
         balance_thresh: 0.1,
@@ -103,6 +113,7 @@ const AUTO_CONFIGS: &[AlphaPPPConfig] = &[
         log_repair_loop_df_thresh_rel: 2.0,
         absolute_df_clean_thresh: 1,
         relative_df_clean_thresh: 0.01,
+        max_candidates: None,
     },
 ];
 
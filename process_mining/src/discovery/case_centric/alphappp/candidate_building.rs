@@ -2,7 +2,14 @@ use std::collections::HashSet;
 
 use rayon::prelude::*;
 
-use crate::core::event_data::case_centric::utils::activity_projection::ActivityProjectionDFG;
+use crate::core::event_data::case_centric::utils::activity_projection::{
+    ActivityProjectionDFG, EventLogActivityProjection,
+};
+
+use super::log_repair::filter_dfg;
+
+/// Set of place candidates (each represented as input transitions and output transitions)
+pub type PlaceCandidates = HashSet<(Vec<usize>, Vec<usize>)>;
 
 fn no_df_between(df_rel: &HashSet<(usize, usize)>, a: &HashSet<usize>, b: &HashSet<usize>) -> bool {
     for &a1 in a {
@@ -70,6 +77,23 @@ pub fn satisfies_cnd_condition(df_rel: &HashSet<(usize, usize)>, a: &[usize], b:
 
 /// Build place candidates (represented as input transitions and output transitions) from a [`ActivityProjectionDFG`]
 pub fn build_candidates(dfg: &ActivityProjectionDFG) -> HashSet<(Vec<usize>, Vec<usize>)> {
+    build_candidates_with_limit(dfg, None).0
+}
+
+/// Build place candidates (see [`build_candidates`]), stopping early once `max_candidates` candidates were found
+///
+/// The limit is checked while combining candidates, so that the memory and time spent is bounded.
+/// Candidates are combined in a fixed (sorted) order, so the returned candidates are deterministic,
+/// even if candidate building was stopped early.
+///
+/// Returns the candidates and whether candidate building was stopped early (i.e., the result is truncated).
+pub fn build_candidates_with_limit(
+    dfg: &ActivityProjectionDFG,
+    max_candidates: Option<usize>,
+) -> (PlaceCandidates, bool) {
+    let remaining = |final_cnds: &HashSet<(Vec<usize>, Vec<usize>)>| {
+        max_candidates.map(|max| max.saturating_sub(final_cnds.len()))
+    };
     let df_relations: HashSet<(usize, usize)> = dfg
         .edges
         .par_iter()
@@ -78,6 +102,7 @@ pub fn build_candidates(dfg: &ActivityProjectionDFG) -> HashSet<(Vec<usize>, Vec
     println!("DF #{:?}", df_relations.len());
     let mut cnds: HashSet<(Vec<usize>, Vec<usize>)> = HashSet::new();
     let mut final_cnds: HashSet<(Vec<usize>, Vec<usize>)> = HashSet::new();
+    let mut truncated = false;
     (0..dfg.nodes.len()).for_each(|a| {
         (0..dfg.nodes.len()).for_each(|b| {
             if df_relations.contains(&(a, b))
@@ -85,7 +110,11 @@ pub fn build_candidates(dfg: &ActivityProjectionDFG) -> HashSet<(Vec<usize>, Vec
                 && !df_relations.contains(&(a, a))
                 && !df_relations.contains(&(b, b))
             {
-                final_cnds.insert((vec![a], vec![b]));
+                if remaining(&final_cnds) == Some(0) {
+                    truncated = true;
+                } else {
+                    final_cnds.insert((vec![a], vec![b]));
+                }
                 cnds.insert((vec![a], vec![b]));
             } else {
                 cnds.insert((vec![a], vec![b]));
@@ -93,48 +122,127 @@ pub fn build_candidates(dfg: &ActivityProjectionDFG) -> HashSet<(Vec<usize>, Vec
         });
     });
 
-    let mut changed = true;
-    let mut new_cnds: HashSet<(Vec<usize>, Vec<usize>)> = cnds.clone();
-    while changed {
-        changed = false;
-        let added_cnds: HashSet<(Vec<usize>, Vec<usize>)> = new_cnds
-            .par_iter()
-            .flat_map(|(a1, b1)| {
-                cnds.par_iter()
-                    .filter_map(|(a2, b2)| {
-                        if !all_dfs_between_vec(&df_relations, a1, b2)
-                            || !all_dfs_between_vec(&df_relations, a2, b1)
-                        {
-                            return None;
-                        }
-                        let mut a = [a1.as_slice(), a2.as_slice()].concat();
-                        let mut b = [b1.as_slice(), b2.as_slice()].concat();
-                        if all_dfs_between_vec(&df_relations, &b, &a) {
-                            return None;
-                        }
-                        a.sort();
-                        a.dedup();
-                        b.sort();
-                        b.dedup();
-                        if a != b
-                            && !cnds.contains(&(a.clone(), b.clone()))
-                            && satisfies_cnd_condition(&df_relations, &a, &b)
-                        {
-                            return Some((a, b));
-                        }
-                        None
-                    })
-                    .collect::<HashSet<(Vec<usize>, Vec<usize>)>>()
-            })
-            .collect();
-        if !added_cnds.is_empty() {
-            changed = true;
-            for cnd in &added_cnds {
-                final_cnds.insert(cnd.clone());
-                cnds.insert(cnd.clone());
+    if truncated {
+        return (final_cnds, true);
+    }
+    let mut new_cnds: Vec<(Vec<usize>, Vec<usize>)> = cnds.iter().cloned().collect();
+    new_cnds.sort();
+    while !new_cnds.is_empty() {
+        let mut added_cnds: HashSet<(Vec<usize>, Vec<usize>)> = HashSet::new();
+        // Combine one candidate at a time (in sorted order) with all others,
+        // so that the limit can be checked within each round
+        for (a1, b1) in &new_cnds {
+            let mut combined: Vec<(Vec<usize>, Vec<usize>)> = cnds
+                .par_iter()
+                .filter_map(|(a2, b2)| {
+                    if !all_dfs_between_vec(&df_relations, a1, b2)
+                        || !all_dfs_between_vec(&df_relations, a2, b1)
+                    {
+                        return None;
+                    }
+                    let mut a = [a1.as_slice(), a2.as_slice()].concat();
+                    let mut b = [b1.as_slice(), b2.as_slice()].concat();
+                    if all_dfs_between_vec(&df_relations, &b, &a) {
+                        return None;
+                    }
+                    a.sort();
+                    a.dedup();
+                    b.sort();
+                    b.dedup();
+                    if a != b
+                        && !cnds.contains(&(a.clone(), b.clone()))
+                        && !added_cnds.contains(&(a.clone(), b.clone()))
+                        && satisfies_cnd_condition(&df_relations, &a, &b)
+                    {
+                        return Some((a, b));
+                    }
+                    None
+                })
+                .collect();
+            combined.sort();
+            combined.dedup();
+            if let Some(remaining) = remaining(&final_cnds) {
+                if combined.len() > remaining {
+                    final_cnds.extend(combined.into_iter().take(remaining));
+                    return (final_cnds, true);
+                }
             }
-            new_cnds = added_cnds;
+            final_cnds.extend(combined.iter().cloned());
+            added_cnds.extend(combined);
         }
+        cnds.extend(added_cnds.iter().cloned());
+        new_cnds = added_cnds.into_iter().collect();
+        new_cnds.sort();
+    }
+    (final_cnds, false)
+}
+
+/// Export the (pre-pruning) place candidates for an [`EventLogActivityProjection`] with activity names resolved
+///
+/// The DFG of the projection is first filtered using [`filter_dfg`] with the passed `(absolute, relative)` thresholds.
+/// No start/end activities or log repair activities are added; callers wanting to inspect
+/// the candidates of the full algorithm should pass the accordingly prepared projection.
+///
+/// Candidates are returned sorted, so the output can be compared directly (e.g., against other implementations).
+pub fn export_candidates(
+    log: &EventLogActivityProjection,
+    dfg_filter: (u64, f32),
+) -> Vec<(Vec<String>, Vec<String>)> {
+    let dfg = ActivityProjectionDFG::from_event_log_projection(log);
+    let dfg = filter_dfg(&dfg, dfg_filter.0, dfg_filter.1);
+    let resolve = |acts: &Vec<usize>| -> Vec<String> {
+        let mut names: Vec<String> = acts.iter().map(|a| log.activities[*a].clone()).collect();
+        names.sort();
+        names
+    };
+    let mut ret: Vec<(Vec<String>, Vec<String>)> = build_candidates(&dfg)
+        .iter()
+        .map(|(a, b)| (resolve(a), resolve(b)))
+        .collect();
+    ret.sort();
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_candidates_stops_at_limit() {
+        let log = crate::event_log!(["a", "b", "d"], ["a", "c", "d"]);
+        let proj: EventLogActivityProjection = (&log).into();
+        let dfg = ActivityProjectionDFG::from_event_log_projection(&proj);
+        let (all, truncated) = build_candidates_with_limit(&dfg, None);
+        assert!(!truncated);
+        assert_eq!(all, build_candidates(&dfg));
+        let (limited, truncated) = build_candidates_with_limit(&dfg, Some(2));
+        assert!(truncated);
+        assert_eq!(limited.len(), 2);
+        assert!(limited.is_subset(&all));
+        // Limit reached while combining candidates
+        let (limited, truncated) = build_candidates_with_limit(&dfg, Some(all.len() - 1));
+        assert!(truncated);
+        assert_eq!(limited.len(), all.len() - 1);
+        assert!(limited.is_subset(&all));
+        // The truncated candidates do not depend on the (parallel) processing order
+        for _ in 0..10 {
+            assert_eq!(
+                build_candidates_with_limit(&dfg, Some(all.len() - 1)).0,
+                limited
+            );
+        }
+    }
+
+    #[test]
+    fn export_candidates_resolves_names() {
+        let log = crate::event_log!(["a", "b", "d"], ["a", "c", "d"]);
+        let proj: EventLogActivityProjection = (&log).into();
+        let cnds = export_candidates(&proj, (0, 0.0));
+        let to_vec = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert!(cnds.contains(&(to_vec(&["a"]), to_vec(&["b", "c"]))));
+        assert!(cnds.contains(&(to_vec(&["b", "c"]), to_vec(&["d"]))));
+        let mut sorted = cnds.clone();
+        sorted.sort();
+        assert_eq!(cnds, sorted);
     }
-    final_cnds
 }
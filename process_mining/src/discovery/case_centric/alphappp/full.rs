@@ -1,23 +1,28 @@
-use std::{
-    collections::HashSet,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use macros_process_mining::register_binding;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::core::{
-    event_data::case_centric::utils::activity_projection::{
-        add_start_end_acts_proj, ActivityProjectionDFG, EventLogActivityProjection, END_ACTIVITY,
-        START_ACTIVITY,
+use crate::{
+    core::{
+        event_data::case_centric::{
+            utils::activity_projection::{
+                add_start_end_acts_proj, log_to_activity_projection_with_classifier,
+                ActivityProjectionDFG, EventLogActivityProjection, END_ACTIVITY, START_ACTIVITY,
+            },
+            EventLogClassifier,
+        },
+        process_models::case_centric::petri_net::petri_net_struct::{
+            ArcType, Marking, TransitionID,
+        },
+        EventLog, PetriNet,
     },
-    process_models::case_centric::petri_net::petri_net_struct::{ArcType, Marking, TransitionID},
-    PetriNet,
+    diagnostics::warn_diagnostic,
 };
 
 use super::{
-    candidate_building::build_candidates,
+    candidate_building::build_candidates_with_limit,
     candidate_pruning::prune_candidates,
     log_repair::{
         add_artificial_acts_for_loops, add_artificial_acts_for_skips, filter_dfg, SILENT_ACT_PREFIX,
@@ -77,6 +82,12 @@ pub struct AlphaPPPConfig {
     pub absolute_df_clean_thresh: u64,
     /// Relative threshold for weighted DFG cleaning
     pub relative_df_clean_thresh: f32,
+    /// Maximum number of place candidates to build (unbounded if `None`)
+    ///
+    /// If the limit is reached, candidate building stops early and the discovered Petri net
+    /// is based only on the candidates built so far (a warning diagnostic is emitted).
+    #[serde(default)]
+    pub max_candidates: Option<usize>,
 }
 impl AlphaPPPConfig {
     /// Serialize Alpha+++ parameters to JSON string
@@ -84,8 +95,14 @@ impl AlphaPPPConfig {
         serde_json::to_string(self).unwrap()
     }
     /// Deserialize Alpha+++ parameters from JSON string
+    ///
+    /// Panics if the JSON is invalid. See [`AlphaPPPConfig::try_from_json`] for a non-panicking version.
     pub fn from_json(json: &str) -> Self {
-        serde_json::from_str(json).unwrap()
+        Self::try_from_json(json).unwrap()
+    }
+    /// Deserialize Alpha+++ parameters from JSON string, returning an error if the JSON is invalid
+    pub fn try_from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
     }
 }
 
@@ -99,6 +116,7 @@ impl Default for AlphaPPPConfig {
             log_repair_loop_df_thresh_rel: 2.0,
             absolute_df_clean_thresh: 10,
             relative_df_clean_thresh: 0.1,
+            max_candidates: None,
         }
     }
 }
@@ -114,6 +132,22 @@ pub fn alphappp_discover_petri_net(
     alphappp_discover_petri_net_with_timing_fn(log_proj, config, &|| 0).0
 }
 
+///
+/// Discover a [`PetriNet`] from an [`EventLog`] using the Alpha+++ Process Discovery algorithm
+///
+/// The activities of events are derived using the passed [`EventLogClassifier`]
+/// (see [`log_to_activity_projection_with_classifier`]).
+///
+/// Returns the discovered Petri net as well as performance measurements
+pub fn alphappp_discover_from_log(
+    log: &EventLog,
+    classifier: &EventLogClassifier,
+    config: AlphaPPPConfig,
+) -> (PetriNet, AlgoDuration) {
+    let log_proj = log_to_activity_projection_with_classifier(log, classifier);
+    alphappp_discover_petri_net_with_timing_fn(&log_proj, config, &get_current_time_millis)
+}
+
 /// Run Alpha+++ discovery
 ///
 /// Measures [`AlgoDuration`] using the passed `get_time_millis_fn` function
@@ -200,8 +234,14 @@ pub fn alphappp_discover_petri_net_with_timing_fn(
     algo_dur.filter_dfg = (get_time_millis_fn() - start) as f32 / 1000.0;
     println!("Filtering DFG took: {:.4}s", algo_dur.filter_dfg);
     start = get_time_millis_fn();
-    let cnds: HashSet<(Vec<usize>, Vec<usize>)> = build_candidates(&dfg);
+    let (cnds, truncated) = build_candidates_with_limit(&dfg, config.max_candidates);
     println!("Built candidates {}", cnds.len());
+    if truncated {
+        warn_diagnostic!(
+            "Alpha+++ candidate building stopped after reaching the maximum of {} candidates; the discovered Petri net is partial",
+            cnds.len()
+        );
+    }
 
     algo_dur.cnd_building = (get_time_millis_fn() - start) as f32 / 1000.0;
     println!("Building candidates took: {:.4}s", algo_dur.cnd_building);
@@ -304,3 +344,67 @@ pub fn cnds_to_names(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_log;
+
+    #[test]
+    fn test_alphappp_discover_from_log() {
+        let log = event_log!(
+            ["a"; {"org:resource" => "r1"}, "b"; {"org:resource" => "r2"}],
+            ["a"; {"org:resource" => "r1"}, "b"; {"org:resource" => "r2"}],
+        );
+        let classifier = EventLogClassifier {
+            name: "Activity and Resource".to_string(),
+            keys: vec!["concept:name".to_string(), "org:resource".to_string()],
+        };
+        let config = AlphaPPPConfig {
+            absolute_df_clean_thresh: 0,
+            ..Default::default()
+        };
+        let (net, _) = alphappp_discover_from_log(&log, &classifier, config);
+        let mut labels: Vec<_> = net
+            .transitions
+            .values()
+            .filter_map(|t| t.label.clone())
+            .collect();
+        labels.sort();
+        assert_eq!(labels, vec!["a+r1", "b+r2"]);
+    }
+
+    #[test]
+    fn test_alphappp_max_candidates() {
+        let log = event_log!(["a", "b", "d"], ["a", "c", "d"], ["a", "e", "d"]);
+        let config = AlphaPPPConfig {
+            absolute_df_clean_thresh: 0,
+            ..Default::default()
+        };
+        let (full, diagnostics) = crate::diagnostics::collect_diagnostics(|| {
+            alphappp_discover_petri_net(&(&log).into(), config)
+        });
+        assert!(diagnostics.is_empty());
+        let config = AlphaPPPConfig {
+            max_candidates: Some(1),
+            ..config
+        };
+        let (partial, diagnostics) = crate::diagnostics::collect_diagnostics(|| {
+            alphappp_discover_petri_net(&(&log).into(), config)
+        });
+        assert_eq!(diagnostics.len(), 1);
+        assert!(partial.places.len() <= 1);
+        assert!(partial.places.len() < full.places.len());
+    }
+
+    #[test]
+    fn test_alphappp_config_try_from_json() {
+        let config = AlphaPPPConfig {
+            balance_thresh: 0.5,
+            ..Default::default()
+        };
+        let parsed = AlphaPPPConfig::try_from_json(&config.to_json()).unwrap();
+        assert_eq!(parsed.balance_thresh, 0.5);
+        assert!(AlphaPPPConfig::try_from_json("{\"balance_thresh\": 0.5}").is_err());
+    }
+}
@@ -1,10 +1,19 @@
 //! Discover [`DirectlyFollowsGraph`]s from Data
 
+use std::collections::HashMap;
+
 use macros_process_mining::register_binding;
 
 use crate::core::{
-    event_data::case_centric::EventLogClassifier,
-    process_models::case_centric::dfg::DirectlyFollowsGraph, EventLog,
+    event_data::case_centric::{
+        utils::activity_projection::{
+            add_start_end_acts_proj, ActivityProjectionDFG, EventLogActivityProjection,
+            END_ACTIVITY, START_ACTIVITY,
+        },
+        EventLogClassifier,
+    },
+    process_models::case_centric::dfg::DirectlyFollowsGraph,
+    EventLog,
 };
 
 /// Discover a [`DirectlyFollowsGraph`] from an [`EventLog`] using the specified [`EventLogClassifier`] to derive the 'activity' names
@@ -42,3 +51,163 @@ pub fn discover_dfg_with_classifier<'a, 'b>(
 pub fn discover_dfg<'b>(event_log: &EventLog) -> DirectlyFollowsGraph<'b> {
     discover_dfg_with_classifier(event_log, &EventLogClassifier::default())
 }
+
+/// Discover a [`DirectlyFollowsGraph`] from an [`EventLogActivityProjection`] with artificial start and end activities
+///
+/// Every trace is extended by the artificial [`START_ACTIVITY`] and [`END_ACTIVITY`] (see [`add_start_end_acts_proj`]),
+/// so the returned graph contains edges from [`START_ACTIVITY`] to all activities starting a trace and
+/// from all activities ending a trace to [`END_ACTIVITY`].
+/// The only start and end activities of the returned graph are [`START_ACTIVITY`] and [`END_ACTIVITY`], respectively.
+///
+/// Activity and edge frequencies take the trace frequencies of the projection into account.
+#[register_binding]
+pub fn dfg_with_artificial_start_end<'b>(
+    log: &EventLogActivityProjection,
+) -> DirectlyFollowsGraph<'b> {
+    let mut log = log.clone();
+    add_start_end_acts_proj(&mut log);
+    let proj_dfg = ActivityProjectionDFG::from_event_log_projection(&log);
+
+    // Counts of the graph are `u32`; larger counts saturate instead of wrapping around
+    let mut act_counts = vec![0u32; log.activities.len()];
+    for (trace, freq) in &log.traces {
+        let freq = u32::try_from(*freq).unwrap_or(u32::MAX);
+        for act in trace {
+            act_counts[*act] = act_counts[*act].saturating_add(freq);
+        }
+    }
+
+    let mut result = DirectlyFollowsGraph::new();
+    for (act, count) in log.activities.iter().zip(act_counts) {
+        result.add_activity(act.clone(), count);
+    }
+    for ((a, b), w) in proj_dfg.edges {
+        result.add_df_relation(
+            log.activities[a].clone().into(),
+            log.activities[b].clone().into(),
+            u32::try_from(w).unwrap_or(u32::MAX),
+        );
+    }
+    result.add_start_activity(START_ACTIVITY.to_string());
+    result.add_end_activity(END_ACTIVITY.to_string());
+    result
+}
+
+/// Filter the directly-follows relations of a [`DirectlyFollowsGraph`], returning a new graph
+///
+/// A relation `(a, b)` with weight `w` is kept if
+/// - `w >= absolute`, and
+/// - `w >= relative * m`, where `m` is the maximum weight of all outgoing relations of `a`.
+///
+/// I.e., `relative` is the fraction (between 0 and 1) of the heaviest outgoing relation of the source activity
+/// which a relation needs to reach.
+/// Activities (and their counts) as well as start and end activities are kept unchanged.
+///
+/// See also [`filter_dfg`](crate::discovery::case_centric::alphappp::log_repair::filter_dfg) for the (differently defined)
+/// filter on [`ActivityProjectionDFG`]s used by Alpha+++.
+pub fn filter_dfg<'a>(
+    dfg: &DirectlyFollowsGraph<'a>,
+    absolute: u64,
+    relative: f64,
+) -> DirectlyFollowsGraph<'a> {
+    let mut max_outgoing: HashMap<&str, u32> = HashMap::new();
+    for ((a, _), w) in &dfg.directly_follows_relations {
+        let max = max_outgoing.entry(a.as_ref()).or_default();
+        *max = (*max).max(*w);
+    }
+    let directly_follows_relations = dfg
+        .directly_follows_relations
+        .iter()
+        .filter(|((a, _), w)| {
+            u64::from(**w) >= absolute
+                && f64::from(**w) >= relative * f64::from(max_outgoing[a.as_ref()])
+        })
+        .map(|(k, w)| (k.clone(), *w))
+        .collect();
+    DirectlyFollowsGraph {
+        activities: dfg.activities.clone(),
+        directly_follows_relations,
+        start_activities: dfg.start_activities.clone(),
+        end_activities: dfg.end_activities.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_log;
+
+    #[test]
+    fn test_dfg_with_artificial_start_end() {
+        let log = event_log!(["a", "b", "c"], ["a", "c"], ["a", "b", "c"]);
+        let proj: EventLogActivityProjection = (&log).into();
+        let dfg = dfg_with_artificial_start_end(&proj);
+
+        assert_eq!(dfg.activities.get(START_ACTIVITY), Some(&3));
+        assert_eq!(dfg.activities.get(END_ACTIVITY), Some(&3));
+        assert_eq!(dfg.activities.get("b"), Some(&2));
+        assert_eq!(
+            dfg.directly_follows_relations
+                .get(&(START_ACTIVITY.into(), "a".into())),
+            Some(&3)
+        );
+        assert_eq!(
+            dfg.directly_follows_relations
+                .get(&("c".into(), END_ACTIVITY.into())),
+            Some(&3)
+        );
+        assert_eq!(
+            dfg.directly_follows_relations
+                .get(&("a".into(), "c".into())),
+            Some(&1)
+        );
+        assert_eq!(dfg.directly_follows_relations.len(), 5);
+        assert!(dfg.is_start_activity(START_ACTIVITY) && dfg.start_activities.len() == 1);
+        assert!(dfg.is_end_activity(END_ACTIVITY) && dfg.end_activities.len() == 1);
+    }
+
+    #[test]
+    fn test_dfg_with_artificial_start_end_saturates_counts() {
+        let log = event_log!(["a", "b"], ["a"]);
+        let mut proj: EventLogActivityProjection = (&log).into();
+        for (_, freq) in &mut proj.traces {
+            *freq = u64::from(u32::MAX);
+        }
+        let dfg = dfg_with_artificial_start_end(&proj);
+        assert_eq!(dfg.activities.get("a"), Some(&u32::MAX));
+        assert_eq!(dfg.activities.get("b"), Some(&u32::MAX));
+        assert_eq!(
+            dfg.directly_follows_relations
+                .get(&(START_ACTIVITY.into(), "a".into())),
+            Some(&u32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_filter_dfg() {
+        let log = event_log!(
+            ["a", "b"],
+            ["a", "b"],
+            ["a", "b"],
+            ["a", "b"],
+            ["a", "c"],
+            ["b", "c"]
+        );
+        let dfg = discover_dfg(&log);
+        let filtered = filter_dfg(&dfg, 0, 0.5);
+        assert!(filtered
+            .directly_follows_relations
+            .contains_key(&("a".into(), "b".into())));
+        // 1 < 0.5 * 4
+        assert!(!filtered
+            .directly_follows_relations
+            .contains_key(&("a".into(), "c".into())));
+        // Only outgoing relation of b
+        assert!(filtered
+            .directly_follows_relations
+            .contains_key(&("b".into(), "c".into())));
+        assert_eq!(filtered.activities, dfg.activities);
+        let filtered = filter_dfg(&dfg, 2, 0.0);
+        assert_eq!(filtered.directly_follows_relations.len(), 1);
+    }
+}
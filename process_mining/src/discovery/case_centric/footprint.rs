@@ -0,0 +1,256 @@
+//! Footprint Matrices of Event Logs
+//!
+//! The footprint of an event log relates every ordered pair of activities based on the directly-follows relation
+//! (as in the Alpha algorithm).
+//! Comparing the footprints of a log and of a model (see [`FootprintMatrix::compare`]) is a simple way to
+//! check their conformance.
+use std::collections::{BTreeSet, HashSet};
+
+use macros_process_mining::register_binding;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "dataframes")]
+use polars::{error::PolarsError, frame::DataFrame, prelude::Column};
+
+use crate::core::event_data::case_centric::utils::activity_projection::EventLogActivityProjection;
+
+///
+/// Relation between two activities `a` and `b` in a [`FootprintMatrix`]
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum FootprintRelation {
+    /// `a → b`: `a` is directly followed by `b`, but not vice versa
+    Causal,
+    /// `a ← b`: `b` is directly followed by `a`, but not vice versa
+    ReverseCausal,
+    /// `a || b`: `a` and `b` directly follow each other (in both orders)
+    Parallel,
+    /// `a # b`: `a` and `b` never directly follow each other
+    Choice,
+}
+
+impl FootprintRelation {
+    /// Get the relation from the directly-follows relations in both directions
+    pub fn from_directly_follows(a_follows_b: bool, b_follows_a: bool) -> Self {
+        match (a_follows_b, b_follows_a) {
+            (true, false) => FootprintRelation::Causal,
+            (false, true) => FootprintRelation::ReverseCausal,
+            (true, true) => FootprintRelation::Parallel,
+            (false, false) => FootprintRelation::Choice,
+        }
+    }
+
+    /// Symbol of the relation (i.e., `→`, `←`, `||`, or `#`)
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            FootprintRelation::Causal => "→",
+            FootprintRelation::ReverseCausal => "←",
+            FootprintRelation::Parallel => "||",
+            FootprintRelation::Choice => "#",
+        }
+    }
+}
+
+///
+/// Footprint matrix, relating all ordered pairs of activities (see [`footprint_matrix`])
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FootprintMatrix {
+    /// Activities (sorted)
+    pub activities: Vec<String>,
+    /// Relations of all ordered pairs of activities
+    ///
+    /// `relations[i][j]` is the relation between `activities[i]` and `activities[j]`
+    pub relations: Vec<Vec<FootprintRelation>>,
+}
+
+///
+/// Differing cell of two [`FootprintMatrix`]es (see [`FootprintMatrix::compare`])
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct FootprintDifference {
+    /// First activity of the pair
+    pub a: String,
+    /// Second activity of the pair
+    pub b: String,
+    /// Relation in the first matrix
+    pub left: FootprintRelation,
+    /// Relation in the second matrix
+    pub right: FootprintRelation,
+}
+
+impl FootprintMatrix {
+    /// Get the relation between the activities `a` and `b`
+    ///
+    /// Returns `None` if one of the activities is not contained in the matrix.
+    pub fn get(&self, a: &str, b: &str) -> Option<FootprintRelation> {
+        let i = self
+            .activities
+            .binary_search_by(|x| x.as_str().cmp(a))
+            .ok()?;
+        let j = self
+            .activities
+            .binary_search_by(|x| x.as_str().cmp(b))
+            .ok()?;
+        Some(self.relations[i][j])
+    }
+
+    ///
+    /// Compare this matrix with another [`FootprintMatrix`], returning all cells with a different relation
+    ///
+    /// All pairs of activities contained in at least one of the two matrices are compared.
+    /// Activities which are missing in a matrix never directly follow any activity there,
+    /// so their relations are [`FootprintRelation::Choice`].
+    /// The differences are ordered by the activity pair.
+    ///
+    pub fn compare(&self, other: &FootprintMatrix) -> Vec<FootprintDifference> {
+        let activities: BTreeSet<&String> = self
+            .activities
+            .iter()
+            .chain(other.activities.iter())
+            .collect();
+        let mut ret = Vec::new();
+        for a in &activities {
+            for b in &activities {
+                let left = self.get(a, b).unwrap_or(FootprintRelation::Choice);
+                let right = other.get(a, b).unwrap_or(FootprintRelation::Choice);
+                if left != right {
+                    ret.push(FootprintDifference {
+                        a: a.to_string(),
+                        b: b.to_string(),
+                        left,
+                        right,
+                    });
+                }
+            }
+        }
+        ret
+    }
+
+    #[cfg(feature = "dataframes")]
+    ///
+    /// Convert this [`FootprintMatrix`] to a Polars [`DataFrame`]
+    ///
+    /// The first column (`activity`) contains the activities of the rows, followed by one column per activity.
+    /// If an activity is itself named `activity`, underscores are appended to the name of the first column
+    /// until it does not collide with any activity (e.g., `activity_`).
+    /// Cells contain the symbols of the relations (see [`FootprintRelation::symbol`]).
+    ///
+    /// Note: This function is only available if the `dataframes` feature is enabled.
+    ///
+    pub fn to_dataframe(&self) -> Result<DataFrame, PolarsError> {
+        let mut label = "activity".to_string();
+        while self.activities.contains(&label) {
+            label.push('_');
+        }
+        let mut columns = vec![Column::new(label.into(), &self.activities)];
+        columns.extend(self.activities.iter().enumerate().map(|(j, b)| {
+            Column::new(
+                b.as_str().into(),
+                self.relations
+                    .iter()
+                    .map(|row| row[j].symbol())
+                    .collect::<Vec<_>>(),
+            )
+        }));
+        DataFrame::new(columns)
+    }
+}
+
+///
+/// Compute the [`FootprintMatrix`] of an [`EventLogActivityProjection`]
+///
+/// The relations are derived from the directly-follows relation of the log:
+/// For activities `a` and `b`, `a → b` if `a` is directly followed by `b` in some trace but never vice versa,
+/// `a || b` if both occur, and `a # b` if neither occurs.
+/// In particular, an activity is parallel to itself iff it is directly followed by itself in some trace.
+///
+#[register_binding]
+pub fn footprint_matrix(log: &EventLogActivityProjection) -> FootprintMatrix {
+    let directly_follows: HashSet<(usize, usize)> = log
+        .traces
+        .iter()
+        .flat_map(|(trace, _)| trace.windows(2).map(|w| (w[0], w[1])))
+        .collect();
+    let mut order: Vec<usize> = (0..log.activities.len()).collect();
+    order.sort_by(|a, b| log.activities[*a].cmp(&log.activities[*b]));
+    let relations = order
+        .iter()
+        .map(|a| {
+            order
+                .iter()
+                .map(|b| {
+                    FootprintRelation::from_directly_follows(
+                        directly_follows.contains(&(*a, *b)),
+                        directly_follows.contains(&(*b, *a)),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+    FootprintMatrix {
+        activities: order.iter().map(|a| log.activities[*a].clone()).collect(),
+        relations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_log;
+
+    #[test]
+    fn test_footprint_matrix() {
+        let log: EventLogActivityProjection =
+            (&event_log!(["a", "b", "c", "d"], ["a", "c", "b", "d"], ["a", "e", "e"])).into();
+        let footprint = footprint_matrix(&log);
+        assert_eq!(footprint.activities, ["a", "b", "c", "d", "e"]);
+        assert_eq!(footprint.get("a", "b"), Some(FootprintRelation::Causal));
+        assert_eq!(
+            footprint.get("d", "b"),
+            Some(FootprintRelation::ReverseCausal)
+        );
+        assert_eq!(footprint.get("b", "c"), Some(FootprintRelation::Parallel));
+        assert_eq!(footprint.get("a", "d"), Some(FootprintRelation::Choice));
+        assert_eq!(footprint.get("e", "e"), Some(FootprintRelation::Parallel));
+        assert_eq!(footprint.get("a", "x"), None);
+
+        let other: EventLogActivityProjection =
+            (&event_log!(["a", "b", "c", "d"], ["a", "x"])).into();
+        let differences = footprint.compare(&footprint_matrix(&other));
+        assert!(differences.contains(&FootprintDifference {
+            a: "b".to_string(),
+            b: "c".to_string(),
+            left: FootprintRelation::Parallel,
+            right: FootprintRelation::Causal,
+        }));
+        assert!(differences.contains(&FootprintDifference {
+            a: "x".to_string(),
+            b: "a".to_string(),
+            left: FootprintRelation::Choice,
+            right: FootprintRelation::ReverseCausal,
+        }));
+        assert!(footprint.compare(&footprint).is_empty());
+    }
+
+    #[cfg(feature = "dataframes")]
+    #[test]
+    fn test_footprint_dataframe() {
+        let log: EventLogActivityProjection = (&event_log!(["a", "b"], ["b", "b"])).into();
+        let df = footprint_matrix(&log).to_dataframe().unwrap();
+        assert_eq!(df.shape(), (2, 3));
+        let b = df.column("b").unwrap().str().unwrap();
+        assert_eq!(b.get(0), Some("→"));
+        assert_eq!(b.get(1), Some("||"));
+
+        // Activity named like the first column
+        let log: EventLogActivityProjection = (&event_log!(["activity", "activity_", "b"])).into();
+        let df = footprint_matrix(&log).to_dataframe().unwrap();
+        assert_eq!(df.shape(), (3, 4));
+        assert_eq!(
+            df.get_column_names(),
+            ["activity__", "activity", "activity_", "b"]
+        );
+    }
+}
@@ -3,3 +3,5 @@
 pub mod alphappp;
 
 pub mod dfg;
+
+pub mod footprint;
@@ -1,5 +1,12 @@
 //! Discovering OC-DECLARE Models from Object-Centric Event Data
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet, VecDeque},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        RwLock,
+    },
+    time::{Duration, Instant},
+};
 
 use itertools::Itertools;
 use macros_process_mining::register_binding;
@@ -8,14 +15,14 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    conformance::oc_declare::get_for_all_evs_perf_thresh,
+    conformance::oc_declare::{get_for_all_evs_perf_counts, get_for_all_evs_perf_thresh},
     core::{
         event_data::object_centric::linked_ocel::{LinkedOCELAccess, SlimLinkedOCEL},
         process_models::oc_declare::{
-            get_activity_object_involvements, get_object_to_object_involvements,
-            get_rev_object_to_object_involvements, OCDeclareArc, OCDeclareArcLabel,
-            OCDeclareArcType, OCDeclareNode, ObjectInvolvementCounts, ObjectTypeAssociation,
-            ALL_OC_DECLARE_ARC_TYPES,
+            get_activity_object_involvements, get_activity_object_qualifier_involvements,
+            get_object_to_object_involvements, get_rev_object_to_object_involvements, OCDeclareArc,
+            OCDeclareArcLabel, OCDeclareArcType, OCDeclareNode, OCDeclareUnaryConstraint,
+            ObjectInvolvementCounts, ObjectTypeAssociation, ALL_OC_DECLARE_ARC_TYPES,
         },
     },
 };
@@ -60,6 +67,17 @@ pub struct OCDeclareDiscoveryOptions {
     pub o2o_mode: O2OMode,
     /// Activities to use for the discovery. If this is `None`, all activities of the OCEL are used
     pub acts_to_use: Option<Vec<String>>,
+    /// Object types to consider in object involvement labels. If this is `None`, all object types of the OCEL are used
+    ///
+    /// For O2O-based involvements, both object types need to be included.
+    #[serde(default)]
+    pub object_types_to_use: Option<Vec<String>>,
+    /// Determines if qualifier-specific object involvements (i.e., restricted to a specific E2O qualifier) are considered
+    /// in addition to the object type-based ones
+    ///
+    /// Note that this enlarges the search space, and thus might considerably increase the discovery runtime.
+    #[serde(default)]
+    pub qualifier_involvements: bool,
     /// What min/max counts to use for the candidate generation steps
     pub counts_for_generation: (Option<usize>, Option<usize>),
     /// What min/max counts to use for the candidate filtering step (when the arrow type is determined)
@@ -72,6 +90,25 @@ pub struct OCDeclareDiscoveryOptions {
     ///
     /// Should be non-empty!
     pub considered_arrow_types: HashSet<OCDeclareArcType>,
+    /// Determines if "never" constraints (e.g., the target never eventually follows the source) are discovered
+    ///
+    /// For associated activity pairs (i.e., where an AS constraint holds), the EF and EP arrow types (if considered)
+    /// are additionally tested with max count 0 (see [`OCDeclareArc::is_never`]).
+    /// Discovered "never" constraints are not reduced or refined.
+    #[serde(default)]
+    pub never_constraints: bool,
+    /// Determines if unary constraints (see [`OCDeclareUnaryConstraint`]) are discovered in addition to the constraint arcs
+    ///
+    /// The discovered unary constraints are part of the [`OCDeclareDiscoveryResult`] (see [`discover_unary_constraints`]).
+    #[serde(default)]
+    pub unary_constraints: bool,
+    /// Time budget for the discovery. If this is `None`, the discovery runs until completion
+    ///
+    /// Once the budget is exceeded, no further activity pairs are evaluated and refinement is skipped,
+    /// so only the constraints found so far are returned (see [`discover_behavior_constraints_within_budget`]).
+    /// At least one activity pair is always evaluated.
+    #[serde(default)]
+    pub time_budget: Option<Duration>,
 }
 impl Default for OCDeclareDiscoveryOptions {
     fn default() -> Self {
@@ -79,54 +116,291 @@ impl Default for OCDeclareDiscoveryOptions {
             noise_threshold: 0.2,
             o2o_mode: O2OMode::None,
             acts_to_use: None,
+            object_types_to_use: None,
+            qualifier_involvements: false,
             counts_for_generation: (Some(1), None),
             counts_for_filter: (Some(1), Some(20)),
             reduction: OCDeclareReductionMode::None,
             refinement: false,
             considered_arrow_types: ALL_OC_DECLARE_ARC_TYPES.iter().copied().collect(),
+            never_constraints: false,
+            unary_constraints: false,
+            time_budget: None,
         }
     }
 }
 
+/// Key of a cached arc evaluation: source/target activity, arc type, label and counts
+type ArcEvalKey = (
+    String,
+    String,
+    OCDeclareArcType,
+    OCDeclareArcLabel,
+    (Option<usize>, Option<usize>),
+);
+
+/// Result of a cached arc evaluation
+#[derive(Debug, Clone, Copy)]
+struct ArcEvalResult {
+    /// If the arc is satisfied by (at least) `1 - noise_threshold` of the source events
+    satisfied: bool,
+    /// Number of violating source events and number of all source events
+    ///
+    /// Only available if the cache collects exact counts (see [`ArcEvalCache::with_counts`])
+    counts: Option<(usize, usize)>,
+}
+
+/// Memoizes the (thresholded) evaluation of candidate arcs within a single discovery run
+///
+/// During discovery, the same candidate arc is often evaluated multiple times
+/// (e.g., when combining labels, determining the arc type and refining the discovered arcs).
+struct ArcEvalCache<'a> {
+    locel: &'a SlimLinkedOCEL,
+    noise_threshold: f64,
+    /// If `true`, arcs are evaluated on all source events (instead of stopping once the threshold is decided),
+    /// so that the exact violation counts are available
+    collect_counts: bool,
+    results: RwLock<HashMap<ArcEvalKey, ArcEvalResult>>,
+    /// Number of lookups (i.e., requested evaluations), only tracked for testing
+    #[cfg(test)]
+    num_lookups: AtomicUsize,
+    /// Number of actual evaluations on the OCEL (i.e., cache misses), only tracked for testing
+    #[cfg(test)]
+    num_evaluations: AtomicUsize,
+}
+
+impl<'a> ArcEvalCache<'a> {
+    fn new(locel: &'a SlimLinkedOCEL, noise_threshold: f64) -> Self {
+        Self {
+            locel,
+            noise_threshold,
+            collect_counts: false,
+            results: RwLock::new(HashMap::new()),
+            #[cfg(test)]
+            num_lookups: AtomicUsize::new(0),
+            #[cfg(test)]
+            num_evaluations: AtomicUsize::new(0),
+        }
+    }
+
+    /// Create a cache which also collects the exact violation counts of all evaluated arcs
+    fn with_counts(locel: &'a SlimLinkedOCEL, noise_threshold: f64) -> Self {
+        Self {
+            collect_counts: true,
+            ..Self::new(locel, noise_threshold)
+        }
+    }
+
+    /// Evaluate the arc, or return the cached result if it was evaluated before
+    fn evaluate(
+        &self,
+        act1: &str,
+        act2: &str,
+        label: &OCDeclareArcLabel,
+        arc_type: &OCDeclareArcType,
+        counts: &(Option<usize>, Option<usize>),
+    ) -> ArcEvalResult {
+        #[cfg(test)]
+        self.num_lookups.fetch_add(1, Ordering::Relaxed);
+        let key = (
+            act1.to_string(),
+            act2.to_string(),
+            *arc_type,
+            label.clone(),
+            *counts,
+        );
+        if let Some(res) = self.results.read().unwrap().get(&key) {
+            return *res;
+        }
+        #[cfg(test)]
+        self.num_evaluations.fetch_add(1, Ordering::Relaxed);
+        let res = if self.collect_counts {
+            let (num_violated, num_evs) =
+                get_for_all_evs_perf_counts(act1, act2, label, arc_type, counts, self.locel);
+            // Same criterion as used by `get_for_all_evs_perf_thresh`
+            let min_s = (num_evs as f64 * (1.0 - self.noise_threshold)).ceil() as usize;
+            ArcEvalResult {
+                satisfied: num_evs - num_violated >= min_s,
+                counts: Some((num_violated, num_evs)),
+            }
+        } else {
+            ArcEvalResult {
+                satisfied: get_for_all_evs_perf_thresh(
+                    act1,
+                    act2,
+                    label,
+                    arc_type,
+                    counts,
+                    self.locel,
+                    self.noise_threshold,
+                ),
+                counts: None,
+            }
+        };
+        self.results.write().unwrap().insert(key, res);
+        res
+    }
+
+    /// Check if the arc is satisfied by (at least) `1 - noise_threshold` of the source events
+    ///
+    /// Only evaluates the arc on the OCEL if it was not evaluated before.
+    fn is_satisfied(
+        &self,
+        act1: &str,
+        act2: &str,
+        label: &OCDeclareArcLabel,
+        arc_type: &OCDeclareArcType,
+        counts: &(Option<usize>, Option<usize>),
+    ) -> bool {
+        self.evaluate(act1, act2, label, arc_type, counts).satisfied
+    }
+
+    /// Get the number of source events violating the arc, together with the total number of source events
+    ///
+    /// Only evaluates the arc on the OCEL if its counts were not collected before.
+    fn arc_violation_counts(&self, arc: &OCDeclareArc) -> (usize, usize) {
+        let res = self.evaluate(
+            arc.from.as_str(),
+            arc.to.as_str(),
+            &arc.label,
+            &arc.arc_type,
+            &arc.counts,
+        );
+        res.counts.unwrap_or_else(|| {
+            get_for_all_evs_perf_counts(
+                arc.from.as_str(),
+                arc.to.as_str(),
+                &arc.label,
+                &arc.arc_type,
+                &arc.counts,
+                self.locel,
+            )
+        })
+    }
+
+    fn is_arc_satisfied(&self, arc: &OCDeclareArc) -> bool {
+        self.is_satisfied(
+            arc.from.as_str(),
+            arc.to.as_str(),
+            &arc.label,
+            &arc.arc_type,
+            &arc.counts,
+        )
+    }
+}
+
 /// Discover behavioral OC-DECLARE constraints from the given OCEL
+///
+/// Repeated evaluations of the same candidate arc (within one call) are cached.
+///
+/// The returned constraints are sorted (i.e., by source, target, arc type and label), so the result is deterministic.
+///
+/// Unary constraints (e.g., existence constraints) are not part of the returned arcs.
+/// To also discover them (see [`OCDeclareDiscoveryOptions::unary_constraints`]),
+/// use [`discover_behavior_constraints_within_budget`] or [`discover_unary_constraints`].
 #[register_binding(name = "discover_oc_declare")]
 pub fn discover_behavior_constraints(
     locel: &SlimLinkedOCEL,
     #[bind(default = Default::default())] options: OCDeclareDiscoveryOptions,
 ) -> Vec<OCDeclareArc> {
+    discover_behavior_constraints_within_budget(locel, options).arcs
+}
+
+/// Result of a (possibly time-limited) OC-DECLARE discovery (see [`discover_behavior_constraints_within_budget`])
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct OCDeclareDiscoveryResult {
+    /// The discovered constraint arcs (sorted)
+    pub arcs: Vec<OCDeclareArc>,
+    /// The discovered unary constraints (sorted)
+    ///
+    /// Only discovered if [`OCDeclareDiscoveryOptions::unary_constraints`] is enabled, otherwise empty.
+    #[serde(default)]
+    pub unary_constraints: Vec<OCDeclareUnaryConstraint>,
+    /// `true` if the time budget was exceeded, i.e., not all activity pairs were evaluated or refinement (or unary constraint discovery) was skipped
+    pub partial: bool,
+}
+
+/// Discover behavioral OC-DECLARE constraints from the given OCEL, respecting the time budget of the options
+///
+/// Like [`discover_behavior_constraints`], but additionally reports if the discovery stopped early because
+/// [`OCDeclareDiscoveryOptions::time_budget`] was exceeded (see [`OCDeclareDiscoveryResult::partial`]).
+/// If enabled in the options, the result also includes the discovered unary constraints.
+#[register_binding(name = "discover_oc_declare_within_budget")]
+pub fn discover_behavior_constraints_within_budget(
+    locel: &SlimLinkedOCEL,
+    #[bind(default = Default::default())] options: OCDeclareDiscoveryOptions,
+) -> OCDeclareDiscoveryResult {
+    let cache = ArcEvalCache::new(locel, options.noise_threshold);
+    discover_behavior_constraints_cached(&options, &cache)
+}
+
+fn discover_behavior_constraints_cached(
+    options: &OCDeclareDiscoveryOptions,
+    cache: &ArcEvalCache<'_>,
+) -> OCDeclareDiscoveryResult {
+    let start = Instant::now();
+    let num_started_pairs = AtomicUsize::new(0);
+    let budget_exceeded = AtomicBool::new(false);
+    let is_over_budget = || {
+        let over = options
+            .time_budget
+            .is_some_and(|budget| start.elapsed() > budget);
+        if over {
+            budget_exceeded.store(true, Ordering::Relaxed);
+        }
+        over
+    };
+    let locel = cache.locel;
     let act_ob_inv: HashMap<String, HashMap<String, ObjectInvolvementCounts>> =
         get_activity_object_involvements(locel);
     let ob_ob_inv: HashMap<String, HashMap<String, ObjectInvolvementCounts>> =
         get_object_to_object_involvements(locel);
     let ob_ob_rev_inv = get_rev_object_to_object_involvements(locel);
+    let act_ob_qualifier_inv = options
+        .qualifier_involvements
+        .then(|| get_activity_object_qualifier_involvements(locel));
     let direction = OCDeclareArcType::AS;
     let acts_to_use = options
         .acts_to_use
         .clone()
         .unwrap_or_else(|| locel.get_ev_types().map(|et| et.to_string()).collect());
-    let ret = acts_to_use
+    let ret: Vec<OCDeclareArc> = acts_to_use
         .iter()
         .cartesian_product(acts_to_use.iter())
         .par_bridge()
         .flat_map(|(act1, act2)| {
-            let obj_invs = get_direct_or_indirect_object_involvements(
+            // Stop evaluating new activity pairs once the time budget is exceeded
+            if num_started_pairs.fetch_add(1, Ordering::Relaxed) > 0 && is_over_budget() {
+                return Vec::new();
+            }
+            let mut obj_invs = get_direct_or_indirect_object_involvements(
                 act1,
                 act2,
                 &act_ob_inv,
                 &ob_ob_inv,
                 &ob_ob_rev_inv,
                 options.o2o_mode,
+                options.object_types_to_use.as_deref(),
             );
-            let act_arcs = get_oi_labels(
+            if let Some(act_ob_qualifier_inv) = &act_ob_qualifier_inv {
+                obj_invs.extend(get_qualified_object_involvements(
+                    act1,
+                    act2,
+                    &act_ob_inv,
+                    act_ob_qualifier_inv,
+                    options.object_types_to_use.as_deref(),
+                ));
+            }
+            let act_arcs = get_oi_labels_cached(
                 act1,
                 act2,
                 obj_invs,
                 direction,
                 &options.counts_for_generation,
-                options.noise_threshold,
-                locel,
+                cache,
             );
-            let old = combine_constraints(act_arcs, act1, act2, direction, &options, locel, true);
+            let old =
+                combine_constraints_cached(act_arcs, act1, act2, direction, options, cache, true);
             let v = old
                 .clone()
                 // .into_iter()
@@ -143,36 +417,156 @@ pub fn discover_behavior_constraints(
                         label,
                         counts: options.counts_for_filter,
                     };
-                    if arc.get_for_all_evs_perf_thresh(locel, options.noise_threshold) {
+                    if cache.is_arc_satisfied(&arc) {
                         arc.counts.1 = None;
-                        get_stricter_arrows_for_as(arc, &options, locel)
+                        get_stricter_arrows_for_as(arc, options, cache)
                     } else {
                         vec![]
                     }
                 });
-            v
+            v.collect::<Vec<_>>()
         })
         .collect();
 
+    // "Never" constraints are neither reduced nor refined
+    let (never_arcs, ret): (Vec<_>, Vec<_>) = ret.into_iter().partition(OCDeclareArc::is_never);
     let reduced_ret = match options.reduction {
         OCDeclareReductionMode::None => ret,
         OCDeclareReductionMode::Lossless => reduce_oc_arcs(ret, true),
         OCDeclareReductionMode::Lossy => reduce_oc_arcs(ret, false),
     };
-    if options.refinement {
-        refine_oc_arcs(
+    let mut ret = if options.refinement && !is_over_budget() {
+        refine_oc_arcs_cached(
             &reduced_ret,
             &act_ob_inv,
             &ob_ob_inv,
             &ob_ob_rev_inv,
-            &options,
-            locel,
+            options,
+            cache,
         )
     } else {
         reduced_ret
+    };
+    ret.extend(never_arcs);
+    // Activity pairs are processed in parallel, so the order of arcs is only deterministic after sorting
+    ret.sort();
+    let unary_constraints = if options.unary_constraints && !is_over_budget() {
+        discover_unary_constraints(locel, options.clone())
+    } else {
+        Vec::new()
+    };
+    OCDeclareDiscoveryResult {
+        arcs: ret,
+        unary_constraints,
+        partial: budget_exceeded.load(Ordering::Relaxed),
     }
 }
 
+/// Discovered OC-DECLARE constraint arc, together with statistics on how well it is supported by the OCEL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct OCDeclareArcWithStats {
+    /// The discovered constraint arc
+    pub arc: OCDeclareArc,
+    /// Fraction of source events satisfying the constraint arc (from 0.0 to 1.0)
+    pub satisfaction: f64,
+    /// Number of source events the constraint arc was evaluated over
+    pub num_source_events: usize,
+}
+
+/// Discover behavioral OC-DECLARE constraints from the given OCEL, together with their support statistics
+///
+/// Like [`discover_behavior_constraints`], but each returned arc carries its satisfaction fraction
+/// and the number of source events (see [`OCDeclareArcWithStats`]).
+/// The statistics are collected while evaluating the candidate arcs during discovery.
+/// Note that, in contrast to [`discover_behavior_constraints`], candidate arcs are always evaluated on all source events
+/// (i.e., the evaluation does not stop early once the noise threshold is decided).
+#[register_binding(name = "discover_oc_declare_with_stats")]
+pub fn discover_behavior_constraints_with_stats(
+    locel: &SlimLinkedOCEL,
+    #[bind(default = Default::default())] options: OCDeclareDiscoveryOptions,
+) -> Vec<OCDeclareArcWithStats> {
+    let cache = ArcEvalCache::with_counts(locel, options.noise_threshold);
+    discover_behavior_constraints_cached(&options, &cache)
+        .arcs
+        .into_iter()
+        .map(|arc| {
+            let (num_violated, num_source_events) = cache.arc_violation_counts(&arc);
+            let satisfaction = if num_source_events == 0 {
+                1.0
+            } else {
+                1.0 - num_violated as f64 / num_source_events as f64
+            };
+            OCDeclareArcWithStats {
+                arc,
+                satisfaction,
+                num_source_events,
+            }
+        })
+        .collect()
+}
+
+/// Discover unary OC-DECLARE constraints (see [`OCDeclareUnaryConstraint`]) from the given OCEL
+///
+/// Complements [`discover_behavior_constraints`], which only discovers binary constraints (arcs).
+/// Also used by [`discover_behavior_constraints_within_budget`] if [`OCDeclareDiscoveryOptions::unary_constraints`] is enabled.
+/// Uses [`OCDeclareDiscoveryOptions::acts_to_use`], [`OCDeclareDiscoveryOptions::object_types_to_use`] and [`OCDeclareDiscoveryOptions::noise_threshold`] of the passed options.
+///
+/// - [`OCDeclareUnaryConstraint::Existence`] constraints are discovered for all pairs of activities and involved object types,
+///   where at most the noise threshold fraction of objects is not involved in an event of the activity.
+///   The max count is set to 1 if this still holds when additionally restricting objects to be involved in at most one such event.
+/// - [`OCDeclareUnaryConstraint::Absence`] constraints are discovered for all activities to use, which do not occur in the OCEL.
+///
+/// The returned constraints are sorted.
+#[register_binding(name = "discover_oc_declare_unary")]
+pub fn discover_unary_constraints(
+    locel: &SlimLinkedOCEL,
+    #[bind(default = Default::default())] options: OCDeclareDiscoveryOptions,
+) -> Vec<OCDeclareUnaryConstraint> {
+    let act_ob_inv = get_activity_object_involvements(locel);
+    let acts_to_use = options
+        .acts_to_use
+        .clone()
+        .unwrap_or_else(|| locel.get_ev_types().map(|et| et.to_string()).collect());
+    let mut ret: Vec<OCDeclareUnaryConstraint> = acts_to_use
+        .par_iter()
+        .flat_map(|act| {
+            let Some(obj_invs) = act_ob_inv.get(act) else {
+                return vec![OCDeclareUnaryConstraint::Absence {
+                    activity: OCDeclareNode::new(act.clone()),
+                }];
+            };
+            obj_invs
+                .keys()
+                .filter(|ot| {
+                    options
+                        .object_types_to_use
+                        .as_ref()
+                        .is_none_or(|ots| ots.contains(ot))
+                })
+                .filter_map(|ot| {
+                    let mut constraint = OCDeclareUnaryConstraint::Existence {
+                        activity: OCDeclareNode::new(act.clone()),
+                        object_type: ot.clone(),
+                        counts: (Some(1), Some(1)),
+                    };
+                    if constraint.get_perf(locel) <= options.noise_threshold {
+                        return Some(constraint);
+                    }
+                    if let OCDeclareUnaryConstraint::Existence { counts, .. } = &mut constraint {
+                        counts.1 = None;
+                    }
+                    if constraint.get_perf(locel) <= options.noise_threshold {
+                        return Some(constraint);
+                    }
+                    None
+                })
+                .collect()
+        })
+        .collect();
+    ret.sort();
+    ret
+}
+
 /// Get possible object involvement labels for given activity pair and object involvements
 ///
 /// Returns the set of viable labels
@@ -184,6 +578,24 @@ pub fn get_oi_labels<'a>(
     counts_for_generation: &(Option<usize>, Option<usize>),
     noise_threshold: f64,
     locel: &SlimLinkedOCEL,
+) -> Vec<OCDeclareArcLabel> {
+    get_oi_labels_cached(
+        act1,
+        act2,
+        obj_invs,
+        direction,
+        counts_for_generation,
+        &ArcEvalCache::new(locel, noise_threshold),
+    )
+}
+
+fn get_oi_labels_cached(
+    act1: &str,
+    act2: &str,
+    obj_invs: Vec<(ObjectTypeAssociation, bool)>,
+    direction: OCDeclareArcType,
+    counts_for_generation: &(Option<usize>, Option<usize>),
+    cache: &ArcEvalCache<'_>,
 ) -> Vec<OCDeclareArcLabel> {
     let mut ret = Vec::new();
     for (ot, is_multiple) in obj_invs {
@@ -193,15 +605,7 @@ pub fn get_oi_labels<'a>(
             any: vec![ot],
             all: vec![],
         };
-        let sat = get_for_all_evs_perf_thresh(
-            act1,
-            act2,
-            &any_label,
-            &direction,
-            counts_for_generation,
-            locel,
-            noise_threshold,
-        );
+        let sat = cache.is_satisfied(act1, act2, &any_label, &direction, counts_for_generation);
         if sat {
             // It IS a viable candidate!
             // Also test Each/All:
@@ -214,15 +618,8 @@ pub fn get_oi_labels<'a>(
                     any: vec![],
                     each: any_label.any.clone(),
                 };
-                let each_sat = get_for_all_evs_perf_thresh(
-                    act1,
-                    act2,
-                    &each_label,
-                    &direction,
-                    counts_for_generation,
-                    locel,
-                    noise_threshold,
-                );
+                let each_sat =
+                    cache.is_satisfied(act1, act2, &each_label, &direction, counts_for_generation);
                 if each_sat {
                     // Each is also valid!
                     ret.push(each_label);
@@ -231,14 +628,12 @@ pub fn get_oi_labels<'a>(
                         any: vec![],
                         each: vec![],
                     };
-                    let all_sat = get_for_all_evs_perf_thresh(
+                    let all_sat = cache.is_satisfied(
                         act1,
                         act2,
                         &all_label,
                         &direction,
                         counts_for_generation,
-                        locel,
-                        noise_threshold,
                     );
                     if all_sat {
                         ret.push(all_label);
@@ -263,13 +658,33 @@ pub fn get_oi_labels<'a>(
 /// Returns the set of combined constraints
 ///
 pub fn combine_constraints<'a>(
-    mut act_arcs: Vec<OCDeclareArcLabel>,
+    act_arcs: Vec<OCDeclareArcLabel>,
     act1: &'a str,
     act2: &'a str,
     direction: OCDeclareArcType,
     options: &OCDeclareDiscoveryOptions,
     locel: &SlimLinkedOCEL,
     iteration_check: bool,
+) -> HashSet<OCDeclareArcLabel> {
+    combine_constraints_cached(
+        act_arcs,
+        act1,
+        act2,
+        direction,
+        options,
+        &ArcEvalCache::new(locel, options.noise_threshold),
+        iteration_check,
+    )
+}
+
+fn combine_constraints_cached(
+    mut act_arcs: Vec<OCDeclareArcLabel>,
+    act1: &str,
+    act2: &str,
+    direction: OCDeclareArcType,
+    options: &OCDeclareDiscoveryOptions,
+    cache: &ArcEvalCache<'_>,
+    iteration_check: bool,
 ) -> HashSet<OCDeclareArcLabel> {
     let mut changed = true;
     let mut old: HashSet<_> = act_arcs.iter().cloned().collect();
@@ -291,14 +706,12 @@ pub fn combine_constraints<'a>(
                 if iteration_check && new_n != iteration + 1 {
                     return None;
                 }
-                let sat = get_for_all_evs_perf_thresh(
+                let sat = cache.is_satisfied(
                     act1,
                     act2,
                     &new_arc_label,
                     &direction,
                     &options.counts_for_generation,
-                    locel,
-                    options.noise_threshold,
                 );
                 if sat {
                     Some(new_arc_label)
@@ -317,6 +730,7 @@ pub fn combine_constraints<'a>(
             .iter()
             .filter(|a| !new_res.iter().any(|a2| *a != a2 && a.is_dominated_by(a2)))
             .cloned()
+            .sorted()
             .collect();
         iteration += 1;
     }
@@ -327,10 +741,12 @@ pub fn combine_constraints<'a>(
 /// Try to find stricter constraints for an AS constraint
 ///
 /// e.g., if AS is satisfied, check if EF, DF, EP, DP are also satisfied
+///
+/// If enabled in the options, "never" constraints (EF/EP with max count 0) are also tested
 fn get_stricter_arrows_for_as(
     mut a: OCDeclareArc,
     options: &OCDeclareDiscoveryOptions,
-    locel: &SlimLinkedOCEL,
+    cache: &ArcEvalCache<'_>,
 ) -> Vec<OCDeclareArc> {
     let mut ret: Vec<OCDeclareArc> = Vec::new();
     if options
@@ -339,13 +755,13 @@ fn get_stricter_arrows_for_as(
     {
         // Test EF
         a.arc_type = OCDeclareArcType::EF;
-        if a.get_for_all_evs_perf_thresh(locel, options.noise_threshold) {
+        if cache.is_arc_satisfied(&a) {
             // Test DF
             a.arc_type = OCDeclareArcType::DF;
             if options
                 .considered_arrow_types
                 .contains(&OCDeclareArcType::DF)
-                && a.get_for_all_evs_perf_thresh(locel, options.noise_threshold)
+                && cache.is_arc_satisfied(&a)
             {
                 ret.push(a.clone());
             } else {
@@ -359,7 +775,7 @@ fn get_stricter_arrows_for_as(
     {
         a.arc_type = OCDeclareArcType::DF;
 
-        if a.get_for_all_evs_perf_thresh(locel, options.noise_threshold) {
+        if cache.is_arc_satisfied(&a) {
             ret.push(a.clone());
         }
     }
@@ -370,13 +786,13 @@ fn get_stricter_arrows_for_as(
     {
         // Test EP
         a.arc_type = OCDeclareArcType::EP;
-        if a.get_for_all_evs_perf_thresh(locel, options.noise_threshold) {
+        if cache.is_arc_satisfied(&a) {
             // Test DP
             a.arc_type = OCDeclareArcType::DP;
             if options
                 .considered_arrow_types
                 .contains(&OCDeclareArcType::DP)
-                && a.get_for_all_evs_perf_thresh(locel, options.noise_threshold)
+                && cache.is_arc_satisfied(&a)
             {
                 ret.push(a.clone());
             } else {
@@ -390,7 +806,7 @@ fn get_stricter_arrows_for_as(
     {
         a.arc_type = OCDeclareArcType::DP;
 
-        if a.get_for_all_evs_perf_thresh(locel, options.noise_threshold) {
+        if cache.is_arc_satisfied(&a) {
             ret.push(a.clone());
         }
     }
@@ -402,8 +818,24 @@ fn get_stricter_arrows_for_as(
         && a.from != a.to
     {
         a.arc_type = OCDeclareArcType::AS;
-        if a.get_for_all_evs_perf_thresh(locel, options.noise_threshold) {
-            ret.push(a);
+        if cache.is_arc_satisfied(&a) {
+            ret.push(a.clone());
+        }
+    }
+
+    if options.never_constraints {
+        for arc_type in [OCDeclareArcType::EF, OCDeclareArcType::EP] {
+            if !options.considered_arrow_types.contains(&arc_type) {
+                continue;
+            }
+            let never_arc = OCDeclareArc {
+                arc_type,
+                counts: (Some(0), Some(0)),
+                ..a.clone()
+            };
+            if cache.is_arc_satisfied(&never_arc) {
+                ret.push(never_arc);
+            }
         }
     }
     ret
@@ -420,7 +852,29 @@ pub fn refine_oc_arcs(
     options: &OCDeclareDiscoveryOptions,
     locel: &SlimLinkedOCEL,
 ) -> Vec<OCDeclareArc> {
-    let act_pairs: HashSet<(_, _)> = all_arcs
+    refine_oc_arcs_cached(
+        all_arcs,
+        act_ob_inv,
+        ob_ob_inv,
+        ob_ob_rev_inv,
+        options,
+        &ArcEvalCache::new(locel, options.noise_threshold),
+    )
+}
+
+fn refine_oc_arcs_cached(
+    all_arcs: &[OCDeclareArc],
+    act_ob_inv: &HashMap<String, HashMap<String, ObjectInvolvementCounts>>,
+    ob_ob_inv: &HashMap<String, HashMap<String, ObjectInvolvementCounts>>,
+    ob_ob_rev_inv: &HashMap<String, HashMap<String, ObjectInvolvementCounts>>,
+    options: &OCDeclareDiscoveryOptions,
+    cache: &ArcEvalCache<'_>,
+) -> Vec<OCDeclareArc> {
+    let locel = cache.locel;
+    let act_ob_qualifier_inv = options
+        .qualifier_involvements
+        .then(|| get_activity_object_qualifier_involvements(locel));
+    let act_pairs: BTreeSet<(_, _)> = all_arcs
         .iter()
         .map(|arc| (arc.from.as_str(), arc.to.as_str()))
         .collect();
@@ -433,22 +887,31 @@ pub fn refine_oc_arcs(
                 .filter(|arc| arc.from.as_str() == act1 && arc.to.as_str() == act2)
                 .cloned()
                 .collect_vec();
-            let obj_invs = get_direct_or_indirect_object_involvements(
+            let mut obj_invs = get_direct_or_indirect_object_involvements(
                 act1,
                 act2,
                 act_ob_inv,
                 ob_ob_inv,
                 ob_ob_rev_inv,
                 options.o2o_mode,
+                options.object_types_to_use.as_deref(),
             );
-            let oi_labels = get_oi_labels(
+            if let Some(act_ob_qualifier_inv) = &act_ob_qualifier_inv {
+                obj_invs.extend(get_qualified_object_involvements(
+                    act1,
+                    act2,
+                    act_ob_inv,
+                    act_ob_qualifier_inv,
+                    options.object_types_to_use.as_deref(),
+                ));
+            }
+            let oi_labels = get_oi_labels_cached(
                 act1,
                 act2,
                 obj_invs,
                 OCDeclareArcType::AS,
                 &(Some(1), None),
-                options.noise_threshold,
-                locel,
+                cache,
             );
 
             // Try to combine with previous labels
@@ -461,14 +924,12 @@ pub fn refine_oc_arcs(
                             None
                         } else {
                             let combined = l.combine(&arc.label);
-                            let sat = get_for_all_evs_perf_thresh(
+                            let sat = cache.is_satisfied(
                                 act1,
                                 act2,
                                 &combined,
                                 &arc.arc_type,
                                 &options.counts_for_filter,
-                                locel,
-                                options.noise_threshold,
                             );
                             if sat {
                                 Some(combined)
@@ -479,8 +940,15 @@ pub fn refine_oc_arcs(
                     })
                     .collect();
                 labels.push(arc.label);
-                let combined =
-                    combine_constraints(labels, act1, act2, arc.arc_type, options, locel, false);
+                let combined = combine_constraints_cached(
+                    labels,
+                    act1,
+                    act2,
+                    arc.arc_type,
+                    options,
+                    cache,
+                    false,
+                );
                 new_arcs.extend(combined.into_iter().map(|a| OCDeclareArc {
                     from: OCDeclareNode::new(act1),
                     to: OCDeclareNode::new(act2),
@@ -503,9 +971,21 @@ fn get_direct_or_indirect_object_involvements<'a>(
     obj_obj_involvement: &'a HashMap<String, HashMap<String, ObjectInvolvementCounts>>,
     rev_obj_obj_involvement: &'a HashMap<String, HashMap<String, ObjectInvolvementCounts>>,
     o2o_mode: O2OMode,
+    object_types_to_use: Option<&[String]>,
 ) -> Vec<(ObjectTypeAssociation, bool)> {
-    let act1_obs: HashSet<_> = act_ob_involvement.get(act1).unwrap().keys().collect();
-    let act2_obs: HashSet<_> = act_ob_involvement.get(act2).unwrap().keys().collect();
+    let use_ot = |ot: &String| object_types_to_use.is_none_or(|ots| ots.contains(ot));
+    let act1_obs: BTreeSet<_> = act_ob_involvement
+        .get(act1)
+        .into_iter()
+        .flat_map(HashMap::keys)
+        .filter(|ot| use_ot(ot))
+        .collect();
+    let act2_obs: BTreeSet<_> = act_ob_involvement
+        .get(act2)
+        .into_iter()
+        .flat_map(HashMap::keys)
+        .filter(|ot| use_ot(ot))
+        .collect();
     let mut res = act1_obs
         .iter()
         .filter(|ot| act2_obs.contains(*ot))
@@ -565,6 +1045,42 @@ fn get_direct_or_indirect_object_involvements<'a>(
     res
 }
 
+/// Returns the qualifier-specific (direct) object type associations for an activity pair
+///
+/// For each object type involved with both activities, an association is returned for every E2O qualifier with which
+/// objects of that type are involved in events of `act1`.
+/// As for [`get_direct_or_indirect_object_involvements`], each item also includes a flag indicating if multiple objects are sometimes involved.
+fn get_qualified_object_involvements(
+    act1: &str,
+    act2: &str,
+    act_ob_involvement: &HashMap<String, HashMap<String, ObjectInvolvementCounts>>,
+    act_ob_qualifier_involvement: &HashMap<
+        String,
+        HashMap<(String, String), ObjectInvolvementCounts>,
+    >,
+    object_types_to_use: Option<&[String]>,
+) -> Vec<(ObjectTypeAssociation, bool)> {
+    let (Some(act1_quals), Some(act2_obs)) = (
+        act_ob_qualifier_involvement.get(act1),
+        act_ob_involvement.get(act2),
+    ) else {
+        return Vec::new();
+    };
+    act1_quals
+        .iter()
+        .filter(|((ot, _q), _)| {
+            act2_obs.contains_key(ot) && object_types_to_use.is_none_or(|ots| ots.contains(ot))
+        })
+        .map(|((ot, q), counts)| {
+            (
+                ObjectTypeAssociation::new_simple(ot).with_qualifier(q),
+                counts.max > 1,
+            )
+        })
+        .sorted_by(|a, b| a.0.cmp(&b.0))
+        .collect()
+}
+
 // /// Reduce OC-DECLARE arcs based on lossless/lossy transitive reduction
 // pub fn reduce_oc_arcs(arcs: &Vec<OCDeclareArc>, lossless: bool) -> Vec<OCDeclareArc> {
 //     let mut ret: HashSet<_> = arcs.clone().into_iter().collect();
@@ -700,3 +1216,401 @@ fn has_dominating_path(
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_discovery() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2"]),
+            ("pack", ["o:1", "i:1"]),
+            ("pack", ["o:1", "i:2"]),
+            ("ship", ["o:1", "i:1", "i:2"]),
+            ("place", ["o:2", "i:3"]),
+            ("pack", ["o:2", "i:3"]),
+            ("ship", ["o:2", "i:3"]),
+            ("place", ["o:3", "i:4"]),
+            ("ship", ["o:3", "i:4"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("o:1", "i:2"),
+            ("o:2", "i:3"),
+            ("o:3", "i:4")
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let options = OCDeclareDiscoveryOptions {
+            o2o_mode: O2OMode::Bidirectional,
+            refinement: true,
+            ..Default::default()
+        };
+        let cache = ArcEvalCache::new(&locel, options.noise_threshold);
+        let arcs = discover_behavior_constraints_cached(&options, &cache).arcs;
+        let mut arc_strs: Vec<String> = arcs.iter().map(|a| a.to_string()).collect();
+        arc_strs.sort();
+        assert_eq!(
+            arc_strs,
+            [
+                "DF(pack, ship, Each(i,o,i<o), All(o>i),1,∞)",
+                "DP(pack, place, Each(i,o,i<o), All(o>i),1,∞)",
+                "EF(place, ship, Each(o), All(i,i<o,o>i),1,∞)",
+                "EP(ship, place, Each(o), All(i,i<o,o>i),1,∞)",
+            ]
+        );
+        for arc in &arcs {
+            assert!(arc.get_for_all_evs_perf_thresh(&locel, options.noise_threshold));
+        }
+        let num_lookups = cache.num_lookups.load(Ordering::Relaxed);
+        let num_evaluations = cache.num_evaluations.load(Ordering::Relaxed);
+        assert!(num_evaluations < num_lookups);
+    }
+
+    #[test]
+    fn test_discover_with_stats() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1"]),
+            ("pay", ["o:1"]),
+            ("place", ["o:2"]),
+            ("pay", ["o:2"]),
+            ("place", ["o:3"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let options = OCDeclareDiscoveryOptions {
+            noise_threshold: 0.5,
+            ..Default::default()
+        };
+        let arcs = discover_behavior_constraints_with_stats(&locel, options);
+        let place_pay = arcs
+            .iter()
+            .find(|a| {
+                a.arc.from.as_str() == "place"
+                    && a.arc.to.as_str() == "pay"
+                    && a.arc.arc_type == OCDeclareArcType::DF
+            })
+            .unwrap();
+        assert_eq!(place_pay.num_source_events, 3);
+        assert!((place_pay.satisfaction - 2.0 / 3.0).abs() < 1e-9);
+        assert!(arcs
+            .iter()
+            .any(|a| a.arc.from.as_str() == "pay" && a.satisfaction == 1.0));
+    }
+
+    #[test]
+    fn test_discover_never_constraints() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1"]),
+            ("pay", ["o:1"]),
+            ("place", ["o:2"]),
+            ("pay", ["o:2"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let discover = |never_constraints| {
+            discover_behavior_constraints(
+                &locel,
+                OCDeclareDiscoveryOptions {
+                    noise_threshold: 0.0,
+                    acts_to_use: Some(vec!["place".to_string(), "pay".to_string()]),
+                    never_constraints,
+                    ..Default::default()
+                },
+            )
+        };
+        assert!(!discover(false).iter().any(OCDeclareArc::is_never));
+
+        let arcs = discover(true);
+        let templates: HashSet<String> = arcs
+            .iter()
+            .filter(|a| a.is_never())
+            .map(|a| a.as_template_string())
+            .collect();
+        assert!(templates.contains("NeverFollows(pay, place, Each(o))"));
+        assert!(templates.contains("NeverPrecedes(place, pay, Each(o))"));
+        assert!(!templates.contains("NeverFollows(place, pay, Each(o))"));
+        for arc in arcs.iter().filter(|a| a.is_never()) {
+            assert_eq!(arc.counts, (Some(0), Some(0)));
+            assert_eq!(arc.get_for_all_evs_perf(&locel), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_discover_qualifier_involvements() {
+        let mut ocel = crate::ocel![
+            events:
+            ("pack", ["o:1", "i:1"]),
+            ("ship", ["o:1", "i:1"]),
+            ("pack", ["o:2", "i:2"]),
+            ("ship", ["o:2", "i:2"]),
+            o2o:
+        ];
+        for ev in ocel.events.iter_mut().filter(|ev| ev.event_type == "pack") {
+            for rel in &mut ev.relationships {
+                rel.qualifier = "packed".to_string();
+            }
+        }
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let discover = |qualifier_involvements: bool| {
+            discover_behavior_constraints(
+                &locel,
+                OCDeclareDiscoveryOptions {
+                    noise_threshold: 0.0,
+                    qualifier_involvements,
+                    ..Default::default()
+                },
+            )
+        };
+        let has_qualified = |arcs: &[OCDeclareArc]| {
+            arcs.iter()
+                .flat_map(|a| a.label.each.iter().chain(&a.label.any).chain(&a.label.all))
+                .any(|ot| ot.qualifier() == Some("packed"))
+        };
+        assert!(!has_qualified(&discover(false)));
+        assert!(has_qualified(&discover(true)));
+    }
+
+    #[test]
+    fn test_discover_restricted_object_types() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            ("pay", ["o:1", "i:1"]),
+            ("place", ["o:2", "i:2"]),
+            ("pay", ["o:2", "i:2"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let discover = |object_types_to_use: Option<Vec<String>>| {
+            discover_behavior_constraints(
+                &locel,
+                OCDeclareDiscoveryOptions {
+                    noise_threshold: 0.0,
+                    object_types_to_use,
+                    ..Default::default()
+                },
+            )
+        };
+        let label_types = |arcs: &[OCDeclareArc]| {
+            arcs.iter()
+                .flat_map(|a| a.label.each.iter().chain(&a.label.any).chain(&a.label.all))
+                .map(|ot| match ot {
+                    ObjectTypeAssociation::Simple { object_type, .. } => object_type.clone(),
+                    ot => panic!("Unexpected O2O association {ot:?}"),
+                })
+                .collect::<HashSet<_>>()
+        };
+        let all = discover(None);
+        assert!(label_types(&all).contains("i"));
+        let only_orders = discover(Some(vec!["o".to_string()]));
+        assert!(!only_orders.is_empty());
+        assert_eq!(label_types(&only_orders), ["o".to_string()].into());
+    }
+
+    #[test]
+    fn test_cached_discovery_order_management() {
+        use crate::core::event_data::object_centric::ocel_xml::xml_ocel_import::import_ocel_xml_path;
+        let path = crate::test_utils::get_test_data_path()
+            .join("ocel")
+            .join("order-management.xml");
+        let locel = SlimLinkedOCEL::from_ocel(import_ocel_xml_path(&path).unwrap());
+        let options = OCDeclareDiscoveryOptions {
+            o2o_mode: O2OMode::Bidirectional,
+            refinement: true,
+            ..Default::default()
+        };
+        let cache = ArcEvalCache::new(&locel, options.noise_threshold);
+        let arcs = discover_behavior_constraints_cached(&options, &cache).arcs;
+        assert!(!arcs.is_empty());
+        let num_lookups = cache.num_lookups.load(Ordering::Relaxed);
+        let num_evaluations = cache.num_evaluations.load(Ordering::Relaxed);
+        println!(
+            "{num_evaluations} of {num_lookups} arc evaluations required ({:.1}% saved by caching)",
+            100.0 * (1.0 - num_evaluations as f64 / num_lookups as f64)
+        );
+        assert!(num_evaluations < num_lookups);
+    }
+
+    #[test]
+    fn test_deterministic_discovery_order() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1", "i:2", "c:1"]),
+            ("pick", ["i:1"]),
+            ("pick", ["i:2"]),
+            ("pack", ["o:1", "i:1", "i:2"]),
+            ("ship", ["o:1", "c:1"]),
+            ("place", ["o:2", "i:3", "c:1"]),
+            ("pick", ["i:3"]),
+            ("pack", ["o:2", "i:3"]),
+            ("ship", ["o:2", "c:1"]),
+            o2o:
+            ("o:1", "i:1"),
+            ("o:1", "i:2"),
+            ("o:2", "i:3"),
+            ("c:1", "o:1"),
+            ("c:1", "o:2")
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        for reduction in [OCDeclareReductionMode::None, OCDeclareReductionMode::Lossy] {
+            let options = OCDeclareDiscoveryOptions {
+                o2o_mode: O2OMode::Bidirectional,
+                reduction,
+                refinement: true,
+                never_constraints: true,
+                ..Default::default()
+            };
+            let arcs = discover_behavior_constraints(&locel, options.clone());
+            assert!(!arcs.is_empty());
+            assert!(arcs.is_sorted());
+            for _ in 0..3 {
+                assert_eq!(discover_behavior_constraints(&locel, options.clone()), arcs);
+            }
+        }
+    }
+
+    #[test]
+    fn test_time_budget() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            ("ship", ["o:1", "i:1"]),
+            ("pay", ["o:1", "i:1"]),
+            ("place", ["o:2", "i:2"]),
+            ("ship", ["o:2", "i:2"]),
+            ("pay", ["o:2", "i:2"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        // Every pair of these activities results in at least one constraint
+        let options = OCDeclareDiscoveryOptions {
+            acts_to_use: Some(vec!["ship".to_string(), "pay".to_string()]),
+            never_constraints: true,
+            refinement: true,
+            ..Default::default()
+        };
+        let full = discover_behavior_constraints_within_budget(&locel, options.clone());
+        assert!(!full.partial);
+
+        let partial = discover_behavior_constraints_within_budget(
+            &locel,
+            OCDeclareDiscoveryOptions {
+                time_budget: Some(Duration::ZERO),
+                ..options
+            },
+        );
+        assert!(partial.partial);
+        assert!(!partial.arcs.is_empty());
+        assert!(partial.arcs.iter().all(|arc| full.arcs.contains(arc)));
+    }
+
+    #[test]
+    fn test_discover_unary_constraints() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            ("place", ["o:2", "i:2"]),
+            ("pay", ["o:1"]),
+            ("pay", ["o:2"]),
+            ("pay", ["o:2"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let options = OCDeclareDiscoveryOptions {
+            noise_threshold: 0.0,
+            acts_to_use: Some(vec![
+                "place".to_string(),
+                "pay".to_string(),
+                "cancel".to_string(),
+            ]),
+            ..Default::default()
+        };
+        let expected = vec![
+            OCDeclareUnaryConstraint::Existence {
+                activity: OCDeclareNode::new("pay"),
+                object_type: "o".to_string(),
+                counts: (Some(1), None),
+            },
+            OCDeclareUnaryConstraint::Existence {
+                activity: OCDeclareNode::new("place"),
+                object_type: "i".to_string(),
+                counts: (Some(1), Some(1)),
+            },
+            OCDeclareUnaryConstraint::Existence {
+                activity: OCDeclareNode::new("place"),
+                object_type: "o".to_string(),
+                counts: (Some(1), Some(1)),
+            },
+            OCDeclareUnaryConstraint::Absence {
+                activity: OCDeclareNode::new("cancel"),
+            },
+        ];
+        assert_eq!(
+            discover_unary_constraints(&locel, options.clone()),
+            expected
+        );
+
+        // Unary constraints are only part of the discovery result if enabled
+        let res = discover_behavior_constraints_within_budget(&locel, options.clone());
+        assert!(res.unary_constraints.is_empty());
+        let res = discover_behavior_constraints_within_budget(
+            &locel,
+            OCDeclareDiscoveryOptions {
+                unary_constraints: true,
+                ..options.clone()
+            },
+        );
+        assert_eq!(res.unary_constraints, expected);
+        assert_eq!(res.arcs, discover_behavior_constraints(&locel, options));
+    }
+
+    #[test]
+    fn test_stats_collected_during_discovery() {
+        let ocel = crate::ocel![
+            events:
+            ("place", ["o:1", "i:1"]),
+            ("ship", ["o:1", "i:1"]),
+            ("place", ["o:2", "i:2"]),
+            ("ship", ["o:2", "i:2"]),
+            ("place", ["o:3", "i:3"]),
+            o2o:
+        ];
+        let locel = SlimLinkedOCEL::from_ocel(ocel);
+        let options = OCDeclareDiscoveryOptions {
+            noise_threshold: 0.5,
+            ..Default::default()
+        };
+        let cache = ArcEvalCache::with_counts(&locel, options.noise_threshold);
+        let arcs = discover_behavior_constraints_cached(&options, &cache).arcs;
+        assert!(!arcs.is_empty());
+        let num_evaluations = cache.num_evaluations.load(Ordering::Relaxed);
+        for arc in &arcs {
+            assert_eq!(
+                cache.arc_violation_counts(arc),
+                get_for_all_evs_perf_counts(
+                    arc.from.as_str(),
+                    arc.to.as_str(),
+                    &arc.label,
+                    &arc.arc_type,
+                    &arc.counts,
+                    &locel,
+                )
+            );
+        }
+        // All statistics were already collected during discovery
+        assert_eq!(
+            cache.num_evaluations.load(Ordering::Relaxed),
+            num_evaluations
+        );
+        assert_eq!(
+            discover_behavior_constraints_with_stats(&locel, options.clone())
+                .into_iter()
+                .map(|a| a.arc)
+                .collect::<Vec<_>>(),
+            discover_behavior_constraints(&locel, options)
+        );
+    }
+}
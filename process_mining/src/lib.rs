@@ -11,7 +11,9 @@
 pub mod analysis;
 pub mod conformance;
 pub mod core;
+pub mod diagnostics;
 pub mod discovery;
+pub mod utils;
 
 pub use core::io::{Exportable, Importable};
 
@@ -0,0 +1,97 @@
+//! Miscellaneous utilities
+//!
+//! Currently, this contains helpers to generate unique IDs (e.g., for newly created events, objects, or
+//! registry items). By default, IDs are random (UUID v4). For reproducible IDs (e.g., in tests), a seed can
+//! be set using [`seed_ids`].
+use std::cell::Cell;
+
+use uuid::Uuid;
+
+thread_local! {
+    /// Seed and counter state of the current thread (`None` if IDs are random)
+    static ID_SEED_STATE: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Mix the bits of the passed value (`SplitMix64` finalizer)
+///
+/// Note that `0` is a fixed point, so this should not be used to advance a random state (see [`splitmix64`]).
+pub(crate) fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Advance the `SplitMix64` state and return the next pseudo-random value
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    mix(*state)
+}
+
+///
+/// Seed the ID generation of the current thread
+///
+/// After seeding, [`new_uuid`] and [`new_id`] return the same sequence of IDs for the same seed.
+/// Passing `None` switches back to random IDs.
+///
+/// Note that the seed only applies to the current thread, so IDs generated on other threads
+/// (e.g., in parallel computations) remain random.
+///
+pub fn seed_ids(seed: Option<u64>) {
+    ID_SEED_STATE.with(|state| state.set(seed));
+}
+
+///
+/// Generate a new unique [`Uuid`] (v4)
+///
+/// The UUID is random, unless a seed was set for the current thread (see [`seed_ids`]).
+///
+pub fn new_uuid() -> Uuid {
+    ID_SEED_STATE.with(|state| match state.get() {
+        None => Uuid::new_v4(),
+        Some(mut s) => {
+            let a = splitmix64(&mut s);
+            let b = splitmix64(&mut s);
+            state.set(Some(s));
+            let mut bytes = [0; 16];
+            bytes[..8].copy_from_slice(&a.to_le_bytes());
+            bytes[8..].copy_from_slice(&b.to_le_bytes());
+            uuid::Builder::from_random_bytes(bytes).into_uuid()
+        }
+    })
+}
+
+///
+/// Generate a new unique ID, consisting of the passed prefix followed by a [`Uuid`] (see [`new_uuid`])
+///
+pub fn new_id(prefix: &str) -> String {
+    format!("{prefix}{}", new_uuid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_ids() {
+        seed_ids(Some(42));
+        let first: Vec<String> = (0..5).map(|_| new_id("ev_")).collect();
+        seed_ids(Some(42));
+        let second: Vec<String> = (0..5).map(|_| new_id("ev_")).collect();
+        assert_eq!(first, second);
+        assert!(first.iter().all(|id| id.starts_with("ev_")));
+        assert_eq!(
+            first.iter().collect::<std::collections::HashSet<_>>().len(),
+            5
+        );
+        assert_eq!(new_uuid().get_version_num(), 4);
+
+        seed_ids(Some(43));
+        assert_ne!(new_id("ev_"), first[0]);
+        // Seed 0 is not a fixed point
+        seed_ids(Some(0));
+        let ids: std::collections::HashSet<Uuid> = (0..5).map(|_| new_uuid()).collect();
+        assert_eq!(ids.len(), 5);
+        seed_ids(None);
+        assert_ne!(new_id("ev_"), new_id("ev_"));
+    }
+}
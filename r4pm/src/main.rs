@@ -1,4 +1,9 @@
-use std::{collections::HashSet, path::PathBuf, process::ExitCode, sync::LazyLock};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::LazyLock,
+};
 
 use anstyle::AnsiColor;
 pub use process_mining::bindings;
@@ -6,6 +11,9 @@ use process_mining::bindings::Binding;
 
 static SPACE: &str = "  ";
 static CLI_NAME: &str = "r4pm";
+static NDJSON_FLAG: &str = "--ndjson";
+static PIPELINE_FLAG: &str = "--pipeline";
+static STEP_RESULT_PLACEHOLDER: &str = "$result_of_step_";
 
 static PRIMARY: LazyLock<anstyle::Style> = LazyLock::new(|| {
     anstyle::Style::new()
@@ -59,7 +67,9 @@ fn main() -> ExitCode {
     if args.len() <= 1 {
         println!(
             "{}\nAvailable functions: {}",
-            warn(format!("Usage: {CLI_NAME} fun_name --arg1 'abc' --arg2 4")),
+            warn(format!(
+                "Usage: {CLI_NAME} fun_name --arg1 'abc' --arg2 4\n       {CLI_NAME} {PIPELINE_FLAG} steps.json"
+            )),
             functions
                 .iter()
                 .map(|f| f.name)
@@ -70,45 +80,51 @@ fn main() -> ExitCode {
     }
     let state = bindings::AppState::default();
 
-    let func_name = &args[1];
-    let binding = functions.iter().find(|f| f.name == func_name);
-    if binding.is_none() {
-        println!("Unknown function: {func_name}");
-        return ExitCode::FAILURE;
+    if args[1] == PIPELINE_FLAG {
+        let Some(path) = args.get(2) else {
+            eprintln!(
+                "{}",
+                warn(format!("Usage: {CLI_NAME} {PIPELINE_FLAG} steps.json"))
+            );
+            return ExitCode::FAILURE;
+        };
+        let ndjson = args.iter().skip(3).any(|a| a == NDJSON_FLAG);
+        if let Err(e) = run_pipeline(&functions, path, &state, ndjson) {
+            eprintln!("{}", warn(e));
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
     }
-    let binding = binding.unwrap();
-    let required_fn_args: HashSet<String> = ((binding.required_args)()).into_iter().collect();
-    print_function_info(binding, &required_fn_args);
-    let fn_args = (binding.args)();
 
-    let mut params = serde_json::Map::new();
+    let binding = match find_binding(&functions, &args[1]) {
+        Ok(binding) => binding,
+        Err(e) => {
+            eprintln!("{}", warn(e));
+            return ExitCode::FAILURE;
+        }
+    };
+    // Keep stdout clean for piping when NDJSON output is requested
+    let ndjson = args.iter().skip(2).any(|a| a == NDJSON_FLAG);
+    if !ndjson {
+        let required_fn_args: HashSet<String> = ((binding.required_args)()).into_iter().collect();
+        print_function_info(binding, &required_fn_args);
+    }
+
+    let mut raw_args = Vec::new();
     let mut output_path: Option<PathBuf> = None;
 
     let mut args_iter = args.iter().skip(2).peekable();
     while let Some(arg) = args_iter.next() {
-        if arg.starts_with("--") {
-            if let Some(value_str) = args_iter.peek() {
-                let arg_name = &arg[2..arg.len()];
-                if let Some((_, schema)) = fn_args.iter().find(|(an, _)| an == arg_name) {
-                    // Initial value is just the string from CLI
-                    let initial_value = serde_json::Value::String(value_str.to_string());
-
-                    // Resolve the argument using the bindings helper
-                    match bindings::resolve_argument(arg_name, initial_value, schema, &state) {
-                        Ok(resolved_value) => {
-                            params.insert(arg_name.to_string(), resolved_value);
-                        }
-                        Err(e) => {
-                            eprintln!(
-                                "{}",
-                                warn(format!("Error resolving argument '{}': {}", arg_name, e))
-                            );
-                            return ExitCode::FAILURE;
-                        }
-                    }
-                }
-                // Skip next element (as it is the value!)
-                args_iter.next();
+        if arg == NDJSON_FLAG {
+            continue;
+        }
+        if let Some(arg_name) = arg.strip_prefix("--") {
+            // Next element is the value (initially just the string from CLI)
+            if let Some(value_str) = args_iter.next() {
+                raw_args.push((
+                    arg_name.to_string(),
+                    serde_json::Value::String(value_str.to_string()),
+                ));
             }
         } else {
             // Might be output path?
@@ -122,73 +138,264 @@ fn main() -> ExitCode {
             }
         }
     }
+    let res = resolve_arguments(binding, raw_args, &state, false)
+        .and_then(|fn_args| call_binding(binding, fn_args, &state));
+    match res {
+        Ok(res) => {
+            if let Err(e) = write_result(&state, &res, output_path.as_deref(), ndjson) {
+                eprintln!("{}", warn(e));
+                return ExitCode::FAILURE;
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", warn(e));
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Find the binding of the function with the passed name
+fn find_binding<'a>(functions: &[&'a Binding], name: &str) -> Result<&'a Binding, String> {
+    functions
+        .iter()
+        .find(|f| f.name == name)
+        .copied()
+        .ok_or_else(|| format!("Unknown function: {name}"))
+}
+
+/// Resolve the passed (raw) arguments of a function call against the argument schemas of the binding
+///
+/// Arguments are resolved using [`bindings::resolve_argument`] (e.g., loading registry items from file paths).
+/// Fails if an argument cannot be resolved or if required arguments are missing.
+/// Unknown arguments are an error if `strict` is set (e.g., in pipelines); otherwise, they are skipped with a warning.
+fn resolve_arguments(
+    binding: &Binding,
+    raw_args: impl IntoIterator<Item = (String, serde_json::Value)>,
+    state: &bindings::AppState,
+    strict: bool,
+) -> Result<serde_json::Value, String> {
+    let fn_args = (binding.args)();
+    let mut params = serde_json::Map::new();
+    for (arg_name, value) in raw_args {
+        let Some((_, schema)) = fn_args.iter().find(|(an, _)| *an == arg_name) else {
+            if strict {
+                return Err(format!("Unknown argument '{arg_name}'"));
+            }
+            eprintln!(
+                "{}",
+                warn(format!("Ignoring unknown argument '{arg_name}'"))
+            );
+            continue;
+        };
+        let resolved = bindings::resolve_argument(&arg_name, value, schema, state)
+            .map_err(|e| format!("Error resolving argument '{arg_name}': {e}"))?;
+        params.insert(arg_name, resolved);
+    }
     // Check if all parameters are there
-    let missing_args: Vec<_> = required_fn_args
+    let missing_args: Vec<_> = (binding.required_args)()
         .into_iter()
         .filter(|k| !params.contains_key(k))
         .collect();
     if !missing_args.is_empty() {
-        eprintln!(
-            "{}",
-            warn(format!(
-                "Missing required arguments: {}",
-                missing_args.join(", ")
-            ))
-        );
-        return ExitCode::FAILURE;
+        return Err(format!(
+            "Missing required arguments: {}",
+            missing_args.join(", ")
+        ));
     }
-    let fn_args = serde_json::Value::Object(params);
-    match bindings::call(binding, &fn_args, &state) {
-        Ok(res) => {
-            // `call` returns JSON bytes; parse once for the CLI's structured handling.
-            let res: serde_json::Value =
-                serde_json::from_slice(&res).unwrap_or(serde_json::Value::Null);
-            if let Some(output_path) = output_path {
-                if let Some(id) = res.as_str() {
-                    let state_guard = state.items.read().unwrap();
-                    if let Some(item) = state_guard.get(id) {
-                        match item.export_to_path(&output_path) {
-                            Ok(_) => {
-                                println!("Exported registry item '{}' to {:?}", id, output_path);
-                            }
-                            Err(e) => {
-                                eprintln!(
-                                    "{}",
-                                    warn(format!("Failed to export registry item: {}", e))
-                                );
-                                return ExitCode::FAILURE;
-                            }
-                        }
-                    } else {
-                        // Not a registry item, just write the JSON
-                        let file = std::fs::File::create(output_path).unwrap();
-                        serde_json::to_writer_pretty(file, &res).unwrap();
-                    }
-                } else {
-                    // Not a string (so not a registry ID), just write the JSON
-                    let file = std::fs::File::create(output_path).unwrap();
-                    serde_json::to_writer_pretty(file, &res).unwrap();
-                }
-            } else {
-                // No output path, print to stdout
-                let mut final_res = res.clone();
-                if let Some(id) = res.as_str() {
-                    let state_guard = state.items.read().unwrap();
-                    if let Some(item) = state_guard.get(id)
-                        && let Ok(val) = item.to_value()
-                    {
-                        final_res = val;
-                    }
+    Ok(serde_json::Value::Object(params))
+}
+
+/// Call the function of the binding with the passed (resolved) arguments
+fn call_binding(
+    binding: &Binding,
+    fn_args: serde_json::Value,
+    state: &bindings::AppState,
+) -> Result<serde_json::Value, String> {
+    let res = bindings::call(binding, &fn_args, state)
+        .map_err(|e| format!("Error calling function: {e}"))?;
+    // `call` returns JSON bytes; parse once for the CLI's structured handling.
+    Ok(serde_json::from_slice(&res).unwrap_or(serde_json::Value::Null))
+}
+
+/// Write the result of a function call, either to the passed output path or to stdout
+///
+/// If the result is the ID of a registry item, the item itself is exported (or printed).
+fn write_result(
+    state: &bindings::AppState,
+    res: &serde_json::Value,
+    output_path: Option<&Path>,
+    ndjson: bool,
+) -> Result<(), String> {
+    let state_guard = state.read();
+    let item = res.as_str().and_then(|id| state_guard.get(id));
+    if let Some(output_path) = output_path {
+        if let Some(item) = item {
+            item.export_to_path(output_path)
+                .map_err(|e| format!("Failed to export registry item: {}", e))?;
+            println!(
+                "Exported registry item '{}' to {:?}",
+                res.as_str().unwrap_or_default(),
+                output_path
+            );
+        } else {
+            // Not a registry item, just write the JSON
+            let file = std::fs::File::create(output_path).map_err(|e| e.to_string())?;
+            write_json(file, res, ndjson).map_err(|e| e.to_string())?;
+        }
+    } else {
+        // No output path, print to stdout
+        let final_res = item
+            .and_then(|item| item.to_value().ok())
+            .unwrap_or_else(|| res.clone());
+        write_json(std::io::stdout().lock(), &final_res, ndjson).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// One step of a pipeline (see [`run_pipeline`])
+#[derive(Debug, serde::Deserialize)]
+struct PipelineStep {
+    /// Name of the function to call
+    function: String,
+    /// Arguments of the function (resolved like CLI arguments)
+    #[serde(default)]
+    args: serde_json::Map<String, serde_json::Value>,
+    /// Optional path to write the result of this step to
+    #[serde(default)]
+    output: Option<PathBuf>,
+}
+
+/// Run all steps of the pipeline file at `path` against a shared [`bindings::AppState`]
+///
+/// Placeholders `$result_of_step_N` (with `N` starting at 1) in the arguments are replaced
+/// by the result of the `N`-th step (e.g., the ID of a registry item).
+/// The result of the last step is printed to stdout, unless the step has an output path.
+fn run_pipeline(
+    functions: &[&Binding],
+    path: &str,
+    state: &bindings::AppState,
+    ndjson: bool,
+) -> Result<(), String> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| format!("Failed to open pipeline file {path:?}: {e}"))?;
+    let steps: Vec<PipelineStep> = serde_json::from_reader(std::io::BufReader::new(file))
+        .map_err(|e| format!("Failed to parse pipeline file {path:?}: {e}"))?;
+    let mut results: Vec<serde_json::Value> = Vec::with_capacity(steps.len());
+    for (i, step) in steps.iter().enumerate() {
+        let step_name = format!("Step {} ({})", i + 1, step.function);
+        let binding =
+            find_binding(functions, &step.function).map_err(|e| format!("{step_name}: {e}"))?;
+        let raw_args = step
+            .args
+            .iter()
+            .map(|(arg_name, value)| {
+                Ok((
+                    arg_name.clone(),
+                    substitute_step_results(value.clone(), &results)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(|e| format!("{step_name}: {e}"))?;
+        let res = resolve_arguments(binding, raw_args, state, true)
+            .and_then(|fn_args| call_binding(binding, fn_args, state))
+            .map_err(|e| format!("{step_name}: {e}"))?;
+        if let Some(output_path) = &step.output {
+            write_result(state, &res, Some(output_path), ndjson)
+                .map_err(|e| format!("{step_name}: {e}"))?;
+        }
+        results.push(res);
+    }
+    if let (Some(res), Some(step)) = (results.last(), steps.last())
+        && step.output.is_none()
+    {
+        write_result(state, res, None, ndjson)?;
+    }
+    Ok(())
+}
+
+/// Replace all `$result_of_step_N` placeholders in the passed value by the results of previous steps
+///
+/// A string consisting only of a placeholder is replaced by the result value itself.
+/// Placeholders inside longer strings are replaced by the result as text.
+fn substitute_step_results(
+    value: serde_json::Value,
+    results: &[serde_json::Value],
+) -> Result<serde_json::Value, String> {
+    let step_result = |n: &str| {
+        n.parse::<usize>()
+            .ok()
+            .and_then(|n| results.get(n.checked_sub(1)?))
+            .ok_or_else(|| {
+                format!(
+                    "Invalid placeholder '{STEP_RESULT_PLACEHOLDER}{n}' ({} previous steps)",
+                    results.len()
+                )
+            })
+    };
+    match value {
+        serde_json::Value::String(s) => {
+            if let Some(n) = s.strip_prefix(STEP_RESULT_PLACEHOLDER)
+                && n.chars().all(|c| c.is_ascii_digit())
+            {
+                return step_result(n).cloned();
+            }
+            let mut ret = String::new();
+            let mut rest = s.as_str();
+            while let Some(pos) = rest.find(STEP_RESULT_PLACEHOLDER) {
+                ret.push_str(&rest[..pos]);
+                let after = &rest[pos + STEP_RESULT_PLACEHOLDER.len()..];
+                let digits = after
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(after.len());
+                match step_result(&after[..digits])? {
+                    serde_json::Value::String(r) => ret.push_str(r),
+                    r => ret.push_str(&r.to_string()),
                 }
-                println!("{}", serde_json::to_string_pretty(&final_res).unwrap());
+                rest = &after[digits..];
             }
+            ret.push_str(rest);
+            Ok(serde_json::Value::String(ret))
         }
-        Err(e) => {
-            eprintln!("{}", warn(format!("Error calling function: {}", e)));
-            return ExitCode::FAILURE;
+        serde_json::Value::Array(values) => values
+            .into_iter()
+            .map(|v| substitute_step_results(v, results))
+            .collect::<Result<_, _>>()
+            .map(serde_json::Value::Array),
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .map(|(k, v)| Ok((k, substitute_step_results(v, results)?)))
+            .collect::<Result<_, String>>()
+            .map(serde_json::Value::Object),
+        v => Ok(v),
+    }
+}
+
+/// Write a JSON result, either pretty-printed or as NDJSON
+///
+/// For NDJSON, each element of an array is written on its own line (other values are written as a single line).
+fn write_json(
+    mut writer: impl std::io::Write,
+    value: &serde_json::Value,
+    ndjson: bool,
+) -> std::io::Result<()> {
+    if !ndjson {
+        serde_json::to_writer_pretty(&mut writer, value)?;
+        return writeln!(writer);
+    }
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                serde_json::to_writer(&mut writer, item)?;
+                writeln!(writer)?;
+            }
+        }
+        value => {
+            serde_json::to_writer(&mut writer, value)?;
+            writeln!(writer)?;
         }
     }
-    ExitCode::SUCCESS
+    Ok(())
 }
 
 fn print_function_info(binding: &Binding, required_fn_args: &HashSet<String>) {
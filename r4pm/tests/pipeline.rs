@@ -0,0 +1,68 @@
+use std::process::Command;
+
+use process_mining::{Exportable, Importable, PetriNet, event_log};
+
+#[test]
+fn pipeline_import_discover_export() {
+    let dir = std::env::temp_dir().join(format!("r4pm-pipeline-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let log_path = dir.join("log.xes");
+    event_log!(["a", "b", "c"], ["a", "c", "b"])
+        .export_to_path(&log_path)
+        .unwrap();
+    let net_path = dir.join("net.pnml");
+    let dfg_path = dir.join("dfg.json");
+    let steps = serde_json::json!([
+        {
+            "function": "sample_fraction",
+            "args": { "log": log_path, "p": 1.0, "seed": 42 }
+        },
+        {
+            "function": "discover_alpha+++",
+            "args": { "log_proj": "$result_of_step_1" },
+            "output": net_path
+        },
+        {
+            "function": "discover_dfg",
+            "args": { "event_log": "$result_of_step_1" },
+            "output": dfg_path
+        }
+    ]);
+    let steps_path = dir.join("steps.json");
+    std::fs::write(&steps_path, steps.to_string()).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_r4pm"))
+        .arg("--pipeline")
+        .arg(&steps_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let net = PetriNet::import_from_path(&net_path).unwrap();
+    assert_eq!(net.transitions.len(), 3);
+    let dfg: serde_json::Value =
+        serde_json::from_reader(std::fs::File::open(&dfg_path).unwrap()).unwrap();
+    assert_eq!(dfg["start_activities"], serde_json::json!(["a"]));
+
+    // Placeholders must refer to previous steps
+    std::fs::write(
+        &steps_path,
+        serde_json::json!([{
+            "function": "discover_dfg",
+            "args": { "event_log": "$result_of_step_1" }
+        }])
+        .to_string(),
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_r4pm"))
+        .arg("--pipeline")
+        .arg(&steps_path)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("$result_of_step_1"));
+    std::fs::remove_dir_all(dir).unwrap();
+}